@@ -0,0 +1,51 @@
+//! Frame rendering with a big pipeline.
+//!
+//! Budget: the event loop polls every 250 ms, but scrolling should feel instant,
+//! so a full `ui()` draw has to stay under 2 ms (an eighth of a 60 Hz frame)
+//! regardless of how many jobs there are. Rows off screen are never formatted,
+//! so 100 and 10k jobs should cost about the same.
+//!
+//! Run with `cargo bench --bench render`.
+
+use career_cli::app::App;
+use career_cli::config::Config;
+use career_cli::fixtures::large_pipeline;
+use career_cli::storage::MemoryStorage;
+use career_cli::ui::ui;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+
+fn app_with(count: usize) -> App {
+    let storage = MemoryStorage::new(large_pipeline(count, 200));
+    App::new(Box::new(storage), Vec::new(), Config::default()).unwrap()
+}
+
+fn draw(c: &mut Criterion) {
+    let mut group = c.benchmark_group("draw");
+    for count in [100, 10_000] {
+        let mut app = app_with(count);
+        let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| {
+                terminal.draw(|f| ui(f, &mut app)).unwrap();
+            })
+        });
+    }
+    group.finish();
+}
+
+fn scroll(c: &mut Criterion) {
+    let mut app = app_with(10_000);
+    let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+    let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+    c.bench_function("scroll 10000", |b| {
+        b.iter(|| {
+            app.handle_key(down);
+            terminal.draw(|f| ui(f, &mut app)).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, draw, scroll);
+criterion_main!(benches);
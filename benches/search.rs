@@ -0,0 +1,32 @@
+//! Looking jobs up in a big pipeline: short ID prefixes (every CLI command)
+//! and company history across archived cohorts (the 'h' popup, redrawn every
+//! frame while it's open).
+//!
+//! Budget: both under 2 ms at 10k current jobs plus 10k archived ones, same as
+//! a frame in benches/render.rs.
+//!
+//! Run with `cargo bench --bench search`.
+
+use career_cli::app::App;
+use career_cli::cli::resolve;
+use career_cli::config::Config;
+use career_cli::fixtures::large_pipeline;
+use career_cli::storage::MemoryStorage;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn short_id(c: &mut Criterion) {
+    let jobs = large_pipeline(10_000, 0);
+    let query = jobs[9_000].short_id();
+    c.bench_function("resolve short id", |b| b.iter(|| resolve(&jobs, black_box(&query)).unwrap()));
+}
+
+fn company_history(c: &mut Criterion) {
+    let storage = MemoryStorage::new(large_pipeline(10_000, 0));
+    let cohorts = vec![("2024".to_string(), large_pipeline(10_000, 0))];
+    let mut app = App::new(Box::new(storage), cohorts, Config::default()).unwrap();
+    app.state.select(Some(0));
+    c.bench_function("company history", |b| b.iter(|| app.company_history().len()));
+}
+
+criterion_group!(benches, short_id, company_history);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+//! Loading and saving a ~50 MB jobs.json, and the one-file-per-job layout with
+//! the same data.
+//!
+//! Budget: opening the TUI and saving on quit should each stay under 500 ms at
+//! this size. Anything slower is noticeable every time the tracker starts.
+//!
+//! Run with `cargo bench --bench storage`.
+
+use career_cli::fixtures::large_pipeline;
+use career_cli::storage::{JobDirStorage, JsonFileStorage, Storage};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// 10k jobs with 5 KB of notes each comes out at roughly 50 MB of JSON
+const JOBS: usize = 10_000;
+const NOTE_BYTES: usize = 5_000;
+
+fn json_file(c: &mut Criterion) {
+    let jobs = large_pipeline(JOBS, NOTE_BYTES);
+    let dir = tempfile::tempdir().unwrap();
+    let storage = JsonFileStorage::new(dir.path().join("jobs.json"));
+    storage.save(&jobs).unwrap();
+
+    let mut group = c.benchmark_group("jobs.json");
+    group.sample_size(10);
+    group.bench_function("load", |b| b.iter(|| storage.load().unwrap()));
+    group.bench_function("save", |b| b.iter(|| storage.save(&jobs).unwrap()));
+    group.finish();
+}
+
+fn job_dir(c: &mut Criterion) {
+    let jobs = large_pipeline(JOBS, NOTE_BYTES);
+    let dir = tempfile::tempdir().unwrap();
+    let storage = JobDirStorage::new(dir.path().join("jobs"));
+    storage.save(&jobs).unwrap();
+
+    let mut group = c.benchmark_group("jobs dir");
+    group.sample_size(10);
+    group.bench_function("load", |b| b.iter(|| storage.load().unwrap()));
+    // Nothing changed, so this is the cost of comparing every file
+    group.bench_function("save unchanged", |b| b.iter(|| storage.save(&jobs).unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, json_file, job_dir);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+//! What I typed into application forms ("Why us?", salary expectations),
+//! kept per job so the same question gets the same answer the next time it
+//! comes up, in another form or in an interview. The briefing lists them,
+//! and the answers picker shows where I said something different before.
+
+use crate::models::Job;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub question: String, // As the form put it, "Why us?" or "Salary expectations"
+    pub answer: String,
+}
+
+impl Answer {
+    /// "Why us? The tooling team" or "Salary expectations: 150k": the
+    /// question ends at its first '?' or ':'. None without both halves.
+    pub fn parse(input: &str) -> Option<Answer> {
+        let (question, answer) = split(input);
+        (!question.is_empty() && !answer.is_empty()).then_some(Answer { question, answer })
+    }
+
+    /// Back into what `parse` reads, for editing
+    pub fn input(&self) -> String {
+        match self.question.ends_with('?') {
+            true => format!("{} {}", self.question, self.answer),
+            false => format!("{}: {}", self.question, self.answer),
+        }
+    }
+}
+
+/// The question and the answer in `input`, the answer empty when there's
+/// no '?' or ':' yet
+pub fn split(input: &str) -> (String, String) {
+    let input = input.trim();
+    match input.find(['?', ':']) {
+        Some(at) => {
+            let question = input[..at].trim_end().to_string() + if input[at..].starts_with('?') { "?" } else { "" };
+            (question, input[at + 1..].trim().to_string())
+        }
+        None => (input.to_string(), String::new()),
+    }
+}
+
+/// Whether two forms asked the same thing: "Why us?" and "why us" did
+pub fn same_question(a: &str, b: &str) -> bool {
+    let key = |question: &str| {
+        let words: String = question.chars().map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { ' ' }).collect();
+        words.split_whitespace().collect::<Vec<_>>().join(" ")
+    };
+    key(a) == key(b)
+}
+
+/// Keep `answer` on `job`, replacing what it said to the same question but
+/// keeping the question as first written. Where it ended up.
+pub fn record(job: &mut Job, answer: Answer) -> usize {
+    match job.answers.iter().position(|a| same_question(&a.question, &answer.question)) {
+        Some(n) => {
+            job.answers[n].answer = answer.answer;
+            n
+        }
+        None => {
+            job.answers.push(answer);
+            job.answers.len() - 1
+        }
+    }
+}
+
+/// Put `answer` in place of the `n`th, question and all, dropping any other
+/// to the question it now asks. Where it ended up.
+pub fn replace(job: &mut Job, n: usize, answer: Answer) -> usize {
+    if n >= job.answers.len() {
+        return record(job, answer);
+    }
+    let asked_again: Vec<usize> = (0..job.answers.len())
+        .filter(|&k| k != n && same_question(&job.answers[k].question, &answer.question))
+        .collect();
+    for &k in asked_again.iter().rev() {
+        job.answers.remove(k);
+    }
+    let n = n - asked_again.iter().filter(|&&k| k < n).count();
+    job.answers[n] = answer;
+    n
+}
+
+/// What I answered to `question` at other jobs, most recently applied first
+pub fn given<'a>(jobs: &'a [Job], job: &Job, question: &str) -> Vec<(&'a Job, &'a Answer)> {
+    let mut given: Vec<(&Job, &Answer)> = jobs
+        .iter()
+        .filter(|other| other.id != job.id)
+        .flat_map(|other| other.answers.iter().map(move |a| (other, a)))
+        .filter(|(_, a)| same_question(&a.question, question))
+        .collect();
+    given.sort_by_key(|(other, _)| std::cmp::Reverse(other.date_applied));
+    given
+}
+
+/// The briefing's section, empty for a job with no answers kept
+pub fn lines(job: &Job) -> Vec<String> {
+    if job.answers.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![String::new(), "## What I put on the form".to_string()];
+    lines.extend(job.answers.iter().map(|a| format!("- {}", a.input())));
+    lines
+}
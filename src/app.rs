@@ -0,0 +1,3100 @@
+use crate::answers::{self, Answer};
+use crate::asof;
+use crate::availability;
+use crate::briefing;
+use crate::bulk::Assignment;
+use crate::companies::{self, Company};
+use crate::complete;
+use crate::config::{config_path, load_config, Config, SortOrder};
+use crate::settings::Setting;
+use crate::skills;
+use crate::usage::{self, UsageEvent, UsageKind};
+use crate::contract;
+use crate::debrief::{self, Debrief};
+use crate::evergreen;
+use crate::filter::Filter;
+use crate::fingerprint;
+use crate::ipc::Request;
+use crate::keymap::{Action, Keymap};
+use crate::metrics;
+use crate::models::{self, Assessment, ChecklistItem, Expense, Interview, Job, JobId, NamedLink, Pipeline, RejectionStage, Relocation, Status};
+use crate::network::Person;
+use crate::posting;
+use crate::spell::Dictionary;
+use crate::statuses;
+use crate::references::{load_references, Reference};
+use crate::salary;
+use crate::schedule;
+use crate::scope;
+use crate::journal::{self, JournalEntry, Source};
+use crate::storage::{changed_fields, load_jobs_from, merge, save_jobs_to, JsonFileStorage, Storage};
+use crate::textarea::TextArea;
+use crate::transcript;
+use crate::transparency;
+use crate::theme::Theme;
+use crate::timezone;
+use crate::validate;
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::widgets::ListState;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use std::cell::OnceCell;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+// Track which screen/mode we are in
+pub enum InputMode {
+    Normal,
+    Editing,
+    CompanyHistory,
+    StatusHistory,
+    Cooldowns,
+    TemplatePicker,
+    QrCode,
+    BulkPreview,
+    SaveFailed,
+    DraftPrompt,
+    Stats,
+    Agenda,
+    Expenses,
+    Offers,
+    Briefing,
+    Onboarding,
+    Notes,
+    Inbox,
+    Settings,
+    Usage,
+    Links,
+    Files,
+    Answers,
+    Network,
+    AsOf,
+    PostingDiff,
+}
+
+// Track which field user is currently typing
+pub enum InputField {
+    Company,
+    Role,
+    Link,
+    Note,
+    Cooldown,
+    Deadline,
+    ReplyBy,
+    Applied,
+    FollowUp,
+    DesiredStart,
+    Timezone,
+    ReqId,
+    Salary,
+    Location,
+    PayDisclosure,
+    Transcript,
+    Tags,
+    Skills,
+    Assessment,
+    Agency,
+    Resume,
+    CoverLetter,
+    Source,
+    CompanyNotes,
+    CompanyWebsite,
+    ContactName,
+    ContactEmail,
+    ContactPhone,
+    ContactLinkedin,
+    Engagement,
+    Interview,
+    Expense,
+    Relocation,
+    StartDate,
+    ChecklistItem,
+    ChecklistDue,
+    NamedLink,
+    Attachment,
+    Answer,
+    Person,
+    AsOf,
+    DebriefRating,
+    DebriefQuestions,
+    DebriefNextSteps,
+    DebriefFollowUp,
+    Filter,
+    BulkSet,
+    SavePath,
+    Setting,
+    RejectionStage,
+    RejectionReason,
+}
+
+/// Which pane the arrow keys move when the detail pane is open
+#[derive(PartialEq, Eq)]
+pub enum Focus {
+    List,
+    Detail,
+}
+
+/// The jobs [stats] counts and the holiday calendar, see `App::counted`
+struct Counted {
+    selection: Option<scope::Selection>,
+    calendar: Option<metrics::HolidayCalendar>,
+}
+
+pub enum EditTarget {
+    New,
+    Existing(usize),
+}
+
+pub struct App {
+    pub jobs: Vec<Job>,
+    pub base: Vec<Job>, // Jobs as loaded, so saving can merge with other sessions
+    pub session_start: Vec<Job>, // Jobs when the TUI opened, for the summary on quit
+    pub state: ListState,
+    pub should_quit: bool,
+    // --- NEW FIELDS ---
+    pub input_mode: InputMode,
+    pub input_field: InputField,
+    pub input_buffer: String,      // What user is currently typing
+    pub notes_editor: TextArea,    // The selected job's notes while InputMode::Notes is open
+    pub editing_description: bool, // The editor holds the posting text instead, see posting.rs
+    pub temp_company: String,      // Store company while typing role
+    pub temp_role: String,         // Store role while typing link
+    pub temp_link: String,         // Store link while typing salary
+    pub temp_salary: (Option<u32>, Option<u32>, Option<String>), // Store salary and its currency while typing location
+    pub edit_target: EditTarget,
+    pub debriefing: Option<(usize, usize)>, // Job and interview the debrief prompts are about
+    pub finishing_draft: Option<usize>,     // Draft the add prompts are filling in
+    pub editing_answer: Option<usize>,      // Answer the prompt replaces, else it adds one
+    clash_warned: Option<String>,           // Interview or link input already warned about, Enter again adds it anyway
+    pub sort: SortOrder,
+    pub show_archived: bool, // Archived jobs are left out of the list unless this is on, see `toggle_pipeline`
+    pub pipeline: Option<Pipeline>, // The side of the search the list shows, None for both
+    pub cohorts: Vec<(String, Vec<Job>)>, // Archived past searches, read-only
+    counted: OnceCell<Counted>,           // Worked out again after each key press, see `counted`
+    pub changes: Vec<JournalEntry>,       // The journal as of the last save, for the detail pane's blame
+    pub inbox: Vec<Job>,                  // Imports waiting for review, see inbox.rs
+    triaged: Vec<JobId>,                  // Inbox ids accepted or discarded since the last save
+    pub companies: Vec<Company>,          // Company records, see companies.rs
+    companies_changed: bool,              // Whether they need writing on the next save
+    pub network: Vec<Person>,             // People I know, see network.rs
+    network_changed: bool,
+    pub as_of: Option<(chrono::NaiveDate, Vec<Job>)>, // The day the as-of view shows and the list as it stood then
+    usage_pending: Vec<UsageEvent>,       // Recorded since the last save, see usage.rs
+    pub usage: Option<usage::Summary>,    // What the usage screen shows while it's open
+    pub message: Option<String>,          // One-off notice shown in the footer until the next key
+    pub config: Config,
+    pub theme: Theme,
+    pub picker_state: ListState,
+    pub filter: Option<Filter>,
+    pub visible: Vec<usize>,         // Indexes into jobs that pass the filter, in list order; `state` selects among these
+    pub bulk: Vec<Assignment>,       // Waiting for confirmation in the bulk preview
+    pub dictionary: Option<Dictionary>, // Spell-check for notes and roles, None when off
+    pub references: Vec<Reference>,     // Read-only here, managed with `career-cli reference`
+    pub detail_open: bool,
+    pub focus: Focus,
+    pub detail_scroll: u16,
+    pub save_error: Option<String>,   // Why the last save on quit failed, shown in the dialog
+    pub exit_message: Option<String>, // Printed after the TUI closes
+    pub keymap: Keymap,
+    config_modified: Option<SystemTime>, // config.toml's mtime when it was last read, see reload_config
+    storage: Box<dyn Storage>,
+}
+
+impl App {
+    pub fn new(storage: Box<dyn Storage>, cohorts: Vec<(String, Vec<Job>)>, config: Config) -> Result<Self> {
+        let jobs = load_jobs_from(storage.as_ref())?;
+        let mut state = ListState::default();
+        if !jobs.is_empty() { state.select(Some(0)); }
+        let keymap = Keymap::from_config(&config.keymap)?;
+        statuses::validate(&config.statuses)?;
+        config.stats.validate(&config.statuses)?;
+
+        let mut app = Self {
+            base: jobs.clone(),
+            session_start: jobs.clone(),
+            // A journal that won't read only costs the blame lines
+            changes: storage.journal().unwrap_or_default(),
+            inbox: storage.inbox()?,
+            triaged: Vec::new(),
+            companies: storage.companies()?,
+            companies_changed: false,
+            network: storage.network()?,
+            network_changed: false,
+            as_of: None,
+            usage_pending: Vec::new(),
+            usage: None,
+            jobs,
+            state,
+            should_quit: false,
+            // Initialize new fields
+            input_mode: InputMode::Normal,
+            input_field: InputField::Company,
+            input_buffer: String::new(),
+            notes_editor: TextArea::default(),
+            editing_description: false,
+            temp_company: String::new(),
+            temp_role: String::new(),
+            temp_link: String::new(),
+            temp_salary: (None, None, None),
+            edit_target: EditTarget::New,
+            debriefing: None,
+            finishing_draft: None,
+            editing_answer: None,
+            clash_warned: None,
+            sort: config.list.sort,
+            show_archived: false,
+            pipeline: Some(Pipeline::Active),
+            cohorts,
+            counted: OnceCell::new(),
+            message: None,
+            theme: Theme::from_config(&config.theme),
+            config,
+            picker_state: ListState::default(),
+            filter: None,
+            visible: Vec::new(),
+            bulk: Vec::new(),
+            dictionary: None,
+            references: Vec::new(),
+            detail_open: false,
+            focus: Focus::List,
+            detail_scroll: 0,
+            save_error: None,
+            exit_message: None,
+            keymap,
+            config_modified: config_modified(),
+            storage,
+        };
+        app.refresh_view();
+        Ok(app)
+    }
+
+    /// Write my changes, merged with anything other sessions saved meanwhile
+    pub fn save(&mut self) -> Result<()> {
+        self.save_as(Source::Edit)
+    }
+
+    /// `save`, with the journal putting the changes down to `source`
+    fn save_as(&mut self, source: Source) -> Result<()> {
+        let saved = save_jobs_to(self.storage.as_ref(), &self.base, &self.jobs, source)?;
+        self.base = saved.clone();
+        self.jobs = saved;
+        self.counted.take();
+        self.changes = self.storage.journal().unwrap_or_default();
+        // Only after the jobs are safe, and keeping anything imported meanwhile
+        if !self.triaged.is_empty() {
+            let mut pending = self.storage.inbox()?;
+            pending.retain(|job| !self.triaged.contains(&job.id));
+            self.storage.save_inbox(&pending)?;
+            self.inbox = pending;
+            self.triaged.clear();
+        }
+        if self.companies_changed {
+            self.storage.save_companies(&self.companies)?;
+            self.companies_changed = false;
+        }
+        if self.network_changed {
+            self.storage.save_network(&self.network)?;
+            self.network_changed = false;
+        }
+        // Not worth failing a save over, it's tried again next time
+        if !self.usage_pending.is_empty() && self.storage.record_usage(&self.usage_pending).is_ok() {
+            self.usage_pending.clear();
+        }
+        self.refresh_view();
+        Ok(())
+    }
+
+    /// Carry out a command another `career-cli` handed over (see ipc.rs)
+    /// and save it, returning what that command prints. Only that change is
+    /// saved: it's made on top of what's on disk, and edits still unsaved
+    /// here stay unsaved. If the save fails nothing changes here either.
+    pub fn handle_request(&mut self, request: Request) -> Result<String> {
+        let mut requested = self.base.clone();
+        let (id, reply, filled) = self.apply_request(&mut requested, request)?;
+        let saved = save_jobs_to(self.storage.as_ref(), &self.base, &requested, Source::Edit)?;
+        let job = saved.iter().find(|j| j.id == id).cloned().context("The change didn't get saved")?;
+
+        // Mine on top of what was just saved, like a save would merge them
+        let before = self.base.iter().position(|j| j.id == id);
+        let shown = match (before, self.jobs.iter().position(|j| j.id == id)) {
+            (Some(b), Some(i)) => {
+                let mine = merge(&self.base[b..=b], &self.jobs[i..=i], std::slice::from_ref(&job));
+                self.jobs[i] = mine.ok().and_then(|mut merged| merged.pop()).unwrap_or_else(|| job.clone());
+                i
+            }
+            (_, Some(i)) => i,
+            (_, None) => {
+                self.jobs.push(job.clone());
+                self.jobs.len() - 1
+            }
+        };
+        match before {
+            Some(b) => self.base[b] = job.clone(),
+            None => self.base.push(job.clone()),
+        }
+        self.file_company(shown);
+        self.changes = self.storage.journal().unwrap_or_default();
+        self.refresh_view();
+
+        let summary = format!("{} {}", reply, fingerprint::merged(&job, &filled));
+        self.message = Some(format!(" {} from the command line ", summary));
+        Ok(format!("{} (in the open TUI)", summary))
+    }
+
+    /// Make `request`'s change to `jobs`, returning the job it changed, how
+    /// to say so and the fields a repeat posting filled in on it
+    fn apply_request(&self, jobs: &mut Vec<Job>, request: Request) -> Result<(JobId, &'static str, Vec<&'static str>)> {
+        Ok(match request {
+            Request::Add { company, role, link, posting, separate } => {
+                validate::company(&company)?;
+                validate::link(&link)?;
+                let company = companies::canonical(&self.companies, jobs, &company);
+                let mut job = Job::from_posting(JobId::new(), company, role, link, &posting);
+                job.cycle = self.config.season.current_cycle();
+                // Already tracked from another board: one job, with this as another listing
+                if !separate && let Some(index) = fingerprint::find(jobs, &job) {
+                    let filled = fingerprint::add_listing(&mut jobs[index], &job, chrono::Local::now().date_naive());
+                    (jobs[index].id, "Kept another listing of", filled)
+                } else {
+                    let id = job.id;
+                    jobs.push(job);
+                    (id, "Added", Vec::new())
+                }
+            }
+            Request::Note { job, text } => {
+                let index = crate::cli::resolve(jobs, &job)?;
+                jobs[index].append_note(&text);
+                (jobs[index].id, "Added note to", Vec::new())
+            }
+        })
+    }
+
+    /// When each field of `job` last changed and what changed it, as
+    /// (field, "2026-10-12 14:03, edit"), newest first. Fields changed
+    /// since the last save come first, as not saved yet.
+    pub fn field_blame(&self, job: &Job) -> Vec<(String, String)> {
+        let unsaved = match self.base.iter().find(|b| b.id == job.id) {
+            Some(saved) => changed_fields(saved, job).unwrap_or_default(),
+            None => return Vec::new(),
+        };
+        let saved = journal::blame(&self.changes, &job.id.to_string())
+            .into_iter()
+            .filter(|b| !unsaved.contains(&b.field))
+            .map(|b| (b.field.clone(), b.describe()));
+        unsaved.iter().map(|field| (field.clone(), "not saved yet".to_string())).chain(saved).collect()
+    }
+
+    /// One line about what changed since the TUI opened, e.g. "Added 3 jobs, 2 status changes".
+    /// None if nothing did.
+    pub fn session_summary(&self) -> Option<String> {
+        let before = |job: &Job| self.session_start.iter().find(|j| j.id == job.id);
+        let added = self.jobs.iter().filter(|j| before(j).is_none()).count();
+        let removed = self
+            .session_start
+            .iter()
+            .filter(|old| !self.jobs.iter().any(|j| j.id == old.id))
+            .count();
+        let changed = |f: fn(&Job, &Job) -> bool| {
+            self.jobs.iter().filter(|j| before(j).is_some_and(|old| f(old, j))).count()
+        };
+        let status_changes = changed(|old, new| old.status != new.status);
+        let notes = changed(|old, new| old.notes != new.notes);
+        let stages = changed(|old, new| old.stage_progress() != new.stage_progress());
+        let follow_ups = changed(|old, new| new.follow_up.is_some() && old.follow_up != new.follow_up);
+
+        let parts: Vec<String> = [
+            (added, "Added", "job", "jobs"),
+            (removed, "Deleted", "job", "jobs"),
+            (status_changes, "", "status change", "status changes"),
+            (notes, "", "job with new notes", "jobs with new notes"),
+            (stages, "", "loop updated", "loops updated"),
+            (follow_ups, "", "follow-up scheduled", "follow-ups scheduled"),
+        ]
+        .into_iter()
+        .filter(|(count, ..)| *count > 0)
+        .map(|(count, verb, one, many)| {
+            let noun = if count == 1 { one } else { many };
+            format!("{} {} {}", verb, count, noun).trim().to_string()
+        })
+        .collect();
+        let next = self.next_interview_label(chrono::Local::now().naive_local());
+        match (parts.is_empty(), next) {
+            (true, None) => None,
+            (true, Some(next)) => Some(format!("Next interview: {}", next)),
+            (false, None) => Some(parts.join(", ")),
+            (false, Some(next)) => Some(format!("{} - next interview: {}", parts.join(", "), next)),
+        }
+    }
+
+    /// "Acme, Thursday 14:00" for the soonest interview still ahead on a job
+    /// that's still going
+    fn next_interview_label(&self, now: chrono::NaiveDateTime) -> Option<String> {
+        let today = now.date();
+        let (job, interview) = self
+            .jobs
+            .iter()
+            .filter(|job| !job.status.is_terminal())
+            .flat_map(|job| job.interviews.iter().map(move |interview| (job, interview)))
+            .filter(|(_, i)| i.date > today || (i.date == today && i.start.is_none_or(|start| start >= now.time())))
+            .min_by_key(|(_, i)| (i.date, i.start))?;
+        let day = match (interview.date - today).num_days() {
+            0 => "today".to_string(),
+            1 => "tomorrow".to_string(),
+            2..=6 => interview.date.format("%A").to_string(),
+            _ => interview.date.format("%Y-%m-%d").to_string(),
+        };
+        Some(match interview.start {
+            Some(start) => format!("{}, {} {}", job.company, day, start.format("%H:%M")),
+            None => format!("{}, {}", job.company, day),
+        })
+    }
+
+    /// Find the configured dictionary, if one is installed
+    pub fn load_dictionary(&mut self) {
+        self.dictionary = Dictionary::load(&self.config.spellcheck);
+        if let Some(dictionary) = &mut self.dictionary {
+            dictionary.learn_from(&self.jobs);
+        }
+    }
+
+    /// Pick up config.toml when it changed since it was last read, so
+    /// keymap and theme edits show without a restart. A config that doesn't
+    /// load or check out keeps the settings as they were and says why.
+    pub fn reload_config(&mut self) {
+        let modified = config_modified();
+        if modified == self.config_modified {
+            return;
+        }
+        self.config_modified = modified;
+        match load_config().and_then(|config| self.apply_config(config)) {
+            Ok(()) => self.message = Some(" Reloaded config.toml ".to_string()),
+            Err(err) => self.message = Some(format!(" config.toml not applied: {:#} ", err)),
+        }
+    }
+
+    /// Switch to `config`, checked the way `App::new` checks it first
+    pub fn apply_config(&mut self, config: Config) -> Result<()> {
+        let keymap = Keymap::from_config(&config.keymap)?;
+        statuses::validate(&config.statuses)?;
+        config.stats.validate(&config.statuses)?;
+        let spellcheck_changed = config.spellcheck != self.config.spellcheck;
+        if config.list.sort != self.config.list.sort {
+            self.sort = config.list.sort;
+        }
+        self.keymap = keymap;
+        self.theme = Theme::from_config(&config.theme);
+        self.config = config;
+        if spellcheck_changed {
+            self.load_dictionary();
+        }
+        self.refresh_view();
+        Ok(())
+    }
+
+    /// Record a session or view, unless [usage] tracking is off
+    pub fn note_usage(&mut self, kind: UsageKind, name: &str) {
+        if self.config.usage.track {
+            self.usage_pending.push(UsageEvent::now(kind, name));
+        }
+    }
+
+    pub fn show_usage(&mut self) {
+        let mut events = self.storage.usage().unwrap_or_default();
+        events.extend(self.usage_pending.iter().cloned());
+        self.usage = Some(usage::summarize(&events, &self.jobs, chrono::Local::now().date_naive()));
+        self.input_mode = InputMode::Usage;
+    }
+
+    /// The screen `input_mode` shows, as usage.rs records it
+    fn view_name(&self) -> Option<&'static str> {
+        Some(match self.input_mode {
+            InputMode::CompanyHistory => "company history",
+            InputMode::StatusHistory => "timeline",
+            InputMode::Cooldowns => "cool-downs",
+            InputMode::QrCode => "qr code",
+            InputMode::Stats => "stats",
+            InputMode::Agenda => "agenda",
+            InputMode::Expenses => "expenses",
+            InputMode::Offers => "offers",
+            InputMode::Briefing => "briefing",
+            InputMode::Onboarding => "onboarding",
+            InputMode::Inbox => "inbox",
+            InputMode::Settings => "settings",
+            InputMode::Usage => "usage",
+            InputMode::Links => "links",
+            InputMode::Files => "files",
+            InputMode::Answers => "answers",
+            InputMode::Network => "network",
+            InputMode::AsOf => "as of",
+            InputMode::PostingDiff => "posting diff",
+            _ => return None,
+        })
+    }
+
+    pub fn show_settings(&mut self) {
+        self.picker_state.select(Some(0));
+        self.input_mode = InputMode::Settings;
+    }
+
+    pub fn picked_setting(&self) -> Option<Setting> {
+        self.picker_state.selected().and_then(|i| Setting::ALL.get(i).copied())
+    }
+
+    /// Enter on the settings screen: the next choice, or a prompt for the value
+    pub fn change_picked_setting(&mut self) {
+        let Some(setting) = self.picked_setting() else { return };
+        if setting.cycles() {
+            let mut config = self.config.clone();
+            setting.cycle(&mut config);
+            self.save_settings(config);
+        } else if setting == Setting::DataDir {
+            self.message = Some(" The data folder is career-cli in Documents, it can't be moved from here ".to_string());
+        } else {
+            self.input_buffer = setting.value(&self.config);
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Setting;
+        }
+    }
+
+    /// Switch to `config` and write it to config.toml. Refused, and nothing
+    /// written, when it wouldn't load.
+    fn save_settings(&mut self, config: Config) {
+        if let Err(err) = self.apply_config(config) {
+            self.message = Some(format!(" Not changed: {:#} ", err));
+            return;
+        }
+        match self.storage.save_config(&self.config) {
+            Ok(()) => {
+                // Our own write, not one for reload_config to announce
+                self.config_modified = config_modified();
+                self.message = Some(" Saved to config.toml ".to_string());
+            }
+            Err(err) => self.message = Some(format!(" Changed for now, but not saved: {:#} ", err)),
+        }
+    }
+
+    /// References live in their own file, and a broken one shouldn't keep the
+    /// tracker from opening, but it says so
+    pub fn load_references(&mut self) {
+        match load_references() {
+            Ok(references) => self.references = references,
+            Err(err) => self.message = Some(format!(" References left out: {:#} ", err)),
+        }
+    }
+
+    /// Save and quit. If the save fails, stay open and ask what to do instead
+    /// of losing the session's work.
+    pub fn quit(&mut self) {
+        match self.save() {
+            Ok(()) => self.should_quit = true,
+            Err(err) => {
+                self.save_error = Some(format!("{:#}", err));
+                self.input_mode = InputMode::SaveFailed;
+            }
+        }
+    }
+
+    pub fn start_save_to_path(&mut self) {
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::SavePath;
+        self.input_buffer = rescue_path().display().to_string();
+    }
+
+    /// Plain jobs.json somewhere else, merged with nothing
+    pub fn save_to_path(&mut self, path: &str) {
+        let path = PathBuf::from(path.trim());
+        match JsonFileStorage::new(&path).save(&self.jobs) {
+            Ok(()) => {
+                self.exit_message = Some(format!("Couldn't save to the data folder, wrote {} instead", path.display()));
+                self.should_quit = true;
+            }
+            Err(err) => {
+                self.save_error = Some(format!("{:#}", err));
+                self.input_mode = InputMode::SaveFailed;
+            }
+        }
+    }
+
+    /// Put the jobs JSON on the clipboard, for when nothing else could save it
+    pub fn copy_jobs_to_clipboard(&mut self) {
+        let result = serde_json::to_string_pretty(&self.jobs).map_err(anyhow::Error::from).and_then(|json| copy_to_clipboard(&json));
+        self.message = Some(match result {
+            Ok(()) => " Copied the jobs JSON to the clipboard (if the terminal allows OSC 52) ".to_string(),
+            Err(err) => format!(" Copy failed: {} ", err),
+        });
+    }
+
+    /// Copy when I'm free over the next two weeks for a recruiter email,
+    /// see availability.rs
+    pub fn copy_availability(&mut self) {
+        if self.config.availability.windows.is_empty() {
+            self.message = Some(" Set [availability] windows in config.toml first ".to_string());
+            return;
+        }
+        let today = chrono::Local::now().date_naive();
+        let Some(text) = availability::message(&self.config.availability, &self.jobs, today, &self.config.interviews) else {
+            self.message = Some(" No free windows in the next two weeks, interviews fill them all ".to_string());
+            return;
+        };
+        self.message = Some(match copy_to_clipboard(&text) {
+            Ok(()) => format!(" Copied availability for {} days to the clipboard ", text.lines().count() - 1),
+            Err(err) => format!(" Copy failed: {} ", err),
+        });
+    }
+
+    /// Index into `jobs` of the highlighted row
+    pub fn selected_index(&self) -> Option<usize> {
+        self.state.selected().and_then(|i| self.visible.get(i).copied())
+    }
+
+    /// Recompute which jobs pass the filter and keep the selection on screen
+    pub fn refresh_view(&mut self) {
+        self.counted.take();
+        let selected = self.selected_index();
+        self.visible = (0..self.jobs.len())
+            .filter(|&i| self.show_archived || !self.jobs[i].archived)
+            .filter(|&i| self.pipeline.is_none_or(|side| self.jobs[i].pipeline() == side))
+            .filter(|&i| self.filter.as_ref().is_none_or(|filter| filter.matches(&self.jobs[i])))
+            .collect();
+        match self.sort {
+            SortOrder::Added => {}
+            // Stable, so equal priorities keep the order they were added in
+            SortOrder::Priority => self.visible.sort_by_key(|&i| self.jobs[i].priority),
+            SortOrder::Touched => {
+                let now = chrono::Utc::now();
+                let mut visible = std::mem::take(&mut self.visible);
+                visible.sort_by_cached_key(|&i| std::cmp::Reverse(self.touched(i, now)));
+                self.visible = visible;
+            }
+            // Jobs with no skills to score go last
+            SortOrder::Fit => self.visible.sort_by_key(|&i| {
+                std::cmp::Reverse(skills::fit(&self.jobs[i], &self.config.skills).map(|fit| fit.score()))
+            }),
+        }
+        // Whoever needs a nudge goes first, whatever the order
+        let today = chrono::Local::now().date_naive();
+        self.visible.sort_by_key(|&i| !self.jobs[i].follow_up_due(today));
+        if self.visible.is_empty() {
+            self.state.select(None);
+        } else if let Some(position) = selected.and_then(|job| self.visible.iter().position(|&i| i == job)) {
+            // The selection follows the job when sorting moves it
+            self.state.select(Some(position));
+        } else {
+            let i = self.state.selected().unwrap_or(0).min(self.visible.len() - 1);
+            self.state.select(Some(i));
+        }
+    }
+
+    /// When job `i` last changed. Edits not saved yet count as `now`, since
+    /// `updated_at` only moves when they're written.
+    fn touched(&self, i: usize, now: chrono::DateTime<chrono::Utc>) -> chrono::DateTime<chrono::Utc> {
+        let job = &self.jobs[i];
+        match self.base.iter().find(|b| b.id == job.id) {
+            Some(saved) if changed_fields(saved, job).is_ok_and(|fields| fields.is_empty()) => job.touched(),
+            _ => now,
+        }
+    }
+
+    /// Let me know about companies whose reapplication window opened in the last month
+    pub fn check_reapply_windows(&mut self) {
+        let now = chrono::Utc::now();
+        let reopened: Vec<String> = models::reapply_windows(&self.jobs)
+            .into_iter()
+            .filter(|(_, date)| *date <= now && now - *date < chrono::Duration::days(30))
+            .map(|(job, _)| job.company.clone())
+            .collect();
+        if !reopened.is_empty() {
+            self.message = Some(format!(" Eligible to reapply: {} ('C' for details) ", reopened.join(", ")));
+        }
+    }
+
+    /// Warn about OAs that expire soon. Shown over the reapply notice since
+    /// a missed OA can't be made up.
+    pub fn check_expiring_assessments(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        let expiring: Vec<String> = models::expiring_assessments(&self.jobs, today, self.config.season.oa_warning_days)
+            .iter()
+            .map(|due| {
+                let days = (due.date - today).num_days();
+                let when = match days {
+                    0 => "today".to_string(),
+                    1 => "tomorrow".to_string(),
+                    _ => format!("in {} days", days),
+                };
+                format!("{} {}", due.job.company, when)
+            })
+            .collect();
+        if !expiring.is_empty() {
+            let key = self.keymap.key_for(Action::Agenda).map(|k| format!(" ({} for the agenda)", k.label())).unwrap_or_default();
+            self.message = Some(format!(" OA expiring: {}{} ", expiring.join(", "), key));
+        }
+    }
+
+    pub fn next(&mut self) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.visible.len() - 1 {
+                    0 // Wrap around to top
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.visible.len() - 1 // Wrap around to bottom
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn toggle_detail(&mut self) {
+        self.detail_open = !self.detail_open;
+        self.focus = Focus::List;
+        self.detail_scroll = 0;
+    }
+
+    pub fn toggle_focus(&mut self) {
+        if self.detail_open {
+            self.focus = if self.focus == Focus::List { Focus::Detail } else { Focus::List };
+        }
+    }
+
+    pub fn scroll_detail(&mut self, delta: i32) {
+        self.detail_scroll = (self.detail_scroll as i32 + delta).max(0) as u16;
+    }
+
+    pub fn submit_input(&mut self) {
+        if let Some(error) = self.input_error() {
+            self.message = Some(format!(" {} ", error));
+            return;
+        }
+        match self.input_field {
+            InputField::Company => {
+                // Spelled the way it's already on file, so both roles share the company's record
+                let typed = self.input_buffer.trim();
+                self.temp_company = companies::canonical(&self.companies, &self.jobs, typed);
+                if self.temp_company != typed {
+                    self.message = Some(format!(" Filed under {}, already on file ", self.temp_company));
+                }
+                self.next_add_field(InputField::Role);
+            }
+            InputField::Role => {
+                self.temp_role = self.input_buffer.clone();
+                self.next_add_field(InputField::Link);
+            }
+            InputField::Link => {
+                let post_link = self.input_buffer.trim().to_string();
+                match self.edit_target {
+                    EditTarget::New => {
+                        // The same role from another board, see fingerprint.rs
+                        let probe = Job::new(JobId::new(), self.temp_company.clone(), self.temp_role.clone(), post_link.clone());
+                        if self.finishing_draft.is_none()
+                            && self.clash_warned.as_deref() != Some(self.input_buffer.as_str())
+                            && let Some(tracked) = fingerprint::find(&self.jobs, &probe).map(|i| &self.jobs[i])
+                        {
+                            self.message = Some(format!(
+                                " Looks like {} - {} ({}), already tracked. Enter again to add it anyway ",
+                                tracked.company,
+                                tracked.role,
+                                tracked.id_prefix(models::short_id_len(&self.jobs))
+                            ));
+                            self.clash_warned = Some(self.input_buffer.clone());
+                            return;
+                        }
+                        self.temp_link = post_link;
+                        self.next_add_field(InputField::Salary);
+                        return;
+                    }
+                    EditTarget::Existing(index) => {
+                        if let Some(job) = self.jobs.get_mut(index) {
+                            job.post_link = post_link;
+                        }
+                    }
+                }
+                self.reset_input();
+            }
+            InputField::Salary => {
+                let Some((min, max, currency)) = salary::parse(&self.input_buffer) else {
+                    self.message = Some(" Salary looks like 120k-150k, £70,000 or a pasted posting line (empty to skip) ".to_string());
+                    return;
+                };
+                match self.edit_target {
+                    EditTarget::New => {
+                        self.temp_salary = (min, max, currency);
+                        self.next_add_field(InputField::Location);
+                        return;
+                    }
+                    EditTarget::Existing(index) => {
+                        if let Some(job) = self.jobs.get_mut(index) {
+                            // A bare "130k" keeps the currency the job already has
+                            if currency.is_some() || (min.is_none() && max.is_none()) {
+                                job.salary_currency = currency;
+                            }
+                            job.salary_min = min;
+                            job.salary_max = max;
+                        }
+                    }
+                }
+                self.reset_input();
+            }
+            InputField::Location => {
+                let (location, work_mode) = models::parse_location(&self.input_buffer);
+                let index = match self.edit_target {
+                    EditTarget::New => {
+                        // Finished drafts count as applied from today, reviewed imports keep their date
+                        let draft = self.finishing_draft.and_then(|i| self.jobs.get(i)).is_none_or(|job| job.draft);
+                        let index = self.add_from_prompts(false);
+                        self.file_company(index);
+                        if draft {
+                            self.jobs[index].date_applied = chrono::Utc::now();
+                        }
+                        index
+                    }
+                    EditTarget::Existing(index) => index,
+                };
+                if let Some(job) = self.jobs.get_mut(index) {
+                    job.location = location;
+                    job.work_mode = work_mode;
+                }
+                let adding = matches!(self.edit_target, EditTarget::New);
+                self.reset_input();
+                // The add prompts end by asking where it came from, first
+                // what the posting said about pay where it has to say
+                if adding {
+                    self.start_pay_disclosure(index);
+                }
+            }
+            InputField::PayDisclosure => {
+                let EditTarget::Existing(index) = self.edit_target else { return };
+                match transparency::parse(&self.input_buffer) {
+                    Some(Err(message)) => {
+                        self.message = Some(format!(" {} ", message));
+                        return;
+                    }
+                    Some(Ok(disclosure)) => {
+                        let job = &mut self.jobs[index];
+                        // The posted range fills in a salary left empty
+                        if job.salary_min.is_none()
+                            && job.salary_max.is_none()
+                            && let Some((min, max, currency)) = disclosure.range.as_deref().and_then(salary::parse)
+                        {
+                            (job.salary_min, job.salary_max, job.salary_currency) = (min, max, currency);
+                        }
+                        job.pay_disclosure = Some(disclosure);
+                    }
+                    None => {}
+                }
+                self.reset_input();
+                self.start_edit_source(index);
+            }
+            InputField::Cooldown => {
+                let input = self.input_buffer.trim();
+                let months = if input.is_empty() {
+                    None
+                } else if let Ok(months) = input.parse::<u32>() {
+                    Some(months)
+                } else {
+                    self.message = Some(" Cool-down must be a number of months ".to_string());
+                    return;
+                };
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    job.cooldown_months = months;
+                    if months.is_some() && job.rejected_at.is_none() {
+                        job.rejected_at = Some(chrono::Utc::now());
+                    }
+                }
+                self.reset_input();
+            }
+            InputField::Deadline | InputField::DesiredStart => {
+                let input = self.input_buffer.trim();
+                let date = if input.is_empty() {
+                    None
+                } else if let Ok(date) = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+                    Some(date)
+                } else {
+                    self.message = Some(" Dates look like 2026-10-31 ".to_string());
+                    return;
+                };
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    match self.input_field {
+                        InputField::Deadline => job.deadline = date,
+                        _ => job.desired_start = date,
+                    }
+                }
+                self.reset_input();
+            }
+            InputField::ReplyBy => {
+                let input = self.input_buffer.trim();
+                let date = if input.is_empty() {
+                    None
+                } else if let Some(date) = models::parse_reply_by(input, chrono::Local::now().date_naive()) {
+                    Some(date)
+                } else {
+                    self.message = Some(" Try 2026-03-03, mar 3, friday or 2w ".to_string());
+                    return;
+                };
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    job.reply_by = date;
+                }
+                self.reset_input();
+            }
+            InputField::Timezone => {
+                let input = self.input_buffer.trim();
+                let zone = if input.is_empty() {
+                    None
+                } else {
+                    match timezone::parse(input) {
+                        Ok(tz) => Some(tz.name().to_string()),
+                        Err(err) => {
+                            self.message = Some(format!(" {} ", err));
+                            return;
+                        }
+                    }
+                };
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    job.timezone = zone;
+                }
+                self.reset_input();
+            }
+            InputField::ReqId => {
+                let input = self.input_buffer.trim();
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    job.req_id = (!input.is_empty()).then(|| input.to_string());
+                }
+                self.reset_input();
+            }
+            InputField::Applied => {
+                // input_error already checked it parses and isn't in the future
+                let date = models::parse_applied(&self.input_buffer, chrono::Local::now().date_naive());
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(date) = date
+                {
+                    self.jobs[index].date_applied = date.and_time(chrono::NaiveTime::MIN).and_utc();
+                }
+                self.reset_input();
+            }
+            InputField::FollowUp => {
+                let EditTarget::Existing(index) = self.edit_target else { return };
+                let now = chrono::Utc::now();
+                if let Some(date) = debrief::parse_follow_up(&self.input_buffer, now.with_timezone(&chrono::Local).date_naive()) {
+                    self.jobs[index].follow_up = date.map(|d| d.and_time(chrono::NaiveTime::MIN).and_utc());
+                } else if let Some(at) = schedule::parse_when(&self.input_buffer, self.jobs[index].tz(), now) {
+                    // At a time of day, so worth a reminder right then. Saved
+                    // first, since the reminder reads it back from disk.
+                    self.jobs[index].follow_up = Some(at);
+                    let id = self.jobs[index].id.to_string();
+                    self.message = match self.save().and_then(|_| schedule::queue(&id, at)) {
+                        Ok(queued) => Some(format!(" Follow-up reminder scheduled, {} ", queued)),
+                        Err(err) => Some(format!(" Follow-up set, but the reminder wasn't scheduled: {} ", err)),
+                    };
+                } else {
+                    self.message = Some(" Follow up on a date like 2026-10-31, in 5d or 1w, or at thu 9am (empty to clear) ".to_string());
+                    return;
+                }
+                self.reset_input();
+            }
+            InputField::Assessment => {
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    let input = self.input_buffer.trim();
+                    if input.eq_ignore_ascii_case("done") {
+                        if !job.complete_assessment() {
+                            self.message = Some(" No open OA for this job ".to_string());
+                        }
+                    } else if !input.is_empty() {
+                        let (platform, expires) = Assessment::parse_invite(input, chrono::Local::now().date_naive());
+                        job.add_assessment(&platform, expires);
+                    }
+                }
+                self.reset_input();
+            }
+            InputField::Agency => {
+                if let EditTarget::Existing(index) = self.edit_target {
+                    let input = self.input_buffer.trim();
+                    self.jobs[index].agency = Some(input.to_string()).filter(|a| !a.is_empty());
+                    self.warn_double_submission(index);
+                }
+                self.reset_input();
+            }
+            InputField::Source => {
+                if let EditTarget::Existing(index) = self.edit_target {
+                    self.jobs[index].source = models::parse_source(&self.input_buffer);
+                }
+                self.reset_input();
+            }
+            InputField::Resume => {
+                if let EditTarget::Existing(index) = self.edit_target {
+                    self.jobs[index].resume_version = self.input_buffer.trim().to_string();
+                    if self.jobs[index].resume_missing() {
+                        self.message = Some(" Saved, but there's no file at that path ".to_string());
+                    }
+                }
+                self.reset_input();
+            }
+            InputField::CoverLetter => {
+                if let EditTarget::Existing(index) = self.edit_target {
+                    let job = &mut self.jobs[index];
+                    (job.letter_status, job.cover_letter) = models::parse_cover_letter(&self.input_buffer);
+                }
+                self.reset_input();
+            }
+            InputField::CompanyNotes | InputField::CompanyWebsite => {
+                if let EditTarget::Existing(index) = self.edit_target {
+                    let company = companies::entry(&mut self.companies, &self.jobs[index].company);
+                    let input = self.input_buffer.trim().to_string();
+                    match self.input_field {
+                        InputField::CompanyNotes => company.notes = input,
+                        _ => company.website = input,
+                    }
+                    self.companies_changed = true;
+                }
+                // Back to the company it was about
+                self.reset_input();
+                self.input_mode = InputMode::CompanyHistory;
+            }
+            InputField::ContactName
+            | InputField::ContactEmail
+            | InputField::ContactPhone
+            | InputField::ContactLinkedin => {
+                let EditTarget::Existing(index) = self.edit_target else {
+                    return self.reset_input();
+                };
+                let input = self.input_buffer.trim().to_string();
+                if matches!(self.input_field, InputField::ContactEmail) && !input.is_empty() && !input.contains('@') {
+                    self.message = Some(" That doesn't look like an email address ".to_string());
+                    return;
+                }
+                let contact = &mut self.jobs[index].contact;
+                let next = match self.input_field {
+                    InputField::ContactName => {
+                        contact.name = input;
+                        Some((InputField::ContactEmail, contact.email.clone()))
+                    }
+                    InputField::ContactEmail => {
+                        contact.email = input;
+                        Some((InputField::ContactPhone, contact.phone.clone()))
+                    }
+                    InputField::ContactPhone => {
+                        contact.phone = input;
+                        Some((InputField::ContactLinkedin, contact.linkedin.clone()))
+                    }
+                    _ => {
+                        contact.linkedin = models::linkedin_url(&input);
+                        None
+                    }
+                };
+                match next {
+                    Some((field, value)) => {
+                        self.input_field = field;
+                        self.input_buffer = value;
+                    }
+                    None => self.reset_input(),
+                }
+            }
+            InputField::Tags => {
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    job.tags = models::parse_tags(&self.input_buffer);
+                }
+                self.reset_input();
+            }
+            InputField::Skills => {
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    job.skills = skills::parse(&self.input_buffer);
+                    self.message = skills::fit(job, &self.config.skills).map(|fit| format!(" Fit {} ", fit.label()));
+                }
+                self.reset_input();
+            }
+            InputField::Engagement => {
+                match contract::parse(&self.input_buffer) {
+                    Ok((engagement, terms)) => {
+                        if let EditTarget::Existing(index) = self.edit_target
+                            && let Some(job) = self.jobs.get_mut(index)
+                        {
+                            job.set_engagement(engagement, terms);
+                        }
+                    }
+                    Err(err) => {
+                        self.message = Some(format!(" {} ", err));
+                        return;
+                    }
+                }
+                self.reset_input();
+            }
+            InputField::Interview => {
+                if let EditTarget::Existing(index) = self.edit_target
+                    && !self.input_buffer.trim().is_empty()
+                {
+                    let interview = Interview::parse(&self.input_buffer, chrono::Local::now().date_naive());
+                    let clashes = models::interview_clashes(&self.jobs, &interview, &self.config.interviews);
+                    if !clashes.is_empty() && self.clash_warned.as_deref() != Some(self.input_buffer.as_str()) {
+                        let clashes: Vec<String> = clashes.iter().map(|c| c.describe()).collect();
+                        self.message = Some(format!(" Careful: {}. Enter again to add it anyway ", clashes.join("; ")));
+                        self.clash_warned = Some(self.input_buffer.clone());
+                        return;
+                    }
+                    self.jobs[index].add_interview(interview);
+                }
+                self.reset_input();
+            }
+            InputField::Expense => {
+                let Some(expense) = Expense::parse(&self.input_buffer) else {
+                    self.message = Some(" Expenses look like: Taxi to the office 42.50 ".to_string());
+                    return;
+                };
+                if let EditTarget::Existing(index) = self.edit_target
+                    && !self.jobs[index].add_expense(expense, chrono::Local::now().date_naive())
+                {
+                    self.message = Some(" Log the interview first, expenses belong to one ".to_string());
+                    return;
+                }
+                self.reset_input();
+            }
+            InputField::Transcript => {
+                let path = self.input_buffer.trim().to_string();
+                if let EditTarget::Existing(index) = self.edit_target
+                    && !path.is_empty()
+                    && let Some(interview) = self.jobs[index].latest_interview_mut(chrono::Local::now().date_naive())
+                    && let Err(err) = transcript::attach(interview, std::path::Path::new(&path))
+                {
+                    self.message = Some(format!(" {} ", err));
+                    return;
+                }
+                self.reset_input();
+            }
+            InputField::Relocation => {
+                let relocation = if self.input_buffer.trim().is_empty() {
+                    None
+                } else if let Some(relocation) = Relocation::parse(&self.input_buffer) {
+                    Some(relocation)
+                } else {
+                    self.message = Some(" Relocation looks like: Seattle, 10000, 6500 (city, package, moving cost) ".to_string());
+                    return;
+                };
+                if let EditTarget::Existing(index) = self.edit_target {
+                    self.jobs[index].relocation = relocation;
+                }
+                self.reset_input();
+            }
+            InputField::StartDate | InputField::ChecklistDue => {
+                let input = self.input_buffer.trim();
+                let date = if input.is_empty() {
+                    None
+                } else if let Ok(date) = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+                    Some(date)
+                } else {
+                    self.message = Some(" Dates look like 2026-11-02 ".to_string());
+                    return;
+                };
+                let picked = self.picker_state.selected().unwrap_or(0);
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(onboarding) = self.jobs[index].onboarding.as_mut()
+                {
+                    match self.input_field {
+                        InputField::StartDate => onboarding.start_date = date,
+                        _ => {
+                            if let Some(item) = onboarding.checklist.get_mut(picked) {
+                                item.due = date;
+                            }
+                        }
+                    }
+                }
+                self.reset_input();
+                self.input_mode = InputMode::Onboarding;
+            }
+            InputField::NamedLink => {
+                let Some(link) = NamedLink::parse(&self.input_buffer) else {
+                    self.message = Some(" A name, then the link, e.g. Status page https://careers.acme.com/me ".to_string());
+                    return;
+                };
+                if let Err(err) = validate::link(&link.url) {
+                    self.message = Some(format!(" {} ", err));
+                    return;
+                }
+                if let EditTarget::Existing(index) = self.edit_target {
+                    self.message = Some(format!(" Added {}, 'o' picks which link to open ", link.name));
+                    self.jobs[index].links.push(link);
+                }
+                self.reset_input();
+            }
+            InputField::Attachment => {
+                let Some(path) = models::attachment_path(&self.input_buffer) else {
+                    self.message = Some(format!(" No file at {} ", self.input_buffer.trim()));
+                    return;
+                };
+                if let EditTarget::Existing(index) = self.edit_target {
+                    let files = &mut self.jobs[index].files;
+                    if !files.contains(&path) {
+                        files.push(path);
+                    }
+                    self.reset_input();
+                    self.picker_state.select(Some(self.jobs[index].files.len() - 1));
+                    self.input_mode = InputMode::Files;
+                } else {
+                    self.reset_input();
+                }
+            }
+            InputField::Answer => {
+                let Some(answer) = Answer::parse(&self.input_buffer) else {
+                    self.message = Some(" A question, then my answer, e.g. Why us? Their tooling, or Notice period: 4 weeks ".to_string());
+                    return;
+                };
+                if let EditTarget::Existing(index) = self.edit_target {
+                    let job = &mut self.jobs[index];
+                    let picked = match self.editing_answer {
+                        Some(n) => answers::replace(job, n, answer),
+                        None => answers::record(job, answer),
+                    };
+                    self.reset_input();
+                    self.picker_state.select(Some(picked));
+                    self.input_mode = InputMode::Answers;
+                } else {
+                    self.reset_input();
+                }
+            }
+            InputField::Person => {
+                let Some(person) = Person::parse(&self.input_buffer) else {
+                    self.message = Some(" A name, then where they work and how we met, e.g. Dana Lee, Acme, RustConf 2025 ".to_string());
+                    return;
+                };
+                self.network.push(person);
+                self.network_changed = true;
+                self.reset_input();
+                self.picker_state.select(Some(self.network.len() - 1));
+                self.input_mode = InputMode::Network;
+            }
+            InputField::AsOf => {
+                let today = chrono::Local::now().date_naive();
+                let Some(date) = models::parse_applied(&self.input_buffer, today) else {
+                    self.message = Some(" Dates look like 2026-01-15, yesterday or 3w ago ".to_string());
+                    return;
+                };
+                self.reset_input();
+                self.show_as_of(date);
+            }
+            InputField::ChecklistItem => {
+                let name = self.input_buffer.trim().to_string();
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(onboarding) = self.jobs[index].onboarding.as_mut()
+                    && !name.is_empty()
+                {
+                    onboarding.checklist.push(ChecklistItem { name, done: false, due: None });
+                }
+                self.reset_input();
+                self.input_mode = InputMode::Onboarding;
+            }
+            InputField::DebriefRating => {
+                let Some(rating) = debrief::parse_rating(&self.input_buffer) else {
+                    self.message = Some(" A number from 1 to 5, or empty ".to_string());
+                    return;
+                };
+                if let Some(interview) = self.debrief_interview() {
+                    interview.debrief.get_or_insert_with(Debrief::default).rating = rating;
+                }
+                self.next_debrief_field(InputField::DebriefQuestions);
+            }
+            InputField::DebriefQuestions | InputField::DebriefNextSteps => {
+                let text = self.input_buffer.trim().to_string();
+                let questions = matches!(self.input_field, InputField::DebriefQuestions);
+                if let Some(debrief) = self.debrief_interview().and_then(|i| i.debrief.as_mut()) {
+                    if questions {
+                        debrief.questions = text;
+                    } else {
+                        debrief.next_steps = text;
+                    }
+                }
+                self.next_debrief_field(if questions { InputField::DebriefNextSteps } else { InputField::DebriefFollowUp });
+            }
+            InputField::DebriefFollowUp => {
+                let Some(date) = debrief::parse_follow_up(&self.input_buffer, chrono::Local::now().date_naive()) else {
+                    self.message = Some(" A date like 2026-10-31, a wait like 5d or 1w, or empty ".to_string());
+                    return;
+                };
+                if let Some(debrief) = self.debrief_interview().and_then(|i| i.debrief.as_mut()) {
+                    debrief.follow_up = date;
+                }
+                self.reset_input();
+                self.message = Some(" Debrief saved ".to_string());
+                self.check_debriefs();
+            }
+            InputField::Filter => {
+                let input = self.input_buffer.trim();
+                if input.is_empty() {
+                    self.filter = None;
+                } else {
+                    match Filter::parse(input, &self.config.statuses) {
+                        // A filter looks through both sides, pipeline: picks one
+                        Ok(filter) => {
+                            self.filter = Some(filter);
+                            self.pipeline = None;
+                        }
+                        Err(err) => {
+                            self.message = Some(format!(" {} ", err));
+                            return;
+                        }
+                    }
+                }
+                self.state.select(Some(0));
+                self.reset_input();
+            }
+            InputField::BulkSet => match Assignment::parse_list(&self.input_buffer) {
+                Ok(assignments) => {
+                    self.bulk = assignments;
+                    self.reset_input();
+                    self.input_mode = InputMode::BulkPreview;
+                }
+                Err(err) => self.message = Some(format!(" {} ", err)),
+            },
+            InputField::RejectionStage => {
+                // input_error already checked it's a stage, or empty
+                let EditTarget::Existing(index) = self.edit_target else { return self.reset_input() };
+                self.jobs[index].rejected_stage = RejectionStage::parse(&self.input_buffer);
+                self.input_buffer = self.jobs[index].rejection_reason.clone();
+                self.input_field = InputField::RejectionReason;
+            }
+            InputField::RejectionReason => {
+                if let EditTarget::Existing(index) = self.edit_target {
+                    self.jobs[index].rejection_reason = self.input_buffer.trim().to_string();
+                }
+                self.reset_input();
+            }
+            InputField::Setting => {
+                // input_error already checked the value
+                let mut config = self.config.clone();
+                let changed = self.picked_setting().is_some_and(|s| s.set(&mut config, &self.input_buffer).is_ok());
+                self.reset_input();
+                self.input_mode = InputMode::Settings;
+                if changed {
+                    self.save_settings(config);
+                }
+            }
+            InputField::SavePath => {
+                let path = self.input_buffer.clone();
+                self.reset_input();
+                self.save_to_path(&path);
+            }
+            InputField::Note => {
+                if let EditTarget::Existing(index) = self.edit_target
+                    && let Some(job) = self.jobs.get_mut(index)
+                {
+                    job.append_note(&self.input_buffer);
+                }
+                self.reset_input();
+            }
+        }
+    }
+
+    pub fn reset_input(&mut self) {
+        self.input_buffer.clear();
+        self.temp_company.clear();
+        self.temp_role.clear();
+        self.temp_link.clear();
+        self.temp_salary = (None, None, None);
+        self.edit_target = EditTarget::New;
+        self.debriefing = None;
+        self.finishing_draft = None;
+        self.editing_answer = None;
+        self.clash_warned = None;
+        self.editing_description = false;
+        self.input_mode = InputMode::Normal;
+        self.input_field = InputField::Company;
+    }
+
+    /// What the open prompt's input breaks of the rules in validate.rs,
+    /// shown under it while typing and checked again on Enter
+    pub fn input_error(&self) -> Option<String> {
+        let input = self.input_buffer.as_str();
+        let result = match self.input_field {
+            InputField::Company => validate::company(input),
+            InputField::Link | InputField::CompanyWebsite => validate::link(input),
+            InputField::Applied => {
+                let today = chrono::Local::now().date_naive();
+                match models::parse_applied(input, today) {
+                    Some(date) => validate::past("Applied date", date, today, &self.config.validation),
+                    None => Err(anyhow::anyhow!("Applied dates look like 2026-10-01, yesterday or 3d ago")),
+                }
+            }
+            InputField::Salary => match salary::parse(input) {
+                Some((min, max, _)) => validate::pay(&[min.map(f64::from), max.map(f64::from)]),
+                None => Ok(()),
+            },
+            InputField::Engagement => match contract::parse(input) {
+                Ok((_, Some(terms))) => validate::pay(&[terms.rate]),
+                _ => Ok(()),
+            },
+            InputField::RejectionStage if !input.trim().is_empty() && RejectionStage::parse(input).is_none() => {
+                Err(anyhow::anyhow!("Rejected at resume, phone, oa or onsite"))
+            }
+            InputField::Setting => match self.picked_setting() {
+                Some(setting) => setting.set(&mut self.config.clone(), input),
+                None => Ok(()),
+            },
+            _ => Ok(()),
+        };
+        result.err().map(|err| err.to_string())
+    }
+
+    /// Move the add prompts on to `field`, filled in from the draft being finished
+    fn next_add_field(&mut self, field: InputField) {
+        self.input_buffer = match self.finishing_draft.and_then(|i| self.jobs.get(i)) {
+            Some(job) => match field {
+                InputField::Company => job.company.clone(),
+                InputField::Role => job.role.clone(),
+                InputField::Link => job.post_link.clone(),
+                InputField::Salary => job.salary_input(),
+                InputField::Location => job.location_input(),
+                _ => String::new(),
+            },
+            None => String::new(),
+        };
+        self.input_field = field;
+    }
+
+    /// Whether Esc should offer to keep what's been typed into the add prompts
+    fn add_in_progress(&self) -> bool {
+        let adding = matches!(
+            self.input_field,
+            InputField::Company | InputField::Role | InputField::Link | InputField::Salary | InputField::Location
+        );
+        let typed = [&self.input_buffer, &self.temp_company, &self.temp_role, &self.temp_link].iter().any(|t| !t.trim().is_empty());
+        adding && matches!(self.edit_target, EditTarget::New) && (typed || self.finishing_draft.is_some())
+    }
+
+    /// The job the add prompts describe, as a new one or into the draft
+    /// being finished, with the location as typed so far. Returns its index.
+    fn add_from_prompts(&mut self, draft: bool) -> usize {
+        let index = match self.finishing_draft.filter(|&i| i < self.jobs.len()) {
+            Some(index) => index,
+            None => {
+                let mut job = Job::new(JobId::new(), String::new(), String::new(), String::new());
+                job.cycle = self.config.season.current_cycle();
+                self.jobs.push(job);
+                self.jobs.len() - 1
+            }
+        };
+        let job = &mut self.jobs[index];
+        job.company = self.temp_company.trim().to_string();
+        job.role = self.temp_role.trim().to_string();
+        job.post_link = self.temp_link.clone();
+        (job.salary_min, job.salary_max, job.salary_currency) = self.temp_salary.clone();
+        job.draft = draft;
+        index
+    }
+
+    /// Keep what's been typed so far as a draft, including the open prompt
+    pub fn save_draft(&mut self) {
+        let typed = self.input_buffer.trim().to_string();
+        let mut location = None;
+        match self.input_field {
+            InputField::Company => self.temp_company = typed,
+            InputField::Role => self.temp_role = typed,
+            InputField::Link => self.temp_link = typed,
+            // Half-typed salaries that don't parse yet are dropped
+            InputField::Salary => {
+                if let Some(parsed) = salary::parse(&typed) {
+                    self.temp_salary = parsed;
+                }
+            }
+            _ => location = Some(models::parse_location(&typed)),
+        }
+        let index = self.add_from_prompts(true);
+        let job = &mut self.jobs[index];
+        if let Some((location, work_mode)) = location {
+            job.location = location;
+            job.work_mode = work_mode;
+        }
+        let message = format!(" Saved as {}, 'f' to finish it ", job.draft_label());
+        self.reset_input();
+        self.message = Some(message);
+    }
+
+    /// Run the add prompts again on the selected draft, from its first
+    /// empty field, with everything it has filled in
+    pub fn start_finish_draft(&mut self) {
+        let Some(i) = self.selected_index() else { return };
+        let job = &self.jobs[i];
+        if !job.draft {
+            self.message = Some(" Only drafts need finishing ".to_string());
+            return;
+        }
+        let field = match job.missing_fields().first() {
+            Some(&"company") => InputField::Company,
+            Some(&"role") => InputField::Role,
+            Some(&"link") => InputField::Link,
+            Some(&"salary") => InputField::Salary,
+            _ => InputField::Location,
+        };
+        self.rerun_add_prompts(i, field);
+    }
+
+    /// The add prompts from `field` on, filled in from job `i`
+    fn rerun_add_prompts(&mut self, i: usize, field: InputField) {
+        let job = &self.jobs[i];
+        self.temp_company = job.company.clone();
+        self.temp_role = job.role.clone();
+        self.temp_link = job.post_link.clone();
+        self.temp_salary = (job.salary_min, job.salary_max, job.salary_currency.clone());
+        self.input_mode = InputMode::Editing;
+        self.edit_target = EditTarget::New;
+        self.finishing_draft = Some(i);
+        self.next_add_field(field);
+    }
+
+    pub fn show_inbox(&mut self) {
+        if self.inbox.is_empty() {
+            self.message = Some(" Nothing waiting for review ".to_string());
+            return;
+        }
+        self.picker_state.select(Some(0));
+        self.input_mode = InputMode::Inbox;
+    }
+
+    /// Take the highlighted import out of the inbox, closing it when that
+    /// was the last one
+    fn take_picked_import(&mut self) -> Option<Job> {
+        let picked = self.picker_state.selected().filter(|&i| i < self.inbox.len())?;
+        let job = self.inbox.remove(picked);
+        self.triaged.push(job.id);
+        if self.inbox.is_empty() {
+            self.input_mode = InputMode::Normal;
+        } else {
+            self.picker_state.select(Some(picked.min(self.inbox.len() - 1)));
+        }
+        Some(job)
+    }
+
+    /// Move the highlighted import into the list. With `edit`, the add
+    /// prompts run over it first.
+    pub fn accept_import(&mut self, edit: bool) {
+        let Some(mut job) = self.take_picked_import() else { return };
+        job.revision = 0;
+        if job.cycle.is_none() {
+            job.cycle = self.config.season.current_cycle();
+        }
+        job.company = companies::canonical(&self.companies, &self.jobs, &job.company);
+        self.message = Some(format!(" Added {} - {} ", job.company, job.role));
+        self.jobs.push(job);
+        let index = self.jobs.len() - 1;
+        self.file_company(index);
+        self.refresh_view();
+        if let Some(row) = self.visible.iter().position(|&i| i == index) {
+            self.state.select(Some(row));
+        }
+        if edit {
+            self.message = None;
+            self.rerun_add_prompts(index, InputField::Company);
+        }
+    }
+
+    pub fn discard_import(&mut self) {
+        if let Some(job) = self.take_picked_import() {
+            self.message = Some(format!(" Discarded {} - {} ", job.company, job.role));
+        }
+    }
+
+    /// "Acme - Engineer" while the add prompts are finishing a draft
+    pub fn draft_heading(&self) -> Option<String> {
+        let job = self.jobs.get(self.finishing_draft?)?;
+        Some(format!("{} - {}", job.company, job.role))
+    }
+
+    pub fn start_add(&mut self) {
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::Company;
+        self.edit_target = EditTarget::New;
+        self.input_buffer.clear();
+    }
+
+    pub fn start_edit_link(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Link;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.post_link.clone();
+        }
+    }
+
+    pub fn start_note(&mut self) {
+        if let Some(i) = self.selected_index()
+            && i < self.jobs.len()
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Note;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer.clear();
+        }
+    }
+
+    /// Open the selected job's notes in the multi-line editor
+    pub fn start_edit_notes(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.notes_editor = TextArea::new(&job.notes);
+            self.edit_target = EditTarget::Existing(i);
+            self.input_mode = InputMode::Notes;
+        }
+    }
+
+    /// Open the selected job's posting text in the same editor, to paste
+    /// the listing into before it comes down
+    pub fn start_edit_description(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.notes_editor = TextArea::new(&job.description);
+            self.editing_description = true;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_mode = InputMode::Notes;
+        }
+    }
+
+    pub fn save_notes(&mut self) {
+        if let EditTarget::Existing(index) = self.edit_target
+            && let Some(job) = self.jobs.get_mut(index)
+        {
+            let text = self.notes_editor.text.trim_end().to_string();
+            if self.editing_description {
+                job.description = text;
+            } else {
+                job.notes = text;
+            }
+        }
+        let pasted_posting = self.editing_description;
+        if pasted_posting
+            && let EditTarget::Existing(index) = self.edit_target
+            && !self.jobs[index].description.is_empty()
+        {
+            let earlier = self.posting_diff().map(|(earlier, _)| earlier.clone());
+            evergreen::snapshot(&mut self.jobs[index], earlier.as_ref(), chrono::Local::now().date_naive());
+        }
+        self.notes_editor = TextArea::default();
+        self.reset_input();
+        // Reapplying, see straight away whether it's the same role again
+        if pasted_posting && self.posting_diff().is_some() {
+            self.input_mode = InputMode::PostingDiff;
+        }
+    }
+
+    /// The selected job's posting text line by line against the last
+    /// application to the same company that kept its own, and that job
+    pub fn posting_diff(&self) -> Option<(&Job, Vec<(posting::Change, &str)>)> {
+        let job = self.jobs.get(self.selected_index()?).filter(|job| !job.description.is_empty())?;
+        let (_, earlier) = posting::earlier(&self.company_history(), job)?;
+        Some((earlier, posting::diff(&earlier.description, &job.description)))
+    }
+
+    pub fn show_posting_diff(&mut self) {
+        if self.posting_diff().is_some() {
+            self.input_mode = InputMode::PostingDiff;
+        } else {
+            self.message = Some(" No earlier application here kept its posting text to compare with ".to_string());
+            self.input_mode = InputMode::Normal;
+        }
+    }
+
+    pub fn start_edit_cooldown(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Cooldown;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.cooldown_months.map(|m| m.to_string()).unwrap_or_default();
+        }
+    }
+
+    pub fn start_edit_deadline(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Deadline;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.deadline.map(|d| d.to_string()).unwrap_or_default();
+        }
+    }
+
+    pub fn start_edit_reply_by(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::ReplyBy;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.reply_by.map(|d| d.to_string()).unwrap_or_default();
+        }
+    }
+
+    pub fn start_edit_applied(&mut self) {
+        if let Some(i) = self.selected_index() {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Applied;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = self.jobs[i].date_applied.format("%Y-%m-%d").to_string();
+        }
+    }
+
+    pub fn start_edit_follow_up(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::FollowUp;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.follow_up.map(|at| at.date_naive().to_string()).unwrap_or_default();
+        }
+    }
+
+    pub fn start_edit_desired_start(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::DesiredStart;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.desired_start.map(|d| d.to_string()).unwrap_or_default();
+        }
+    }
+
+    pub fn start_edit_timezone(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Timezone;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.timezone.clone().unwrap_or_default();
+        }
+    }
+
+    pub fn start_edit_req_id(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::ReqId;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.req_id.clone().unwrap_or_default();
+        }
+    }
+
+    pub fn start_edit_salary(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Salary;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.salary_input();
+        }
+    }
+
+    pub fn start_edit_location(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Location;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.location_input();
+        }
+    }
+
+    pub fn start_edit_tags(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Tags;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.tags.join(" ");
+        }
+    }
+
+    pub fn start_edit_skills(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Skills;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.skills.join(", ");
+        }
+    }
+
+    /// The '/' prompt started on a tag, for narrowing the list to one
+    pub fn start_tag_filter(&mut self) {
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::Filter;
+        self.input_buffer = "#".to_string();
+    }
+
+    pub fn start_assessment(&mut self) {
+        if let Some(i) = self.selected_index() {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Assessment;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer.clear();
+        }
+    }
+
+    pub fn start_edit_agency(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Agency;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.agency.clone().unwrap_or_default();
+        }
+    }
+
+    /// Asked after the location when adding a job somewhere postings must
+    /// give a pay range, prefilled with the salary already entered.
+    /// Straight on to the source elsewhere.
+    pub fn start_pay_disclosure(&mut self, i: usize) {
+        let Some(job) = self.jobs.get(i) else { return };
+        let Some(jurisdiction) = transparency::jurisdiction(&job.location) else {
+            self.start_edit_source(i);
+            return;
+        };
+        let range = job.salary_label().filter(|label| !label.starts_with(char::is_alphabetic));
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::PayDisclosure;
+        self.edit_target = EditTarget::Existing(i);
+        self.input_buffer = match range {
+            Some(range) => format!("{} {}", jurisdiction, range),
+            None => format!("{} ", jurisdiction),
+        };
+    }
+
+    /// Job `i`'s source, prefilled with a guess when it has none
+    pub fn start_edit_source(&mut self, i: usize) {
+        let Some(job) = self.jobs.get(i) else { return };
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::Source;
+        self.edit_target = EditTarget::Existing(i);
+        self.input_buffer = job.source.clone().or_else(|| job.guess_source()).unwrap_or_default();
+    }
+
+    pub fn start_edit_resume(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Resume;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.resume_version.clone();
+        }
+    }
+
+    pub fn start_edit_cover_letter(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::CoverLetter;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.cover_letter_input();
+        }
+    }
+
+    /// Name, email, phone and LinkedIn one after the other, each prefilled.
+    /// Esc keeps the ones already entered.
+    pub fn start_edit_contact(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::ContactName;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.contact.name.clone();
+        }
+    }
+
+    pub fn start_edit_engagement(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Engagement;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = contract::to_input(job.engagement, job.contract.as_ref());
+        }
+    }
+
+    pub fn start_interview(&mut self) {
+        if let Some(i) = self.selected_index() {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Interview;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer.clear();
+        }
+    }
+
+    pub fn start_expense(&mut self) {
+        match self.selected_index() {
+            Some(i) if self.jobs[i].interviews.is_empty() => {
+                self.message = Some(" Log the interview first, expenses belong to one ".to_string());
+            }
+            Some(i) => {
+                self.input_mode = InputMode::Editing;
+                self.input_field = InputField::Expense;
+                self.edit_target = EditTarget::Existing(i);
+                self.input_buffer.clear();
+            }
+            None => {}
+        }
+    }
+
+    pub fn start_transcript(&mut self) {
+        match self.selected_index() {
+            Some(i) if self.jobs[i].interviews.is_empty() => {
+                self.message = Some(" Log the interview first, transcripts belong to one ".to_string());
+            }
+            Some(i) => {
+                self.input_mode = InputMode::Editing;
+                self.input_field = InputField::Transcript;
+                self.edit_target = EditTarget::Existing(i);
+                self.input_buffer.clear();
+            }
+            None => {}
+        }
+    }
+
+    pub fn start_edit_relocation(&mut self) {
+        if let Some(i) = self.selected_index()
+            && let Some(job) = self.jobs.get(i)
+        {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Relocation;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer = job.relocation.as_ref().map(Relocation::to_input).unwrap_or_default();
+        }
+    }
+
+    pub fn show_onboarding(&mut self) {
+        match self.selected_index().map(|i| &self.jobs[i]) {
+            Some(job) if job.status == Status::Accepted => {
+                self.picker_state.select(Some(0));
+                self.input_mode = InputMode::Onboarding;
+            }
+            Some(_) => self.message = Some(" Onboarding starts once the offer is Accepted ".to_string()),
+            None => {}
+        }
+    }
+
+    /// Prompt for something in the onboarding popup, coming back to it after
+    fn start_onboarding_input(&mut self, field: InputField) {
+        let Some(i) = self.selected_index() else {
+            return;
+        };
+        let onboarding = self.jobs[i].onboarding.get_or_insert_with(Default::default);
+        let picked = self.picker_state.selected().unwrap_or(0);
+        self.input_buffer = match field {
+            InputField::StartDate => onboarding.start_date.map(|d| d.to_string()).unwrap_or_default(),
+            InputField::ChecklistDue => {
+                onboarding.checklist.get(picked).and_then(|item| item.due).map(|d| d.to_string()).unwrap_or_default()
+            }
+            _ => String::new(),
+        };
+        self.input_mode = InputMode::Editing;
+        self.input_field = field;
+        self.edit_target = EditTarget::Existing(i);
+    }
+
+    fn toggle_picked_checklist_item(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        if let Some(item) = self
+            .selected_index()
+            .and_then(|i| self.jobs[i].onboarding.as_mut())
+            .and_then(|o| o.checklist.get_mut(picked))
+        {
+            item.done = !item.done;
+        }
+    }
+
+    /// Nudge about paperwork that's overdue or a start date in the coming week
+    pub fn check_onboarding(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        let mut reminders = Vec::new();
+        for job in self.jobs.iter().filter(|j| j.status == Status::Accepted) {
+            let Some(onboarding) = &job.onboarding else {
+                continue;
+            };
+            let overdue = onboarding.checklist.iter().filter(|i| !i.done && i.due.is_some_and(|d| d < today)).count();
+            if overdue > 0 {
+                reminders.push(format!("{} has {} overdue onboarding items", job.company, overdue));
+            }
+            if let Some(start) = onboarding.start_date
+                && (0..=7).contains(&(start - today).num_days())
+            {
+                let (done, total) = onboarding.progress();
+                reminders.push(format!("{} starts {} ({}/{} onboarding done)", job.company, start, done, total));
+            }
+        }
+        if !reminders.is_empty() {
+            self.message = Some(format!(" {} ", reminders.join(", ")));
+        }
+    }
+
+    pub fn show_offers(&mut self) {
+        if self.jobs.iter().any(|j| j.status == models::Status::Offer) {
+            self.input_mode = InputMode::Offers;
+        } else {
+            self.message = Some(" No offers to compare yet ".to_string());
+        }
+    }
+
+    pub fn show_briefing(&mut self) {
+        if self.selected_index().is_some() {
+            self.input_mode = InputMode::Briefing;
+        }
+    }
+
+    /// The selected job's briefing sheet, see briefing.rs
+    pub fn briefing(&self) -> Vec<String> {
+        match self.selected_index().and_then(|i| self.jobs.get(i)) {
+            Some(job) => briefing::briefing(job, &self.company_history(), &self.config.interviews, chrono::Local::now().date_naive()),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn export_briefing(&mut self) {
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get(i)) else {
+            return;
+        };
+        let today = chrono::Local::now().date_naive();
+        self.message = Some(match briefing::export(job, &self.briefing(), today) {
+            Ok(path) => format!(" Briefing saved to {} ", path.display()),
+            Err(e) => format!(" Couldn't save the briefing: {} ", e),
+        });
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn show_expenses(&mut self) {
+        self.picker_state.select(Some(0));
+        self.input_mode = InputMode::Expenses;
+    }
+
+    /// Mark the highlighted expense in the outstanding list as paid back
+    pub fn reimburse_picked_expense(&mut self) {
+        let picked = self.picker_state.selected().and_then(|i| models::outstanding_expenses(&self.jobs).get(i).map(|o| o.position));
+        if let Some((j, i, e)) = picked {
+            self.jobs[j].interviews[i].expenses[e].reimbursed = true;
+        }
+        let remaining = models::outstanding_expenses(&self.jobs).len();
+        if remaining == 0 {
+            self.input_mode = InputMode::Normal;
+        } else {
+            self.picker_state.select(Some(self.picker_state.selected().unwrap_or(0).min(remaining - 1)));
+        }
+    }
+
+    fn warn_double_submission(&mut self, index: usize) {
+        let others: Vec<String> = models::double_submissions(&self.jobs, &self.jobs[index])
+            .iter()
+            .map(|other| format!("{} ({})", other.agency.as_deref().unwrap_or_default(), other.role))
+            .collect();
+        if !others.is_empty() {
+            self.message = Some(format!(
+                " Double submission risk: {} already put forward by {} ",
+                self.jobs[index].company,
+                others.join(", ")
+            ));
+        }
+    }
+
+    /// What Tab would fill in for the company or role being typed
+    pub fn suggestion(&self) -> Option<String> {
+        if !matches!(self.input_mode, InputMode::Editing) {
+            return None;
+        }
+        let known = self.jobs.iter().chain(self.cohorts.iter().flat_map(|(_, jobs)| jobs));
+        match self.input_field {
+            InputField::Company => complete::complete_company(&self.input_buffer, known),
+            InputField::Role => complete::complete_role(&self.input_buffer, known),
+            InputField::Location => complete::complete(&self.input_buffer, known.map(|j| j.location.as_str())),
+            InputField::Agency => complete::complete(&self.input_buffer, known.filter_map(|j| j.agency.as_deref())),
+            InputField::Source => {
+                complete::complete(&self.input_buffer, known.filter_map(|j| j.source.as_deref()).chain(models::SOURCES))
+            }
+            InputField::Resume => complete::complete(&self.input_buffer, known.map(|j| j.resume_version.as_str())),
+            // A question other forms asked, then once it's typed out, what I
+            // answered the last time
+            InputField::Answer => {
+                let (question, answer) = answers::split(&self.input_buffer);
+                if answer.is_empty() && self.input_buffer.contains(['?', ':']) {
+                    let EditTarget::Existing(i) = self.edit_target else { return None };
+                    let (_, earlier) = *answers::given(&self.jobs, &self.jobs[i], &question).first()?;
+                    return Some(format!("{} {}", self.input_buffer.trim_end(), earlier.answer));
+                }
+                let questions = self.jobs.iter().flat_map(|j| &j.answers).map(|a| a.question.as_str());
+                complete::complete(&self.input_buffer, questions)
+            }
+            // The skill after the last comma, from other jobs and my own list
+            InputField::Skills => {
+                let start = self.input_buffer.rfind(',').map_or(0, |i| i + 1);
+                let (before, word) = self.input_buffer.split_at(start);
+                let known = self.jobs.iter().flat_map(|j| &j.skills).chain(&self.config.skills.have);
+                let skill = complete::complete(word, known.map(String::as_str))?;
+                let gap = if before.is_empty() { "" } else { " " };
+                Some(format!("{}{}{}", before.trim_end(), gap, skill))
+            }
+            // Tags complete word by word, in the filter only after a '#'
+            InputField::Tags | InputField::Filter => {
+                let start = self.input_buffer.rfind(' ').map_or(0, |i| i + 1);
+                let (before, word) = self.input_buffer.split_at(start);
+                let word = match self.input_field {
+                    InputField::Filter => word.strip_prefix('#')?,
+                    _ => word.trim_start_matches('#'),
+                };
+                let tags = self.jobs.iter().flat_map(|j| &j.tags).map(String::as_str);
+                let tag = complete::complete(word, tags)?;
+                let hash = if matches!(self.input_field, InputField::Filter) { "#" } else { "" };
+                Some(format!("{}{}{}", before.trim_start(), hash, tag))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn accept_suggestion(&mut self) {
+        if let Some(suggestion) = self.suggestion() {
+            self.input_buffer = suggestion;
+        }
+    }
+
+    pub fn start_filter(&mut self) {
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::Filter;
+        self.input_buffer = self.filter.as_ref().map(|f| f.as_str().to_string()).unwrap_or_default();
+    }
+
+    /// Bulk edits work on whatever the list currently shows
+    pub fn start_bulk_set(&mut self) {
+        if self.visible.is_empty() {
+            return;
+        }
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::BulkSet;
+        self.input_buffer.clear();
+    }
+
+    /// Saved straight away as a save of its own, after whatever was edited
+    /// before it, so the journal, blame and undo know it as the bulk set
+    pub fn apply_bulk(&mut self) {
+        // By id, the save can bring in other sessions' jobs and reorder them
+        let ids: Vec<JobId> = self.visible.iter().map(|&i| self.jobs[i].id).collect();
+        let mut still_open = 0;
+        let result = self.save().and_then(|_| {
+            for job in self.jobs.iter_mut().filter(|job| ids.contains(&job.id)) {
+                for assignment in &self.bulk {
+                    if !assignment.apply(job) {
+                        still_open += 1;
+                    }
+                }
+            }
+            self.save_as(Source::Bulk)
+        });
+        self.message = Some(match result {
+            Ok(()) if still_open > 0 => format!(" Updated {} jobs, {} still open not archived ", ids.len(), still_open),
+            Ok(()) => format!(" Updated {} jobs ", ids.len()),
+            Err(err) => format!(" Bulk set not saved: {:#} ", err),
+        });
+        self.bulk.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn start_template_picker(&mut self) {
+        if self.selected_index().is_some() {
+            self.picker_state.select(Some(0));
+            self.input_mode = InputMode::TemplatePicker;
+        }
+    }
+
+    pub fn show_stats(&mut self) {
+        self.picker_state.select(Some(0));
+        self.input_mode = InputMode::Stats;
+    }
+
+    /// What the stats and response times count from. Kept between renders,
+    /// since the detail pane asks on every one, until a key press or a
+    /// reload could have changed the jobs or the config.
+    fn counted(&self) -> &Counted {
+        self.counted.get_or_init(|| Counted {
+            selection: self.config.stats.select(&self.jobs, &self.cohorts, &self.config.statuses).ok(),
+            calendar: metrics::HolidayCalendar::from_config(&self.config.metrics).ok(),
+        })
+    }
+
+    /// The jobs [stats] in config.toml takes in
+    pub fn counted_jobs(&self) -> &[Job] {
+        // Validated on startup, so this only falls back if that changes
+        self.counted().selection.as_ref().map_or(&self.jobs, |selection| &selection.jobs)
+    }
+
+    /// What [stats] left out, for the stats view
+    pub fn counted_note(&self) -> Option<String> {
+        self.counted().selection.as_ref().and_then(|selection| selection.note())
+    }
+
+    pub fn holiday_calendar(&self) -> Option<&metrics::HolidayCalendar> {
+        self.counted().calendar.as_ref()
+    }
+
+    /// The stats view's numbers
+    pub fn stat_figures(&self) -> Vec<metrics::Figure> {
+        let jobs = self.counted_jobs();
+        let health = metrics::pipeline_health(jobs, &self.config.metrics.health, &self.config.evergreen, chrono::Utc::now());
+        metrics::figures(jobs, &health)
+    }
+
+    /// How long `job` has waited for a reply next to how long the others took
+    pub fn response_wait(&self, job: &Job, today: chrono::NaiveDate) -> Option<metrics::ResponseWait> {
+        let calendar = self.holiday_calendar()?;
+        metrics::response_wait(job, self.counted_jobs(), self.config.metrics.business_days, calendar, today)
+    }
+
+    /// Back to the list, filtered down to the jobs behind the picked number
+    pub fn drill_into_stat(&mut self) {
+        let figures = self.stat_figures();
+        let Some(figure) = self.picker_state.selected().and_then(|i| figures.get(i)) else { return };
+        self.filter = match figure.filter.as_deref().map(|source| Filter::parse(source, &self.config.statuses)) {
+            Some(Ok(filter)) => Some(filter),
+            Some(Err(err)) => {
+                self.message = Some(format!(" {} ", err));
+                return;
+            }
+            None => None,
+        };
+        // The figures count closed jobs too
+        self.pipeline = None;
+        self.state.select(Some(0));
+        self.input_mode = InputMode::Normal;
+        self.message = Some(match &self.filter {
+            Some(filter) => format!(" {}: {} ", figure.label, filter.as_str()),
+            None => " Showing all jobs ".to_string(),
+        });
+    }
+
+    pub fn move_picker(&mut self, delta: isize) {
+        let len = match self.input_mode {
+            InputMode::Expenses => models::outstanding_expenses(&self.jobs).len(),
+            InputMode::Inbox => self.inbox.len(),
+            InputMode::Settings => Setting::ALL.len(),
+            InputMode::Stats => self.stat_figures().len(),
+            InputMode::Links => self.selected_index().map_or(0, |i| self.jobs[i].all_links().len()),
+            InputMode::Files => self.selected_index().map_or(0, |i| self.jobs[i].files.len()),
+            InputMode::Answers => self.selected_index().map_or(0, |i| self.jobs[i].answers.len()),
+            InputMode::Network => self.network.len(),
+            InputMode::AsOf => self.as_of.as_ref().map_or(0, |(_, jobs)| jobs.len()),
+            InputMode::Onboarding => self
+                .selected_index()
+                .and_then(|i| self.jobs[i].onboarding.as_ref())
+                .map_or(0, |o| o.checklist.len()),
+            _ => self.config.templates().len(),
+        } as isize;
+        if len == 0 {
+            return;
+        }
+        let current = self.picker_state.selected().unwrap_or(0) as isize;
+        self.picker_state.select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    pub fn apply_picked_template(&mut self) {
+        let templates = self.config.templates();
+        if let Some(template) = self.picker_state.selected().and_then(|i| templates.get(i))
+            && let Some(job) = self.selected_index().and_then(|i| self.jobs.get_mut(i))
+        {
+            job.apply_template(template);
+        }
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn advance_stage(&mut self, forward: bool) {
+        if let Some(job) = self.selected_index().and_then(|i| self.jobs.get_mut(i)) {
+            if forward {
+                job.complete_next_stage();
+            } else {
+                job.reopen_last_stage();
+            }
+        }
+    }
+
+    pub fn show_qr_code(&mut self) {
+        match self.selected_index().and_then(|i| self.jobs.get(i)) {
+            Some(_) if !cfg!(feature = "qr") => {
+                self.message = Some(" This build leaves out QR codes, rebuild with --features qr ".to_string())
+            }
+            Some(job) if !job.post_link.trim().is_empty() => self.input_mode = InputMode::QrCode,
+            Some(_) => self.message = Some(" This job has no link ".to_string()),
+            None => {}
+        }
+    }
+
+    /// Give job `index`'s company a record if it has none yet
+    fn file_company(&mut self, index: usize) {
+        let count = self.companies.len();
+        companies::entry(&mut self.companies, &self.jobs[index].company);
+        self.companies_changed |= self.companies.len() > count;
+    }
+
+    /// The record the job's company refers to, when it has one
+    pub fn company_record(&self, job: &Job) -> Option<&Company> {
+        companies::find(&self.companies, &job.company)
+    }
+
+    /// Edit the selected job's company notes, shared by every role there
+    pub fn start_edit_company_notes(&mut self) {
+        self.start_company_field(InputField::CompanyNotes, |company| company.notes.clone());
+    }
+
+    pub fn start_edit_company_website(&mut self) {
+        self.start_company_field(InputField::CompanyWebsite, |company| company.website.clone());
+    }
+
+    fn start_company_field(&mut self, field: InputField, current: fn(&Company) -> String) {
+        let Some(i) = self.selected_index() else { return };
+        self.input_buffer = self.company_record(&self.jobs[i]).map(current).unwrap_or_default();
+        self.input_mode = InputMode::Editing;
+        self.input_field = field;
+        self.edit_target = EditTarget::Existing(i);
+    }
+
+    pub fn show_company_history(&mut self) {
+        if self.selected_index().is_some() {
+            self.input_mode = InputMode::CompanyHistory;
+        }
+    }
+
+    /// Start the debrief prompts for the oldest interview that's over and
+    /// hasn't had one, selecting its job so it's clear which one it's about
+    pub fn check_debriefs(&mut self) {
+        let Some((job, interview)) = debrief::pending(&self.jobs, chrono::Local::now().naive_local(), &self.config.interviews) else {
+            return;
+        };
+        if let Some(row) = self.visible.iter().position(|&i| i == job) {
+            self.state.select(Some(row));
+        }
+        self.debriefing = Some((job, interview));
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::DebriefRating;
+        self.input_buffer.clear();
+    }
+
+    /// "Acme Onsite (2026-10-14)" while the debrief prompts are open
+    pub fn debrief_heading(&self) -> Option<String> {
+        let (job, interview) = self.debriefing?;
+        let job = self.jobs.get(job)?;
+        let interview = job.interviews.get(interview)?;
+        Some(format!("{} {} ({})", job.company, interview.name, interview.date))
+    }
+
+    fn debrief_interview(&mut self) -> Option<&mut Interview> {
+        let (job, interview) = self.debriefing?;
+        self.jobs.get_mut(job)?.interviews.get_mut(interview)
+    }
+
+    fn next_debrief_field(&mut self, field: InputField) {
+        self.input_buffer.clear();
+        self.input_field = field;
+    }
+
+    pub fn show_status_history(&mut self) {
+        if self.selected_index().is_some() {
+            self.input_mode = InputMode::StatusHistory;
+        }
+    }
+
+    /// Every application to the selected job's company, archived cohorts included, oldest first
+    pub fn company_history(&self) -> Vec<(&str, &Job)> {
+        match self.selected_index().and_then(|i| self.jobs.get(i)) {
+            Some(current) => models::company_history(&self.cohorts, &self.jobs, &current.company),
+            None => Vec::new(),
+        }
+    }
+
+    /// Move the selected job forward a step. Terminal statuses stay put,
+    /// '-' is how a job closes or reopens.
+    pub fn cycle_current_status(&mut self) {
+        let Some(i) = self.selected_index() else { return };
+        let Some(job) = self.jobs.get_mut(i) else { return };
+        if job.status.is_terminal() {
+            self.message = Some(format!(" {} is final, '-' closes it another way or reopens it ", job.status_label()));
+            return;
+        }
+        match statuses::next(&self.config.statuses, job).cloned() {
+            Some(def) => statuses::apply(job, &def),
+            None => job.cycle_status(),
+        }
+        self.note_moved_out_of_view(i);
+    }
+
+    /// Close the selected job one way and then another, and back to
+    /// Applied, see `statuses::close`
+    pub fn close_current_status(&mut self) {
+        let Some(i) = self.selected_index() else { return };
+        let Some(job) = self.jobs.get_mut(i) else { return };
+        let was_rejected = job.status == Status::Rejected;
+        statuses::close(&self.config.statuses, job);
+        // Asked while it's fresh, and either can be skipped
+        if job.status == Status::Rejected && !was_rejected {
+            self.input_buffer = job.rejected_stage.map(|stage| stage.label().to_string()).unwrap_or_default();
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::RejectionStage;
+            self.edit_target = EditTarget::Existing(i);
+        } else {
+            self.note_moved_out_of_view(i);
+        }
+    }
+
+    /// Say where job `i` went when its new status takes it off the list
+    fn note_moved_out_of_view(&mut self, i: usize) {
+        let job = &self.jobs[i];
+        if self.pipeline.is_some_and(|side| side != job.pipeline()) {
+            self.message = Some(format!(" {} - {} is {} now, 'Z' shows it ", job.company, job.role, job.pipeline().label()));
+        }
+    }
+
+    /// Open the posting, or pick which link to open when the job has more
+    pub fn open_current_link(&mut self) {
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get(i)) else { return };
+        if job.links.is_empty() {
+            if !job.post_link.trim().is_empty() {
+                let _ = open::that(&job.post_link);
+            }
+        } else {
+            self.picker_state.select(Some(0));
+            self.input_mode = InputMode::Links;
+        }
+    }
+
+    fn open_picked_link(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        if let Some(job) = self.selected_index().and_then(|i| self.jobs.get(i))
+            && let Some((_, url)) = job.all_links().get(picked)
+        {
+            let _ = open::that(url);
+        }
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Drop the picked link, unless it's the posting's own
+    fn remove_picked_link(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get_mut(i)) else { return };
+        let offset = usize::from(!job.post_link.trim().is_empty());
+        match picked.checked_sub(offset) {
+            Some(n) if n < job.links.len() => {
+                let link = job.links.remove(n);
+                self.message = Some(format!(" Removed {} ", link.name));
+            }
+            _ => self.message = Some(" The posting link is changed with 'e' ".to_string()),
+        }
+        if job.links.is_empty() {
+            self.input_mode = InputMode::Normal;
+        } else {
+            let last = job.all_links().len() - 1;
+            self.picker_state.select(Some(picked.min(last)));
+        }
+    }
+
+    pub fn start_add_link(&mut self) {
+        if let Some(i) = self.selected_index() {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::NamedLink;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer.clear();
+        }
+    }
+
+    /// Pick which of the job's files to open, or ask for one when it has none
+    pub fn show_files(&mut self) {
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get(i)) else { return };
+        if job.files.is_empty() {
+            self.start_add_file();
+        } else {
+            self.picker_state.select(Some(0));
+            self.input_mode = InputMode::Files;
+        }
+    }
+
+    /// Open the picked file with whatever the system opens it with
+    fn open_picked_file(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        let Some(path) = self.selected_index().and_then(|i| self.jobs[i].files.get(picked)) else { return };
+        let path = models::home_path(path);
+        if path.exists() {
+            let _ = open::that(&path);
+            self.input_mode = InputMode::Normal;
+        } else {
+            self.message = Some(format!(" {} isn't there anymore, 'd' drops it ", path.display()));
+        }
+    }
+
+    /// Forget the picked file. The file itself stays where it is.
+    fn remove_picked_file(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get_mut(i)) else { return };
+        if picked < job.files.len() {
+            let path = job.files.remove(picked);
+            self.message = Some(format!(" Dropped {} ", path));
+        }
+        if job.files.is_empty() {
+            self.input_mode = InputMode::Normal;
+        } else {
+            self.picker_state.select(Some(picked.min(job.files.len() - 1)));
+        }
+    }
+
+    pub fn start_add_file(&mut self) {
+        if let Some(i) = self.selected_index() {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Attachment;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer.clear();
+        }
+    }
+
+    /// Pick from the answers kept from the job's application form, or ask
+    /// for one when there are none
+    pub fn show_answers(&mut self) {
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get(i)) else { return };
+        if job.answers.is_empty() {
+            self.start_add_answer();
+        } else {
+            self.picker_state.select(Some(0));
+            self.input_mode = InputMode::Answers;
+        }
+    }
+
+    /// Change the picked answer, its question typed out ahead of it
+    fn edit_picked_answer(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        let Some(i) = self.selected_index() else { return };
+        let Some(answer) = self.jobs[i].answers.get(picked) else { return };
+        self.input_buffer = answer.input();
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::Answer;
+        self.edit_target = EditTarget::Existing(i);
+        self.editing_answer = Some(picked);
+    }
+
+    fn remove_picked_answer(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get_mut(i)) else { return };
+        if picked < job.answers.len() {
+            let answer = job.answers.remove(picked);
+            self.message = Some(format!(" Dropped {} ", answer.question));
+        }
+        if job.answers.is_empty() {
+            self.input_mode = InputMode::Normal;
+        } else {
+            self.picker_state.select(Some(picked.min(job.answers.len() - 1)));
+        }
+    }
+
+    pub fn start_add_answer(&mut self) {
+        if let Some(i) = self.selected_index() {
+            self.input_mode = InputMode::Editing;
+            self.input_field = InputField::Answer;
+            self.edit_target = EditTarget::Existing(i);
+            self.input_buffer.clear();
+        }
+    }
+
+    /// Ask which day to see the list as it stood on, starting from the
+    /// one showing when the as-of view is open
+    pub fn start_as_of(&mut self) {
+        self.input_buffer = self.as_of.as_ref().map(|(date, _)| date.to_string()).unwrap_or_default();
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::AsOf;
+    }
+
+    /// The list at the end of `date`, from the journal as of the last save
+    /// and the status history of everything since
+    pub fn show_as_of(&mut self, date: chrono::NaiveDate) {
+        match asof::as_of(&self.changes, &self.jobs, date, &self.config.statuses) {
+            Ok(jobs) => {
+                self.picker_state.select((!jobs.is_empty()).then_some(0));
+                self.as_of = Some((date, jobs));
+                self.input_mode = InputMode::AsOf;
+            }
+            Err(err) => self.message = Some(format!(" Couldn't replay the journal: {:#} ", err)),
+        }
+    }
+
+    /// A day earlier or later in the as-of view, never past today
+    fn step_as_of(&mut self, days: i64) {
+        let Some((date, _)) = self.as_of else { return };
+        let today = chrono::Local::now().date_naive();
+        let date = (date + chrono::Duration::days(days)).min(today);
+        self.show_as_of(date);
+    }
+
+    /// The people I know, or a prompt for the first one
+    pub fn show_network(&mut self) {
+        if self.network.is_empty() {
+            self.start_add_person();
+        } else {
+            self.picker_state.select(Some(0));
+            self.input_mode = InputMode::Network;
+        }
+    }
+
+    pub fn start_add_person(&mut self) {
+        self.input_mode = InputMode::Editing;
+        self.input_field = InputField::Person;
+        self.input_buffer.clear();
+    }
+
+    /// Link the picked person to the selected job, or unlink them
+    fn link_picked_person(&mut self) {
+        let Some(job) = self.selected_index().map(|i| &self.jobs[i]) else { return };
+        let Some(person) = self.picker_state.selected().and_then(|p| self.network.get_mut(p)) else { return };
+        let what = if person.toggle_job(job.id) { "linked to" } else { "unlinked from" };
+        self.message = Some(format!(" {} {} {} - {} ", person.name, what, job.company, job.role));
+        self.network_changed = true;
+    }
+
+    /// Note that I talked to the picked person today
+    fn contacted_picked_person(&mut self) {
+        let Some(person) = self.picker_state.selected().and_then(|p| self.network.get_mut(p)) else { return };
+        person.last_contact = Some(chrono::Local::now().date_naive());
+        self.network_changed = true;
+    }
+
+    fn remove_picked_person(&mut self) {
+        let picked = self.picker_state.selected().unwrap_or(0);
+        if picked < self.network.len() {
+            let person = self.network.remove(picked);
+            self.message = Some(format!(" Removed {} ", person.name));
+            self.network_changed = true;
+        }
+        if self.network.is_empty() {
+            self.input_mode = InputMode::Normal;
+        } else {
+            self.picker_state.select(Some(picked.min(self.network.len() - 1)));
+        }
+    }
+
+    /// Show the active jobs, then the closed ones, then both, then the
+    /// archived ones too
+    pub fn toggle_pipeline(&mut self) {
+        (self.pipeline, self.show_archived) = match (self.pipeline, self.show_archived) {
+            (Some(Pipeline::Active), _) => (Some(Pipeline::Closed), false),
+            (Some(Pipeline::Closed), _) => (None, false),
+            (None, false) => (None, true),
+            (None, true) => (Some(Pipeline::Active), false),
+        };
+        self.message = Some(match (self.pipeline, self.show_archived) {
+            (Some(side), _) => format!(" Showing {} jobs ", side.label()),
+            (None, false) => " Showing active and closed jobs ".to_string(),
+            (None, true) => " Showing every job, archived ones too ".to_string(),
+        });
+    }
+
+    /// Put the selected closed job out of the way, or bring it back
+    pub fn toggle_archived(&mut self) {
+        let Some(job) = self.selected_index().and_then(|i| self.jobs.get_mut(i)) else { return };
+        if !job.archived && job.is_open() {
+            self.message = Some(" Only closed jobs can be archived ".to_string());
+            return;
+        }
+        job.archived = !job.archived;
+        let message = match (job.archived, self.show_archived) {
+            (true, false) => format!(" Archived {} - {}, 'Z' shows archived jobs ", job.company, job.role),
+            (true, true) => format!(" Archived {} - {} ", job.company, job.role),
+            (false, _) => format!(" {} - {} is back in the list ", job.company, job.role),
+        };
+        self.message = Some(message);
+    }
+
+    pub fn delete_current_job(&mut self) {
+        if let Some(i) = self.selected_index()
+            && i < self.jobs.len()
+        {
+            self.jobs.remove(i);
+
+            // Adjusts the selection if we deleted the last item
+            self.refresh_view();
+        }
+    }
+
+    /// Apply one key press to the current mode
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        self.message = None;
+        self.counted.take();
+        let from_list = matches!(self.input_mode, InputMode::Normal);
+        match self.input_mode {
+            // --- NORMAL MODE ---
+            InputMode::Normal => match self.keymap.action_for(&key) {
+                // With the detail pane focused the arrows scroll it instead
+                _ if self.focus == Focus::Detail && key.code == KeyCode::PageDown => self.scroll_detail(10),
+                _ if self.focus == Focus::Detail && key.code == KeyCode::PageUp => self.scroll_detail(-10),
+                Some(Action::Down) if self.focus == Focus::Detail => self.scroll_detail(1),
+                Some(Action::Up) if self.focus == Focus::Detail => self.scroll_detail(-1),
+                Some(Action::Quit) => self.quit(),
+                Some(Action::Down) => {
+                    self.next();
+                    self.detail_scroll = 0;
+                }
+                Some(Action::Up) => {
+                    self.previous();
+                    self.detail_scroll = 0;
+                }
+                Some(Action::Details) => self.toggle_detail(),
+                Some(Action::Stats) => self.show_stats(),
+                Some(Action::SwitchFocus) => self.toggle_focus(),
+                Some(Action::Add) => self.start_add(),
+                Some(Action::FinishDraft) => self.start_finish_draft(),
+                Some(Action::CyclePriority) => {
+                    if let Some(i) = self.selected_index() {
+                        self.jobs[i].priority = self.jobs[i].priority.next();
+                    }
+                }
+                Some(Action::Star) => {
+                    if let Some(i) = self.selected_index() {
+                        self.jobs[i].starred = !self.jobs[i].starred;
+                    }
+                }
+                Some(Action::Sort) => self.sort = self.sort.next(),
+                Some(Action::EditLink) => self.start_edit_link(),
+                Some(Action::Note) => self.start_note(),
+                Some(Action::EditNotes) => self.start_edit_notes(),
+                Some(Action::Description) => self.start_edit_description(),
+                // NEW COMMANDS
+                Some(Action::CycleStatus) => self.cycle_current_status(),
+                Some(Action::CloseStatus) => self.close_current_status(),
+                Some(Action::Delete) => self.delete_current_job(),
+                Some(Action::Archive) => self.toggle_archived(),
+                Some(Action::Pipeline) => self.toggle_pipeline(),
+                Some(Action::OpenLink) => self.open_current_link(),
+                Some(Action::AddLink) => self.start_add_link(),
+                Some(Action::Files) => self.show_files(),
+                Some(Action::Answers) => self.show_answers(),
+                Some(Action::Network) => self.show_network(),
+                Some(Action::AsOf) => self.start_as_of(),
+                Some(Action::CompanyHistory) => self.show_company_history(),
+                Some(Action::StatusHistory) => self.show_status_history(),
+                Some(Action::EditCooldown) => self.start_edit_cooldown(),
+                Some(Action::Cooldowns) => self.input_mode = InputMode::Cooldowns,
+                Some(Action::EditDeadline) => self.start_edit_deadline(),
+                Some(Action::ReplyBy) => self.start_edit_reply_by(),
+                Some(Action::EditApplied) => self.start_edit_applied(),
+                Some(Action::FollowUp) => self.start_edit_follow_up(),
+                Some(Action::EditStart) => self.start_edit_desired_start(),
+                Some(Action::Timezone) => self.start_edit_timezone(),
+                Some(Action::ReqId) => self.start_edit_req_id(),
+                Some(Action::Salary) => self.start_edit_salary(),
+                Some(Action::Location) => self.start_edit_location(),
+                Some(Action::Tags) => self.start_edit_tags(),
+                Some(Action::Skills) => self.start_edit_skills(),
+                Some(Action::TagFilter) => self.start_tag_filter(),
+                Some(Action::Agenda) => self.input_mode = InputMode::Agenda,
+                Some(Action::Inbox) => self.show_inbox(),
+                Some(Action::Assessment) => self.start_assessment(),
+                Some(Action::Agency) => self.start_edit_agency(),
+                Some(Action::Source) => {
+                    if let Some(i) = self.selected_index() {
+                        self.start_edit_source(i);
+                    }
+                }
+                Some(Action::Resume) => self.start_edit_resume(),
+                Some(Action::CoverLetter) => self.start_edit_cover_letter(),
+                Some(Action::Contact) => self.start_edit_contact(),
+                Some(Action::Engagement) => self.start_edit_engagement(),
+                Some(Action::Interview) => self.start_interview(),
+                Some(Action::Expense) => self.start_expense(),
+                Some(Action::Transcript) => self.start_transcript(),
+                Some(Action::Briefing) => self.show_briefing(),
+                Some(Action::Expenses) => self.show_expenses(),
+                Some(Action::Relocation) => self.start_edit_relocation(),
+                Some(Action::Offers) => self.show_offers(),
+                Some(Action::Onboarding) => self.show_onboarding(),
+                Some(Action::QrCode) => self.show_qr_code(),
+                Some(Action::LoopTemplate) => self.start_template_picker(),
+                Some(Action::CompleteStage) => self.advance_stage(true),
+                Some(Action::ReopenStage) => self.advance_stage(false),
+                Some(Action::Filter) => self.start_filter(),
+                Some(Action::BulkEdit) => self.start_bulk_set(),
+                Some(Action::Settings) => self.show_settings(),
+                Some(Action::Usage) => self.show_usage(),
+                Some(Action::Availability) => self.copy_availability(),
+                Some(Action::Back) if self.focus == Focus::Detail => self.focus = Focus::List,
+                Some(Action::Back) => self.filter = None,
+                None => {}
+            },
+
+            // --- SAVE FAILED: pick another way to keep the work ---
+            InputMode::SaveFailed => match key.code {
+                KeyCode::Char('r') => self.quit(),
+                KeyCode::Char('a') => self.start_save_to_path(),
+                KeyCode::Char('c') => self.copy_jobs_to_clipboard(),
+                KeyCode::Char('D') => {
+                    self.exit_message = Some("Quit without saving, this session's changes were dropped".to_string());
+                    self.jobs = self.base.clone(); // so the summary doesn't report them
+                    self.should_quit = true;
+                }
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            // --- BULK PREVIEW: only 'y' applies ---
+            InputMode::BulkPreview => {
+                if key.code == KeyCode::Char('y') {
+                    self.apply_bulk();
+                } else {
+                    self.bulk.clear();
+                    self.input_mode = InputMode::Normal;
+                    self.message = Some(" Bulk edit cancelled ".to_string());
+                }
+            }
+
+            // Arrows step through the numbers, Enter lists the jobs behind one
+            InputMode::Stats => match key.code {
+                KeyCode::Down | KeyCode::Right => self.move_picker(1),
+                KeyCode::Up | KeyCode::Left => self.move_picker(-1),
+                KeyCode::Enter => self.drill_into_stat(),
+                _ => self.input_mode = InputMode::Normal,
+            },
+
+            InputMode::Expenses => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter => self.reimburse_picked_expense(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::Inbox => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter | KeyCode::Char('a') => self.accept_import(false),
+                KeyCode::Char('e') => self.accept_import(true),
+                KeyCode::Char('d') => self.discard_import(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::Links => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter => self.open_picked_link(),
+                KeyCode::Char('n') => self.start_add_link(),
+                KeyCode::Char('d') => self.remove_picked_link(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::Files => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter => self.open_picked_file(),
+                KeyCode::Char('n') => self.start_add_file(),
+                KeyCode::Char('d') => self.remove_picked_file(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::Answers => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter => self.edit_picked_answer(),
+                KeyCode::Char('n') => self.start_add_answer(),
+                KeyCode::Char('d') => self.remove_picked_answer(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::Network => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter => self.link_picked_person(),
+                KeyCode::Char('c') => self.contacted_picked_person(),
+                KeyCode::Char('n') => self.start_add_person(),
+                KeyCode::Char('d') => self.remove_picked_person(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::AsOf => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Left => self.step_as_of(-1),
+                KeyCode::Right => self.step_as_of(1),
+                KeyCode::PageUp => self.step_as_of(-7),
+                KeyCode::PageDown => self.step_as_of(7),
+                KeyCode::Enter => self.start_as_of(),
+                KeyCode::Esc => {
+                    self.as_of = None;
+                    self.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
+
+            InputMode::Onboarding => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter | KeyCode::Char(' ') => self.toggle_picked_checklist_item(),
+                KeyCode::Char('s') => self.start_onboarding_input(InputField::StartDate),
+                KeyCode::Char('u') => self.start_onboarding_input(InputField::ChecklistDue),
+                KeyCode::Char('n') => self.start_onboarding_input(InputField::ChecklistItem),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            // --- NOTES EDITOR: Enter is a newline, Ctrl-S keeps the changes ---
+            InputMode::Notes => match key.code {
+                KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => self.save_notes(),
+                KeyCode::Esc => {
+                    let what = if self.editing_description { "Posting text" } else { "Notes" };
+                    self.notes_editor = TextArea::default();
+                    self.reset_input();
+                    self.message = Some(format!(" {} left unchanged ", what));
+                }
+                KeyCode::Enter => self.notes_editor.insert('\n'),
+                KeyCode::Char(c) => self.notes_editor.insert(c),
+                KeyCode::Backspace => self.notes_editor.backspace(),
+                KeyCode::Delete => self.notes_editor.delete(),
+                KeyCode::Left => self.notes_editor.left(),
+                KeyCode::Right => self.notes_editor.right(),
+                KeyCode::Up => self.notes_editor.up(),
+                KeyCode::Down => self.notes_editor.down(),
+                KeyCode::Home => self.notes_editor.home(),
+                KeyCode::End => self.notes_editor.end(),
+                _ => {}
+            },
+
+            InputMode::Settings => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter => self.change_picked_setting(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::TemplatePicker => match key.code {
+                KeyCode::Down => self.move_picker(1),
+                KeyCode::Up => self.move_picker(-1),
+                KeyCode::Enter => self.apply_picked_template(),
+                KeyCode::Esc => self.input_mode = InputMode::Normal,
+                _ => {}
+            },
+
+            InputMode::DraftPrompt => match key.code {
+                KeyCode::Char('s') => self.save_draft(),
+                KeyCode::Char('d') => {
+                    let message = if self.finishing_draft.is_some() { " Draft left as it was " } else { " Discarded " };
+                    self.reset_input();
+                    self.message = Some(message.to_string());
+                }
+                KeyCode::Esc => self.input_mode = InputMode::Editing,
+                _ => {}
+            },
+
+            InputMode::Briefing => match key.code {
+                KeyCode::Char('e') => self.export_briefing(),
+                _ => self.input_mode = InputMode::Normal,
+            },
+
+            InputMode::CompanyHistory => match key.code {
+                KeyCode::Char('n') => self.start_edit_company_notes(),
+                KeyCode::Char('w') => self.start_edit_company_website(),
+                KeyCode::Char('d') => self.show_posting_diff(),
+                _ => self.input_mode = InputMode::Normal,
+            },
+
+            // --- POPUPS: any key closes ---
+            InputMode::StatusHistory
+            | InputMode::Cooldowns
+            | InputMode::QrCode
+            | InputMode::Agenda
+            | InputMode::Offers
+            | InputMode::Usage
+            | InputMode::PostingDiff => {
+                self.input_mode = InputMode::Normal
+            }
+
+            // --- EDITING MODE ---
+            InputMode::Editing => match key.code {
+                KeyCode::Enter => self.submit_input(),
+                KeyCode::Tab => self.accept_suggestion(),
+                KeyCode::Esc if self.add_in_progress() => self.input_mode = InputMode::DraftPrompt,
+                KeyCode::Esc => {
+                    // Cancel input. A skipped debrief isn't asked for again.
+                    if let Some(interview) = self.debrief_interview() {
+                        interview.debrief.get_or_insert_with(Debrief::default);
+                    }
+                    self.reset_input();
+                }
+                KeyCode::Backspace => {
+                    self.input_buffer.pop();
+                }
+                KeyCode::Char(c) => {
+                    self.input_buffer.push(c);
+                }
+                _ => {}
+            },
+        }
+
+        if from_list && let Some(view) = self.view_name() {
+            self.note_usage(UsageKind::View, view);
+        }
+        // Edits can move jobs in or out of the filter
+        self.refresh_view();
+    }
+}
+
+fn config_modified() -> Option<SystemTime> {
+    std::fs::metadata(config_path().ok()?).and_then(|m| m.modified()).ok()
+}
+
+/// Put `text` on the clipboard with an OSC 52 escape, which works over SSH
+/// and in most terminals without any clipboard daemon
+fn copy_to_clipboard(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", BASE64.encode(text))?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Somewhere likely writable for the save-elsewhere prompt: the temp dir
+fn rescue_path() -> PathBuf {
+    std::env::temp_dir().join(format!("career-cli-jobs-{}.json", chrono::Local::now().format("%Y%m%d-%H%M%S")))
+}
@@ -0,0 +1,82 @@
+//! The pipeline as it stood on a past day, for retros and for seeing how a
+//! decision looked at the time. The journal replayed to the end of that day
+//! is the record. Jobs it only learned of later (everything before the
+//! first journal write, and jobs added with an earlier applied date) count
+//! from when I applied, with their status wound back through their history.
+
+use crate::journal::{self, JournalEntry};
+use crate::models::{Job, Status};
+use crate::statuses::{self, StatusDef};
+use anyhow::Result;
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+
+/// The last moment of `date` where I am
+pub fn end_of(date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.succ_opt().unwrap_or(date).and_hms_opt(0, 0, 0).unwrap_or_default();
+    let local = Local.from_local_datetime(&midnight).earliest().map(|t| t.with_timezone(&Utc));
+    local.unwrap_or_else(|| midnight.and_utc()) - chrono::Duration::nanoseconds(1)
+}
+
+/// Undo what happened to `job` after `at`: its later status changes, and
+/// the response and rejection they stamped
+pub fn wind_back(job: &mut Job, at: DateTime<Utc>, defs: &[StatusDef]) {
+    if let Some(i) = job.history.iter().position(|change| change.at > at) {
+        let label = job.history[i].from.clone();
+        job.history.truncate(i);
+        match Status::parse(&label) {
+            Some(status) => (job.status, job.custom_status) = (status, None),
+            None => {
+                if let Some(def) = statuses::find(defs, &label)
+                    && let Some(status) = def.category()
+                {
+                    (job.status, job.custom_status) = (status, Some(def.name.clone()));
+                }
+            }
+        }
+    }
+    if job.responded_at.is_some_and(|d| d > at) {
+        job.responded_at = None;
+    }
+    if job.rejected_at.is_some_and(|d| d > at) {
+        job.rejected_at = None;
+    }
+}
+
+/// The job list at the end of `date`, oldest application first. `jobs` is
+/// the list now, for what the journal hadn't seen by then.
+pub fn as_of(entries: &[JournalEntry], jobs: &[Job], date: NaiveDate, defs: &[StatusDef]) -> Result<Vec<Job>> {
+    let cutoff = end_of(date);
+    let known: Vec<JournalEntry> = entries.iter().filter(|e| e.timestamp <= cutoff).cloned().collect();
+    let mut past = journal::replay(&known)?;
+    // Ever in the journal by then, so its absence from the replay means it was deleted
+    let seen = |job: &Job| known.iter().any(|e| journal::same_id(&e.job_id, &job.id.to_string()));
+    let later: Vec<Job> = jobs
+        .iter()
+        .filter(|job| !job.draft && !seen(job) && job.date_applied <= cutoff)
+        .cloned()
+        .collect();
+    past.extend(later);
+    for job in &mut past {
+        wind_back(job, cutoff, defs);
+    }
+    past.sort_by_key(|job| job.date_applied);
+    Ok(past)
+}
+
+/// "12 jobs: 8 Applied, 3 Interviewing, 1 Offer", statuses in the order
+/// they first come up
+pub fn summary(jobs: &[Job]) -> String {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for job in jobs {
+        let label = job.status_label();
+        match counts.iter_mut().find(|(name, _)| *name == label) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((label, 1)),
+        }
+    }
+    let counts: Vec<String> = counts.iter().map(|(name, n)| format!("{} {}", n, name)).collect();
+    match counts.is_empty() {
+        true => "no jobs".to_string(),
+        false => format!("{} jobs: {}", jobs.len(), counts.join(", ")),
+    }
+}
@@ -0,0 +1,114 @@
+//! When I'm free for interviews, as a message to paste into a recruiter
+//! email. The weekly windows live in config.toml; an interview already
+//! booked on an open job takes its time out of them, with the [interviews]
+//! buffer either side. One with no time set takes the whole day, since
+//! there's no telling when it is.
+//!
+//! ```toml
+//! [availability]
+//! timezone = "ET"   # added after the times, leave out to say nothing
+//! windows = [
+//!     { day = "Tue", from = "14:00", to = "17:00" },
+//!     { day = "Wed", from = "09:00", to = "12:00" },
+//! ]
+//! ```
+//!
+//! '@' in the TUI copies it, `career-cli availability` prints it.
+
+use crate::config::InterviewConfig;
+use crate::models::Job;
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Days ahead the message covers, starting tomorrow
+pub const DAYS_AHEAD: u64 = 14;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct Availability {
+    pub timezone: String,
+    pub windows: Vec<Window>,
+}
+
+/// Free from `from` to `to` every `day`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Window {
+    pub day: Weekday,
+    pub from: NaiveTime,
+    pub to: NaiveTime,
+}
+
+impl Window {
+    /// "2-5pm", "9:30am-12pm" or "11am-1pm"
+    pub fn label(&self) -> String {
+        let same_half = (self.from.hour() < 12) == (self.to.hour() < 12);
+        let from = if same_half { clock(self.from, false) } else { clock(self.from, true) };
+        format!("{}-{}", from, clock(self.to, true))
+    }
+}
+
+/// "2pm", "9:30am", or just "2" when the am/pm comes later
+fn clock(time: NaiveTime, suffix: bool) -> String {
+    let (pm, hour) = time.hour12();
+    let minutes = if time.minute() == 0 { String::new() } else { format!(":{:02}", time.minute()) };
+    let half = match (suffix, pm) {
+        (false, _) => "",
+        (true, true) => "pm",
+        (true, false) => "am",
+    };
+    format!("{}{}{}", hour, minutes, half)
+}
+
+/// What's booked on `date` on open jobs, buffer included. None when an
+/// interview that day has no time, which rules out the whole day.
+fn busy(jobs: &[Job], date: NaiveDate, config: &InterviewConfig) -> Option<Vec<(NaiveDateTime, NaiveDateTime)>> {
+    let buffer = TimeDelta::minutes(config.buffer_minutes as i64);
+    jobs.iter()
+        .filter(|j| j.is_open())
+        .flat_map(|j| j.interviews.iter().filter(|i| i.date == date))
+        .map(|i| i.span(config.length_minutes).map(|(start, end)| (start - buffer, end + buffer)))
+        .collect()
+}
+
+/// Days from tomorrow on with some of a window still free, each with
+/// what's left of its windows in order of the day
+pub fn free_days(availability: &Availability, jobs: &[Job], today: NaiveDate, config: &InterviewConfig) -> Vec<(NaiveDate, Vec<Window>)> {
+    (1..=DAYS_AHEAD)
+        .filter_map(|n| today.checked_add_days(Days::new(n)))
+        .filter_map(|date| {
+            let busy = busy(jobs, date, config)?;
+            let mut free: Vec<(NaiveDateTime, NaiveDateTime)> = availability
+                .windows
+                .iter()
+                .filter(|w| w.day == date.weekday())
+                .map(|w| (date.and_time(w.from), date.and_time(w.to)))
+                .collect();
+            // What's left either side of each booking
+            for (start, end) in busy {
+                free = free.into_iter().flat_map(|(from, to)| [(from, to.min(start)), (from.max(end), to)]).collect();
+            }
+            free.retain(|(from, to)| from < to);
+            free.sort();
+            let windows: Vec<Window> = free.into_iter().map(|(from, to)| Window { day: date.weekday(), from: from.time(), to: to.time() }).collect();
+            (!windows.is_empty()).then_some((date, windows))
+        })
+        .collect()
+}
+
+/// The message itself, a line per free day. None when no window is free.
+pub fn message(availability: &Availability, jobs: &[Job], today: NaiveDate, config: &InterviewConfig) -> Option<String> {
+    let days = free_days(availability, jobs, today, config);
+    if days.is_empty() {
+        return None;
+    }
+    let zone = match availability.timezone.trim() {
+        "" => String::new(),
+        zone => format!(" (times {})", zone),
+    };
+    let mut lines = vec![format!("I'm free for a call at these times over the next two weeks{}:", zone)];
+    for (date, windows) in days {
+        let times: Vec<String> = windows.iter().map(|w| w.label()).collect();
+        lines.push(format!("- {}: {}", date.format("%a %b %-d"), times.join(", ")));
+    }
+    Some(lines.join("\n"))
+}
@@ -0,0 +1,186 @@
+//! The sheet I read five minutes before a call: who I'm meeting and whether
+//! I've met them before, where the loop stands, the notes, and what I asked
+//! and answered the last times I talked to this company. Built as Markdown
+//! so the TUI popup and the exported file are the same text.
+
+use crate::answers;
+use crate::config::InterviewConfig;
+use crate::models::{Interview, Job};
+use crate::travel;
+use crate::storage::get_data_dir;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::fs;
+use std::path::PathBuf;
+
+/// Most recent note lines shown, older ones are one `show` away
+const NOTE_LINES: usize = 10;
+
+/// The next interview from today on, or the latest one when they're all past
+pub fn next_interview(job: &Job, today: NaiveDate) -> Option<&Interview> {
+    job.interviews
+        .iter()
+        .filter(|i| i.date >= today)
+        .min_by_key(|i| i.date)
+        .or_else(|| job.interviews.iter().max_by_key(|i| i.date))
+}
+
+/// The briefing for `job`'s next interview. `history` is every application
+/// to the company, as from `models::company_history`, and may include `job`.
+pub fn briefing(job: &Job, history: &[(&str, &Job)], config: &InterviewConfig, today: NaiveDate) -> Vec<String> {
+    let interview = next_interview(job, today);
+    let mut lines = vec![format!("# {} - {}", job.company, job.role), String::new()];
+    match interview {
+        Some(interview) => {
+            let with = if interview.interviewers.is_empty() {
+                String::new()
+            } else {
+                format!(" with {}", interview.interviewers.join(", "))
+            };
+            lines.push(format!("**{}** on {} ({}){}", interview.name, interview.date, when(interview.date, today), with));
+        }
+        None => lines.push("No interview logged yet".to_string()),
+    }
+    if !job.contact.is_empty() {
+        lines.push(format!("Contact: {}", job.contact.label()));
+    }
+    if let Some(interview) = interview.filter(|i| i.date >= today) {
+        lines.extend(travel::lines(interview, config));
+    }
+
+    lines.push(String::new());
+    lines.push("## Loop".to_string());
+    match job.stage_progress() {
+        Some((done, total)) => {
+            lines.push(format!("{} of {} stages done, status {}", done, total, job.status_label()));
+            for stage in &job.stages {
+                lines.push(format!("- [{}] {}", if stage.done { "x" } else { " " }, stage.name));
+            }
+        }
+        None => lines.push(format!("No loop set up, status {}", job.status_label())),
+    }
+    let mut past: Vec<&Interview> = job.interviews.iter().filter(|i| i.date < today).collect();
+    past.sort_by_key(|i| i.date);
+    for earlier in past {
+        lines.push(format!("- {} {}{}", earlier.date, earlier.name, names(earlier)));
+    }
+
+    if let Some(interview) = interview
+        && !interview.interviewers.is_empty()
+    {
+        lines.push(String::new());
+        lines.push("## Interviewers".to_string());
+        for person in &interview.interviewers {
+            let met: Vec<String> = history
+                .iter()
+                .flat_map(|(cohort, other)| other.interviews.iter().map(move |i| (cohort, other, i)))
+                .filter(|(_, _, i)| !std::ptr::eq(*i, interview) && i.date <= today)
+                .filter(|(_, _, i)| i.interviewers.iter().any(|n| n.eq_ignore_ascii_case(person)))
+                .map(|(cohort, other, i)| format!("{} on {} ({}, {})", i.name, i.date, other.role, cohort))
+                .collect();
+            if met.is_empty() {
+                lines.push(format!("- {}: first time", person));
+            } else {
+                lines.push(format!("- {}: met in {}", person, met.join("; ")));
+            }
+        }
+    }
+
+    let notes: Vec<&str> = job.notes.lines().filter(|l| !l.trim().is_empty()).collect();
+    if !notes.is_empty() {
+        lines.push(String::new());
+        lines.push("## Notes".to_string());
+        lines.extend(notes[notes.len().saturating_sub(NOTE_LINES)..].iter().map(|l| l.to_string()));
+    }
+
+    let prior: Vec<String> = history
+        .iter()
+        .flat_map(|(cohort, other)| other.notes.lines().map(move |l| (cohort, other, l)))
+        .filter(|(_, _, line)| is_question_or_answer(line))
+        .map(|(cohort, other, line)| {
+            if std::ptr::eq(*other, job) {
+                format!("- {}", line.trim())
+            } else {
+                format!("- {} ({}, {})", line.trim(), other.role, cohort)
+            }
+        })
+        .collect();
+    // What they asked in earlier interviews, from the debriefs
+    let asked = history
+        .iter()
+        .flat_map(|(cohort, other)| other.interviews.iter().map(move |i| (cohort, other, i)))
+        .filter_map(|(cohort, other, i)| Some((cohort, other, i, i.debrief.as_ref().filter(|d| !d.questions.is_empty())?)))
+        .map(|(cohort, other, i, debrief)| {
+            if std::ptr::eq(*other, job) {
+                format!("- {} {} asked: {}", i.date, i.name, debrief.questions)
+            } else {
+                format!("- {} {} asked: {} ({}, {})", i.date, i.name, debrief.questions, other.role, cohort)
+            }
+        });
+    let prior: Vec<String> = prior.into_iter().chain(asked).collect();
+    if !prior.is_empty() {
+        lines.push(String::new());
+        lines.push("## Questions and answers".to_string());
+        lines.extend(prior);
+    }
+    lines.extend(answers::lines(job));
+
+    let earlier: Vec<String> = history
+        .iter()
+        .filter(|(_, other)| !std::ptr::eq(*other, job))
+        .map(|(cohort, other)| {
+            format!("- {} {}, {} ({})", other.date_applied.format("%Y-%m-%d"), other.role, other.status_label(), cohort)
+        })
+        .collect();
+    if !earlier.is_empty() {
+        lines.push(String::new());
+        lines.push("## Earlier applications".to_string());
+        lines.extend(earlier);
+    }
+    lines
+}
+
+/// Note lines starting with "Q:" or "A:", after the timestamp `append_note` adds
+fn is_question_or_answer(line: &str) -> bool {
+    let line = line.trim();
+    let text = match line.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+        Some((_, text)) => text.trim_start(),
+        None => line,
+    };
+    let prefix = text.get(..2).unwrap_or_default();
+    prefix.eq_ignore_ascii_case("q:") || prefix.eq_ignore_ascii_case("a:")
+}
+
+fn names(interview: &Interview) -> String {
+    if interview.interviewers.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", interview.interviewers.join(", "))
+    }
+}
+
+fn when(date: NaiveDate, today: NaiveDate) -> String {
+    match (date - today).num_days() {
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        days if days > 1 => format!("in {} days", days),
+        days => format!("{} days ago", -days),
+    }
+}
+
+/// briefings/<company>-<date>.md in the data dir, dated by the interview
+pub fn export(job: &Job, lines: &[String], today: NaiveDate) -> Result<PathBuf> {
+    let dir = get_data_dir()?.join("briefings");
+    fs::create_dir_all(&dir).context("Failed to create briefings directory")?;
+    let date = next_interview(job, today).map_or(today, |i| i.date);
+    let company: String = job
+        .company
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let path = dir.join(format!("{}-{}.md", company, date));
+    fs::write(&path, lines.join("\n") + "\n").context("Failed to write briefing")?;
+    Ok(path)
+}
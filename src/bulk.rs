@@ -0,0 +1,247 @@
+use crate::contract::{self, ContractTerms, Engagement};
+use crate::models::{self, Job, LetterStatus, Priority, Status};
+use crate::validate;
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+
+/// One `field=value` from `bulk set --set ...` or the TUI's bulk prompt.
+/// Tags also take `tag+=remote` and `tag-=remote`, and `tags=a b` replaces
+/// them all (spaces between, since commas split assignments).
+#[derive(Debug, Clone)]
+pub enum Assignment {
+    Status(Status),
+    Company(String),
+    Role(String),
+    Link(String),
+    Cooldown(Option<u32>),
+    Cycle(Option<String>),
+    Deadline(Option<NaiveDate>),
+    Agency(Option<String>),
+    Source(Option<String>),
+    Priority(Priority),
+    Letter(LetterStatus),
+    Engagement(Engagement, Option<ContractTerms>),
+    Archived(bool),
+    Starred(bool),
+    AddTags(Vec<String>),
+    RemoveTags(Vec<String>),
+    Tags(Vec<String>),
+}
+
+impl Assignment {
+    pub fn parse(input: &str) -> Result<Assignment> {
+        let Some((field, value)) = input.split_once('=') else {
+            bail!("Expected field=value, got '{}'", input);
+        };
+        let value = value.trim();
+        Ok(match field.trim().to_lowercase().as_str() {
+            "status" => Assignment::Status(
+                Status::parse(value).with_context(|| format!("Unknown status '{}'", value))?,
+            ),
+            "company" => {
+                validate::company(value)?;
+                Assignment::Company(value.to_string())
+            }
+            "role" => Assignment::Role(value.to_string()),
+            "link" => {
+                validate::link(value)?;
+                Assignment::Link(value.to_string())
+            }
+            "cooldown" => Assignment::Cooldown(if value.is_empty() {
+                None
+            } else {
+                Some(value.parse().context("Cool-down must be a number of months")?)
+            }),
+            "cycle" | "season" => Assignment::Cycle(Some(value.to_string()).filter(|v| !v.is_empty())),
+            "deadline" | "due" => Assignment::Deadline(if value.is_empty() {
+                None
+            } else {
+                Some(NaiveDate::parse_from_str(value, "%Y-%m-%d").context("Deadlines look like 2026-10-31")?)
+            }),
+            // Empty or "direct" means no agency
+            "agency" => Assignment::Agency(Some(value.to_string()).filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("direct"))),
+            "source" | "from" => Assignment::Source(models::parse_source(value)),
+            "priority" | "prio" => Assignment::Priority(
+                Priority::parse(value).with_context(|| format!("Unknown priority '{}' (high, medium or low)", value))?,
+            ),
+            "letter" | "cover" => Assignment::Letter(
+                LetterStatus::parse(value).with_context(|| format!("Unknown cover letter status '{}' (needed, sent or none)", value))?,
+            ),
+            "engagement" | "type" => {
+                let (engagement, terms) = contract::parse(value)?;
+                Assignment::Engagement(engagement, terms)
+            }
+            "archived" | "archive" => Assignment::Archived(yes_or_no("archived", value)?),
+            "starred" | "star" => Assignment::Starred(yes_or_no("starred", value)?),
+            "tag+" | "tags+" => Assignment::AddTags(some_tags(value)?),
+            "tag-" | "tags-" => Assignment::RemoveTags(some_tags(value)?),
+            "tag" | "tags" => Assignment::Tags(models::parse_tags(value)),
+            other => bail!(
+                "Can't bulk set '{}' (try status, company, role, link, cooldown, cycle, deadline, agency, source, priority, letter, engagement, archived, starred, tags, tag+ or tag-)",
+                other
+            ),
+        })
+    }
+
+    /// Several assignments separated by commas, e.g. "status=Ghosted, cooldown=6"
+    pub fn parse_list(input: &str) -> Result<Vec<Assignment>> {
+        let assignments: Vec<Assignment> = input
+            .split(',')
+            .filter(|part| !part.trim().is_empty())
+            .map(Assignment::parse)
+            .collect::<Result<_>>()?;
+        if assignments.is_empty() {
+            bail!("Nothing to set");
+        }
+        Ok(assignments)
+    }
+
+    pub fn field(&self) -> &'static str {
+        match self {
+            Assignment::Status(_) => "status",
+            Assignment::Company(_) => "company",
+            Assignment::Role(_) => "role",
+            Assignment::Link(_) => "link",
+            Assignment::Cooldown(_) => "cooldown",
+            Assignment::Cycle(_) => "cycle",
+            Assignment::Deadline(_) => "deadline",
+            Assignment::Agency(_) => "agency",
+            Assignment::Source(_) => "source",
+            Assignment::Priority(_) => "priority",
+            Assignment::Letter(_) => "letter",
+            Assignment::Engagement(..) => "engagement",
+            Assignment::Archived(_) => "archived",
+            Assignment::Starred(_) => "starred",
+            Assignment::AddTags(_) | Assignment::RemoveTags(_) | Assignment::Tags(_) => "tags",
+        }
+    }
+
+    /// "old -> new" for the preview
+    pub fn describe(&self, job: &Job) -> String {
+        let months = |m: Option<u32>| m.map(|m| m.to_string()).unwrap_or_else(|| "-".to_string());
+        let date = |d: Option<NaiveDate>| d.map(|d| d.to_string()).unwrap_or_else(|| "-".to_string());
+        let (old, new) = match self {
+            Assignment::Status(status) => (format!("{:?}", job.status), format!("{:?}", status)),
+            Assignment::Company(value) => (job.company.clone(), value.clone()),
+            Assignment::Role(value) => (job.role.clone(), value.clone()),
+            Assignment::Link(value) => (job.post_link.clone(), value.clone()),
+            Assignment::Cooldown(value) => (months(job.cooldown_months), months(*value)),
+            Assignment::Cycle(value) => (
+                job.cycle.clone().unwrap_or_else(|| "-".to_string()),
+                value.clone().unwrap_or_else(|| "-".to_string()),
+            ),
+            Assignment::Deadline(value) => (date(job.deadline), date(*value)),
+            Assignment::Agency(value) => (
+                job.agency.clone().unwrap_or_else(|| "direct".to_string()),
+                value.clone().unwrap_or_else(|| "direct".to_string()),
+            ),
+            Assignment::Source(value) => (
+                job.source.clone().unwrap_or_else(|| "-".to_string()),
+                value.clone().unwrap_or_else(|| "-".to_string()),
+            ),
+            Assignment::Priority(value) => (format!("{:?}", job.priority), format!("{:?}", value)),
+            Assignment::Letter(value) => (format!("{:?}", job.letter_status), format!("{:?}", value)),
+            Assignment::Engagement(engagement, terms) => (
+                contract::to_input(job.engagement, job.contract.as_ref()),
+                contract::to_input(*engagement, terms.as_ref()),
+            ),
+            Assignment::Archived(true) if refused(job) => (yes_no(false), "no, still open".to_string()),
+            Assignment::Archived(value) => (yes_no(job.archived), yes_no(*value)),
+            Assignment::Starred(value) => (yes_no(job.starred), yes_no(*value)),
+            Assignment::AddTags(_) | Assignment::RemoveTags(_) | Assignment::Tags(_) => {
+                let mut after = job.clone();
+                self.apply(&mut after);
+                (tag_list(&job.tags), tag_list(&after.tags))
+            }
+        };
+        format!("{}: {} -> {}", self.field(), old, new)
+    }
+
+    /// Make the change on `job`. False when it's turned down, the same as
+    /// for a single job: only closed jobs can be archived.
+    pub fn apply(&self, job: &mut Job) -> bool {
+        match self {
+            Assignment::Archived(true) if refused(job) => return false,
+            Assignment::Status(status) if job.status != *status => job.set_status(status.clone()),
+            Assignment::Status(_) => {}
+            Assignment::Company(value) => job.company = value.clone(),
+            Assignment::Role(value) => job.role = value.clone(),
+            Assignment::Link(value) => job.post_link = value.clone(),
+            Assignment::Cooldown(value) => job.cooldown_months = *value,
+            Assignment::Cycle(value) => job.cycle = value.clone(),
+            Assignment::Deadline(value) => job.deadline = *value,
+            Assignment::Agency(value) => job.agency = value.clone(),
+            Assignment::Source(value) => job.source = value.clone(),
+            Assignment::Priority(value) => job.priority = *value,
+            Assignment::Letter(value) => job.letter_status = *value,
+            Assignment::Engagement(engagement, terms) => job.set_engagement(*engagement, terms.clone()),
+            Assignment::Archived(value) => job.archived = *value,
+            Assignment::Starred(value) => job.starred = *value,
+            Assignment::AddTags(tags) => {
+                for tag in tags {
+                    if !job.tags.contains(tag) {
+                        job.tags.push(tag.clone());
+                    }
+                }
+            }
+            Assignment::RemoveTags(tags) => job.tags.retain(|tag| !tags.contains(tag)),
+            Assignment::Tags(tags) => job.tags = tags.clone(),
+        }
+        true
+    }
+}
+
+/// Archiving a job that's still open, which isn't allowed one job at a time either
+fn refused(job: &Job) -> bool {
+    !job.archived && job.is_open()
+}
+
+/// Preview lines for the jobs at `indexes`, one per job
+pub fn preview(jobs: &[Job], indexes: &[usize], assignments: &[Assignment]) -> Vec<String> {
+    let id_len = models::short_id_len(jobs);
+    indexes
+        .iter()
+        .map(|&i| {
+            // Each change as it lands after the ones before it, so
+            // "status=rejected, archived=yes" shows the archiving going ahead
+            let mut job = jobs[i].clone();
+            let changes: Vec<String> = assignments
+                .iter()
+                .map(|a| {
+                    let change = a.describe(&job);
+                    a.apply(&mut job);
+                    change
+                })
+                .collect();
+            format!("{}  {} - {}  ({})", job.id_prefix(id_len), jobs[i].company, jobs[i].role, changes.join(", "))
+        })
+        .collect()
+}
+
+fn yes_or_no(field: &str, value: &str) -> Result<bool> {
+    match value.to_lowercase().as_str() {
+        "yes" | "true" => Ok(true),
+        "no" | "false" => Ok(false),
+        _ => bail!("{} is yes or no, got '{}'", field, value),
+    }
+}
+
+fn some_tags(value: &str) -> Result<Vec<String>> {
+    let tags = models::parse_tags(value);
+    if tags.is_empty() {
+        bail!("Which tags? e.g. tag+=remote");
+    }
+    Ok(tags)
+}
+
+/// "#remote #referral", or "-" for none
+fn tag_list(tags: &[String]) -> String {
+    match tags.is_empty() {
+        true => "-".to_string(),
+        false => tags.iter().map(|tag| format!("#{}", tag)).collect::<Vec<_>>().join(" "),
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_string()
+}
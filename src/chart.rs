@@ -0,0 +1,141 @@
+use crate::models::{Job, Status};
+use anyhow::{bail, Result};
+use chrono::{Datelike, Duration, Utc};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const WEEKS_SHOWN: i64 = 12;
+
+pub enum ChartKind {
+    Funnel,
+    Weekly,
+    Sources,
+}
+
+impl ChartKind {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "funnel" => Ok(ChartKind::Funnel),
+            "weekly" => Ok(ChartKind::Weekly),
+            "sources" => Ok(ChartKind::Sources),
+            _ => bail!("Unknown chart '{}' (expected funnel, weekly or sources)", name),
+        }
+    }
+}
+
+/// Render the chart as a standalone SVG file
+pub fn render_chart(kind: &ChartKind, jobs: &[Job], output: &Path) -> Result<()> {
+    if output.extension().and_then(|e| e.to_str()) != Some("svg") {
+        bail!("Charts can only be written as .svg files");
+    }
+
+    let (title, bars) = match kind {
+        ChartKind::Funnel => ("Application funnel", funnel(jobs)),
+        ChartKind::Weekly => ("Applications per week", weekly(jobs)),
+        ChartKind::Sources => ("Applications by source", sources(jobs)),
+    };
+    draw_bars(output, title, &bars)
+}
+
+/// How many applications made it to each stage
+fn funnel(jobs: &[Job]) -> Vec<(String, u32)> {
+    let interviewed = jobs
+        .iter()
+        .filter(|j| {
+            matches!(j.status, Status::Interviewing | Status::Offer | Status::Accepted) || j.stages.iter().any(|s| s.done)
+        })
+        .count();
+    let offers = jobs.iter().filter(|j| matches!(j.status, Status::Offer | Status::Accepted)).count();
+
+    vec![
+        ("Applied".to_string(), jobs.len() as u32),
+        ("Interviewed".to_string(), interviewed as u32),
+        ("Offer".to_string(), offers as u32),
+    ]
+}
+
+/// Applications per week for the last few weeks, oldest first
+fn weekly(jobs: &[Job]) -> Vec<(String, u32)> {
+    let today = Utc::now().date_naive();
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let first_monday = this_monday - Duration::weeks(WEEKS_SHOWN - 1);
+
+    let mut weeks: Vec<(String, u32)> = (0..WEEKS_SHOWN)
+        .map(|i| ((first_monday + Duration::weeks(i)).format("%b %d").to_string(), 0))
+        .collect();
+    for job in jobs {
+        let days = (job.date_applied.date_naive() - first_monday).num_days();
+        if days >= 0 && days / 7 < WEEKS_SHOWN {
+            weeks[(days / 7) as usize].1 += 1;
+        }
+    }
+    weeks
+}
+
+/// Where applications came from, by their source or else the post link domain
+fn sources(jobs: &[Job]) -> Vec<(String, u32)> {
+    let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+    for job in jobs {
+        let source = job.source.clone().or_else(|| job.link_domain()).unwrap_or_else(|| "unknown".to_string());
+        *counts.entry(source).or_default() += 1;
+    }
+    let mut bars: Vec<(String, u32)> = counts.into_iter().collect();
+    bars.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    bars
+}
+
+#[cfg(feature = "charts")]
+use svg::draw_bars;
+
+/// Stands in for the SVG drawing in builds without it
+#[cfg(not(feature = "charts"))]
+fn draw_bars(_output: &Path, _title: &str, _bars: &[(String, u32)]) -> Result<()> {
+    bail!("This build of career-cli leaves out charts, rebuild it with --features charts")
+}
+
+#[cfg(feature = "charts")]
+mod svg {
+    use anyhow::{anyhow, Result};
+    use plotters::prelude::*;
+    use std::path::Path;
+
+    pub fn draw_bars(output: &Path, title: &str, bars: &[(String, u32)]) -> Result<()> {
+        let width = (120 + bars.len() as u32 * 70).max(640);
+        let root = SVGBackend::new(output, (width, 480)).into_drawing_area();
+        root.fill(&WHITE).map_err(|e| anyhow!("{:?}", e))?;
+
+        let max = bars.iter().map(|(_, v)| *v).max().unwrap_or(0).max(1);
+        let labels: Vec<String> = bars.iter().map(|(l, _)| l.clone()).collect();
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption(title, ("sans-serif", 24))
+            .margin(16)
+            .x_label_area_size(40)
+            .y_label_area_size(40)
+            .build_cartesian_2d((0..bars.len() as u32).into_segmented(), 0..max + max / 10 + 1)
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(bars.len())
+            .x_label_formatter(&|value| match value {
+                SegmentValue::CenterOf(i) => labels.get(*i as usize).cloned().unwrap_or_default(),
+                _ => String::new(),
+            })
+            .draw()
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(RGBColor(70, 110, 200).filled())
+                    .margin(12)
+                    .data(bars.iter().enumerate().map(|(i, (_, v))| (i as u32, *v))),
+            )
+            .map_err(|e| anyhow!("{:?}", e))?;
+
+        root.present().map_err(|e| anyhow!("{:?}", e))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,2564 @@
+use crate::bulk::{preview, Assignment};
+use crate::chart::{render_chart, ChartKind};
+use crate::companies::{self, load_companies, save_companies, Company};
+use crate::config::{load_config, save_config, StorageLayout};
+use crate::contract::Engagement;
+use crate::export::{export_pdf, to_csv, to_markdown, write_if_changed, Format};
+use crate::filter::Filter;
+use crate::inbox;
+use crate::ipc::{self, Request};
+use crate::journal::{blame, read_journal, record_undo, replay, JournalOp, Source};
+use crate::metrics::{pipeline_health, rejection_stages, response_times, response_wait, HolidayCalendar};
+use crate::models::{
+    agenda, company_history, double_submissions, normalize_company, parse_applied, DueKind, expiring_assessments,
+    interview_clashes, outstanding_expenses, reapply_windows, short_id_len, Assessment, ChecklistItem, Expense, Interview, Job, JobId, NamedLink, Pipeline, Priority, RejectionStage, StageKind, Status,
+};
+use crate::preset::{self, Preset};
+use crate::usage::{append_usage, read_usage, summarize, UsageEvent, UsageKind};
+use crate::validate;
+use crate::reminders::{upcoming, AdminReminder, Interval};
+use crate::network::{find as find_person, load_network, save_network, Person};
+use crate::outreach::{find as find_outreach, load_outreach, save_outreach, Outreach, Reply};
+use crate::references::{find as find_reference, load_references, save_references, Reference};
+use chrono::Utc;
+use crate::storage::{load_archived_cohorts, load_jobs, storage_for};
+use anyhow::{bail, Context, Result};
+use std::io::{BufRead, Read, Write};
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: career-cli [command]
+
+Run without a command to open the TUI. Only one can be open at a time; while it is, add and
+note hand their change to it instead of writing the files themselves, and other commands that
+change something refuse.
+
+Commands:
+  list [--archived]             Print all jobs with their IDs, archived ones too with --archived
+  show <id>                     Print a single job
+  asof <date>                   The list as it stood at the end of a past day (2026-01-15,
+                                yesterday or 3w ago), replayed from the journal and each
+                                job's status history
+  add <company> <role> [link]   Add a job
+  add --stdin                   Add a job from stdin (company, role, link on separate lines,
+                                then the posting text, kept on the job and read for its salary
+                                range)
+  add --new ...                 Add it even if it looks like a job already tracked. Without
+                                it, a repeat is kept as another listing of that job instead
+  import <file.csv>             Queue the rows of a CSV export for review (columns company, role,
+                                and optionally link, status, applied, location, salary, source,
+                                notes)
+  inbox [accept|discard <n>|all]
+                                List imports waiting for review, or accept or discard them.
+                                Nothing imported joins the list until it's accepted here or
+                                with 'm' in the TUI
+  note <id> <text>              Append a timestamped line to a job's notes
+  note <id> --stdin             Same, reading the note text from stdin
+  cooldowns                     List rejections and when I can reapply
+  agenda [--notify]             Upcoming deadlines, follow-ups, OA expiries and admin reminders.
+                                --notify sends a desktop notification for OAs about to expire,
+                                reminders due by tomorrow or missed, follow-ups due today and
+                                interviews waiting for a debrief (for cron)
+  reminders [add <name> <date> [every] | remove <name>]
+                                Admin deadlines not tied to a job, like a weekly unemployment
+                                certification (every as 1w, 14d or 1m). Shown in the agenda; a
+                                one-off stays there, late, until it's removed
+  oa <id> <platform> [expiry]   Record an OA invite, expiry as 2026-10-31 or 7d
+  oa <id> done                  Mark the open OA as taken
+  interview <id> [date] [time] <name> [with <people>] [--yes]
+                                Log an interview, e.g. interview 3f2a 2026-10-20 14:00-15:00 Onsite
+                                with Ana, Bo. Asks first if it overlaps another or leaves less
+                                than [interviews] buffer_minutes between them
+  briefing <id> [--output <file>]
+                                One-page Markdown briefing for the next interview: interviewers
+                                and when I met them before, loop progress, notes, and my past
+                                questions and answers (note lines starting Q: or A:)
+  travel <id> [--on <date|stage>] [address <text> | book <ref> | depart <date> | early <minutes> | none]
+                                Where the next interview (or the one on that date or stage) is
+                                and how I'm getting there: the address, booking references, the
+                                day I set off when it's earlier, and how early to arrive. In its
+                                briefing and the agenda
+  transcript <id> <file>        Attach a transcript or recording to the job's latest interview
+  search <words>                Lines in notes, postings and interview transcripts with all the
+                                words
+  description <id> [--stdin | --fetch | --diff]
+                                Print the posting text kept on a job, or replace it from stdin
+                                or by fetching the job's link (needs curl). add --stdin keeps
+                                it too
+  expense <id> <what> <amount>  Add an expense to the job's latest interview
+  expenses [reimburse <id>]     List unreimbursed expenses with the total owed, or mark a
+                                job's expenses as paid back
+  onboarding <id> [done <n> | start <date> | due <n> <date> | add <item>]
+                                Paperwork between accepting and the first day
+  applied <id> <date>           Back-date when I applied, e.g. 2026-09-14, yesterday or 3d ago
+  rejected <id> [stage] [reason]
+                                Mark it rejected, with how far it got (resume, phone, oa or
+                                onsite) and the reason they gave. stats counts the stages.
+  start <id> <date>             Record the start date an offer asks for (none to clear)
+  links <id> [add <name> <url> | remove <name>]
+                                List a job's links, or keep another besides the posting, like
+                                the portal's status page or the take-home repo
+  files <id> [add <path> | remove <name> | open <name>]
+                                List the files kept with a job (take-home brief, offer PDF),
+                                keep another, forget one, or open it with the system's viewer
+  answers [<id> [add <question>? <answer> | remove <question>]]
+                                What I put on application forms. Without an ID, every question
+                                and what I answered where; with one, that job's answers. add
+                                keeps one (Salary expectations: 150k works too) and says where
+                                I answered the same question differently
+  follow-up <id> <when>         Nudge them at a set time, e.g. thu 9am, tomorrow 2pm or
+                                2026-10-22 14:00, in their timezone when the job has one. An
+                                at job then notifies me and opens an email to the contact
+  timezone <id> [zone | none]   Show or set the company's timezone (Europe/Berlin or Berlin),
+                                and whether it's their business hours right now
+  reply-by <id> <date | none>   When they said I'd hear back (2026-03-03, mar 3, friday or 2w).
+                                Flagged in the list and the agenda once it's passed
+  transparency [<id> <where> <range | none> | <id> clear]
+                                Postings in pay-transparency places that gave no range, by
+                                jurisdiction, or record what one posting gave, e.g.
+                                transparency 3f2a Colorado $120k-150k. Adding a job asks too
+  skills <id> [<skills> | none] Show or set the skills a job asks for, comma-separated, and how
+                                well I fit against [skills] have in config.toml
+  availability                  When I'm free for interviews over the next two weeks, from the
+                                [availability] windows in config.toml, skipping days that
+                                already have one. Ready to paste into a recruiter email
+  offers                        Compare offers side by side: rate, city, relocation, and the
+                                resign-by and decide-by dates my notice period implies
+  companies                     List company records: website, roles applied to, notes
+  company <name> [website|notes <value>]
+                                Show or set what's on file about a company, shared by every
+                                role there. \"google llc\" finds Google
+  references                    List references, when they were last used and where
+  reference add <name> <relationship> <contact>
+                                Add a reference, e.g. reference add Ana \"Manager at Initech\" ana@initech.com
+  reference give <name> <id> [--yes]
+                                Record giving a reference to a job, warning first if they're
+                                already in two open processes and I haven't asked them since
+  reference asked <name>        Record that I checked they're fine with another call
+  outreach                      Cold messages sent, where each got to, and how many turned into
+                                a reply, a referral and an application
+  outreach add <company> <contact> [date]
+                                Record a message sent (today unless dated, e.g. 3d ago)
+  outreach replied|declined|referred <company | contact>
+                                Record what came of it
+  outreach applied <company | contact> <id>
+                                Link it to the application it led to. One at the company after
+                                the message counts without this
+  network                       People I know: where they work, how we met, when we last talked
+                                and the jobs they're linked to
+  network add <name>, <company>, <how we met>
+                                Add someone, e.g. network add Dana Lee, Acme, RustConf 2025
+  network talked <name> [date]  Record talking to them (today unless dated, e.g. 3d ago)
+  network link|unlink <name> <id>
+                                Link them to a job, shown on it in the TUI, or undo that
+  network remove <name>         Take them out of the network
+  contact <id> [<field> <value>]
+                                Show or set who to talk to about a job: name, email, phone
+                                or linkedin (empty value to clear)
+  agencies                      Submissions, responses and offers per recruiting agency,
+                                and companies two agencies have put me forward to
+  stats [--business-days | --calendar-days]
+                                Counts, response rate, time to response and pipeline health
+  usage                         How I've used career-cli: sessions against applications each
+                                week, most used screens and commands. Never leaves this machine
+                                ([usage] track = false in config.toml stops it)
+  chart <kind> [--output <file>]
+                                Write a funnel, weekly or sources chart as SVG
+  export --format <pdf|csv|markdown> [--output <file>]
+                                One-page printable pipeline summary, or every job as a spreadsheet
+                                (which import reads back) or a Markdown table
+  export --scheduled            Refresh the copy set up under [export] in config.toml, for cron:
+                                0 2 * * * career-cli export --scheduled
+                                stats, chart and export take --include and --exclude with
+                                archived, trashed (tagged #trash), withdrawn, drafts or all, over the
+                                [stats] defaults in config.toml
+  bulk set --filter <expr> --set <field=value>... [--yes]
+                                Change every matching job after a preview. Tags take
+                                tag+=remote, tag-=remote or tags=a b
+  storage migrate <file|dir>    Copy the data into the other storage layout
+  config export [--output <file>]
+                                Write theme, keymap and loop templates as a preset
+  config import <file|name> [--yes]
+                                Show what a preset changes, then apply it (built in: vim)
+  archive <cohort> [--yes]      Move every closed job (accepted, rejected, ghosted, withdrawn)
+                                out of the list into archive/<cohort>.json, e.g. archive 2025.
+                                Each cohort file is a JSON array of jobs like jobs.json; stats
+                                and company history can still count them
+  undo [--yes]                  Take back the most recent save, from this session or an earlier
+                                one (a delete, a bulk edit). Run again to go further back
+  doctor                        Check the config and every saved job against the field rules
+                                (links that don't parse, empty companies, dates in the future)
+  journal log [count]           Show the most recent changes (default 20)
+  journal replay [--output <file>]
+                                Rebuild the job list from the journal alone
+  blame <id>                    When each of a job's fields last changed, and whether an edit,
+                                a bulk set, an import or an undo changed it
+  help                          Show this message
+
+<id> is the short ID shown in the list. Any unique prefix of the full ID (a UUID, printed by
+show) works, like git hashes.
+Filters look like: status:applied AND applied<2024-12-01 (see src/filter.rs).";
+
+pub fn run(args: &[String]) -> Result<()> {
+    let command = args[0].as_str();
+    let rest = &args[1..];
+
+    let result = match command {
+        "list" => list(rest),
+        "show" => show(rest),
+        "asof" => asof(rest),
+        "add" => add(rest),
+        "note" => note(rest),
+        "import" => import(rest),
+        "inbox" => inbox_command(rest),
+        "cooldowns" => cooldowns(),
+        "agenda" | "deadlines" => agenda_command(rest),
+        "oa" => oa(rest),
+        "reminders" => reminders(rest),
+        "agencies" => agencies(),
+        "contact" => contact(rest),
+        "companies" => list_companies(),
+        "company" => company(rest),
+        "references" => list_references(),
+        "reference" => reference(rest),
+        "outreach" => outreach(rest),
+        "network" => network(rest),
+        "offers" => offers(),
+        "availability" => availability(),
+        "start" => start_date(rest),
+        "applied" => applied(rest),
+        "rejected" => rejected(rest),
+        "onboarding" => onboarding(rest),
+        "interview" => interview(rest),
+        "expense" => expense(rest),
+        "briefing" => briefing(rest),
+        "transcript" => transcript(rest),
+        "search" => search(rest),
+        "description" => description(rest),
+        "timezone" => timezone(rest),
+        "reply-by" => reply_by(rest),
+        "skills" => skills(rest),
+        "transparency" => transparency(rest),
+        "follow-up" => follow_up(rest),
+        "links" => links(rest),
+        "files" => files(rest),
+        "answers" => answers(rest),
+        "travel" => travel(rest),
+        "expenses" => expenses(rest),
+        "stats" => stats(rest),
+        "doctor" => doctor(),
+        "chart" => chart(rest),
+        "export" => export(rest),
+        "bulk" => bulk(rest),
+        "storage" => storage(rest),
+        "config" => config(rest),
+        "journal" => journal(rest),
+        "blame" => blame_command(rest),
+        "archive" => archive(rest),
+        "undo" => undo(rest),
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        "usage" => usage_command(),
+        _ => bail!("Unknown command '{}'\n\n{}", command, USAGE),
+    };
+    // Only commands that exist and ran, never their arguments. An unknown
+    // command has already bailed, and one turned away over a mistyped
+    // argument didn't really get used.
+    if result.is_ok() && load_config().is_ok_and(|config| config.usage.track) {
+        let _ = append_usage(&[UsageEvent::now(UsageKind::Command, command)]);
+    }
+    result
+}
+
+/// Find the job whose ID starts with `query`, a short ID or the whole UUID
+pub fn resolve(jobs: &[Job], query: &str) -> Result<usize> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        bail!("Missing job ID");
+    }
+
+    let matches: Vec<usize> = jobs
+        .iter()
+        .enumerate()
+        .filter(|(_, job)| job.id.to_string().starts_with(&query))
+        .map(|(i, _)| i)
+        .collect();
+
+    match matches.as_slice() {
+        [] => bail!("No job matches ID '{}'", query),
+        [i] => Ok(*i),
+        _ => {
+            let candidates: Vec<String> = matches
+                .iter()
+                .map(|&i| format!("  {}  {} - {}", jobs[i].short_id(), jobs[i].company, jobs[i].role))
+                .collect();
+            bail!("ID '{}' is ambiguous:\n{}", query, candidates.join("\n"))
+        }
+    }
+}
+
+fn list(args: &[String]) -> Result<()> {
+    let all = match args {
+        [] => false,
+        [flag] if flag == "--archived" => true,
+        _ => bail!("Usage: career-cli list [--archived]"),
+    };
+    let jobs = load_jobs()?;
+    let archived = jobs.iter().filter(|j| j.archived).count();
+    let id_len = short_id_len(&jobs);
+    for job in jobs.iter().filter(|j| all || !j.archived) {
+        println!(
+            "{}  {:<20} {:<24} {}",
+            job.id_prefix(id_len),
+            job.company,
+            job.role,
+            if job.draft { job.draft_label() } else { job.status_label() }
+        );
+    }
+    if archived > 0 && !all {
+        println!("{} archived not shown (list --archived)", archived);
+    }
+    Ok(())
+}
+
+fn asof(args: &[String]) -> Result<()> {
+    let today = chrono::Local::now().date_naive();
+    let date = match args {
+        [] => bail!("Usage: career-cli asof <date>"),
+        words => parse_applied(&words.join(" "), today).context("Dates look like 2026-01-15, yesterday or 3w ago")?,
+    };
+    let config = load_config()?;
+    let current = load_jobs()?;
+    let jobs = crate::asof::as_of(&crate::journal::read_journal()?, &current, date, &config.statuses)?;
+    println!("As of {}, {}", date, crate::asof::summary(&jobs));
+    // Long enough to tell apart from today's jobs too, for `show`
+    let id_len = short_id_len(jobs.iter().chain(&current));
+    for job in &jobs {
+        println!(
+            "{}  {}  {:<20} {:<24} {}",
+            job.id_prefix(id_len),
+            job.date_applied.format("%Y-%m-%d"),
+            job.company,
+            job.role,
+            if job.draft { job.draft_label() } else { job.status_label() }
+        );
+    }
+    Ok(())
+}
+
+fn show(args: &[String]) -> Result<()> {
+    let jobs = load_jobs()?;
+    let Some(query) = args.first() else {
+        bail!("Usage: career-cli show <id>");
+    };
+    let job = &jobs[resolve(&jobs, query)?];
+
+    println!("ID:      {}", job.id);
+    println!("Company: {}", job.company);
+    let record = companies::find(&load_companies()?, &job.company).cloned();
+    if let Some(website) = record.as_ref().map(|c| &c.website).filter(|w| !w.is_empty()) {
+        println!("Website: {}", website);
+    }
+    println!("Role:    {}", job.role);
+    if let Some(req_id) = &job.req_id {
+        println!("Req ID:  {}", req_id);
+    }
+    println!("Status:  {}{}", job.status_label(), if job.archived { " (archived)" } else { "" });
+    if let Some(rejection) = job.rejection_label() {
+        println!("Rejected: {}", rejection);
+    }
+    for listing in &job.listings {
+        println!("Listed:  {}", crate::fingerprint::describe(listing));
+    }
+    for path in &job.files {
+        println!("File:    {}", crate::models::describe_file(path));
+    }
+    for answer in &job.answers {
+        println!("Answer:  {}", answer.input());
+    }
+    if let Some(evergreen) = crate::evergreen::label(job, &load_config()?.evergreen) {
+        println!("Posting: {}", evergreen);
+    }
+    if job.priority != Priority::Medium {
+        println!("Priority: {:?}", job.priority);
+    }
+    if job.starred {
+        println!("Starred: yes");
+    }
+    if job.draft {
+        println!("Draft:   missing {}", job.missing_fields().join(", "));
+    }
+    for change in &job.history {
+        println!("         {} {} -> {}", change.at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"), change.from, change.to);
+    }
+    println!("Applied: {}", job.date_applied.format("%Y-%m-%d"));
+    if let Some(created) = job.created_at {
+        println!("Created: {}", created.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"));
+    }
+    if let Some(updated) = job.updated_at.filter(|u| Some(*u) != job.created_at) {
+        println!("Updated: {}", updated.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"));
+    }
+    if !job.post_link.is_empty() {
+        println!("Link:    {}", job.post_link);
+    }
+    for link in &job.links {
+        println!("         {}: {}", link.name, link.url);
+    }
+    if job.engagement.is_contract() {
+        let terms = job.contract.as_ref().map(|t| t.describe()).unwrap_or_default();
+        println!("Type:    {} {}", job.engagement, terms);
+    }
+    if let Some(location) = job.location_label() {
+        println!("Where:   {}", location);
+    }
+    if let Some(tz) = job.tz() {
+        println!("Zone:    {}, {}", tz.name(), crate::timezone::describe(tz, Utc::now()));
+    }
+    if let Some(salary) = job.salary_label() {
+        println!("Salary:  {}", salary);
+    }
+    if let Some(disclosure) = &job.pay_disclosure {
+        println!("Pay law: {}", disclosure.label());
+    }
+    if !job.contact.is_empty() {
+        let label = job.contact.label();
+        println!("Contact: {}", label);
+        if !job.contact.linkedin.is_empty() && label != job.contact.linkedin {
+            println!("         {}", job.contact.linkedin);
+        }
+    }
+    let people: Vec<String> = crate::network::linked(&load_network()?, job).iter().map(|p| p.label()).collect();
+    if !people.is_empty() {
+        println!("People:  {}", people.join(", "));
+    }
+    if let Some(agency) = &job.agency {
+        println!("Agency:  {}", agency);
+    }
+    if let Some(source) = &job.source {
+        println!("Source:  {}", source);
+    }
+    if !job.resume_version.is_empty() {
+        let missing = if job.resume_missing() { " (file not found)" } else { "" };
+        println!("Resume:  {}{}", job.resume_version, missing);
+    }
+    if let Some(letter) = job.cover_letter_label() {
+        println!("Letter:  {}", letter);
+    }
+    if let Some(onboarding) = &job.onboarding {
+        let (done, total) = onboarding.progress();
+        let start = onboarding.start_date.map(|d| format!(", starts {}", d)).unwrap_or_default();
+        println!("Onboard: {}/{} done{}", done, total, start);
+    }
+    if let Some(relocation) = &job.relocation {
+        let money = |a: Option<f64>| a.map(|a| format!("{:.0}", a)).unwrap_or_else(|| "-".to_string());
+        println!(
+            "Move:    {} (package {}, moving cost {})",
+            relocation.city,
+            money(relocation.package),
+            money(relocation.moving_cost)
+        );
+    }
+    if !job.tags.is_empty() {
+        let tags: Vec<String> = job.tags.iter().map(|t| format!("#{}", t)).collect();
+        println!("Tags:    {}", tags.join(" "));
+    }
+    if !job.skills.is_empty() {
+        println!("Skills:  {}", job.skills.join(", "));
+    }
+    if let Some(fit) = crate::skills::fit(job, &load_config()?.skills) {
+        println!("Fit:     {}", fit.label());
+    }
+    if let Some(cycle) = &job.cycle {
+        println!("Cycle:   {}", cycle);
+    }
+    if let Some(deadline) = job.deadline {
+        println!("Due:     {}", deadline);
+    }
+    if let Some(date) = job.reply_by {
+        let late = if job.reply_overdue(chrono::Local::now().date_naive()) { " (no reply yet)" } else { "" };
+        println!("Reply:   by {}{}", date, late);
+    }
+    let config = load_config()?;
+    let history = match config.stats.select(&jobs, &archived_cohorts_or_warn(), &config.statuses) {
+        Ok(selection) => selection.jobs,
+        Err(err) => {
+            eprintln!("Warning: counting every job, [stats] in config.toml: {:#}", err);
+            jobs.clone()
+        }
+    };
+    let calendar = HolidayCalendar::from_config(&config.metrics)?;
+    let today = chrono::Local::now().date_naive();
+    if let Some(wait) = response_wait(job, &history, config.metrics.business_days, &calendar, today) {
+        println!("Waiting: {}", wait.label());
+    }
+    if let Some(at) = job.follow_up {
+        match job.tz().filter(|_| at.time() != chrono::NaiveTime::MIN) {
+            Some(tz) => println!("Nudge:   {} their time", at.with_timezone(&tz).format("%Y-%m-%d %H:%M %Z")),
+            None => println!("Nudge:   {}", at.format("%Y-%m-%d")),
+        }
+    }
+    if let Some(start) = job.offered_start() {
+        match crate::offers::timeline(job, &load_config()?.notice).filter(|_| job.status == Status::Offer) {
+            Some(t) => println!("Start:   {} (resign by {}, decide by {})", start, t.resign_by, t.decide_by),
+            None => println!("Start:   {}", start),
+        }
+    }
+    for interview in &job.interviews {
+        match interview.interviewers.as_slice() {
+            [] => println!("Interview: {}", interview.when()),
+            people => println!("Interview: {} with {}", interview.when(), people.join(", ")),
+        }
+        if let Some(path) = &interview.attachment {
+            println!("         attached {}", path.display());
+        }
+        if let Some(debrief) = &interview.debrief {
+            if !debrief.summary().is_empty() {
+                println!("         debrief: {}", debrief.summary());
+            }
+            if !debrief.questions.is_empty() {
+                println!("         asked: {}", debrief.questions);
+            }
+        }
+        for expense in &interview.expenses {
+            let paid = if expense.reimbursed { "reimbursed" } else { "owed" };
+            println!("         {} {:.2} ({})", expense.description, expense.amount, paid);
+        }
+    }
+    for assessment in &job.assessments {
+        let state = match (assessment.completed, assessment.expires) {
+            (Some(date), _) => format!("taken {}", date),
+            (None, Some(date)) => format!("expires {}", date),
+            (None, None) => "open".to_string(),
+        };
+        println!("OA:      {} (received {}, {})", assessment.platform, assessment.received, state);
+    }
+    if let Some((done, total)) = job.stage_progress() {
+        let template = job.loop_template.as_deref().unwrap_or("custom");
+        println!("Loop:    {} ({} of {} stages complete)", template, done, total);
+        for stage in &job.stages {
+            let kind = if stage.kind == StageKind::Assessment { " (OA)" } else { "" };
+            println!("         [{}] {}{}", if stage.done { "x" } else { " " }, stage.name, kind);
+        }
+    }
+    if let Some(company) = record.filter(|c| !c.notes.is_empty()) {
+        println!("\nAbout {}:\n{}", company.name, company.notes);
+    }
+    if !job.notes.is_empty() {
+        println!("\n{}", job.notes);
+    }
+    Ok(())
+}
+
+fn add(args: &[String]) -> Result<()> {
+    let (separate, args) = match args {
+        [flag, rest @ ..] if flag == "--new" => (true, rest),
+        _ => (false, args),
+    };
+    let stdin = args == ["--stdin"];
+    let fields: Vec<String> = if stdin {
+        read_stdin()?
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect()
+    } else {
+        args.to_vec()
+    };
+
+    // From stdin, anything after the link is the posting, read for its pay
+    let (company, role, link, posting) = match fields.as_slice() {
+        [company, role] => (company, role, String::new(), &[][..]),
+        [company, role, link] => (company, role, link.clone(), &[][..]),
+        [company, role, link, posting @ ..] if stdin => (company, role, link.clone(), posting),
+        _ => bail!("Usage: career-cli add [--new] <company> <role> [link]"),
+    };
+    validate::company(company)?;
+    validate::link(&link)?;
+    let posting = posting.join("\n");
+    let request = Request::Add { company: company.clone(), role: role.clone(), link: link.clone(), posting: posting.clone(), separate };
+    if let Some(reply) = ipc::send(&request)? {
+        println!("{}", reply);
+        return Ok(());
+    }
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let mut records = load_companies()?;
+    let company = file_company(&mut records, &jobs, company)?;
+    let mut new_job = Job::from_posting(JobId::new(), company, role.clone(), link, &posting);
+    new_job.cycle = load_config()?.season.current_cycle();
+    if !separate && let Some(i) = crate::fingerprint::find(&jobs, &new_job) {
+        let filled = crate::fingerprint::add_listing(&mut jobs[i], &new_job, chrono::Local::now().date_naive());
+        save_jobs(&base, &jobs, Source::Edit)?;
+        println!(
+            "Already tracking {}  {}, kept this as another listing of it (add --new to add it anyway)",
+            jobs[i].id_prefix(short_id_len(&jobs)),
+            crate::fingerprint::merged(&jobs[i], &filled)
+        );
+        return Ok(());
+    }
+    jobs.push(new_job.clone());
+    let saved = save_jobs(&base, &jobs, Source::Edit)?;
+
+    // The id can change if another session added a job at the same moment
+    let job = saved
+        .iter()
+        .find(|j| j.date_applied == new_job.date_applied && j.company == new_job.company)
+        .context("Job was not saved")?;
+    let id = job.id_prefix(short_id_len(&saved));
+    match job.salary_label() {
+        Some(pay) => println!("Added {}  {} - {} ({})", id, job.company, job.role, pay),
+        None => println!("Added {}  {} - {}", id, job.company, job.role),
+    }
+    Ok(())
+}
+
+fn import(args: &[String]) -> Result<()> {
+    let [path] = args else {
+        bail!("Usage: career-cli import <file.csv>");
+    };
+    let text = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    let (jobs, skipped) = inbox::parse_csv(&text)?;
+    for reason in &skipped {
+        eprintln!("Skipped {}", reason);
+    }
+    let mut pending = inbox::load_inbox()?;
+    let base = load_jobs()?;
+    let mut tracked = base.clone();
+    // Postings already tracked become listings of theirs instead, see fingerprint.rs
+    let (jobs, again) = crate::fingerprint::sift(&mut tracked, &mut pending, jobs, chrono::Local::now().date_naive());
+    if !again.is_empty() {
+        save_jobs(&base, &tracked, Source::Import)?;
+        for merged in &again {
+            println!("Already tracked, kept as another listing: {}", merged);
+        }
+    }
+    let count = jobs.len();
+    inbox::queue(&mut pending, jobs);
+    check_no_tui()?;
+    inbox::save_inbox(&pending)?;
+    println!("Queued {} for review, {} waiting. See them with career-cli inbox or 'm' in the TUI.", count, pending.len());
+    Ok(())
+}
+
+fn inbox_command(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli inbox [accept <n>|all | discard <n>|all]";
+    let mut pending = inbox::load_inbox()?;
+    let (accept, which) = match args {
+        [] => {
+            if pending.is_empty() {
+                println!("Nothing waiting for review");
+            }
+            for (n, job) in pending.iter().enumerate() {
+                let line = format!("#{:<3} {}  {} - {}  {}", n + 1, job.date_applied.format("%Y-%m-%d"), job.company, job.role, job.post_link);
+                println!("{}", line.trim_end());
+            }
+            return Ok(());
+        }
+        [command, which] if command == "accept" => (true, which),
+        [command, which] if command == "discard" => (false, which),
+        _ => bail!(usage),
+    };
+    let picked: Vec<Job> = if which == "all" {
+        std::mem::take(&mut pending)
+    } else {
+        let n: usize = which.trim_start_matches('#').parse().context(usage)?;
+        if n == 0 || n > pending.len() {
+            bail!("Nothing in the inbox numbered {}", n);
+        }
+        vec![pending.remove(n - 1)]
+    };
+
+    if accept {
+        let base = load_jobs()?;
+        let mut jobs = base.clone();
+        let cycle = load_config()?.season.current_cycle();
+        let mut records = load_companies()?;
+        for mut job in picked.iter().cloned() {
+            job.company = file_company(&mut records, &jobs, &job.company)?;
+            job.revision = 0;
+            job.cycle = job.cycle.or_else(|| cycle.clone());
+            jobs.push(job);
+        }
+        save_jobs(&base, &jobs, Source::Import)?;
+    }
+    // The jobs are saved before they leave the inbox, so a failure keeps them
+    check_no_tui()?;
+    inbox::save_inbox(&pending)?;
+    let verb = if accept { "Accepted" } else { "Discarded" };
+    for job in &picked {
+        println!("{} {} - {}", verb, job.company, job.role);
+    }
+    Ok(())
+}
+
+/// `name` as it's spelled on file, giving it a record if it's new
+fn file_company(records: &mut Vec<Company>, jobs: &[Job], name: &str) -> Result<String> {
+    let name = companies::canonical(records, jobs, name);
+    if companies::find(records, &name).is_none() {
+        records.push(Company::new(&name));
+        check_no_tui()?;
+        save_companies(records)?;
+    }
+    Ok(name)
+}
+
+fn list_companies() -> Result<()> {
+    let records = load_companies()?;
+    if records.is_empty() {
+        println!("No companies on file yet, they're added with their first job");
+    }
+    let jobs = load_jobs()?;
+    for company in &records {
+        let key = normalize_company(&company.name);
+        let roles = jobs.iter().filter(|j| normalize_company(&j.company) == key).count();
+        let line = format!(
+            "{:<24} {:<28} {} role{}  {}",
+            company.name,
+            company.website,
+            roles,
+            if roles == 1 { "" } else { "s" },
+            company.notes.lines().next().unwrap_or("")
+        );
+        println!("{}", line.trim_end());
+    }
+    Ok(())
+}
+
+fn company(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli company <name> [website|notes <value>]";
+    let Some(name) = args.first() else {
+        bail!(usage);
+    };
+    let mut records = load_companies()?;
+    let jobs = load_jobs()?;
+    let name = companies::canonical(&records, &jobs, name);
+    let known = companies::find(&records, &name).is_some() || jobs.iter().any(|j| j.company == name);
+    if !known {
+        bail!("No company called {} on file", name);
+    }
+    match &args[1..] {
+        [] => {
+            let company = companies::find(&records, &name).cloned().unwrap_or_else(|| Company::new(&name));
+            println!("Company: {}", company.name);
+            if !company.website.is_empty() {
+                println!("Website: {}", company.website);
+            }
+            for (cohort, job) in company_history(&load_archived_cohorts()?, &jobs, &name) {
+                println!("         {} {} - {} ({}, {})", job.date_applied.format("%Y-%m-%d"), job.company, job.role, job.status_label(), cohort);
+            }
+            if !company.notes.is_empty() {
+                println!("\n{}", company.notes);
+            }
+        }
+        [field, value @ ..] => {
+            let value = value.join(" ").trim().to_string();
+            let record = companies::entry(&mut records, &name);
+            match field.as_str() {
+                "website" => {
+                    validate::link(&value)?;
+                    record.website = value;
+                }
+                "notes" => record.notes = value,
+                _ => bail!(usage),
+            }
+            check_no_tui()?;
+            save_companies(&records)?;
+            println!("Updated {}", name);
+        }
+    }
+    Ok(())
+}
+
+fn note(args: &[String]) -> Result<()> {
+    let [query, words @ ..] = args else {
+        bail!("Usage: career-cli note <id> <text>");
+    };
+    let text = if words == ["--stdin"] {
+        read_stdin()?
+    } else {
+        words.join(" ")
+    };
+    if text.trim().is_empty() {
+        bail!("Usage: career-cli note <id> <text>");
+    }
+    if let Some(reply) = ipc::send(&Request::Note { job: query.clone(), text: text.clone() })? {
+        println!("{}", reply);
+        return Ok(());
+    }
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    jobs[i].append_note(&text);
+    save_jobs(&base, &jobs, Source::Edit)?;
+
+    println!("Added note to {} - {}", jobs[i].company, jobs[i].role);
+    Ok(())
+}
+
+fn description(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli description <id> [--stdin | --fetch | --diff]";
+    let (query, flag) = match args {
+        [query] => (query, None),
+        [query, flag] if ["--stdin", "--fetch", "--diff"].contains(&flag.as_str()) => (query, Some(flag.as_str())),
+        _ => bail!(usage),
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let text = match flag {
+        None => {
+            if jobs[i].description.is_empty() {
+                println!("No posting text kept for {} - {}", jobs[i].company, jobs[i].role);
+            } else {
+                println!("{}", jobs[i].description);
+            }
+            return Ok(());
+        }
+        Some("--diff") => {
+            let cohorts = load_archived_cohorts()?;
+            let history = company_history(&cohorts, &jobs, &jobs[i].company);
+            let Some((cohort, earlier)) = crate::posting::earlier(&history, &jobs[i]) else {
+                bail!("No earlier application to {} kept its posting text", jobs[i].company);
+            };
+            let changes = crate::posting::diff(&earlier.description, &jobs[i].description);
+            println!(
+                "{} ({}, {}) -> {}: {}",
+                earlier.role,
+                earlier.date_applied.format("%Y-%m-%d"),
+                cohort,
+                jobs[i].role,
+                crate::posting::summary(&changes)
+            );
+            for (change, line) in changes {
+                let mark = match change {
+                    crate::posting::Change::Added => '+',
+                    crate::posting::Change::Removed => '-',
+                    crate::posting::Change::Same => ' ',
+                };
+                println!("{} {}", mark, line);
+            }
+            return Ok(());
+        }
+        Some("--stdin") => read_stdin()?,
+        _ => crate::posting::fetch(&jobs[i].post_link)?,
+    };
+    if text.trim().is_empty() {
+        bail!("Nothing to keep, the text is empty");
+    }
+    jobs[i].description = text.trim().to_string();
+    let cohorts = load_archived_cohorts()?;
+    let earlier = {
+        let history = company_history(&cohorts, &jobs, &jobs[i].company);
+        crate::posting::earlier(&history, &jobs[i]).map(|(_, job)| job.clone())
+    };
+    crate::evergreen::snapshot(&mut jobs[i], earlier.as_ref(), chrono::Local::now().date_naive());
+    save_jobs(&base, &jobs, Source::Edit)?;
+    println!("Kept {} lines of posting text for {} - {}", jobs[i].description.lines().count(), jobs[i].company, jobs[i].role);
+    if let Some(label) = crate::evergreen::label(&jobs[i], &load_config()?.evergreen) {
+        println!("Posting: {}", label);
+    }
+    if let Some(earlier) = &earlier {
+        let changes = crate::posting::diff(&earlier.description, &jobs[i].description);
+        println!(
+            "Against the {} posting from {}: {} (career-cli description {} --diff)",
+            earlier.role,
+            earlier.date_applied.format("%Y-%m-%d"),
+            crate::posting::summary(&changes),
+            query
+        );
+    }
+    Ok(())
+}
+
+fn links(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli links <id> [add <name> <url> | remove <name>]";
+    let [query, rest @ ..] = args else {
+        bail!(usage);
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let job = &mut jobs[i];
+    match rest {
+        [] => {
+            for (name, url) in job.all_links() {
+                println!("{:<16} {}", name, url);
+            }
+            return Ok(());
+        }
+        [command, words @ ..] if command == "add" => {
+            let link = NamedLink::parse(&words.join(" ")).context(usage)?;
+            crate::validate::link(&link.url)?;
+            println!("Added {} to {} - {}", link.name, job.company, job.role);
+            job.links.push(link);
+        }
+        [command, words @ ..] if command == "remove" && !words.is_empty() => {
+            let name = words.join(" ");
+            let Some(n) = job.links.iter().position(|l| l.name.eq_ignore_ascii_case(&name)) else {
+                bail!("{} - {} has no link named {}", job.company, job.role, name);
+            };
+            job.links.remove(n);
+            println!("Removed {} from {} - {}", name, job.company, job.role);
+        }
+        _ => bail!(usage),
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    Ok(())
+}
+
+fn files(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli files <id> [add <path> | remove <name> | open <name>]";
+    let [query, rest @ ..] = args else {
+        bail!(usage);
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let job = &mut jobs[i];
+    // A file by its name or path, "offer" finding ~/Downloads/offer.pdf
+    let find = |job: &Job, name: &str| -> Result<usize> {
+        let name = name.to_lowercase();
+        let named = |path: &String| {
+            let file = std::path::Path::new(path).file_name().map(|f| f.to_string_lossy().to_lowercase()).unwrap_or_default();
+            *path == name || file == name || file.starts_with(&name)
+        };
+        match job.files.iter().position(named) {
+            Some(n) => Ok(n),
+            None => bail!("{} - {} has no file named {}", job.company, job.role, name),
+        }
+    };
+    match rest {
+        [] => {
+            if job.files.is_empty() {
+                println!("No files kept with {} - {}", job.company, job.role);
+            }
+            for path in &job.files {
+                println!("{}", crate::models::describe_file(path));
+            }
+            return Ok(());
+        }
+        [command, words @ ..] if command == "add" && !words.is_empty() => {
+            let input = words.join(" ");
+            let path = crate::models::attachment_path(&input).with_context(|| format!("No file at {}", input))?;
+            if job.files.contains(&path) {
+                bail!("{} is already kept with {} - {}", path, job.company, job.role);
+            }
+            println!("Kept {} with {} - {}", path, job.company, job.role);
+            job.files.push(path);
+        }
+        [command, words @ ..] if command == "remove" && !words.is_empty() => {
+            let n = find(job, &words.join(" "))?;
+            let path = job.files.remove(n);
+            println!("Forgot {} (the file itself is still there)", path);
+        }
+        [command, words @ ..] if command == "open" && !words.is_empty() => {
+            let path = crate::models::home_path(&job.files[find(job, &words.join(" "))?]);
+            if !path.exists() {
+                bail!("{} isn't there anymore", path.display());
+            }
+            open::that(&path).with_context(|| format!("Couldn't open {}", path.display()))?;
+            return Ok(());
+        }
+        _ => bail!(usage),
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    Ok(())
+}
+
+fn answers(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli answers [<id> [add <question>? <answer> | remove <question>]]";
+    let [query, rest @ ..] = args else {
+        // Every question once, in the order first answered, with each answer
+        let jobs = load_jobs()?;
+        let mut questions: Vec<&str> = Vec::new();
+        for answer in jobs.iter().flat_map(|j| &j.answers) {
+            if !questions.iter().any(|q| crate::answers::same_question(q, &answer.question)) {
+                questions.push(&answer.question);
+            }
+        }
+        if questions.is_empty() {
+            println!("No form answers kept yet");
+        }
+        for question in questions {
+            println!("{}", question);
+            for job in &jobs {
+                for answer in job.answers.iter().filter(|a| crate::answers::same_question(&a.question, question)) {
+                    println!("  {} - {}: {}", job.company, job.role, answer.answer);
+                }
+            }
+        }
+        return Ok(());
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    match rest {
+        [] => {
+            let job = &jobs[i];
+            if job.answers.is_empty() {
+                println!("No form answers kept for {} - {}", job.company, job.role);
+            }
+            for answer in &job.answers {
+                println!("{}", answer.question);
+                println!("  {}", answer.answer);
+            }
+            return Ok(());
+        }
+        [command, words @ ..] if command == "add" => {
+            let answer = crate::answers::Answer::parse(&words.join(" ")).context(usage)?;
+            for (other, earlier) in crate::answers::given(&jobs, &jobs[i], &answer.question) {
+                if !earlier.answer.eq_ignore_ascii_case(&answer.answer) {
+                    println!("{} - {} got a different answer: {}", other.company, other.role, earlier.answer);
+                }
+            }
+            println!("Kept the answer to {} for {} - {}", answer.question, jobs[i].company, jobs[i].role);
+            crate::answers::record(&mut jobs[i], answer);
+        }
+        [command, words @ ..] if command == "remove" && !words.is_empty() => {
+            let question = words.join(" ");
+            let job = &mut jobs[i];
+            let Some(n) = job.answers.iter().position(|a| crate::answers::same_question(&a.question, &question)) else {
+                bail!("{} - {} has no answer to {}", job.company, job.role, question);
+            };
+            let answer = job.answers.remove(n);
+            println!("Dropped the answer to {}", answer.question);
+        }
+        _ => bail!(usage),
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    Ok(())
+}
+
+fn follow_up(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli follow-up <id> <when> | follow-up <id> --send [<time>]";
+    let [query, words @ ..] = args else {
+        bail!(usage);
+    };
+    if words.is_empty() {
+        bail!(usage);
+    }
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    if words[0] == "--send" {
+        // The at job stays queued when the follow-up is moved or cleared.
+        // Ones queued before they carried their time go out once it's due.
+        let queued_for = match words.get(1) {
+            Some(time) => chrono::DateTime::parse_from_rfc3339(time).with_context(|| format!("'{}' isn't a time", time))?.with_timezone(&Utc),
+            None => jobs[i].follow_up.unwrap_or_else(Utc::now),
+        };
+        if let Some(reason) = jobs[i].follow_up_held(queued_for, Utc::now()) {
+            println!("Not following up with {} - {}: {}", jobs[i].company, jobs[i].role, reason);
+            return Ok(());
+        }
+        return send_follow_up(&jobs[i]);
+    }
+
+    let when = crate::schedule::parse_when(&words.join(" "), jobs[i].tz(), Utc::now())
+        .context("Follow up at a time still to come, like thu 9am, tomorrow 2pm or 2026-10-22 14:00")?;
+    jobs[i].follow_up = Some(when);
+    save_jobs(&base, &jobs, Source::Edit)?;
+    let queued = crate::schedule::queue(&jobs[i].id.to_string(), when)
+        .context("Saved the follow-up, but couldn't schedule the reminder")?;
+    let local = when.with_timezone(&chrono::Local).format("%a %b %-d %H:%M");
+    match jobs[i].tz() {
+        Some(tz) => println!("Follow up with {} {} their time ({} mine), {}", jobs[i].company, when.with_timezone(&tz).format("%a %b %-d %H:%M %Z"), local, queued),
+        None => println!("Follow up with {} {}, {}", jobs[i].company, local, queued),
+    }
+    Ok(())
+}
+
+/// What the at job runs: a notification, and an email draft to the contact
+/// when there's an address to send it to
+fn send_follow_up(job: &Job) -> Result<()> {
+    let who = if job.contact.name.is_empty() { "the recruiter" } else { job.contact.name.as_str() };
+    send_notification("Follow up", &format!("Nudge {} at {} about {}", who, job.company, job.role))?;
+    if !job.contact.email.is_empty() {
+        let subject = format!("Following up on the {} role", job.role);
+        let draft = url::Url::parse_with_params(&format!("mailto:{}", job.contact.email), &[("subject", subject)])
+            .context("The contact's email doesn't make a mailto link")?;
+        let _ = open::that(draft.as_str());
+    }
+    Ok(())
+}
+
+fn reply_by(args: &[String]) -> Result<()> {
+    let (query, when) = match args {
+        [query, when @ ..] if !when.is_empty() => (query, when.join(" ")),
+        _ => bail!("Usage: career-cli reply-by <id> <date | none>"),
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    jobs[i].reply_by = match when.as_str() {
+        "none" => None,
+        when => Some(
+            crate::models::parse_reply_by(when, chrono::Local::now().date_naive())
+                .with_context(|| format!("'{}' isn't a date, try 2026-03-03, mar 3, friday or 2w", when))?,
+        ),
+    };
+    save_jobs(&base, &jobs, Source::Edit)?;
+    match jobs[i].reply_by {
+        Some(date) => println!("Expecting to hear from {} about {} by {}", jobs[i].company, jobs[i].role, date),
+        None => println!("Cleared the reply date for {} - {}", jobs[i].company, jobs[i].role),
+    }
+    Ok(())
+}
+
+fn skills(args: &[String]) -> Result<()> {
+    let [query, words @ ..] = args else {
+        bail!("Usage: career-cli skills <id> [<skills> | none]");
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    match words.join(" ").trim() {
+        "" => {}
+        "none" => jobs[i].skills.clear(),
+        list => jobs[i].skills = crate::skills::parse(list),
+    }
+    if !words.is_empty() {
+        save_jobs(&base, &jobs, Source::Edit)?;
+    }
+    let job = &jobs[i];
+    if job.skills.is_empty() {
+        println!("No skills listed for {} - {}", job.company, job.role);
+        return Ok(());
+    }
+    println!("Skills:  {}", job.skills.join(", "));
+    match crate::skills::fit(job, &load_config()?.skills) {
+        Some(fit) => println!("Fit:     {}", fit.label()),
+        None => println!("Fit:     list mine under [skills] have in config.toml to score it"),
+    }
+    Ok(())
+}
+
+fn transparency(args: &[String]) -> Result<()> {
+    let [query, words @ ..] = args else {
+        let jobs = load_jobs()?;
+        let report = crate::transparency::report(&jobs);
+        if report.is_empty() {
+            println!("No postings in pay-transparency places recorded yet");
+        }
+        for (jurisdiction, all, missing) in report {
+            println!("{}: {} of {} postings gave no range", jurisdiction, missing.len(), all.len());
+            for job in missing {
+                println!(
+                    "  {}  {:<20} {:<24} {}",
+                    job.date_applied.format("%Y-%m-%d"),
+                    job.company,
+                    job.role,
+                    job.post_link
+                );
+            }
+        }
+        return Ok(());
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    match words.join(" ").trim() {
+        "" => bail!("Usage: career-cli transparency <id> <where> <range | none>"),
+        "clear" => jobs[i].pay_disclosure = None,
+        input => match crate::transparency::parse(input) {
+            Some(Err(message)) => bail!(message),
+            disclosure => jobs[i].pay_disclosure = disclosure.and_then(Result::ok),
+        },
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    let job = &jobs[i];
+    match &job.pay_disclosure {
+        Some(disclosure) => println!("{} - {}: {}", job.company, job.role, disclosure.label()),
+        None => println!("Cleared the pay range record for {} - {}", job.company, job.role),
+    }
+    Ok(())
+}
+
+fn timezone(args: &[String]) -> Result<()> {
+    let (query, zone) = match args {
+        [query] => (query, None),
+        [query, zone @ ..] => (query, Some(zone.join(" "))),
+        _ => bail!("Usage: career-cli timezone <id> [zone | none]"),
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    match zone.as_deref() {
+        None => match jobs[i].tz() {
+            Some(tz) => println!("{}, {}", tz.name(), crate::timezone::describe(tz, Utc::now())),
+            None => println!("No timezone set for {} - {}", jobs[i].company, jobs[i].role),
+        },
+        Some("none") => {
+            jobs[i].timezone = None;
+            save_jobs(&base, &jobs, Source::Edit)?;
+            println!("Cleared the timezone for {} - {}", jobs[i].company, jobs[i].role);
+        }
+        Some(zone) => {
+            let tz = crate::timezone::parse(zone)?;
+            jobs[i].timezone = Some(tz.name().to_string());
+            save_jobs(&base, &jobs, Source::Edit)?;
+            println!("{} - {} is on {}: {}", jobs[i].company, jobs[i].role, tz.name(), crate::timezone::describe(tz, Utc::now()));
+        }
+    }
+    Ok(())
+}
+
+fn cooldowns() -> Result<()> {
+    let jobs = load_jobs()?;
+    let now = Utc::now();
+    let id_len = short_id_len(&jobs);
+    for (job, date) in reapply_windows(&jobs) {
+        let when = if date <= now {
+            "eligible now".to_string()
+        } else {
+            format!("in {} days", (date - now).num_days())
+        };
+        println!(
+            "{}  {:<20} {:<24} {}  ({})",
+            job.id_prefix(id_len),
+            job.company,
+            job.role,
+            date.format("%Y-%m-%d"),
+            when
+        );
+    }
+    Ok(())
+}
+
+fn agenda_command(args: &[String]) -> Result<()> {
+    let notify = match args {
+        [] => false,
+        [flag] if flag == "--notify" => true,
+        _ => bail!("Usage: career-cli agenda [--notify]"),
+    };
+    let jobs = load_jobs()?;
+    let config = load_config()?;
+    let today = chrono::Local::now().date_naive();
+    let when = |days: i64| match days {
+        ..0 => format!("{} days late", -days),
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        _ => format!("in {} days", days),
+    };
+
+    if notify {
+        for due in expiring_assessments(&jobs, today, config.season.oa_warning_days) {
+            let body = format!("{} - {} expires {}", due.job.company, due.label(), due.date);
+            send_notification("OA expiring", &body)?;
+        }
+        for due in agenda(&jobs).iter().filter(|due| matches!(due.kind, DueKind::FollowUp) && due.date == today) {
+            send_notification("Follow up", &format!("Haven't heard from {} about {}? Time to chase", due.job.company, due.job.role))?;
+        }
+        // The day after they said I'd hear back by
+        for due in agenda(&jobs).iter().filter(|due| matches!(due.kind, DueKind::Reply) && due.date.succ_opt() == Some(today)) {
+            let body = format!("{} said you'd hear back about {} by {}", due.job.company, due.job.role, due.date.format("%b %-d"));
+            send_notification("No reply", &body)?;
+        }
+        // Travel days, and when to be at the door on the day itself
+        for due in agenda(&jobs) {
+            let DueKind::Travel(interview) = due.kind else { continue };
+            if due.date == today && interview.date > today {
+                let body = format!("Off to {} for the {} on {}", due.job.company, interview.name, interview.date.format("%b %-d"));
+                send_notification("Travel day", &body)?;
+            }
+            if interview.date == today {
+                let address = interview.travel.as_ref().map_or("", |t| t.address.as_str());
+                let by = crate::travel::arrive_label(interview, &config.interviews).map(|by| format!(" by {}", by));
+                let body = format!("{} {}: be at {}{}", due.job.company, interview.name, address, by.unwrap_or_default());
+                send_notification("Onsite today", body.trim_end())?;
+            }
+        }
+        if let Some((j, i)) = crate::debrief::pending(&jobs, chrono::Local::now().naive_local(), &config.interviews) {
+            let body = format!("How did the {} {} go? Open career-cli to write it down", jobs[j].company, jobs[j].interviews[i].name);
+            send_notification("Interview debrief", &body)?;
+        }
+        for (date, reminder) in upcoming(&config.reminders, today) {
+            let days = (date - today).num_days();
+            match days {
+                ..0 => send_notification("Reminder", &format!("{} was due {} ({})", reminder.name, date.format("%b %-d"), when(days)))?,
+                _ if days <= 1 => send_notification("Reminder", &format!("{} due {}", reminder.name, when(days)))?,
+                _ => {}
+            }
+        }
+        return Ok(());
+    }
+
+    let id_len = short_id_len(&jobs);
+    for due in agenda(&jobs) {
+        let days = (due.date - today).num_days();
+        let when = match days {
+            ..0 if due.is_assessment() => "expired".to_string(),
+            _ => when(days),
+        };
+        println!(
+            "{}  {}  {:<12} {:<18} {:<20} {}",
+            due.job.id_prefix(id_len),
+            due.date,
+            when,
+            due.label(),
+            due.job.company,
+            due.job.role
+        );
+    }
+
+    // Admin paperwork gets its own block, it isn't about any one job
+    let admin = upcoming(&config.reminders, today);
+    if !admin.is_empty() {
+        println!("\nAdmin");
+        for (date, reminder) in admin {
+            let line = format!("      {}  {:<12} {:<39} {}", date, when((date - today).num_days()), reminder.name, reminder.describe());
+            println!("{}", line.trim_end());
+        }
+    }
+    Ok(())
+}
+
+fn reminders(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli reminders [add <name> <date> [every] | remove <name>]";
+    let mut config = load_config()?;
+    match args {
+        [] => {
+            let today = chrono::Local::now().date_naive();
+            for reminder in &config.reminders {
+                let next = match reminder.next_due(today) {
+                    Some(date) if date < today => format!("late since {}", date),
+                    Some(date) => format!("next {}", date),
+                    None => "done".to_string(),
+                };
+                let line = format!("{:<32} {:<16} {}", reminder.name, next, reminder.describe());
+                println!("{}", line.trim_end());
+            }
+            return Ok(());
+        }
+        [command, name, due, rest @ ..] if command == "add" && rest.len() <= 1 => {
+            let due = chrono::NaiveDate::parse_from_str(due, "%Y-%m-%d").context("Dates look like 2026-10-19")?;
+            let every = rest.first().map(|every| Interval::parse(every)).transpose()?;
+            config.reminders.push(AdminReminder { name: name.clone(), due, every, until: None });
+            println!("Added {}", name);
+        }
+        [command, name] if command == "remove" => {
+            let before = config.reminders.len();
+            config.reminders.retain(|r| !r.name.eq_ignore_ascii_case(name));
+            if config.reminders.len() == before {
+                bail!("No reminder named '{}'", name);
+            }
+            println!("Removed {}", name);
+        }
+        _ => bail!(usage),
+    }
+    check_no_tui()?;
+    save_config(&config)
+}
+
+/// Desktop notification through whatever the OS ships with
+fn send_notification(title: &str, body: &str) -> Result<()> {
+    let status = if cfg!(target_os = "macos") {
+        let script = format!("display notification {} with title {}", applescript_string(body), applescript_string(title));
+        std::process::Command::new("osascript").args(["-e", &script]).status()
+    } else {
+        std::process::Command::new("notify-send").args([title, body]).status()
+    };
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        _ => {
+            // No notifier (e.g. over SSH), cron mails stdout instead
+            println!("{}: {}", title, body);
+            Ok(())
+        }
+    }
+}
+
+/// `text` as an AppleScript string literal. Only backslashes and quotes
+/// are escaped there; anything else, newlines and unicode too, goes in as is.
+pub fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn oa(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli oa <id> <platform> [2026-10-31 | 7d] | oa <id> done";
+    let [query, words @ ..] = args else {
+        bail!(usage);
+    };
+    if words.is_empty() {
+        bail!(usage);
+    }
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let job = &mut jobs[i];
+    if words == ["done"] {
+        if !job.complete_assessment() {
+            bail!("{} - {} has no open OA", job.company, job.role);
+        }
+        println!("Marked the OA for {} - {} as taken", job.company, job.role);
+    } else {
+        let (platform, expires) = Assessment::parse_invite(&words.join(" "), chrono::Local::now().date_naive());
+        job.add_assessment(&platform, expires);
+        match expires {
+            Some(date) => println!("Added {} OA for {}, expires {}", platform, job.company, date),
+            None => println!("Added {} OA for {}", platform, job.company),
+        }
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    Ok(())
+}
+
+fn interview(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli interview <id> [date] [time] <name> [with <people>] [--yes]";
+    let (yes, args) = match args {
+        [rest @ .., flag] if flag == "--yes" || flag == "-y" => (true, rest),
+        _ => (false, args),
+    };
+    let [query, words @ ..] = args else {
+        bail!(usage);
+    };
+    if words.is_empty() {
+        bail!(usage);
+    }
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let interview = Interview::parse(&words.join(" "), chrono::Local::now().date_naive());
+    let clashes = interview_clashes(&jobs, &interview, &load_config()?.interviews);
+    if !clashes.is_empty() {
+        for clash in &clashes {
+            println!("Careful: {}", clash.describe());
+        }
+        if !yes && !confirm("Add it anyway?")? {
+            return Ok(());
+        }
+    }
+    println!("Logged {} on {} for {} - {}", interview.name, interview.when(), jobs[i].company, jobs[i].role);
+    jobs[i].add_interview(interview);
+    save_jobs(&base, &jobs, Source::Edit)?;
+    Ok(())
+}
+
+fn briefing(args: &[String]) -> Result<()> {
+    let (query, output) = match args {
+        [query] => (query, None),
+        [query, flag, path] if flag == "--output" || flag == "-o" => (query, Some(PathBuf::from(path))),
+        _ => bail!("Usage: career-cli briefing <id> [--output <file.md>]"),
+    };
+    let jobs = load_jobs()?;
+    let cohorts = archived_cohorts_or_warn();
+    let job = &jobs[resolve(&jobs, query)?];
+    let history = company_history(&cohorts, &jobs, &job.company);
+    let lines = crate::briefing::briefing(job, &history, &load_config()?.interviews, chrono::Local::now().date_naive());
+    match output {
+        Some(path) => {
+            std::fs::write(&path, lines.join("\n") + "\n").with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Wrote {}", path.display());
+        }
+        None => println!("{}", lines.join("\n")),
+    }
+    Ok(())
+}
+
+fn travel(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli travel <id> [--on <date|stage>] [address <text> | book <ref> | depart <date> | early <minutes> | none]";
+    let (query, selector, rest) = match args {
+        [query, flag, selector, rest @ ..] if flag == "--on" => (query, Some(selector), rest),
+        [query, rest @ ..] => (query, None, rest),
+        [] => bail!(usage),
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let today = chrono::Local::now().date_naive();
+    let config = load_config()?;
+    let job = &mut jobs[i];
+    let n = match selector {
+        Some(selector) => match crate::travel::find_interview(&job.interviews, selector, today) {
+            Some(n) => n,
+            None => bail!("{} - {} has no interview on or called '{}'", job.company, job.role, selector),
+        },
+        None => {
+            let Some(next) = crate::briefing::next_interview(job, today).filter(|n| n.date >= today) else {
+                bail!("{} - {} has no interview coming up (career-cli interview to log one)", job.company, job.role);
+            };
+            job.interviews.iter().position(|i| std::ptr::eq(i, next)).unwrap_or_default()
+        }
+    };
+    let interview = &mut job.interviews[n];
+    let value = rest.get(1..).unwrap_or_default().join(" ");
+    match rest.first().map(String::as_str) {
+        None => {
+            let lines = crate::travel::lines(interview, &config.interviews);
+            if lines.is_empty() {
+                println!("Nothing to travel to for the {} on {}", interview.name, interview.date);
+            }
+            for line in lines.iter().skip(2) {
+                println!("{}", line);
+            }
+            return Ok(());
+        }
+        Some("none") => interview.travel = None,
+        Some("address") if !value.is_empty() => interview.travel.get_or_insert_with(Default::default).address = value,
+        Some("book") if !value.is_empty() => interview.travel.get_or_insert_with(Default::default).bookings.push(value),
+        Some("depart") => {
+            let date = crate::models::parse_reply_by(&value, today)
+                .filter(|date| *date <= interview.date)
+                .with_context(|| format!("Set off on or before the {} on {}, e.g. 2026-10-21", interview.name, interview.date))?;
+            interview.travel.get_or_insert_with(Default::default).depart = Some(date);
+        }
+        Some("early") => {
+            let minutes = value.trim_end_matches("min").trim().parse().with_context(|| format!("Minutes look like 30, got '{}'", value))?;
+            interview.travel.get_or_insert_with(Default::default).arrive_minutes = Some(minutes);
+        }
+        _ => bail!(usage),
+    }
+    println!("Travel for the {} on {} at {}", interview.name, interview.date, job.company);
+    save_jobs(&base, &jobs, Source::Edit)?;
+    Ok(())
+}
+
+fn transcript(args: &[String]) -> Result<()> {
+    let [query, path] = args else {
+        bail!("Usage: career-cli transcript <id> <file>");
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let (company, role) = (jobs[i].company.clone(), jobs[i].role.clone());
+    let Some(interview) = jobs[i].latest_interview_mut(chrono::Local::now().date_naive()) else {
+        bail!("{} - {} has no interviews, log one first with `career-cli interview`", company, role);
+    };
+    crate::transcript::attach(interview, std::path::Path::new(path))?;
+    let words = interview.transcript.split_whitespace().count();
+    let name = interview.name.clone();
+    save_jobs(&base, &jobs, Source::Edit)?;
+    if words > 0 {
+        println!("Attached a {} word transcript to {} at {}", words, name, company);
+    } else {
+        println!("Attached a recording to {} at {}", name, company);
+    }
+    Ok(())
+}
+
+fn search(args: &[String]) -> Result<()> {
+    if args.is_empty() {
+        bail!("Usage: career-cli search <words>");
+    }
+    let jobs = load_jobs()?;
+    let hits = crate::transcript::search(&jobs, &args.join(" "));
+    let id_len = short_id_len(&jobs);
+    for hit in &hits {
+        println!(
+            "{}  {:<20} {:<20} {}",
+            hit.job.id_prefix(id_len),
+            hit.job.company,
+            hit.source,
+            hit.line.trim()
+        );
+    }
+    if hits.is_empty() {
+        println!("Nothing found");
+    }
+    Ok(())
+}
+
+fn expense(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli expense <id> <description> <amount>";
+    let [query, words @ ..] = args else {
+        bail!(usage);
+    };
+    let expense = Expense::parse(&words.join(" ")).context(usage)?;
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let (description, amount) = (expense.description.clone(), expense.amount);
+    if !jobs[i].add_expense(expense, chrono::Local::now().date_naive()) {
+        bail!("{} - {} has no interviews, log one first with `career-cli interview`", jobs[i].company, jobs[i].role);
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    println!("Added {} {:.2} to {}", description, amount, jobs[i].company);
+    Ok(())
+}
+
+fn expenses(args: &[String]) -> Result<()> {
+    match args {
+        [] => {
+            let jobs = load_jobs()?;
+            let outstanding = outstanding_expenses(&jobs);
+            let id_len = short_id_len(&jobs);
+            for o in &outstanding {
+                println!(
+                    "{}  {}  {:<20} {:<16} {:<24} {:>9.2}",
+                    o.job.id_prefix(id_len),
+                    o.interview.date,
+                    o.job.company,
+                    o.interview.name,
+                    o.expense.description,
+                    o.expense.amount
+                );
+            }
+            let total: f64 = outstanding.iter().map(|o| o.expense.amount).sum();
+            println!("Owed: {:.2} across {} expenses", total, outstanding.len());
+            Ok(())
+        }
+        [command, query] if command == "reimburse" => {
+            let base = load_jobs()?;
+            let mut jobs = base.clone();
+            let i = resolve(&jobs, query)?;
+            let mut total = 0.0;
+            for expense in jobs[i].interviews.iter_mut().flat_map(|i| &mut i.expenses).filter(|e| !e.reimbursed) {
+                expense.reimbursed = true;
+                total += expense.amount;
+            }
+            save_jobs(&base, &jobs, Source::Edit)?;
+            println!("Marked {:.2} from {} as reimbursed", total, jobs[i].company);
+            Ok(())
+        }
+        _ => bail!("Usage: career-cli expenses [reimburse <id>]"),
+    }
+}
+
+fn onboarding(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli onboarding <id> [done <n> | start <date> | due <n> <date> | add <item>]";
+    let [query, command @ ..] = args else {
+        bail!(usage);
+    };
+    let date = |text: &str| chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d").context("Dates look like 2026-11-02");
+    let item = |text: &str| text.parse::<usize>().ok().filter(|n| *n > 0).context("Items are numbered from 1");
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    if jobs[i].status != Status::Accepted {
+        bail!("{} - {} isn't Accepted yet", jobs[i].company, jobs[i].role);
+    }
+    let onboarding = jobs[i].onboarding.get_or_insert_with(Default::default);
+    match command {
+        [] => {}
+        [verb, n] if verb == "done" => {
+            let n = item(n)?;
+            onboarding.checklist.get_mut(n - 1).context("No such item")?.done = true;
+        }
+        [verb, when] if verb == "start" => onboarding.start_date = Some(date(when)?),
+        [verb, n, when] if verb == "due" => {
+            let n = item(n)?;
+            onboarding.checklist.get_mut(n - 1).context("No such item")?.due = Some(date(when)?);
+        }
+        [verb, name @ ..] if verb == "add" && !name.is_empty() => {
+            onboarding.checklist.push(ChecklistItem { name: name.join(" "), done: false, due: None })
+        }
+        _ => bail!(usage),
+    }
+
+    let onboarding = onboarding.clone();
+    if !command.is_empty() {
+        save_jobs(&base, &jobs, Source::Edit)?;
+    }
+    match onboarding.start_date {
+        Some(date) => println!("{} - starts {}", jobs[i].company, date),
+        None => println!("{} - no start date yet", jobs[i].company),
+    }
+    for (n, item) in onboarding.checklist.iter().enumerate() {
+        let due = item.due.map(|d| format!("  due {}", d)).unwrap_or_default();
+        println!("{:>3}. [{}] {}{}", n + 1, if item.done { "x" } else { " " }, item.name, due);
+    }
+    Ok(())
+}
+
+fn applied(args: &[String]) -> Result<()> {
+    let [query, when @ ..] = args else {
+        bail!("Usage: career-cli applied <id> <date>");
+    };
+    let today = chrono::Local::now().date_naive();
+    let date = parse_applied(&when.join(" "), today).context("Applied dates look like 2026-10-01, yesterday or 3d ago")?;
+    let at = date.and_time(chrono::NaiveTime::MIN).and_utc();
+    validate::past("Applied date", date, today, &load_config()?.validation)?;
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    jobs[i].date_applied = at;
+    save_jobs(&base, &jobs, Source::Edit)?;
+    println!("{} - {} applied {}", jobs[i].company, jobs[i].role, date);
+    Ok(())
+}
+
+fn rejected(args: &[String]) -> Result<()> {
+    let [query, rest @ ..] = args else {
+        bail!("Usage: career-cli rejected <id> [resume|phone|oa|onsite] [reason]");
+    };
+    // A first word that isn't a stage starts the reason
+    let (stage, reason) = match rest.split_first() {
+        Some((first, reason)) if RejectionStage::parse(first).is_some() => (RejectionStage::parse(first), reason),
+        _ => (None, rest),
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let job = &mut jobs[i];
+    if job.status != Status::Rejected {
+        job.set_status(Status::Rejected);
+    }
+    job.rejected_stage = stage.or(job.rejected_stage);
+    if !reason.is_empty() {
+        job.rejection_reason = reason.join(" ");
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    let job = &jobs[i];
+    match job.rejection_label() {
+        Some(label) => println!("{} - {} rejected ({})", job.company, job.role, label),
+        None => println!("{} - {} rejected", job.company, job.role),
+    }
+    Ok(())
+}
+
+fn start_date(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli start <id> <date|none>";
+    let [query, when] = args else {
+        bail!(usage);
+    };
+    let date = match when.as_str() {
+        "none" => None,
+        when => Some(chrono::NaiveDate::parse_from_str(when, "%Y-%m-%d").context("Dates look like 2026-12-01")?),
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    jobs[i].desired_start = date;
+    save_jobs(&base, &jobs, Source::Edit)?;
+    match date {
+        Some(date) => println!("{} - {} starts {}", jobs[i].company, jobs[i].role, date),
+        None => println!("Cleared the start date for {} - {}", jobs[i].company, jobs[i].role),
+    }
+    Ok(())
+}
+
+fn offers() -> Result<()> {
+    let jobs = load_jobs()?;
+    let config = load_config()?;
+    let matrix = crate::offers::comparison(&jobs, &config.notice, &config.currency, chrono::Local::now().date_naive());
+    if matrix.columns.is_empty() {
+        println!("No offers yet.");
+        return Ok(());
+    }
+    for line in matrix.lines() {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn availability() -> Result<()> {
+    let config = load_config()?;
+    if config.availability.windows.is_empty() {
+        bail!("No [availability] windows in config.toml yet");
+    }
+    match crate::availability::message(&config.availability, &load_jobs()?, chrono::Local::now().date_naive(), &config.interviews) {
+        Some(message) => println!("{}", message),
+        None => println!("Every window in the next two weeks already has an interview."),
+    }
+    Ok(())
+}
+
+fn list_references() -> Result<()> {
+    let references = load_references()?;
+    if references.is_empty() {
+        println!("No references yet. Add one with `career-cli reference add`.");
+        return Ok(());
+    }
+    let jobs = load_jobs()?;
+    for reference in &references {
+        let last_used = reference.last_used().map(|d| d.to_string()).unwrap_or_else(|| "never".to_string());
+        println!(
+            "{:<20} {:<24} {:<28} last used {}, {} open",
+            reference.name,
+            reference.relationship,
+            reference.contact,
+            last_used,
+            reference.active_uses(&jobs).len()
+        );
+        if !reference.given.is_empty() {
+            let companies: Vec<&str> = reference.given.iter().map(|g| g.company.as_str()).collect();
+            println!("{:<20} given to {}", "", companies.join(", "));
+        }
+    }
+    Ok(())
+}
+
+fn reference(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli reference add <name> <relationship> <contact> | give <name> <id> [--yes] | asked <name>";
+    let mut references = load_references()?;
+    let today = chrono::Local::now().date_naive();
+    match args {
+        [command, name, relationship, contact] if command == "add" => {
+            if references.iter().any(|r| r.name.eq_ignore_ascii_case(name.trim())) {
+                bail!("There's already a reference named {}", name);
+            }
+            references.push(Reference::new(name, relationship, contact));
+            check_no_tui()?;
+            save_references(&references)?;
+            println!("Added {}", name.trim());
+        }
+        [command, name, query, flags @ ..] if command == "give" => {
+            let yes = match flags {
+                [] => false,
+                [flag] if flag == "--yes" || flag == "-y" => true,
+                _ => bail!(usage),
+            };
+            let r = find_reference(&references, name)?;
+            let jobs = load_jobs()?;
+            let job = &jobs[resolve(&jobs, query)?];
+            if let Some(warning) = references[r].overuse_warning(&jobs, job) {
+                println!("{}", warning);
+                if !yes && !confirm("Give them anyway?")? {
+                    return Ok(());
+                }
+            }
+            references[r].give(job, today);
+            check_no_tui()?;
+            save_references(&references)?;
+            println!("Gave {} to {} - {}", references[r].name, job.company, job.role);
+        }
+        [command, name] if command == "asked" => {
+            let r = find_reference(&references, name)?;
+            references[r].asked_on = Some(today);
+            check_no_tui()?;
+            save_references(&references)?;
+            println!("{} is happy to take another call", references[r].name);
+        }
+        _ => bail!(usage),
+    }
+    Ok(())
+}
+
+fn outreach(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli outreach [add <company> <contact> [date] | replied|declined|referred <company | contact> | applied <company | contact> <id>]";
+    let mut outreach = load_outreach()?;
+    let jobs = load_jobs()?;
+    let today = chrono::Local::now().date_naive();
+    match args {
+        [] => {
+            if outreach.is_empty() {
+                println!("No outreach yet. Record a message with `career-cli outreach add`.");
+                return Ok(());
+            }
+            for o in &outreach {
+                println!("{} {:<20} {:<28} {}", o.sent, o.company, o.contact, o.progress(&jobs));
+            }
+            println!("\n{}", crate::outreach::funnel(&outreach, &jobs).label());
+            return Ok(());
+        }
+        [command, company, contact, when @ ..] if command == "add" => {
+            let sent = match when {
+                [] => today,
+                _ => parse_applied(&when.join(" "), today).context("Dates look like 2026-10-01, yesterday or 3d ago")?,
+            };
+            outreach.push(Outreach::new(company, contact, sent));
+            println!("Sent to {} at {} on {}", contact.trim(), company.trim(), sent);
+        }
+        [command, query] if command == "replied" || command == "declined" => {
+            let i = find_outreach(&outreach, query)?;
+            outreach[i].reply = if command == "replied" { Reply::Replied } else { Reply::Declined };
+            println!("{} {}", outreach[i].contact, command);
+        }
+        [command, query] if command == "referred" => {
+            let i = find_outreach(&outreach, query)?;
+            outreach[i].referred = Some(today);
+            if outreach[i].reply == Reply::Waiting {
+                outreach[i].reply = Reply::Replied;
+            }
+            println!("{} referred me to {}", outreach[i].contact, outreach[i].company);
+        }
+        [command, query, id] if command == "applied" => {
+            let i = find_outreach(&outreach, query)?;
+            let job = &jobs[resolve(&jobs, id)?];
+            outreach[i].job_id = Some(job.id);
+            println!("Message to {} led to {} - {}", outreach[i].contact, job.company, job.role);
+        }
+        _ => bail!(usage),
+    }
+    check_no_tui()?;
+    save_outreach(&outreach)
+}
+
+fn network(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli network [add <name>, <company>, <how we met> | talked <name> [date] | link|unlink <name> <id> | remove <name>]";
+    // Up front, so nothing says it's done before it's refused
+    if !args.is_empty() {
+        check_no_tui()?;
+    }
+    let mut people = load_network()?;
+    let jobs = load_jobs()?;
+    let today = chrono::Local::now().date_naive();
+    match args {
+        [] => {
+            if people.is_empty() {
+                println!("No one in the network yet. Add someone with `career-cli network add`.");
+                return Ok(());
+            }
+            let id_len = short_id_len(&jobs);
+            for person in &people {
+                println!("{:<20} {:<16} {:<28} {}", person.name, person.company, person.met, person.last_contact_label(today));
+                let linked: Vec<String> = jobs
+                    .iter()
+                    .filter(|j| person.jobs.contains(&j.id))
+                    .map(|j| format!("{} - {} ({})", j.company, j.role, j.id_prefix(id_len)))
+                    .collect();
+                if !linked.is_empty() {
+                    println!("{:<20} linked to {}", "", linked.join(", "));
+                }
+            }
+            return Ok(());
+        }
+        [command, words @ ..] if command == "add" => {
+            let person = Person::parse(&words.join(" ")).context(usage)?;
+            println!("Added {} to the network", person.label());
+            people.push(person);
+        }
+        [command, query, when @ ..] if command == "talked" => {
+            let i = find_person(&people, query)?;
+            let date = match when {
+                [] => today,
+                _ => parse_applied(&when.join(" "), today).context("Dates look like 2026-10-01, yesterday or 3d ago")?,
+            };
+            people[i].last_contact = Some(date);
+            println!("Talked to {} on {}", people[i].name, date);
+        }
+        [command, query, id] if command == "link" || command == "unlink" => {
+            let i = find_person(&people, query)?;
+            let job = &jobs[resolve(&jobs, id)?];
+            let linked = people[i].jobs.contains(&job.id);
+            if linked == (command == "link") {
+                println!("{} is already {}ed {} {} - {}", people[i].name, command, if linked { "to" } else { "from" }, job.company, job.role);
+                return Ok(());
+            }
+            people[i].toggle_job(job.id);
+            println!("{} {}ed {} {} - {}", people[i].name, command, if linked { "from" } else { "to" }, job.company, job.role);
+        }
+        [command, query] if command == "remove" => {
+            let i = find_person(&people, query)?;
+            let person = people.remove(i);
+            println!("Removed {} from the network", person.name);
+        }
+        _ => bail!(usage),
+    }
+    save_network(&people)
+}
+
+fn contact(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli contact <id> [name|email|phone|linkedin <value>]";
+    let Some(query) = args.first() else {
+        bail!(usage);
+    };
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let i = resolve(&jobs, query)?;
+    let contact = &mut jobs[i].contact;
+    let value = args.get(2..).unwrap_or_default().join(" ");
+    match args.get(1).map(String::as_str) {
+        None => {
+            for (name, value) in [("Name", &contact.name), ("Email", &contact.email), ("Phone", &contact.phone), ("LinkedIn", &contact.linkedin)] {
+                println!("{:<9}{}", name, value);
+            }
+            return Ok(());
+        }
+        Some("name") => contact.name = value,
+        Some("email") if !value.is_empty() && !value.contains('@') => bail!("'{}' doesn't look like an email address", value),
+        Some("email") => contact.email = value,
+        Some("phone") => contact.phone = value,
+        Some("linkedin") => contact.linkedin = crate::models::linkedin_url(&value),
+        Some(other) => bail!("Unknown contact field '{}' (name, email, phone or linkedin)", other),
+    }
+    save_jobs(&base, &jobs, Source::Edit)?;
+    println!("Updated the contact for {} - {}", jobs[i].company, jobs[i].role);
+    Ok(())
+}
+
+fn agencies() -> Result<()> {
+    let jobs = load_jobs()?;
+    let mut names: Vec<Option<&str>> = jobs.iter().map(|j| j.agency.as_deref()).collect();
+    names.sort();
+    names.dedup();
+
+    println!("{:<24} {:>6} {:>9} {:>10} {:>6}", "Agency", "Jobs", "Responded", "Interviews", "Offers");
+    for name in names {
+        let via: Vec<&Job> = jobs.iter().filter(|j| j.agency.as_deref() == name).collect();
+        let responded = via.iter().filter(|j| j.responded_at.is_some()).count();
+        let interviews = via
+            .iter()
+            .filter(|j| matches!(j.status, Status::Interviewing | Status::Offer | Status::Accepted) || j.stages.iter().any(|s| s.done))
+            .count();
+        let offers = via.iter().filter(|j| matches!(j.status, Status::Offer | Status::Accepted)).count();
+        println!(
+            "{:<24} {:>6} {:>9} {:>10} {:>6}",
+            name.unwrap_or("(direct)"),
+            via.len(),
+            responded,
+            interviews,
+            offers
+        );
+    }
+
+    let mut warned = Vec::new();
+    for job in &jobs {
+        let others = double_submissions(&jobs, job);
+        if others.is_empty() || warned.contains(&job.id) {
+            continue;
+        }
+        warned.extend(others.iter().map(|o| o.id));
+        let agencies: Vec<&str> = std::iter::once(job)
+            .chain(others)
+            .filter_map(|j| j.agency.as_deref())
+            .collect();
+        println!("\nDouble submission risk: {} via {}", job.company, agencies.join(", "));
+    }
+    Ok(())
+}
+
+/// The jobs a stats-like command counts, after the [stats] defaults and
+/// its --include/--exclude flags, with the other arguments handed back
+fn counted_jobs(config: &crate::config::Config, args: &[String]) -> Result<(Vec<Job>, Vec<String>)> {
+    let (scope, rest) = config.stats.clone().with_flags(args)?;
+    let cohorts = if scope.archived { load_archived_cohorts()? } else { archived_cohorts_or_warn() };
+    let selection = scope.select(&load_jobs()?, &cohorts, &config.statuses)?;
+    if let Some(note) = selection.note() {
+        eprintln!("{} (--include to count them)", note);
+    }
+    Ok((selection.jobs, rest))
+}
+
+fn stats(args: &[String]) -> Result<()> {
+    let config = load_config()?;
+    let (jobs, args) = counted_jobs(&config, args)?;
+    let business_days = match args.as_slice() {
+        [] => config.metrics.business_days,
+        [flag] if flag == "--business-days" => true,
+        [flag] if flag == "--calendar-days" => false,
+        _ => bail!("Usage: career-cli stats [--business-days | --calendar-days] [--include <kinds>] [--exclude <kinds>]"),
+    };
+
+    println!("Total:         {}", jobs.len());
+    for side in [Pipeline::Active, Pipeline::Closed] {
+        println!("{:<14} {}", format!("{:?}:", side), jobs.iter().filter(|j| j.pipeline() == side).count());
+    }
+    for status in Status::ALL {
+        let count = jobs.iter().filter(|j| j.status == status).count();
+        println!("{:<14} {}", format!("{:?}:", status), count);
+    }
+
+    let rejections = rejection_stages(&jobs);
+    if !rejections.is_empty() {
+        let counts: Vec<String> = rejections.iter().map(|(stage, n)| format!("{} {}", stage, n)).collect();
+        println!("Rejected at:   {}", counts.join(", "));
+    }
+
+    // Contract leads compare on day rate, hourly ones converted at 8h a day
+    if jobs.iter().any(|j| j.engagement.is_contract()) {
+        println!();
+        for engagement in Engagement::ALL {
+            let of_type: Vec<&Job> = jobs.iter().filter(|j| j.engagement == engagement).collect();
+            if of_type.is_empty() {
+                continue;
+            }
+            let open = of_type.iter().filter(|j| j.is_open()).count();
+            let rates: Vec<f64> = of_type.iter().filter_map(|j| j.contract.as_ref()?.day_rate()).collect();
+            let rate = if rates.is_empty() {
+                String::new()
+            } else {
+                let max = rates.iter().cloned().fold(f64::MIN, f64::max);
+                format!(", day rate avg {:.0}, best {:.0}", rates.iter().sum::<f64>() / rates.len() as f64, max)
+            };
+            println!("{:<14} {} jobs, {} open{}", format!("{}:", engagement), of_type.len(), open, rate);
+        }
+    }
+
+    // Seasons are tracked separately, a summer internship search isn't the new-grad one
+    let mut cycles: Vec<&str> = jobs.iter().filter_map(|j| j.cycle.as_deref()).collect();
+    cycles.sort();
+    cycles.dedup();
+    if !cycles.is_empty() {
+        println!();
+        for cycle in cycles {
+            let in_cycle: Vec<&Job> = jobs.iter().filter(|j| j.cycle.as_deref() == Some(cycle)).collect();
+            let open = in_cycle.iter().filter(|j| j.is_open()).count();
+            let offers = in_cycle.iter().filter(|j| matches!(j.status, Status::Offer | Status::Accepted)).count();
+            println!("{:<24} {} jobs, {} open, {} offers", cycle, in_cycle.len(), open, offers);
+        }
+    }
+
+    // Cold messages, from sent through to an application
+    let outreach = load_outreach()?;
+    if !outreach.is_empty() {
+        println!("\nOutreach:      {}", crate::outreach::funnel(&outreach, &jobs).label());
+    }
+
+    let calendar = HolidayCalendar::from_config(&config.metrics)?;
+    let mut times = response_times(&jobs, business_days, &calendar);
+    if !jobs.is_empty() {
+        println!(
+            "\nResponse rate: {:.0}% ({} of {})",
+            times.len() as f64 * 100.0 / jobs.len() as f64,
+            times.len(),
+            jobs.len()
+        );
+    }
+    if !times.is_empty() {
+        times.sort();
+        let average = times.iter().sum::<i64>() as f64 / times.len() as f64;
+        let unit = if business_days { "business days" } else { "days" };
+        println!(
+            "Time to response: avg {:.1} {}, median {} {}",
+            average,
+            unit,
+            times[times.len() / 2],
+            unit
+        );
+    }
+
+    let health = pipeline_health(&jobs, &config.metrics.health, &config.evergreen, Utc::now());
+    println!("\nPipeline health: {:.0}/100", health.score);
+    for signal in &health.signals {
+        println!("  {:<17} {:>3.0}%  {}", signal.name, signal.score * 100.0, signal.detail);
+    }
+    let drags: Vec<&str> = health.drags().iter().map(|s| s.name).collect();
+    if !drags.is_empty() {
+        println!("Dragging it down: {}", drags.join(", "));
+    }
+
+    if let Some(runway) = crate::runway::runway(&jobs, &config.runway, chrono::Local::now().date_naive()) {
+        println!();
+        for line in runway.lines() {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+fn usage_command() -> Result<()> {
+    let summary = summarize(&read_usage()?, &load_jobs()?, chrono::Local::now().date_naive());
+    for warning in summary.warnings() {
+        println!("{}\n", warning);
+    }
+    for line in summary.lines() {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn doctor() -> Result<()> {
+    let config = match load_config() {
+        Ok(config) => config,
+        Err(err) => bail!("config.toml: {:#}", err),
+    };
+    let mut problems = 0;
+    let checks = [
+        ("statuses", crate::statuses::validate(&config.statuses)),
+        ("stats", config.stats.validate(&config.statuses)),
+        ("export", config.export.target().map(|_| ())),
+        ("keymap", crate::keymap::Keymap::from_config(&config.keymap).map(|_| ())),
+    ];
+    for (section, result) in checks {
+        if let Err(err) = result {
+            println!("config.toml [{}]: {:#}", section, err);
+            problems += 1;
+        }
+    }
+
+    let jobs = load_jobs()?;
+    let today = chrono::Local::now().date_naive();
+    let id_len = short_id_len(&jobs);
+    for job in &jobs {
+        for problem in validate::job(job, &config.validation, today) {
+            println!("{}  {} - {}  {}", job.id_prefix(id_len), job.company, job.role, problem);
+            problems += 1;
+        }
+    }
+    match problems {
+        0 => println!("No problems in config.toml or {} jobs", jobs.len()),
+        n => bail!("{} problem{} found", n, if n == 1 { "" } else { "s" }),
+    }
+    Ok(())
+}
+
+fn chart(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli chart <funnel|weekly|sources> [--output <file.svg>] [--include <kinds>] [--exclude <kinds>]";
+    let (jobs, args) = counted_jobs(&load_config()?, args)?;
+    let (name, output) = match args.as_slice() {
+        [name] => (name, PathBuf::from(format!("{}.svg", name))),
+        [name, flag, path] if flag == "--output" || flag == "-o" => (name, PathBuf::from(path)),
+        _ => bail!(usage),
+    };
+
+    let kind = ChartKind::parse(name)?;
+    render_chart(&kind, &jobs, &output)?;
+    println!("Wrote {}", output.display());
+    Ok(())
+}
+
+fn export(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli export --format <pdf|csv|markdown> [--output <file>] [--include <kinds>] [--exclude <kinds>] | export --scheduled";
+    let config = load_config()?;
+    if args == ["--scheduled"] {
+        return scheduled_export(&config);
+    }
+    let (jobs, args) = counted_jobs(&config, args)?;
+    let mut format = None;
+    let mut output = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => format = args.next().cloned(),
+            "--output" | "-o" => output = args.next().map(PathBuf::from),
+            _ => bail!(usage),
+        }
+    }
+
+    let format = match format.as_deref() {
+        Some(name) => Format::parse(name).with_context(|| format!("Unknown export format '{}'", name))?,
+        None => bail!(usage),
+    };
+    let output = output.unwrap_or_else(|| PathBuf::from(format!("pipeline.{}", format.extension())));
+    match format {
+        Format::Pdf => export_pdf(&jobs, &output)?,
+        Format::Csv => std::fs::write(&output, to_csv(&jobs)).with_context(|| format!("Failed to write {}", output.display()))?,
+        Format::Markdown => std::fs::write(&output, to_markdown(&jobs, chrono::Local::now().date_naive()))
+            .with_context(|| format!("Failed to write {}", output.display()))?,
+    }
+    println!("Wrote {}", output.display());
+    Ok(())
+}
+
+/// The copy in [export] of config.toml, for cron. Quiet unless something
+/// goes wrong, and the file is left alone when nothing changed so sync
+/// clients don't upload the same copy every night.
+fn scheduled_export(config: &crate::config::Config) -> Result<()> {
+    let Some((path, format)) = config.export.target().context("Bad [export] in config.toml")? else {
+        bail!("Set a path under [export] in config.toml first, e.g. path = \"~/Dropbox/job-search.csv\"");
+    };
+    let cohorts = if config.stats.archived { load_archived_cohorts()? } else { Vec::new() };
+    let jobs = config.stats.select(&load_jobs()?, &cohorts, &config.statuses)?.jobs;
+    let content = match format {
+        Format::Markdown => to_markdown(&jobs, chrono::Local::now().date_naive()),
+        _ => to_csv(&jobs),
+    };
+    write_if_changed(&path, &content)?;
+    Ok(())
+}
+
+fn bulk(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli bulk set --filter <expr> --set <field=value> [--set ...] [--yes]";
+    let [command, rest @ ..] = args else {
+        bail!(usage);
+    };
+    if command != "set" {
+        bail!(usage);
+    }
+
+    let mut filter = None;
+    let mut assignments = Vec::new();
+    let mut yes = false;
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--filter" => filter = Some(rest.next().context(usage)?),
+            "--set" => assignments.extend(Assignment::parse_list(rest.next().context(usage)?)?),
+            "--yes" | "-y" => yes = true,
+            _ => bail!(usage),
+        }
+    }
+    let Some(filter) = filter else {
+        bail!(usage);
+    };
+    if assignments.is_empty() {
+        bail!(usage);
+    }
+    let filter = Filter::parse(filter, &load_config()?.statuses)?;
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    let matching: Vec<usize> = (0..jobs.len()).filter(|&i| filter.matches(&jobs[i])).collect();
+    if matching.is_empty() {
+        println!("No jobs match '{}'", filter.as_str());
+        return Ok(());
+    }
+
+    // Always show what's about to change, even with --yes
+    for line in preview(&jobs, &matching, &assignments) {
+        println!("  {}", line);
+    }
+    if !yes && !confirm(&format!("Apply to {} jobs?", matching.len()))? {
+        println!("Nothing changed.");
+        return Ok(());
+    }
+
+    let mut still_open = 0;
+    for &i in &matching {
+        for assignment in &assignments {
+            if !assignment.apply(&mut jobs[i]) {
+                still_open += 1;
+            }
+        }
+    }
+    save_jobs(&base, &jobs, Source::Bulk)?;
+    println!("Updated {} jobs.", matching.len());
+    if still_open > 0 {
+        println!("{} still open, so not archived: only closed jobs can be.", still_open);
+    }
+    for &i in &matching {
+        let others = double_submissions(&jobs, &jobs[i]);
+        if let Some(other) = others.first() {
+            println!(
+                "Warning: {} is also being submitted by {}, double submission risk",
+                jobs[i].company,
+                other.agency.as_deref().unwrap_or_default()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Commands write through this, which refuses while a TUI is open: its
+/// next save would merge jobs fine but put its own copy of the other files
+/// back. add and note hand their change to it instead (see ipc.rs).
+fn save_jobs(base: &[Job], jobs: &[Job], source: Source) -> Result<Vec<Job>> {
+    check_no_tui()?;
+    crate::storage::save_jobs(base, jobs, source)
+}
+
+fn check_no_tui() -> Result<()> {
+    if ipc::is_open()? {
+        bail!("career-cli is open in another terminal. Make this change there, or quit it first.");
+    }
+    Ok(())
+}
+
+/// The archive, or none of it with a warning when a cohort file can't be read
+fn archived_cohorts_or_warn() -> Vec<(String, Vec<Job>)> {
+    load_archived_cohorts().unwrap_or_else(|err| {
+        eprintln!("Warning: leaving out the archive: {:#}", err);
+        Vec::new()
+    })
+}
+
+/// y/N prompt on stdin
+fn confirm(question: &str) -> Result<bool> {
+    print!("{} [y/N] ", question);
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer).context("Failed to read stdin")?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+fn storage(args: &[String]) -> Result<()> {
+    let target = match args {
+        [command, layout] if command == "migrate" && layout == "file" => StorageLayout::File,
+        [command, layout] if command == "migrate" && layout == "dir" => StorageLayout::Dir,
+        _ => bail!("Usage: career-cli storage migrate <file|dir>"),
+    };
+    let current = load_config()?.storage.layout;
+    if current == target {
+        bail!("Already using the '{}' layout", args[1]);
+    }
+
+    // The old copy is left in place as a backup
+    let jobs = load_jobs()?;
+    check_no_tui()?;
+    storage_for(target)?.save(&jobs)?;
+    println!("Copied {} jobs to the '{}' layout.", jobs.len(), args[1]);
+    println!("Set this in config.toml to start using it:\n\n[storage]\nlayout = \"{}\"", args[1]);
+    Ok(())
+}
+
+fn config(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli config export [--output <file>] | config import <file|name> [--yes]";
+    let current = load_config()?;
+
+    match args {
+        [command, rest @ ..] if command == "export" => {
+            let content = Preset::from_config(&current).to_toml()?;
+            match rest {
+                [] => print!("{}", content),
+                [flag, path] if flag == "--output" || flag == "-o" => {
+                    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path))?;
+                    println!("Wrote {}", path);
+                }
+                _ => bail!(usage),
+            }
+            Ok(())
+        }
+        [command, source, rest @ ..] if command == "import" => {
+            let yes = match rest {
+                [] => false,
+                [flag] if flag == "--yes" || flag == "-y" => true,
+                _ => bail!(usage),
+            };
+            let preset = Preset::load(source).with_context(|| {
+                format!("Built-in presets: {}", preset::builtin_names().join(", "))
+            })?;
+            let updated = preset.apply(&current);
+
+            let changes = preset::diff(&current, &updated)?;
+            if changes.is_empty() {
+                println!("'{}' matches the current settings, nothing to do.", source);
+                return Ok(());
+            }
+            for line in &changes {
+                println!("  {}", line);
+            }
+            if !yes && !confirm(&format!("Apply {} changes to config.toml?", changes.len()))? {
+                println!("Nothing changed.");
+                return Ok(());
+            }
+            check_no_tui()?;
+            save_config(&updated)?;
+            println!("Imported '{}'. The old config is in config.toml.bak.", source);
+            Ok(())
+        }
+        _ => bail!(usage),
+    }
+}
+
+fn archive(args: &[String]) -> Result<()> {
+    let (yes, cohort) = match args {
+        [cohort] => (false, cohort),
+        [cohort, flag] if flag == "--yes" || flag == "-y" => (true, cohort),
+        _ => bail!("Usage: career-cli archive <cohort> [--yes]"),
+    };
+    let base = load_jobs()?;
+    let (closed, open): (Vec<Job>, Vec<Job>) = base.iter().cloned().partition(|job| job.status.is_terminal());
+    if closed.is_empty() {
+        println!("No closed jobs to archive");
+        return Ok(());
+    }
+
+    println!("Move {} closed jobs into archive/{}.json:", closed.len(), cohort);
+    for job in &closed {
+        println!("  {} - {} ({})", job.company, job.role, job.status_label());
+    }
+    if !yes && !confirm("Archive them?")? {
+        println!("Nothing changed.");
+        return Ok(());
+    }
+
+    // Written before they leave the list, so a failed save can't lose them
+    check_no_tui()?;
+    crate::storage::archive_cohort(cohort, &closed)?;
+    save_jobs(&base, &open, Source::Edit)?;
+    println!("Archived {} jobs as {}", closed.len(), cohort);
+    Ok(())
+}
+
+fn undo(args: &[String]) -> Result<()> {
+    let yes = match args {
+        [] => false,
+        [flag] if flag == "--yes" || flag == "-y" => true,
+        _ => bail!("Usage: career-cli undo [--yes]"),
+    };
+    let journal = read_journal()?;
+    let Some(operation) = crate::undo::last_undoable(&journal) else {
+        println!("Nothing to undo");
+        return Ok(());
+    };
+
+    let base = load_jobs()?;
+    let mut jobs = base.clone();
+    println!("Undo the save from {}:", operation.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"));
+    for line in operation.describe(&jobs) {
+        println!("  {}", line);
+    }
+    if !yes && !confirm("Undo it?")? {
+        println!("Nothing changed.");
+        return Ok(());
+    }
+
+    let warnings = operation.revert(&mut jobs)?;
+    save_jobs(&base, &jobs, Source::Undo)?;
+    // The journal entries the save just wrote are the undo's own
+    let reverted_at = read_journal()?.get(journal.len()).map_or_else(Utc::now, |entry| entry.timestamp);
+    record_undo(operation.timestamp, operation.device_id, reverted_at)?;
+    for warning in &warnings {
+        println!("Skipped: {}", warning);
+    }
+    println!("Undone.");
+    Ok(())
+}
+
+fn blame_command(args: &[String]) -> Result<()> {
+    let [query] = args else {
+        bail!("Usage: career-cli blame <id>");
+    };
+    let jobs = load_jobs()?;
+    let job = &jobs[resolve(&jobs, query)?];
+    let changes = blame(&read_journal()?, &job.id.to_string());
+    println!("{} - {}", job.company, job.role);
+    if changes.is_empty() {
+        println!("  Nothing changed since it was added");
+    }
+    let width = changes.iter().map(|b| b.field.len()).max().unwrap_or(0);
+    for change in &changes {
+        println!("  {:<width$}  {}", change.field, change.describe(), width = width);
+    }
+    Ok(())
+}
+
+fn journal(args: &[String]) -> Result<()> {
+    let usage = "Usage: career-cli journal log [count] | journal replay [--output <file>]";
+    let entries = read_journal()?;
+
+    match args {
+        [command] | [command, _] if command == "log" => {
+            let count = match args.get(1) {
+                Some(n) => n.parse::<usize>().context("Count must be a number")?,
+                None => 20,
+            };
+            for entry in entries.iter().skip(entries.len().saturating_sub(count)) {
+                let change = match entry.op {
+                    JournalOp::Create => "created".to_string(),
+                    JournalOp::Delete => "deleted".to_string(),
+                    JournalOp::Undo => match entry.undoes {
+                        Some(undone) => format!("undid the save at {}", undone.format("%Y-%m-%d %H:%M:%S")),
+                        None => "undo".to_string(),
+                    },
+                    JournalOp::Update => format!(
+                        "{}: {} -> {}",
+                        entry.field.as_deref().unwrap_or("?"),
+                        entry.old.as_ref().map(|v| v.to_string()).unwrap_or_else(|| "-".into()),
+                        entry.new.as_ref().map(|v| v.to_string()).unwrap_or_else(|| "-".into()),
+                    ),
+                };
+                let source = entry.source.map(|s| format!(", {}", s.label())).unwrap_or_default();
+                println!(
+                    "{}  job {:<4} {}  [{}{}]",
+                    entry.timestamp.format("%Y-%m-%d %H:%M:%S"),
+                    entry.job_id,
+                    change,
+                    &entry.device_id[..entry.device_id.len().min(8)],
+                    source
+                );
+            }
+            Ok(())
+        }
+        [command, rest @ ..] if command == "replay" => {
+            let jobs = replay(&entries)?;
+            let json = serde_json::to_string_pretty(&jobs)?;
+            match rest {
+                [] => println!("{}", json),
+                [flag, path] if flag == "--output" || flag == "-o" => {
+                    std::fs::write(path, json).with_context(|| format!("Failed to write {}", path))?;
+                    println!("Replayed {} entries into {} jobs, wrote {}", entries.len(), jobs.len(), path);
+                }
+                _ => bail!(usage),
+            }
+            Ok(())
+        }
+        _ => bail!(usage),
+    }
+}
+
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .context("Failed to read stdin")?;
+    Ok(input)
+}
@@ -0,0 +1,83 @@
+//! Companies as records of their own, kept in companies.json next to the
+//! jobs, so what I know about a company (its site, "hiring freeze until
+//! Q1", who the recruiter is) is written once and shows on every role there.
+//!
+//! Jobs refer to their company by name, matched the way `normalize_company`
+//! matches them, so "Google" and "google LLC" are one record. Adding a job
+//! snaps what I typed to the spelling already on file, which keeps the list
+//! from filling up with variants in the first place.
+
+use crate::models::{normalize_company, Job};
+use crate::storage::get_data_dir;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Company {
+    pub name: String,
+    #[serde(default)]
+    pub website: String,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl Company {
+    pub fn new(name: &str) -> Company {
+        Company { name: name.trim().to_string(), website: String::new(), notes: String::new() }
+    }
+}
+
+fn companies_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("companies.json"))
+}
+
+pub fn load_companies() -> Result<Vec<Company>> {
+    let path = companies_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).context("Failed to read companies.json")?;
+    serde_json::from_str(&content).context("Failed to parse companies.json")
+}
+
+pub fn save_companies(companies: &[Company]) -> Result<()> {
+    let json = serde_json::to_string_pretty(companies).context("Failed to serialize companies")?;
+    fs::write(companies_path()?, json).context("Failed to write companies.json")
+}
+
+/// The record `name` refers to, however it's spelled
+pub fn find<'a>(companies: &'a [Company], name: &str) -> Option<&'a Company> {
+    let key = normalize_company(name);
+    if key.is_empty() {
+        return None;
+    }
+    companies.iter().find(|c| normalize_company(&c.name) == key)
+}
+
+/// How `name` is already spelled: the record's name, or the first job
+/// there when it has no record yet. What was typed when it's new.
+pub fn canonical(companies: &[Company], jobs: &[Job], name: &str) -> String {
+    if let Some(company) = find(companies, name) {
+        return company.name.clone();
+    }
+    let key = normalize_company(name);
+    // Drafts haven't been checked, and may be the job being added itself
+    match jobs.iter().find(|j| !j.draft && !key.is_empty() && normalize_company(&j.company) == key) {
+        Some(job) => job.company.clone(),
+        None => name.trim().to_string(),
+    }
+}
+
+/// The record for `name`, added when there isn't one yet
+pub fn entry<'a>(companies: &'a mut Vec<Company>, name: &str) -> &'a mut Company {
+    let key = normalize_company(name);
+    match companies.iter().position(|c| normalize_company(&c.name) == key) {
+        Some(i) => &mut companies[i],
+        None => {
+            companies.push(Company::new(name));
+            companies.last_mut().unwrap()
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Tab completion for the add form, so "Acme Inc" doesn't turn into "acme inc." and
+//! break company history and dedup.
+
+use crate::models::Job;
+use std::collections::HashMap;
+
+/// Titles I keep typing, offered even before any job uses them
+pub const COMMON_TITLES: &[&str] = &[
+    "Backend Engineer",
+    "Data Engineer",
+    "Data Scientist",
+    "DevOps Engineer",
+    "Engineering Manager",
+    "Frontend Engineer",
+    "Full Stack Engineer",
+    "Machine Learning Engineer",
+    "Mobile Engineer",
+    "Platform Engineer",
+    "Principal Engineer",
+    "Product Manager",
+    "Security Engineer",
+    "Senior Software Engineer",
+    "Site Reliability Engineer",
+    "Software Engineer",
+    "Staff Engineer",
+    "Staff Software Engineer",
+];
+
+/// Best completion for `prefix`: the most used spelling that starts with it
+/// (ignoring case), ties broken alphabetically. None when nothing is longer
+/// than what's typed.
+pub fn complete<'a>(prefix: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    let prefix = prefix.trim_start().to_lowercase();
+    if prefix.is_empty() {
+        return None;
+    }
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for candidate in candidates {
+        let candidate = candidate.trim();
+        if candidate.len() > prefix.len() && candidate.to_lowercase().starts_with(&prefix) {
+            *counts.entry(candidate).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+        .map(|(candidate, _)| candidate.to_string())
+}
+
+pub fn complete_company<'a>(prefix: &str, jobs: impl IntoIterator<Item = &'a Job>) -> Option<String> {
+    complete(prefix, jobs.into_iter().map(|j| j.company.as_str()))
+}
+
+pub fn complete_role<'a>(prefix: &str, jobs: impl IntoIterator<Item = &'a Job>) -> Option<String> {
+    complete(
+        prefix,
+        jobs.into_iter().map(|j| j.role.as_str()).chain(COMMON_TITLES.iter().copied()),
+    )
+}
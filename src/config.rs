@@ -0,0 +1,541 @@
+use crate::availability::Availability;
+use crate::export::Format;
+use crate::models::home_path;
+use crate::reminders::AdminReminder;
+use crate::scope::Scope;
+use crate::statuses::StatusDef;
+use crate::storage::get_data_dir;
+use crate::theme::{ColorMode, GlyphStyle};
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use toml_edit::DocumentMut;
+use std::fs;
+use std::path::PathBuf;
+
+/// Settings from ~/Documents/career-cli/config.toml. Every field is optional.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Config {
+    pub loop_templates: Vec<LoopTemplate>,
+    pub metrics: MetricsConfig,
+    pub theme: ThemeConfig,
+    pub storage: StorageConfig,
+    pub spellcheck: SpellcheckConfig,
+    pub keymap: BTreeMap<String, Vec<String>>, // Action name -> keys, see keymap.rs
+    pub season: SeasonConfig,
+    pub notice: NoticeConfig,
+    pub runway: RunwayConfig,
+    pub reminders: Vec<AdminReminder>, // Not tied to a job, see reminders.rs
+    pub statuses: Vec<StatusDef>,      // Empty for the built-in statuses, see statuses.rs
+    pub stats: Scope,                  // What stats, charts and exports count, see scope.rs
+    pub validation: ValidationConfig,
+    pub export: ExportConfig,
+    pub list: ListConfig,
+    pub usage: UsageConfig,
+    pub availability: Availability, // Weekly free time for interviews, see availability.rs
+    pub interviews: InterviewConfig,
+    pub currency: CurrencyConfig,
+    pub evergreen: EvergreenConfig,
+    pub skills: SkillsConfig,
+}
+
+/// The skills I have, to score jobs against the ones they ask for, see
+/// skills.rs. Case doesn't matter.
+///
+/// ```toml
+/// [skills]
+/// have = ["rust", "postgresql", "kubernetes", "machine learning"]
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SkillsConfig {
+    pub have: Vec<String>,
+}
+
+/// When a posting that keeps turning up counts as evergreen, see
+/// evergreen.rs
+///
+/// ```toml
+/// [evergreen]
+/// days = 90         # up at least this long, first sighting to last
+/// sightings = 3     # seen on at least this many days
+/// counts_as = 0.5   # one is worth this much of an open process in the health score
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct EvergreenConfig {
+    pub days: u32,
+    pub sightings: usize,
+    pub counts_as: f64,
+}
+
+impl Default for EvergreenConfig {
+    fn default() -> Self {
+        Self { days: 90, sightings: 3, counts_as: 0.5 }
+    }
+}
+
+/// The currency offers are compared in, see currency.rs. Pay given
+/// without a currency is taken to be in it.
+///
+/// ```toml
+/// [currency]
+/// base = "EUR"                          # default USD
+/// rates = { GBP = 1.26, SEK = 0.095 }   # US dollars per unit, over the built-in table
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct CurrencyConfig {
+    pub base: String,
+    pub rates: BTreeMap<String, f64>,
+}
+
+impl Default for CurrencyConfig {
+    fn default() -> Self {
+        Self { base: "USD".to_string(), rates: BTreeMap::new() }
+    }
+}
+
+/// How close two interviews can be before adding one warns about the
+/// other. Interviews without a time clash with anything on the same day.
+///
+/// ```toml
+/// [interviews]
+/// buffer_minutes = 30   # free time wanted between two interviews
+/// length_minutes = 60   # how long one without an end time is assumed to run
+/// arrive_minutes = 20   # how early to be at an onsite's address, see travel.rs
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct InterviewConfig {
+    pub buffer_minutes: u32,
+    pub length_minutes: u32,
+    pub arrive_minutes: u32,
+}
+
+impl Default for InterviewConfig {
+    fn default() -> Self {
+        Self { buffer_minutes: 30, length_minutes: 60, arrive_minutes: 20 }
+    }
+}
+
+/// Local usage stats, see usage.rs. Nothing leaves the machine either way.
+///
+/// ```toml
+/// [usage]
+/// track = false   # stop recording, default true
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct UsageConfig {
+    pub track: bool,
+}
+
+impl Default for UsageConfig {
+    fn default() -> Self {
+        Self { track: true }
+    }
+}
+
+/// How the list opens. '=' still switches the order for the session.
+///
+/// ```toml
+/// [list]
+/// sort = "priority"   # "added" (default, oldest first), "priority", "touched" (last changed first) or "fit" (best skills match first)
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ListConfig {
+    pub sort: SortOrder,
+}
+
+/// Order of the list. Added keeps jobs.json order, oldest first; Touched
+/// puts the most recently changed first.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Added,
+    Priority,
+    Touched,
+    Fit, // Best skills match first, see skills.rs
+}
+
+impl SortOrder {
+    /// The one '=' switches to
+    pub fn next(self) -> SortOrder {
+        match self {
+            SortOrder::Added => SortOrder::Priority,
+            SortOrder::Priority => SortOrder::Touched,
+            SortOrder::Touched => SortOrder::Fit,
+            SortOrder::Fit => SortOrder::Added,
+        }
+    }
+}
+
+/// A copy of the list kept current somewhere a partner or coach can see it,
+/// written by `career-cli export --scheduled` from cron, e.g. nightly with
+/// `0 2 * * * career-cli export --scheduled`. Counts the same jobs as
+/// stats, see [stats].
+///
+/// ```toml
+/// [export]
+/// path = "~/Dropbox/job-search.csv"   # or .md for a Markdown table
+/// format = "csv"                       # csv or markdown, when the extension doesn't say
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ExportConfig {
+    pub path: Option<String>,
+    pub format: Option<String>,
+}
+
+impl ExportConfig {
+    /// Where the scheduled export goes and in what format, None when it isn't set up
+    pub fn target(&self) -> Result<Option<(PathBuf, Format)>> {
+        let Some(path) = self.path.as_deref().map(str::trim).filter(|p| !p.is_empty()) else {
+            return Ok(None);
+        };
+        let path = home_path(path);
+        let format = match self.format.as_deref() {
+            Some(name) => Format::parse(name).with_context(|| format!("Unknown export format '{}'", name))?,
+            None => Format::for_path(&path).context("Set format to csv or markdown, the path doesn't say which")?,
+        };
+        if format == Format::Pdf {
+            bail!("Scheduled exports are csv or markdown");
+        }
+        Ok(Some((path, format)))
+    }
+}
+
+/// Field rules, see validate.rs
+///
+/// ```toml
+/// [validation]
+/// allow_future_dates = true   # applied and response dates after today are fine
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ValidationConfig {
+    pub allow_future_dates: bool,
+}
+
+/// Money left for the search. Off until a monthly burn is set.
+///
+/// ```toml
+/// [runway]
+/// savings = 24000
+/// monthly_burn = 3000
+/// severance_end = "2026-12-31" # savings only start going down after this
+/// hiring_weeks = 6             # roughly how long from applying to an offer
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct RunwayConfig {
+    pub savings: f64,
+    pub monthly_burn: f64,
+    pub severance_end: Option<NaiveDate>,
+    pub hiring_weeks: u32,
+}
+
+impl Default for RunwayConfig {
+    fn default() -> Self {
+        Self { savings: 0.0, monthly_burn: 0.0, severance_end: None, hiring_weeks: 6 }
+    }
+}
+
+/// My current job's notice period, for working back from an offer's start
+/// date to when I have to resign and decide.
+///
+/// ```toml
+/// [notice]
+/// period_weeks = 4           # notice owed to my current employer
+/// decision_buffer_days = 3   # between saying yes and handing in notice
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct NoticeConfig {
+    pub period_weeks: u32,
+    pub decision_buffer_days: u32,
+}
+
+impl Default for NoticeConfig {
+    fn default() -> Self {
+        Self { period_weeks: 4, decision_buffer_days: 3 }
+    }
+}
+
+/// Internship and new-grad recruiting runs in seasons with hard deadlines
+/// and an OA before anyone talks to you.
+///
+/// ```toml
+/// [season]
+/// enabled = true
+/// cycle = "Summer 2026 Internship"   # given to every job added from now on
+/// oa_warning_days = 3                # warn about OAs expiring this soon
+/// ```
+///
+/// Season mode adds the "Internship" and "New Grad" loop templates, which
+/// start with an online assessment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct SeasonConfig {
+    pub enabled: bool,
+    pub cycle: Option<String>,
+    pub oa_warning_days: u32,
+}
+
+impl Default for SeasonConfig {
+    fn default() -> Self {
+        Self { enabled: false, cycle: None, oa_warning_days: 3 }
+    }
+}
+
+impl SeasonConfig {
+    /// Cycle for a newly added job, if season mode is on
+    pub fn current_cycle(&self) -> Option<String> {
+        self.cycle.clone().filter(|_| self.enabled)
+    }
+}
+
+/// ```toml
+/// [spellcheck]
+/// enabled = true
+/// language = "en_GB"                  # looks for en_GB.dic, default "en_US"
+/// dictionary = "/path/to/custom.dic"  # skip the search and use this file
+/// ```
+///
+/// Dictionaries are hunspell .dic files, searched for in the data dir's
+/// dictionaries/ folder, then /usr/share/hunspell and /usr/share/myspell.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct SpellcheckConfig {
+    pub enabled: bool,
+    pub language: String,
+    pub dictionary: Option<PathBuf>,
+}
+
+impl Default for SpellcheckConfig {
+    fn default() -> Self {
+        Self { enabled: true, language: "en_US".to_string(), dictionary: None }
+    }
+}
+
+/// ```toml
+/// [storage]
+/// layout = "dir"   # "file" (one jobs.json, default) or "dir" (one file per job)
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub layout: StorageLayout,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageLayout {
+    #[default]
+    File,
+    Dir,
+}
+
+/// ```toml
+/// [theme]
+/// color_mode = "256"   # "auto" (default), "16", "256" or "truecolor"
+/// glyphs = "auto"      # status column: "off" (default), "auto", "ascii", "unicode" or "nerd"
+///
+/// [theme.status_glyphs] # one cell each, overrides the set above
+/// Offer = "$"
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub color_mode: ColorMode,
+    pub glyphs: GlyphStyle,
+    pub status_glyphs: BTreeMap<String, String>,
+}
+
+/// ```toml
+/// [metrics]
+/// business_days = true
+/// holiday_locale = "us"          # "us", "uk" or "none"
+/// holidays = ["2025-12-26"]      # extra days off
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct MetricsConfig {
+    pub business_days: bool,
+    pub holiday_locale: Option<String>,
+    pub holidays: Vec<NaiveDate>,
+    pub health: HealthConfig,
+}
+
+/// Targets and weights behind the pipeline health score. A signal at or above
+/// its target scores full marks; weight 0 leaves it out.
+///
+/// ```toml
+/// [metrics.health]
+/// active_target = 10         # open processes I want going at once
+/// stale_days = 14            # no news for this long counts as stale
+/// weekly_target = 5          # applications per week
+/// interview_target = 2       # interviews in progress
+/// weights = { active = 1.0, staleness = 1.0, volume = 1.0, interviews = 1.0 }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct HealthConfig {
+    pub active_target: u32,
+    pub stale_days: u32,
+    pub weekly_target: u32,
+    pub interview_target: u32,
+    pub weights: HealthWeights,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self { active_target: 10, stale_days: 14, weekly_target: 5, interview_target: 2, weights: HealthWeights::default() }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct HealthWeights {
+    pub active: f64,
+    pub staleness: f64,
+    pub volume: f64,
+    pub interviews: f64,
+}
+
+impl Default for HealthWeights {
+    fn default() -> Self {
+        Self { active: 1.0, staleness: 1.0, volume: 1.0, interviews: 1.0 }
+    }
+}
+
+/// Expected interview stages for a kind of company, e.g.
+///
+/// ```toml
+/// [[loop_templates]]
+/// name = "FAANG"
+/// stages = ["Recruiter call", "Phone screen", "Onsite 1", "Onsite 2"]
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoopTemplate {
+    pub name: String,
+    pub stages: Vec<String>,
+}
+
+impl Config {
+    /// Templates from the config file, or a few sensible defaults if none are defined
+    pub fn templates(&self) -> Vec<LoopTemplate> {
+        if !self.loop_templates.is_empty() {
+            return self.loop_templates.clone();
+        }
+
+        let template = |name: &str, stages: &[&str]| LoopTemplate {
+            name: name.to_string(),
+            stages: stages.iter().map(|s| s.to_string()).collect(),
+        };
+        let mut templates = vec![
+            template(
+                "FAANG",
+                &[
+                    "Recruiter call",
+                    "Phone screen",
+                    "Onsite: coding 1",
+                    "Onsite: coding 2",
+                    "Onsite: system design",
+                    "Onsite: behavioral",
+                    "Onsite: hiring manager",
+                ],
+            ),
+            template(
+                "Startup",
+                &["Recruiter call", "Hiring manager", "Take-home", "Final round"],
+            ),
+            template("Agency", &["Recruiter call", "Client interview", "Final round"]),
+        ];
+        if self.season.enabled {
+            templates.push(template("Internship", &["OA", "Recruiter call", "Technical interview"]));
+            templates.push(template(
+                "New Grad",
+                &["OA", "Recruiter call", "Technical interview", "Final round: coding", "Final round: behavioral"],
+            ));
+        }
+        templates
+    }
+}
+
+pub fn config_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("config.toml"))
+}
+
+pub fn load_config() -> Result<Config> {
+    let path = config_path()?;
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+
+    let content = fs::read_to_string(&path).context("Failed to read config.toml")?;
+    toml::from_str(&content).context("Failed to parse config.toml")
+}
+
+/// Write config.toml, keeping the previous one as config.toml.bak. Only
+/// the settings that changed are rewritten, comments and the rest stay.
+pub fn save_config(config: &Config) -> Result<()> {
+    let path = config_path()?;
+    let existing = if path.exists() {
+        fs::copy(&path, path.with_extension("toml.bak")).context("Failed to back up config.toml")?;
+        fs::read_to_string(&path).context("Failed to read config.toml")?
+    } else {
+        String::new()
+    };
+    fs::write(&path, updated_config(&existing, config)?).context("Failed to write config.toml")
+}
+
+/// `existing` config.toml text with the settings that differ from `config`
+/// changed in place. A file that doesn't parse is written over whole.
+pub fn updated_config(existing: &str, config: &Config) -> Result<String> {
+    let (Ok(mut document), Ok(before)) = (existing.parse::<DocumentMut>(), toml::from_str::<Config>(existing)) else {
+        return toml::to_string_pretty(config).context("Failed to serialize config");
+    };
+    let before = toml::Value::try_from(before).context("Failed to serialize config")?;
+    let after = toml::Value::try_from(config).context("Failed to serialize config")?;
+    write_changes(document.as_table_mut(), &before, &after)?;
+    Ok(document.to_string())
+}
+
+fn write_changes(table: &mut toml_edit::Table, before: &toml::Value, after: &toml::Value) -> Result<()> {
+    let (Some(before), Some(after)) = (before.as_table(), after.as_table()) else {
+        return Ok(());
+    };
+    for (key, value) in after {
+        let old = before.get(key);
+        if old == Some(value) {
+            continue;
+        }
+        if let (Some(old), true) = (old, value.is_table())
+            && let Some(section) = table.entry(key).or_insert(toml_edit::table()).as_table_mut()
+        {
+            write_changes(section, old, value)?;
+            continue;
+        }
+        let mut wrapper = toml::Table::new();
+        wrapper.insert(key.clone(), value.clone());
+        let mut item = toml_edit::ser::to_document(&wrapper)
+            .context("Failed to serialize config")?
+            .remove(key)
+            .context("Failed to serialize config")?;
+        // A comment after the old value stays after the new one
+        if let (Some(new), Some(old)) = (item.as_value_mut(), table.get(key).and_then(|item| item.as_value())) {
+            *new.decor_mut() = old.decor().clone();
+        }
+        table.insert(key, item);
+    }
+    for key in before.keys().filter(|key| !after.contains_key(*key)) {
+        table.remove(key);
+    }
+    Ok(())
+}
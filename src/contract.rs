@@ -0,0 +1,160 @@
+//! Contract and freelance leads alongside permanent roles. They're judged on
+//! rate, length and start date rather than salary, so they carry those.
+//!
+//! Typed as one line, in any order after the engagement type:
+//!
+//! ```text
+//! contract 650/day 6m 2026-11-01
+//! freelance £95/h
+//! full-time
+//! ```
+
+use anyhow::{bail, Context, Result};
+use crate::salary;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, PartialOrd, Ord)]
+pub enum Engagement {
+    #[default]
+    FullTime,
+    Contract,
+    Freelance,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateUnit {
+    #[default]
+    Hour,
+    Day,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ContractTerms {
+    pub rate: Option<f64>,
+    pub per: RateUnit,
+    pub currency: Option<String>, // ISO code like "GBP", None when the rate didn't say
+    pub duration_months: Option<u32>,
+    pub start: Option<NaiveDate>,
+}
+
+/// Hours in a billable day, for comparing hourly and daily rates
+pub const HOURS_PER_DAY: f64 = 8.0;
+
+impl Engagement {
+    pub const ALL: [Engagement; 3] = [Engagement::FullTime, Engagement::Contract, Engagement::Freelance];
+
+    pub fn parse(name: &str) -> Option<Engagement> {
+        match name.trim().to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "fulltime" | "ft" | "perm" | "permanent" => Some(Engagement::FullTime),
+            "contract" | "contractor" => Some(Engagement::Contract),
+            "freelance" | "freelancer" => Some(Engagement::Freelance),
+            _ => None,
+        }
+    }
+
+    pub fn is_contract(self) -> bool {
+        self != Engagement::FullTime
+    }
+}
+
+impl fmt::Display for Engagement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Engagement::FullTime => "Full-time",
+            Engagement::Contract => "Contract",
+            Engagement::Freelance => "Freelance",
+        })
+    }
+}
+
+impl ContractTerms {
+    /// Rate normalized to a day, None when no rate is known
+    pub fn day_rate(&self) -> Option<f64> {
+        self.rate.map(|rate| match self.per {
+            RateUnit::Hour => rate * HOURS_PER_DAY,
+            RateUnit::Day => rate,
+        })
+    }
+
+    /// "650/day" or "£95/h", or None without a rate
+    pub fn rate_label(&self) -> Option<String> {
+        let unit = match self.per {
+            RateUnit::Hour => "h",
+            RateUnit::Day => "day",
+        };
+        // Kept to one word, so "CHF650/day" reads back
+        let symbol = self.currency.as_deref().map(salary::symbol).unwrap_or_default();
+        self.rate.map(|rate| format!("{}{}/{}", symbol.trim(), rate, unit))
+    }
+
+    /// "650/day, 6 months from 2026-11-01"
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = self.rate_label().into_iter().collect();
+        if let Some(months) = self.duration_months {
+            parts.push(format!("{} months", months));
+        }
+        let mut text = parts.join(", ");
+        if let Some(start) = self.start {
+            text = format!("{} from {}", text, start).trim().to_string();
+        }
+        text
+    }
+}
+
+/// The line `parse` reads back, for prefilling the editor
+pub fn to_input(engagement: Engagement, terms: Option<&ContractTerms>) -> String {
+    let mut words = vec![engagement.to_string().to_lowercase()];
+    if let Some(terms) = terms {
+        words.extend(terms.rate_label());
+        words.extend(terms.duration_months.map(|m| format!("{}m", m)));
+        words.extend(terms.start.map(|d| d.to_string()));
+    }
+    words.join(" ")
+}
+
+/// "contract 650/day 6m 2026-11-01" into the engagement and its terms.
+/// Full-time takes no terms.
+pub fn parse(input: &str) -> Result<(Engagement, Option<ContractTerms>)> {
+    let mut words = input.split_whitespace();
+    let Some(first) = words.next() else {
+        bail!("Expected full-time, contract or freelance");
+    };
+    let engagement = Engagement::parse(first)
+        .with_context(|| format!("Unknown engagement '{}' (try full-time, contract or freelance)", first))?;
+
+    let mut terms = ContractTerms::default();
+    for word in words {
+        if let Some((amount, unit)) = word.split_once('/') {
+            terms.rate = Some(parse_amount(amount)?);
+            terms.currency = salary::currency_prefix(amount).map(str::to_string);
+            terms.per = match unit.to_lowercase().as_str() {
+                "h" | "hr" | "hour" => RateUnit::Hour,
+                "d" | "day" => RateUnit::Day,
+                _ => bail!("Rates are per hour or per day, like 95/h or 650/day"),
+            };
+        } else if let Some(months) = word.strip_suffix('m').and_then(|m| m.parse().ok()) {
+            terms.duration_months = Some(months);
+        } else if let Ok(date) = NaiveDate::parse_from_str(word, "%Y-%m-%d") {
+            terms.start = Some(date);
+        } else {
+            bail!("Didn't understand '{}' (rate like 650/day, length like 6m, start like 2026-11-01)", word);
+        }
+    }
+
+    if !engagement.is_contract() {
+        if terms != ContractTerms::default() {
+            bail!("Full-time roles don't take a rate or length");
+        }
+        return Ok((engagement, None));
+    }
+    Ok((engagement, Some(terms)))
+}
+
+/// "650", "£650" or "1,200"
+fn parse_amount(text: &str) -> Result<f64> {
+    let digits: String = text.chars().filter(|c| c.is_ascii_digit() || *c == '.').collect();
+    digits.parse().with_context(|| format!("'{}' isn't an amount", text))
+}
@@ -0,0 +1,67 @@
+//! Pay in one currency, so offers in USD, EUR and GBP line up in the
+//! comparison. Rates are a built-in table of rough values, overridden or
+//! added to in config.toml, see CurrencyConfig.
+
+use crate::config::CurrencyConfig;
+use crate::contract::ContractTerms;
+use crate::models::Job;
+use crate::salary;
+
+/// What one unit is worth in US dollars. Rough, and only for comparing.
+const USD_PER_UNIT: &[(&str, f64)] = &[
+    ("USD", 1.0),
+    ("EUR", 1.08),
+    ("GBP", 1.27),
+    ("CAD", 0.73),
+    ("AUD", 0.66),
+    ("CHF", 1.13),
+];
+
+fn usd_per_unit(code: &str, config: &CurrencyConfig) -> Option<f64> {
+    let code = code.trim().to_uppercase();
+    config
+        .rates
+        .get(&code)
+        .copied()
+        .or_else(|| USD_PER_UNIT.iter().find(|(c, _)| *c == code).map(|(_, rate)| *rate))
+        .filter(|rate| *rate > 0.0)
+}
+
+/// `amount` of `from` in `to`, None when either rate is unknown
+pub fn convert(amount: f64, from: &str, to: &str, config: &CurrencyConfig) -> Option<f64> {
+    if from.eq_ignore_ascii_case(to) {
+        return Some(amount);
+    }
+    Some(amount * usd_per_unit(from, config)? / usd_per_unit(to, config)?)
+}
+
+/// A job's currency: the one its pay was given in, else my own
+fn currency_of<'a>(given: Option<&'a str>, config: &'a CurrencyConfig) -> &'a str {
+    given.unwrap_or(&config.base)
+}
+
+/// The job's pay in the base currency: the yearly salary range for a
+/// permanent role, the day rate for a contract. "$98k-130k" or "$702/day".
+pub fn normalized(job: &Job, config: &CurrencyConfig) -> Option<String> {
+    let symbol = salary::symbol(&config.base);
+    if let Some(terms) = job.contract.as_ref().filter(|_| job.engagement.is_contract()) {
+        return normalized_rate(terms, config).map(|rate| format!("{}{:.0}/day", symbol, rate));
+    }
+    let from = currency_of(job.salary_currency.as_deref(), config);
+    let k = |amount: u32| -> Option<String> {
+        let converted = convert(amount as f64, from, &config.base, config)?;
+        Some(format!("{}k", (converted / 1000.0).round()))
+    };
+    match (job.salary_min, job.salary_max) {
+        (Some(min), Some(max)) if min == max => Some(format!("{}{}", symbol, k(min)?)),
+        (Some(min), Some(max)) => Some(format!("{}{}-{}", symbol, k(min)?, k(max)?)),
+        (Some(min), None) => Some(format!("from {}{}", symbol, k(min)?)),
+        (None, Some(max)) => Some(format!("up to {}{}", symbol, k(max)?)),
+        (None, None) => None,
+    }
+}
+
+/// A contract's day rate in the base currency
+pub fn normalized_rate(terms: &ContractTerms, config: &CurrencyConfig) -> Option<f64> {
+    convert(terms.day_rate()?, currency_of(terms.currency.as_deref(), config), &config.base, config)
+}
@@ -0,0 +1,84 @@
+//! A few questions right after an interview, while I still remember it:
+//! how it went, what they asked, what happens next, and when to chase them
+//! if nothing does. The TUI asks on the first launch after the interview's over.
+
+use crate::config::InterviewConfig;
+use crate::models::Job;
+use chrono::{Days, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+/// Interviews older than this don't get a prompt, it's too late to remember much
+pub const PROMPT_WITHIN_DAYS: i64 = 14;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Debrief {
+    pub rating: Option<u8>, // How I think it went, 1-5
+    pub questions: String,  // What they asked me
+    pub next_steps: String,
+    pub follow_up: Option<NaiveDate>, // Chase them on this day if I haven't heard back
+}
+
+impl Debrief {
+    /// "4/5, follow up 2026-10-24", for the detail pane and `show`
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(rating) = self.rating {
+            parts.push(format!("{}/5", rating));
+        }
+        if !self.next_steps.is_empty() {
+            parts.push(format!("next: {}", self.next_steps));
+        }
+        if let Some(date) = self.follow_up {
+            parts.push(format!("follow up {}", date));
+        }
+        parts.join(", ")
+    }
+}
+
+/// The oldest interview of an open job that's over and hasn't been
+/// debriefed, as (job index, interview index)
+pub fn pending(jobs: &[Job], now: NaiveDateTime, config: &InterviewConfig) -> Option<(usize, usize)> {
+    let today = now.date();
+    jobs.iter()
+        .enumerate()
+        .filter(|(_, job)| job.is_open())
+        .flat_map(|(j, job)| job.interviews.iter().enumerate().map(move |(i, interview)| (j, i, interview)))
+        .filter(|(_, _, interview)| {
+            interview.debrief.is_none() && interview.is_over(now, config.length_minutes) && (today - interview.date).num_days() <= PROMPT_WITHIN_DAYS
+        })
+        .min_by_key(|(_, _, interview)| interview.date)
+        .map(|(j, i, _)| (j, i))
+}
+
+/// "1" to "5", Some(None) when left empty and None when it's neither
+pub fn parse_rating(input: &str) -> Option<Option<u8>> {
+    match input.trim() {
+        "" => Some(None),
+        text => text.parse().ok().filter(|r| (1..=5).contains(r)).map(Some),
+    }
+}
+
+/// "2026-10-24", "5d" or "1w" from `today`. Some(None) for no reminder
+/// (empty or "no"), None when it doesn't parse.
+pub fn parse_follow_up(input: &str, today: NaiveDate) -> Option<Option<NaiveDate>> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() || input == "no" {
+        return Some(None);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(Some(date));
+    }
+    let days: u64 = match (input.strip_suffix('d'), input.strip_suffix('w')) {
+        (Some(days), _) => days.parse().ok()?,
+        (_, Some(weeks)) => weeks.parse::<u64>().ok()? * 7,
+        _ => return None,
+    };
+    today.checked_add_days(Days::new(days)).map(Some)
+}
+
+/// The follow-up still worth doing for `job`: from its latest interview,
+/// as long as no later one has been logged since
+pub fn follow_up(job: &Job) -> Option<NaiveDate> {
+    job.interviews.iter().max_by_key(|i| i.date)?.debrief.as_ref()?.follow_up
+}
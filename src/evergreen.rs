@@ -0,0 +1,56 @@
+//! Postings that never come down. A req that's been up for months, turning
+//! up in every import or reposted word for word, is usually a pipeline
+//! for future openings rather than a seat someone's waiting to fill, so
+//! it's marked in the list and counts for less in the health score.
+//!
+//! What it goes on is `Job::sightings`: a day for each time the posting
+//! was seen still up, from an import that brought it in again (see
+//! fingerprint.rs) or its text kept again (see posting.rs) and reading the
+//! same as before.
+
+use crate::config::EvergreenConfig;
+use crate::models::Job;
+use crate::posting::{self, Change};
+use chrono::NaiveDate;
+
+/// Note that `job`'s posting was up on `day`
+pub fn seen_on(job: &mut Job, day: NaiveDate) {
+    if let Err(at) = job.sightings.binary_search(&day) {
+        job.sightings.insert(at, day);
+    }
+}
+
+/// Days between the first and last time the posting was seen up
+pub fn open_for(job: &Job) -> Option<i64> {
+    let (first, last) = (job.sightings.first()?, job.sightings.last()?);
+    Some((*last - *first).num_days())
+}
+
+/// Seen up often enough, over long enough, to be a standing req
+pub fn is_evergreen(job: &Job, config: &EvergreenConfig) -> bool {
+    job.sightings.len() >= config.sightings && open_for(job).is_some_and(|days| days >= config.days as i64)
+}
+
+/// "likely evergreen, seen up 4 times over 5 months", for the detail pane
+pub fn label(job: &Job, config: &EvergreenConfig) -> Option<String> {
+    if !is_evergreen(job, config) {
+        return None;
+    }
+    let days = open_for(job)?;
+    let span = if days >= 60 { format!("{} months", days / 30) } else { format!("{} days", days) };
+    Some(format!("likely evergreen, seen up {} times over {}", job.sightings.len(), span))
+}
+
+/// Keeping `job`'s posting text on `day`: it was up then, and if it reads
+/// the same as `earlier`'s (the last application to the company that kept
+/// its text, see `posting::earlier`) it's been up since that one too
+pub fn snapshot(job: &mut Job, earlier: Option<&Job>, day: NaiveDate) {
+    seen_on(job, day);
+    let Some(earlier) = earlier else { return };
+    if posting::diff(&earlier.description, &job.description).iter().all(|(change, _)| *change == Change::Same) {
+        seen_on(job, earlier.date_applied.date_naive());
+        for seen in &earlier.sightings {
+            seen_on(job, *seen);
+        }
+    }
+}
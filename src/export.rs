@@ -0,0 +1,306 @@
+use crate::models::Job;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::fs;
+use std::path::Path;
+
+/// What `career-cli export` can write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Pdf,
+    Csv,
+    Markdown,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name.trim().to_lowercase().as_str() {
+            "pdf" => Some(Format::Pdf),
+            "csv" => Some(Format::Csv),
+            "markdown" | "md" => Some(Format::Markdown),
+            _ => None,
+        }
+    }
+
+    /// From the extension, as in jobs.csv
+    pub fn for_path(path: &Path) -> Option<Format> {
+        Format::parse(path.extension()?.to_str()?)
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Pdf => "pdf",
+            Format::Csv => "csv",
+            Format::Markdown => "md",
+        }
+    }
+}
+
+/// The columns `career-cli import` reads, so a copy can come back in
+const CSV_COLUMNS: [&str; 9] = ["company", "role", "status", "applied", "location", "salary", "source", "link", "notes"];
+
+/// One row per job, with a header
+pub fn to_csv(jobs: &[Job]) -> String {
+    let mut csv = CSV_COLUMNS.join(",");
+    csv.push('\n');
+    for job in jobs {
+        let cells = [
+            job.company.clone(),
+            job.role.clone(),
+            // The built-in status a custom one counts as, since that's what import reads
+            format!("{:?}", job.status),
+            job.date_applied.format("%Y-%m-%d").to_string(),
+            job.location_input(),
+            job.salary_input(),
+            job.source.clone().unwrap_or_default(),
+            job.post_link.clone(),
+            job.notes.clone(),
+        ];
+        let cells: Vec<String> = cells.iter().map(|cell| csv_field(cell)).collect();
+        csv.push_str(&cells.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// A table for reading, with what's next for each job instead of its notes
+pub fn to_markdown(jobs: &[Job], today: NaiveDate) -> String {
+    let open = jobs.iter().filter(|j| j.is_open()).count();
+    let mut md = format!("# Job search\n\nAs of {}: {} jobs, {} still open.\n\n", today, jobs.len(), open);
+    md.push_str("| Company | Role | Status | Applied | Location | Salary | Next |\n");
+    md.push_str("|---|---|---|---|---|---|---|\n");
+    for job in jobs {
+        let next = match (job.stages.iter().find(|s| !s.done), job.follow_up) {
+            (Some(stage), _) => stage.name.clone(),
+            (None, Some(at)) => format!("follow up {}", at.format("%Y-%m-%d")),
+            (None, None) => String::new(),
+        };
+        let cells = [
+            job.company.clone(),
+            job.role.clone(),
+            job.status_label(),
+            job.date_applied.format("%Y-%m-%d").to_string(),
+            job.location_label().unwrap_or_default(),
+            job.salary_label().unwrap_or_default(),
+            next,
+        ];
+        let cells: Vec<String> = cells.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        md.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    md
+}
+
+/// Replace `path` with `content` unless it already holds exactly that.
+/// Written beside it and renamed over, so whatever syncs the file never
+/// sees half of it. Returns whether anything was written.
+pub fn write_if_changed(path: &Path, content: &str) -> Result<bool> {
+    if fs::read_to_string(path).ok().as_deref() == Some(content) {
+        return Ok(false);
+    }
+    let partial = path.with_extension("partial");
+    fs::write(&partial, content).with_context(|| format!("Failed to write {}", partial.display()))?;
+    fs::rename(&partial, path).with_context(|| format!("Failed to replace {}", path.display()))?;
+    Ok(true)
+}
+
+#[cfg(feature = "pdf")]
+pub use pdf::export_pdf;
+
+/// Stands in for the PDF export in builds without it
+#[cfg(not(feature = "pdf"))]
+pub fn export_pdf(_jobs: &[Job], _output: &Path) -> Result<()> {
+    anyhow::bail!("This build of career-cli leaves out PDF export, rebuild it with --features pdf")
+}
+
+#[cfg(feature = "pdf")]
+mod pdf {
+    use crate::models::{Job, Status};
+    use anyhow::{anyhow, Context, Result};
+    use chrono::Local;
+    use printpdf::{BuiltinFont, IndirectFontRef, Line, Mm, PdfDocument, PdfLayerReference, Point};
+    use std::fs::File;
+    use std::io::BufWriter;
+    use std::path::Path;
+
+    // A4 portrait, everything in mm
+    const PAGE_WIDTH: f32 = 210.0;
+    const PAGE_HEIGHT: f32 = 297.0;
+    const MARGIN: f32 = 15.0;
+    const ROW_HEIGHT: f32 = 5.5;
+
+    // Column x positions for the job table
+    const COLUMNS: [(&str, f32); 5] = [
+        ("Company", MARGIN),
+        ("Role", MARGIN + 45.0),
+        ("Applied", MARGIN + 105.0),
+        ("Progress", MARGIN + 127.0),
+        ("Next step", MARGIN + 147.0),
+    ];
+
+    struct Page {
+        layer: PdfLayerReference,
+        regular: IndirectFontRef,
+        bold: IndirectFontRef,
+        y: f32,
+    }
+
+    impl Page {
+        fn text(&self, text: &str, size: f32, x: f32, bold: bool) {
+            let font = if bold { &self.bold } else { &self.regular };
+            self.layer.use_text(ascii(text), size, Mm(x), Mm(self.y), font);
+        }
+
+        fn rule(&self) {
+            let y = self.y + 1.5;
+            self.layer.add_line(Line {
+                points: vec![
+                    (Point::new(Mm(MARGIN), Mm(y)), false),
+                    (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(y)), false),
+                ],
+                is_closed: false,
+            });
+        }
+
+        fn has_room(&self) -> bool {
+            self.y > MARGIN + ROW_HEIGHT
+        }
+    }
+
+    /// One-page pipeline summary for printing. Open processes first, closed ones
+    /// after; anything that doesn't fit gets a "... and N more" line.
+    pub fn export_pdf(jobs: &[Job], output: &Path) -> Result<()> {
+        let (doc, page_index, layer_index) =
+            PdfDocument::new("Job Search Pipeline", Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Pipeline");
+        let regular = doc
+            .add_builtin_font(BuiltinFont::Helvetica)
+            .map_err(|e| anyhow!("{:?}", e))?;
+        let bold = doc
+            .add_builtin_font(BuiltinFont::HelveticaBold)
+            .map_err(|e| anyhow!("{:?}", e))?;
+        let mut page = Page {
+            layer: doc.get_page(page_index).get_layer(layer_index),
+            regular,
+            bold,
+            y: PAGE_HEIGHT - MARGIN - 5.0,
+        };
+
+        page.text("Job Search Pipeline", 18.0, MARGIN, true);
+        page.y -= 7.0;
+        page.text(&Local::now().format("%A, %B %-d %Y").to_string(), 10.0, MARGIN, false);
+        page.y -= 8.0;
+
+        let count = |status: Status| jobs.iter().filter(|j| j.status == status).count();
+        let summary = format!(
+            "Total {}   |   Applied {}   |   Interviewing {}   |   Offers {}   |   Accepted {}   |   Rejected {}   |   Ghosted {}   |   Withdrawn {}",
+            jobs.len(),
+            count(Status::Applied),
+            count(Status::Interviewing),
+            count(Status::Offer),
+            count(Status::Accepted),
+            count(Status::Rejected),
+            count(Status::Ghosted),
+            count(Status::Withdrawn),
+        );
+        page.text(&summary, 10.0, MARGIN, false);
+        page.y -= 10.0;
+
+        let sections = [
+            ("Accepted", vec![Status::Accepted]),
+            ("Offers", vec![Status::Offer]),
+            ("Interviewing", vec![Status::Interviewing]),
+            ("Applied", vec![Status::Applied]),
+            ("Closed", vec![Status::Rejected, Status::Ghosted, Status::Withdrawn]),
+        ];
+
+        let mut written = 0;
+        for (heading, statuses) in sections {
+            let mut section: Vec<&Job> = jobs.iter().filter(|j| statuses.contains(&j.status)).collect();
+            if section.is_empty() {
+                continue;
+            }
+            section.sort_by_key(|j| j.date_applied);
+
+            // Heading + column titles + at least one row
+            if page.y < MARGIN + ROW_HEIGHT * 4.0 {
+                break;
+            }
+            page.y -= 2.0;
+            page.text(&format!("{} ({})", heading, section.len()), 12.0, MARGIN, true);
+            page.y -= ROW_HEIGHT + 1.0;
+            for (title, x) in COLUMNS {
+                page.text(title, 8.0, x, true);
+            }
+            page.rule();
+            page.y -= ROW_HEIGHT;
+
+            for job in section {
+                if !page.has_room() {
+                    break;
+                }
+                write_row(&page, job);
+                page.y -= ROW_HEIGHT;
+                written += 1;
+            }
+        }
+
+        if written < jobs.len() {
+            page.y = MARGIN;
+            page.text(
+                &format!("... and {} more (see `career-cli list`)", jobs.len() - written),
+                8.0,
+                MARGIN,
+                false,
+            );
+        }
+
+        let file = File::create(output).with_context(|| format!("Failed to create {}", output.display()))?;
+        doc.save(&mut BufWriter::new(file))
+            .map_err(|e| anyhow!("Failed to write PDF: {:?}", e))
+    }
+
+    fn write_row(page: &Page, job: &Job) {
+        let progress = match job.stage_progress() {
+            Some((done, total)) => format!("{}/{}", done, total),
+            None => "-".to_string(),
+        };
+        let next_step = job
+            .stages
+            .iter()
+            .find(|s| !s.done)
+            .map(|s| s.name.clone())
+            .unwrap_or_default();
+
+        let cells = [
+            clip(&job.company, 26),
+            clip(&job.role, 34),
+            job.date_applied.format("%Y-%m-%d").to_string(),
+            progress,
+            clip(&next_step, 28),
+        ];
+        for ((_, x), cell) in COLUMNS.iter().zip(cells.iter()) {
+            page.text(cell, 8.0, *x, false);
+        }
+    }
+
+    fn clip(value: &str, max_len: usize) -> String {
+        if value.chars().count() <= max_len {
+            return value.to_string();
+        }
+        let mut clipped: String = value.chars().take(max_len - 3).collect();
+        clipped.push_str("...");
+        clipped
+    }
+
+    /// Text with the built-in PDF fonts is written byte for byte, keep it ASCII
+    fn ascii(text: &str) -> String {
+        text.chars().map(|c| if c.is_ascii() { c } else { '?' }).collect()
+    }
+}
@@ -0,0 +1,365 @@
+//! Small query language for picking jobs, shared by `bulk set` and the TUI's
+//! '/' filter.
+//!
+//! ```text
+//! status:applied AND applied<2024-12-01
+//! status:"phone screen" OR status:onsite
+//! pipeline:closed NOT status:accepted
+//! company:acme OR company:globex
+//! NOT status:rejected (role:backend OR role:platform)
+//! cycle:"summer 2026" deadline<=2026-10-31
+//! agency:hays OR agency:none
+//! source:referral OR source:linkedin
+//! contact:dana
+//! req:r-10234
+//! resume:backend-v3
+//! letter:needed
+//! priority:high NOT status:rejected
+//! rejected:phone OR rejected:none
+//! status:applied quiet:21
+//! * NOT status:rejected
+//! type:contract OR type:freelance
+//! tag:remote NOT #dream-company
+//! transcript:kubernetes
+//! NOT (mode:onsite NOT location:berlin)
+//! ```
+//!
+//! Terms next to each other are ANDed. Text fields match case-insensitive
+//! substrings, a bare word matches company or role. Tags match whole, and
+//! `#remote` is short for `tag:remote`. A lone `*` means `starred:yes`.
+//! `quiet:21` is jobs with no news in the last 21 days, counted from when
+//! the filter was set.
+
+use crate::contract::Engagement;
+use crate::models::{Job, LetterStatus, Pipeline, Priority, RejectionStage, Status, WorkMode};
+use crate::statuses::StatusDef;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+#[derive(Debug, Clone)]
+pub struct Filter {
+    source: String,
+    expr: Expr,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Term(Term),
+}
+
+#[derive(Debug, Clone)]
+enum Term {
+    Status(Status),       // Custom statuses counting as this one match too
+    CustomStatus(String), // A name from [[statuses]], see statuses.rs
+    Pipeline(Pipeline),
+    Company(String),
+    Role(String),
+    ReqId(String),
+    Domain(String),
+    Notes(String),
+    Transcript(String),
+    Cycle(String),
+    Contact(String),
+    Resume(String),
+    Letter(LetterStatus),
+    Priority(Priority),
+    Starred(bool),
+    Agency(String), // "none" for direct applications, "any" for any agency
+    Source(String), // "none" for jobs without one
+    Engagement(Engagement),
+    Tag(String),
+    Location(String),
+    WorkMode(WorkMode),
+    Applied(Compare, NaiveDate),
+    Deadline(Compare, NaiveDate), // Jobs without a deadline never match
+    Rejected(Option<RejectionStage>), // None for rejections with no stage recorded
+    Quiet(DateTime<Utc>),             // Nothing heard since
+    Any(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Compare {
+    Before,
+    OnOrBefore,
+    On,
+    OnOrAfter,
+    After,
+}
+
+impl Filter {
+    /// `statuses` are the configured [[statuses]], the names `status:` takes
+    /// besides the built-in ones
+    pub fn parse(source: &str, statuses: &[StatusDef]) -> Result<Filter> {
+        let tokens = tokenize(source)?;
+        if tokens.is_empty() {
+            bail!("Empty filter");
+        }
+        let mut parser = Parser { tokens, pos: 0, statuses };
+        let expr = parser.or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            bail!("Unexpected '{}' in filter", token);
+        }
+        Ok(Filter { source: source.trim().to_string(), expr })
+    }
+
+    /// The filter as typed
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    pub fn matches(&self, job: &Job) -> bool {
+        self.expr.matches(job)
+    }
+}
+
+impl Expr {
+    fn matches(&self, job: &Job) -> bool {
+        match self {
+            Expr::And(a, b) => a.matches(job) && b.matches(job),
+            Expr::Or(a, b) => a.matches(job) || b.matches(job),
+            Expr::Not(a) => !a.matches(job),
+            Expr::Term(term) => term.matches(job),
+        }
+    }
+}
+
+impl Term {
+    fn matches(&self, job: &Job) -> bool {
+        let contains = |haystack: &str, needle: &str| haystack.to_lowercase().contains(needle);
+        match self {
+            Term::Status(status) => job.status == *status,
+            Term::CustomStatus(name) => job.custom_status.as_deref().is_some_and(|custom| custom.to_lowercase() == *name),
+            Term::Pipeline(side) => job.pipeline() == *side,
+            Term::Company(text) => contains(&job.company, text),
+            Term::Role(text) => contains(&job.role, text),
+            Term::ReqId(text) => job.req_id.as_deref().is_some_and(|req_id| contains(req_id, text)),
+            Term::Domain(text) => job.link_domain().is_some_and(|domain| contains(&domain, text)),
+            Term::Notes(text) => contains(&job.notes, text),
+            Term::Transcript(text) => job.interviews.iter().any(|i| contains(&i.transcript, text)),
+            Term::Cycle(text) => job.cycle.as_deref().is_some_and(|cycle| contains(cycle, text)),
+            Term::Priority(priority) => job.priority == *priority,
+            Term::Starred(starred) => job.starred == *starred,
+            Term::Resume(text) => job.resume_version.to_lowercase().contains(text.as_str()),
+            Term::Letter(status) => job.letter_status == *status,
+            Term::Contact(text) => job.contact.search_text().contains(text.as_str()),
+            Term::Engagement(engagement) => job.engagement == *engagement,
+            Term::Tag(tag) => job.has_tag(tag),
+            Term::Location(text) => contains(&job.location, text),
+            Term::WorkMode(mode) => job.work_mode == Some(*mode),
+            Term::Source(text) => match (text.as_str(), job.source.as_deref()) {
+                ("none", source) => source.is_none(),
+                (text, source) => source.is_some_and(|source| contains(source, text)),
+            },
+            Term::Agency(text) => match (text.as_str(), job.agency.as_deref()) {
+                ("none" | "direct", agency) => agency.is_none(),
+                ("any", agency) => agency.is_some(),
+                (text, agency) => agency.is_some_and(|agency| contains(agency, text)),
+            },
+            Term::Applied(compare, date) => compare.holds(job.date_applied.date_naive(), *date),
+            Term::Deadline(compare, date) => job.deadline.is_some_and(|deadline| compare.holds(deadline, *date)),
+            Term::Rejected(stage) => job.status == Status::Rejected && job.rejected_stage == *stage,
+            Term::Quiet(since) => job.responded_at.unwrap_or(job.date_applied).max(job.date_applied) < *since,
+            Term::Any(text) => contains(&job.company, text) || contains(&job.role, text),
+        }
+    }
+
+    fn parse(token: &str, statuses: &[StatusDef]) -> Result<Term> {
+        // The field name comes first, so the '<' in "notes:a<b" is part of
+        // the note. Longest operators first so "<=" isn't read as "<".
+        let (field, rest) = token.split_at(token.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(token.len()));
+        for (op, compare) in [
+            ("<=", Compare::OnOrBefore),
+            (">=", Compare::OnOrAfter),
+            ("<", Compare::Before),
+            (">", Compare::After),
+        ] {
+            if let Some(value) = rest.strip_prefix(op) {
+                return Ok(match field.to_lowercase().as_str() {
+                    "applied" => Term::Applied(compare, parse_date(value)?),
+                    "deadline" | "due" => Term::Deadline(compare, parse_date(value)?),
+                    _ => bail!("Only 'applied' and 'deadline' can be compared with {}, got '{}'", op, token),
+                });
+            }
+        }
+
+        if let Some(tag) = token.strip_prefix('#').filter(|t| !t.is_empty()) {
+            return Ok(Term::Tag(tag.to_lowercase()));
+        }
+        if token == "*" {
+            return Ok(Term::Starred(true));
+        }
+        let Some((field, value)) = token.split_once(':') else {
+            return Ok(Term::Any(token.to_lowercase()));
+        };
+        let text = value.to_lowercase();
+        Ok(match field.to_lowercase().as_str() {
+            "status" => match Status::parse(value) {
+                Some(status) => Term::Status(status),
+                None if statuses.iter().any(|def| def.name.to_lowercase() == text) => Term::CustomStatus(text),
+                None => bail!("Unknown status '{}', neither a built-in one nor in [[statuses]]", value),
+            },
+            "pipeline" => Term::Pipeline(
+                Pipeline::parse(value).with_context(|| format!("Unknown pipeline '{}' (active or closed)", value))?,
+            ),
+            "company" => Term::Company(text),
+            "role" => Term::Role(text),
+            "req" | "req_id" | "requisition" => Term::ReqId(text),
+            "domain" | "link" => Term::Domain(text),
+            "notes" | "note" => Term::Notes(text),
+            "transcript" => Term::Transcript(text),
+            "cycle" | "season" => Term::Cycle(text),
+            "agency" | "via" => Term::Agency(text),
+            "source" | "from" => Term::Source(text),
+            "contact" | "recruiter" => Term::Contact(text),
+            "resume" | "cv" => Term::Resume(text),
+            "letter" | "cover" => Term::Letter(
+                LetterStatus::parse(value).with_context(|| format!("Unknown cover letter status '{}' (needed, sent or none)", value))?,
+            ),
+            "type" | "engagement" => Term::Engagement(
+                Engagement::parse(value).with_context(|| format!("Unknown engagement type '{}'", value))?,
+            ),
+            "priority" | "prio" => Term::Priority(
+                Priority::parse(value).with_context(|| format!("Unknown priority '{}' (high, medium or low)", value))?,
+            ),
+            "starred" | "star" => Term::Starred(match text.as_str() {
+                "yes" | "true" => true,
+                "no" | "false" => false,
+                _ => bail!("starred is yes or no, got '{}'", value),
+            }),
+            "tag" | "tags" => Term::Tag(text),
+            "location" | "city" => Term::Location(text),
+            "mode" | "workmode" => Term::WorkMode(
+                WorkMode::parse(value).with_context(|| format!("Unknown work mode '{}' (remote, hybrid or onsite)", value))?,
+            ),
+            "rejected" | "rejected_at" => Term::Rejected(match text.as_str() {
+                "none" => None,
+                _ => Some(RejectionStage::parse(value).with_context(|| format!("Unknown rejection stage '{}'", value))?),
+            }),
+            "quiet" => {
+                let days: i64 = text.parse().with_context(|| format!("quiet takes a number of days, got '{}'", value))?;
+                Term::Quiet(Utc::now() - Duration::days(days))
+            }
+            "applied" => Term::Applied(Compare::On, parse_date(value)?),
+            "deadline" | "due" => Term::Deadline(Compare::On, parse_date(value)?),
+            _ => bail!("Unknown filter field '{}'", field),
+        })
+    }
+}
+
+impl Compare {
+    fn holds(self, value: NaiveDate, date: NaiveDate) -> bool {
+        match self {
+            Compare::Before => value < date,
+            Compare::OnOrBefore => value <= date,
+            Compare::On => value == date,
+            Compare::OnOrAfter => value >= date,
+            Compare::After => value > date,
+        }
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .with_context(|| format!("Dates look like 2024-12-01, got '{}'", value))
+}
+
+/// Words, parentheses, and "quoted strings" (quotes can appear mid-word, as in company:"Acme Corp")
+fn tokenize(source: &str) -> Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in source.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if quoted => current.push(c),
+            '(' | ')' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if quoted {
+        bail!("Unclosed quote in filter");
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<String>,
+    pos: usize,
+    statuses: &'a [StatusDef],
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn eat(&mut self, keyword: &str) -> bool {
+        if self.peek().is_some_and(|t| t.eq_ignore_ascii_case(keyword)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.eat("OR") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.unary()?;
+        loop {
+            if self.eat("AND") {
+                expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+            } else if self.peek().is_some_and(|t| t != ")" && !t.eq_ignore_ascii_case("OR")) {
+                // Juxtaposition means AND
+                expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        if self.eat("NOT") {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        if self.eat("(") {
+            let expr = self.or()?;
+            if !self.eat(")") {
+                bail!("Missing ')' in filter");
+            }
+            return Ok(expr);
+        }
+        match self.peek() {
+            None => bail!("Filter ends too early"),
+            Some(")") => bail!("Unexpected ')' in filter"),
+            Some(token) => {
+                let term = Term::parse(token, self.statuses)?;
+                self.pos += 1;
+                Ok(Expr::Term(term))
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Telling when two postings are the same role, so one that turns up on
+//! three boards is tracked once, with the other two kept as listings on
+//! it. Imports, `career-cli add` and the add wizard all check before a
+//! job comes in.
+//!
+//! A posting's fingerprints are its links with the tracking noise taken
+//! out (scheme, www., utm_ and friends, fragments, trailing slashes) and a
+//! hash of its company and title, written the same way whatever the board
+//! made of them. Links match any job; the title only an open one, so
+//! reapplying to a role I was turned down for isn't taken as a repeat.
+
+use crate::evergreen;
+use crate::models::{normalize_company, Job, Listing};
+use chrono::NaiveDate;
+use url::Url;
+
+/// Query parameters that say how I got to a posting rather than which one it is
+const TRACKING: &[&str] = &[
+    "ref", "refid", "ref_id", "referrer", "src", "source", "gh_src", "trk", "trackingid", "lever-source",
+    "lever-origin", "fbclid", "gclid", "mc_cid", "mc_eid", "from", "campaign",
+];
+
+/// Abbreviations boards use in titles, and what they stand for
+const TITLE_WORDS: &[(&str, &str)] = &[
+    ("sr", "senior"),
+    ("snr", "senior"),
+    ("jr", "junior"),
+    ("eng", "engineer"),
+    ("engr", "engineer"),
+    ("swe", "software engineer"),
+    ("sde", "software engineer"),
+    ("dev", "developer"),
+    ("mgr", "manager"),
+];
+
+/// "https://www.Acme.com/jobs/42/?utm_source=x#apply" -> "acme.com/jobs/42"
+pub fn canonical_url(link: &str) -> Option<String> {
+    let link = link.trim();
+    if link.is_empty() {
+        return None;
+    }
+    let parsed = Url::parse(link).or_else(|_| Url::parse(&format!("https://{}", link))).ok()?;
+    let host = parsed.host_str()?.to_lowercase();
+    let mut query: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| {
+            let key = key.to_lowercase();
+            !key.starts_with("utm_") && !TRACKING.contains(&key.as_str())
+        })
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    query.sort();
+    let mut canonical = format!("{}{}", host.trim_start_matches("www."), parsed.path().trim_end_matches('/'));
+    if !query.is_empty() {
+        let pairs: Vec<String> = query.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+        canonical.push('?');
+        canonical.push_str(&pairs.join("&"));
+    }
+    Some(canonical)
+}
+
+/// Company and title as words, with what's in brackets ("(Remote)",
+/// "(m/f/d)") dropped and abbreviations spelled out:
+/// "Acme Inc", "Sr. Backend Eng (Remote)" -> "acme|senior backend engineer"
+pub fn title_key(company: &str, role: &str) -> String {
+    let mut bare = String::new();
+    let mut depth = 0;
+    for c in role.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = (depth - 1).max(0),
+            c if depth == 0 => bare.push(c),
+            _ => {}
+        }
+    }
+    let cleaned: String = bare.to_lowercase().chars().map(|c| if c.is_alphanumeric() { c } else { ' ' }).collect();
+    let words: Vec<&str> = cleaned
+        .split_whitespace()
+        .map(|word| TITLE_WORDS.iter().find(|(short, _)| *short == word).map_or(word, |(_, long)| *long))
+        .collect();
+    format!("{}|{}", normalize_company(company), words.join(" "))
+}
+
+/// FNV-1a, so the hash is the same from one build to the next
+fn hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// The links a job is known by, canonical
+fn link_prints(job: &Job) -> Vec<String> {
+    std::iter::once(job.post_link.as_str())
+        .chain(job.listings.iter().map(|l| l.link.as_str()))
+        .filter_map(canonical_url)
+        .collect()
+}
+
+/// Every fingerprint of `job`: "url:acme.com/jobs/42" for each link it's
+/// known by, and "title:" with the hash of its company and title
+pub fn fingerprints(job: &Job) -> Vec<String> {
+    let mut prints: Vec<String> = link_prints(job).into_iter().map(|link| format!("url:{}", link)).collect();
+    prints.push(format!("title:{:016x}", hash(&title_key(&job.company, &job.role))));
+    prints
+}
+
+/// The job in `jobs` that `posting` is a repeat of, if any
+pub fn find(jobs: &[Job], posting: &Job) -> Option<usize> {
+    let prints = fingerprints(posting);
+    jobs.iter().position(|tracked| {
+        fingerprints(tracked)
+            .iter()
+            .any(|print| prints.contains(print) && (print.starts_with("url:") || tracked.is_open()))
+    })
+}
+
+/// Keep `posting`, seen on `day`, as another listing of `tracked`: its link
+/// when that's a new one, or just its source when it came without a link.
+/// Either way the posting was up then, see evergreen.rs. What `tracked` is
+/// still missing (a description, a salary, a location) comes from the
+/// posting, and the fields that did are returned so the merge can be told.
+pub fn add_listing(tracked: &mut Job, posting: &Job, day: NaiveDate) -> Vec<&'static str> {
+    evergreen::seen_on(tracked, day);
+    let source = posting.source.clone().or_else(|| posting.guess_source());
+    let new = match canonical_url(&posting.post_link) {
+        Some(link) => !link_prints(tracked).contains(&link),
+        None => source.is_some() && source != tracked.source && !tracked.listings.iter().any(|l| l.source == source),
+    };
+    if new {
+        tracked.listings.push(Listing { source, link: posting.post_link.trim().to_string(), seen: day });
+    }
+
+    let mut filled = Vec::new();
+    if tracked.description.trim().is_empty() && !posting.description.trim().is_empty() {
+        tracked.description = posting.description.clone();
+        filled.push("description");
+    }
+    if tracked.salary_min.is_none() && tracked.salary_max.is_none() && (posting.salary_min.is_some() || posting.salary_max.is_some()) {
+        (tracked.salary_min, tracked.salary_max) = (posting.salary_min, posting.salary_max);
+        tracked.salary_currency = posting.salary_currency.clone();
+        filled.push("salary");
+    }
+    if tracked.location.is_empty() && tracked.work_mode.is_none() && (!posting.location.is_empty() || posting.work_mode.is_some()) {
+        (tracked.location, tracked.work_mode) = (posting.location.clone(), posting.work_mode);
+        filled.push("location");
+    }
+    filled
+}
+
+/// "Acme - Engineer" with what a repeat of it filled in, as
+/// "Acme - Engineer, taking its description and salary"
+pub fn merged(tracked: &Job, filled: &[&str]) -> String {
+    let taken = match filled {
+        [] => String::new(),
+        [only] => format!(", taking its {}", only),
+        [rest @ .., last] => format!(", taking its {} and {}", rest.join(", "), last),
+    };
+    format!("{} - {}{}", tracked.company, tracked.role, taken)
+}
+
+/// Split an import into the postings that are new and the ones that were
+/// already tracked, in the list or the inbox. Repeats become listings of
+/// the job they repeat instead of coming in twice, and are returned as
+/// what they were merged into (see `merged`); the new ones get their first
+/// sighting.
+pub fn sift(jobs: &mut [Job], pending: &mut [Job], incoming: Vec<Job>, day: NaiveDate) -> (Vec<Job>, Vec<String>) {
+    let mut fresh: Vec<Job> = Vec::new();
+    let mut again = Vec::new();
+    for mut posting in incoming {
+        let tracked = match find(jobs, &posting) {
+            Some(i) => Some(&mut jobs[i]),
+            None => match find(pending, &posting) {
+                Some(i) => Some(&mut pending[i]),
+                None => find(&fresh, &posting).map(|i| &mut fresh[i]),
+            },
+        };
+        match tracked {
+            Some(tracked) => {
+                let filled = add_listing(tracked, &posting, day);
+                again.push(merged(tracked, &filled));
+            }
+            None => {
+                evergreen::seen_on(&mut posting, day);
+                fresh.push(posting);
+            }
+        }
+    }
+    (fresh, again)
+}
+
+/// "LinkedIn, 2026-10-12: https://..." for the detail pane and `show`
+pub fn describe(listing: &Listing) -> String {
+    let source = listing.source.as_deref().unwrap_or("Elsewhere");
+    if listing.link.is_empty() {
+        format!("{}, {}", source, listing.seen)
+    } else {
+        format!("{}, {}: {}", source, listing.seen, listing.link)
+    }
+}
@@ -0,0 +1,159 @@
+//! Deterministic jobs for tests and for tools embedding the tracker.
+//!
+//! ```
+//! use career_cli::fixtures::JobBuilder;
+//! use career_cli::models::Status;
+//!
+//! let job = JobBuilder::new("Acme", "Engineer").id(3).status(Status::Interviewing).build();
+//! assert_eq!(job.company, "Acme");
+//! ```
+
+use crate::models::{Job, JobId, Stage, Status};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+
+/// Every fixture date is counted from here, so nothing depends on the clock
+pub fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap()
+}
+
+pub struct JobBuilder {
+    job: Job,
+}
+
+impl JobBuilder {
+    pub fn new(company: &str, role: &str) -> Self {
+        let mut job = Job::new(JobId::legacy(1), company.to_string(), role.to_string(), String::new());
+        job.date_applied = epoch();
+        Self { job }
+    }
+
+    /// Numbered like the ids files had before they were UUIDs, see `JobId::legacy`
+    pub fn id(mut self, n: usize) -> Self {
+        self.job.id = JobId::legacy(n as u64);
+        self
+    }
+
+    pub fn status(mut self, status: Status) -> Self {
+        self.job.status = status;
+        self
+    }
+
+    pub fn link(mut self, link: &str) -> Self {
+        self.job.post_link = link.to_string();
+        self
+    }
+
+    pub fn notes(mut self, notes: &str) -> Self {
+        self.job.notes = notes.to_string();
+        self
+    }
+
+    /// Applied this many days after `epoch()`
+    pub fn applied_days_after_epoch(mut self, days: i64) -> Self {
+        self.job.date_applied = epoch() + Duration::days(days);
+        self
+    }
+
+    pub fn applied_at(mut self, date: DateTime<Utc>) -> Self {
+        self.job.date_applied = date;
+        self
+    }
+
+    pub fn responded_at(mut self, date: DateTime<Utc>) -> Self {
+        self.job.responded_at = Some(date);
+        self
+    }
+
+    pub fn rejected_at(mut self, date: DateTime<Utc>, cooldown_months: u32) -> Self {
+        self.job.status = Status::Rejected;
+        self.job.rejected_at = Some(date);
+        self.job.cooldown_months = Some(cooldown_months);
+        self
+    }
+
+    /// Stage names, with the first `done` of them completed
+    pub fn stages(mut self, names: &[&str], done: usize) -> Self {
+        self.job.stages = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Stage { done: i < done, ..Stage::new(name) })
+            .collect();
+        self
+    }
+
+    pub fn cycle(mut self, cycle: &str) -> Self {
+        self.job.cycle = Some(cycle.to_string());
+        self
+    }
+
+    pub fn deadline(mut self, date: NaiveDate) -> Self {
+        self.job.deadline = Some(date);
+        self
+    }
+
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.job.revision = revision;
+        self
+    }
+
+    pub fn build(self) -> Job {
+        self.job
+    }
+}
+
+/// A small mixed pipeline with unique ids, one job per status
+pub fn sample_jobs() -> Vec<Job> {
+    vec![
+        JobBuilder::new("Acme", "Backend Engineer")
+            .id(1)
+            .link("https://boards.greenhouse.io/acme/jobs/1")
+            .build(),
+        JobBuilder::new("Globex", "SRE")
+            .id(2)
+            .status(Status::Interviewing)
+            .applied_days_after_epoch(3)
+            .responded_at(epoch() + Duration::days(10))
+            .stages(&["Recruiter call", "Phone screen", "Onsite"], 1)
+            .build(),
+        JobBuilder::new("Initech", "Platform Engineer")
+            .id(3)
+            .status(Status::Offer)
+            .applied_days_after_epoch(7)
+            .responded_at(epoch() + Duration::days(12))
+            .build(),
+        JobBuilder::new("Hooli", "Staff Engineer")
+            .id(4)
+            .applied_days_after_epoch(10)
+            .rejected_at(epoch() + Duration::days(30), 6)
+            .build(),
+        JobBuilder::new("Umbrella", "Developer")
+            .id(5)
+            .status(Status::Ghosted)
+            .applied_days_after_epoch(14)
+            .build(),
+    ]
+}
+
+/// `count` jobs cycling through statuses, companies and boards, each with
+/// `note_bytes` of notes. For benchmarks and stress testing the UI.
+pub fn large_pipeline(count: usize, note_bytes: usize) -> Vec<Job> {
+    let companies = ["Acme", "Globex Inc.", "Initech", "Hooli", "Umbrella Corp", "Stark Industries", "Wayne Enterprises"];
+    let boards = ["boards.greenhouse.io", "jobs.lever.co", "www.linkedin.com", "careers.example.com"];
+    let statuses = Status::ALL;
+    let line = "Talked to the recruiter about the team, comp band and next steps.\n";
+    let notes = line.repeat(note_bytes / line.len() + 1)[..note_bytes].to_string();
+
+    (0..count)
+        .map(|i| {
+            let board = boards[i % boards.len()];
+            JobBuilder::new(companies[i % companies.len()], &format!("Software Engineer {}", i))
+                .id(i + 1)
+                .status(statuses[i % statuses.len()].clone())
+                .link(&format!("https://{}/jobs/{}", board, i))
+                .notes(&notes)
+                .applied_days_after_epoch((i / 10) as i64)
+                .stages(&["Recruiter call", "Phone screen", "Onsite"], i % 4)
+                .build()
+        })
+        .collect()
+}
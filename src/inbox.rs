@@ -0,0 +1,133 @@
+//! Imported jobs waiting for review. Anything that comes in from outside
+//! lands in inbox.json first, and only what I accept (in the TUI or with
+//! `career-cli inbox accept`) joins the real list, so a sloppy export can't
+//! fill it with junk.
+//!
+//! They're numbered by their place in the inbox, and keep the id they were
+//! given on import when accepted.
+
+use crate::models::{self, Job, JobId, Status};
+use crate::salary;
+use crate::storage::get_data_dir;
+use crate::validate;
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use std::fs;
+use std::path::PathBuf;
+
+fn inbox_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("inbox.json"))
+}
+
+pub fn load_inbox() -> Result<Vec<Job>> {
+    let path = inbox_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).context("Failed to read inbox.json")?;
+    serde_json::from_str(&content).context("Failed to parse inbox.json")
+}
+
+pub fn save_inbox(pending: &[Job]) -> Result<()> {
+    let json = serde_json::to_string_pretty(pending).context("Failed to serialize the inbox")?;
+    fs::write(inbox_path()?, json).context("Failed to write inbox.json")
+}
+
+/// Add `incoming` after what's already waiting
+pub fn queue(pending: &mut Vec<Job>, incoming: Vec<Job>) {
+    pending.extend(incoming);
+}
+
+/// Rows of a CSV export, one job each. The header names the columns:
+/// company and role are required, link, status, applied (2026-10-01),
+/// location, salary, source and notes are picked up when there. Rows that
+/// don't make a valid job are returned as "line 4: ..." instead.
+pub fn parse_csv(text: &str) -> Result<(Vec<Job>, Vec<String>)> {
+    let mut rows = records(text).into_iter();
+    let Some((_, header)) = rows.next() else {
+        bail!("The CSV is empty");
+    };
+    let column = |names: &[&str]| header.iter().position(|h| names.contains(&h.trim().to_lowercase().as_str()));
+    let (Some(company), Some(role)) = (column(&["company", "employer"]), column(&["role", "title", "position"])) else {
+        bail!("The CSV needs a company and a role column");
+    };
+    let link = column(&["link", "url", "post_link"]);
+    let status = column(&["status"]);
+    let applied = column(&["applied", "date", "date_applied"]);
+    let location = column(&["location", "city"]);
+    let pay = column(&["salary", "pay", "compensation"]);
+    let notes = column(&["notes", "note"]);
+    let source = column(&["source", "channel"]);
+
+    let (mut jobs, mut skipped) = (Vec::new(), Vec::new());
+    for (line, row) in rows {
+        let cell = |index: Option<usize>| index.and_then(|i| row.get(i)).map_or("", |c| c.trim());
+        if row.iter().all(|c| c.trim().is_empty()) {
+            continue;
+        }
+        match row_to_job([cell(Some(company)), cell(Some(role)), cell(link), cell(status), cell(applied), cell(location), cell(pay), cell(source), cell(notes)]) {
+            Ok(job) => jobs.push(job),
+            Err(e) => skipped.push(format!("line {}: {}", line, e)),
+        }
+    }
+    Ok((jobs, skipped))
+}
+
+fn row_to_job([company, role, link, status, applied, location, pay, source, notes]: [&str; 9]) -> Result<Job> {
+    validate::company(company)?;
+    if role.is_empty() {
+        bail!("Role can't be empty");
+    }
+    validate::link(link)?;
+    let mut job = Job::new(JobId::new(), company.to_string(), role.to_string(), link.to_string());
+    if !status.is_empty() {
+        job.status = Status::parse(status).with_context(|| format!("Unknown status '{}'", status))?;
+    }
+    if !applied.is_empty() {
+        let date = NaiveDate::parse_from_str(applied, "%Y-%m-%d").with_context(|| format!("Applied dates look like 2026-10-01, not '{}'", applied))?;
+        job.date_applied = date.and_time(chrono::NaiveTime::MIN).and_utc();
+    }
+    (job.location, job.work_mode) = models::parse_location(location);
+    let (min, max, currency) = salary::parse(pay).with_context(|| format!("Couldn't read the salary '{}'", pay))?;
+    (job.salary_min, job.salary_max, job.salary_currency) = (min, max, currency);
+    job.source = models::parse_source(source);
+    job.notes = notes.to_string();
+    Ok(job)
+}
+
+/// The fields of each record with the line it starts on. Quoted fields can
+/// hold commas, newlines and doubled quotes.
+fn records(text: &str) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let (mut record, mut field) = (Vec::new(), String::new());
+    let (mut line, mut start, mut quoted) = (1, 1, false);
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                records.push((start, std::mem::take(&mut record)));
+                line += 1;
+                start = line;
+            }
+            c => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((start, record));
+    }
+    records
+}
@@ -0,0 +1,174 @@
+//! One TUI at a time, with a control socket (tui.sock in the data dir) that
+//! `career-cli add` and `career-cli note` hand their change to while it's
+//! open. The TUI makes the change itself and saves, so nothing else writes
+//! underneath it, and the new job shows up in the list straight away. Other
+//! commands that write refuse while it's open, see `is_open`.
+//!
+//! A connection carries one request and one reply, a line of JSON each.
+//! Unix only; elsewhere there's no socket and the commands write the files
+//! directly as before.
+
+use crate::storage::get_data_dir;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// What a command asks the open TUI to do
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "command", rename_all = "lowercase")]
+pub enum Request {
+    Add {
+        company: String,
+        role: String,
+        link: String,
+        posting: String,
+        #[serde(default)]
+        separate: bool, // Add it even if it repeats a tracked job, see fingerprint.rs
+    },
+    Note { job: String, text: String },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Reply {
+    ok: bool,
+    message: String, // What the command prints, or the error
+}
+
+pub fn socket_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("tui.sock"))
+}
+
+/// Hand `request` to the open TUI and return what it said. None when no
+/// TUI is open, for the command to do it itself.
+pub fn send(request: &Request) -> Result<Option<String>> {
+    send_to(&socket_path()?, request)
+}
+
+/// Whether a TUI is open and answering on the socket
+pub fn is_open() -> Result<bool> {
+    Ok(listening(&socket_path()?))
+}
+
+#[cfg(unix)]
+pub use unix::{listening, send_to, Server};
+
+#[cfg(unix)]
+mod unix {
+    use super::*;
+    use std::io::{BufRead, BufReader, ErrorKind, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::Path;
+    use std::time::Duration;
+
+    /// The open TUI's end of the socket. Removed again when dropped.
+    pub struct Server {
+        listener: UnixListener,
+        path: PathBuf,
+    }
+
+    impl Server {
+        /// Claim the socket in the data dir, failing when another TUI has it
+        pub fn start() -> Result<Server> {
+            Server::bind(&socket_path()?)
+        }
+
+        pub fn bind(path: &Path) -> Result<Server> {
+            if path.exists() {
+                if listening(path) {
+                    bail!("career-cli is already open in another terminal. Commands like add reach it from here.");
+                }
+                // Left behind by a TUI that didn't get to clean up
+                std::fs::remove_file(path).with_context(|| format!("Failed to remove {}", path.display()))?;
+            }
+            let listener = UnixListener::bind(path).with_context(|| format!("Failed to listen on {}", path.display()))?;
+            listener.set_nonblocking(true)?;
+            Ok(Server { listener, path: path.to_path_buf() })
+        }
+
+        /// Answer every request waiting, without blocking when there are none
+        pub fn serve(&self, mut handle: impl FnMut(Request) -> Result<String>) {
+            while let Ok((stream, _)) = self.listener.accept() {
+                // A client that hangs up early only loses its own reply
+                let _ = answer(stream, &mut handle);
+            }
+        }
+    }
+
+    impl Drop for Server {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+
+    fn answer(stream: UnixStream, handle: &mut impl FnMut(Request) -> Result<String>) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+        let mut line = String::new();
+        BufReader::new(&stream).read_line(&mut line)?;
+        let reply = match serde_json::from_str::<Request>(&line).context("Didn't understand the request") {
+            Ok(request) => match handle(request) {
+                Ok(message) => Reply { ok: true, message },
+                Err(err) => Reply { ok: false, message: format!("{:#}", err) },
+            },
+            Err(err) => Reply { ok: false, message: format!("{:#}", err) },
+        };
+        writeln!(&stream, "{}", serde_json::to_string(&reply)?)?;
+        Ok(())
+    }
+
+    pub fn listening(path: &Path) -> bool {
+        UnixStream::connect(path).is_ok()
+    }
+
+    pub fn send_to(path: &Path, request: &Request) -> Result<Option<String>> {
+        let Ok(mut stream) = UnixStream::connect(path) else {
+            return Ok(None);
+        };
+        // The TUI checks between key presses, a few times a second
+        stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+        writeln!(stream, "{}", serde_json::to_string(request)?)?;
+        let mut line = String::new();
+        match BufReader::new(&stream).read_line(&mut line) {
+            Err(err) if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                bail!("The open TUI didn't answer in time")
+            }
+            result => result.context("Lost the connection to the open TUI")?,
+        };
+        let reply: Reply = serde_json::from_str(&line).context("The open TUI sent back something unexpected")?;
+        if !reply.ok {
+            bail!(reply.message);
+        }
+        Ok(Some(reply.message))
+    }
+}
+
+#[cfg(not(unix))]
+pub use other::{listening, send_to, Server};
+
+#[cfg(not(unix))]
+mod other {
+    use super::*;
+    use std::path::Path;
+
+    pub struct Server;
+
+    impl Server {
+        pub fn start() -> Result<Server> {
+            Ok(Server)
+        }
+
+        pub fn bind(_path: &Path) -> Result<Server> {
+            Ok(Server)
+        }
+
+        pub fn serve(&self, _handle: impl FnMut(Request) -> Result<String>) {}
+    }
+
+    pub fn listening(_path: &Path) -> bool {
+        false
+    }
+
+    pub fn send_to(_path: &Path, _request: &Request) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
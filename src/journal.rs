@@ -0,0 +1,283 @@
+use crate::models::{Job, JobId};
+use crate::storage::get_data_dir;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::hash_map::RandomState;
+use std::fs::{self, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Fields every save keeps up by itself. They're journaled like the rest
+/// but aren't changes of mine, so blame, undo and merging look past them.
+pub const BOOKKEEPING: &[&str] = &["revision", "updated_at"];
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JournalOp {
+    Create,
+    Update,
+    Delete,
+    Undo,
+}
+
+/// What made a change: me editing a job, in the TUI or with a command, a
+/// `bulk set`, accepting an import from the inbox, or an undo
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Edit,
+    Bulk,
+    Import,
+    Undo,
+}
+
+impl Source {
+    pub fn label(self) -> &'static str {
+        match self {
+            Source::Edit => "edit",
+            Source::Bulk => "bulk set",
+            Source::Import => "import",
+            Source::Undo => "undo",
+        }
+    }
+}
+
+/// One line of journal.jsonl. Creates carry the whole job in `new`, deletes the
+/// whole job in `old`, updates one field each. An undo is a marker after the
+/// changes that reverted an earlier save, see undo.rs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JournalEntry {
+    pub op: JournalOp,
+    pub job_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+    pub timestamp: DateTime<Utc>,
+    pub device_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undoes: Option<DateTime<Utc>>, // Undo markers only: the save that was reverted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undoes_device: Option<String>, // And the device that saved it, None in markers from before it was kept
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>, // None in entries written before sources were recorded
+}
+
+/// Whether two `job_id`s are the same job. Entries written before ids were
+/// UUIDs have the old number, which stands for `JobId::legacy` of it.
+pub fn same_id(a: &str, b: &str) -> bool {
+    match (a.parse::<JobId>(), b.parse::<JobId>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn journal_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("journal.jsonl"))
+}
+
+/// Random per-machine id. Lives outside the data dir on purpose, since that
+/// folder may be synced between machines.
+pub fn device_id() -> Result<String> {
+    let dirs = ProjectDirs::from("", "", "career-cli").context("Could not determine home directory")?;
+    let path = dirs.data_local_dir().join("device_id");
+    if let Ok(id) = fs::read_to_string(&path) {
+        return Ok(id.trim().to_string());
+    }
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(Utc::now().timestamp_nanos_opt().unwrap_or_default() as u128);
+    hasher.write_u32(std::process::id());
+    let id = format!("{:016x}", hasher.finish());
+
+    fs::create_dir_all(dirs.data_local_dir()).context("Failed to create local data directory")?;
+    fs::write(&path, &id).context("Failed to write device id")?;
+    Ok(id)
+}
+
+/// What changed between two snapshots of the job list
+pub fn diff(before: &[Job], after: &[Job], source: Option<Source>) -> Result<Vec<JournalEntry>> {
+    let device_id = device_id()?;
+    let timestamp = Utc::now();
+    let entry = |op, job_id: &str, field: Option<&str>, old: Option<Value>, new: Option<Value>| JournalEntry {
+        op,
+        job_id: job_id.to_string(),
+        field: field.map(str::to_string),
+        old,
+        new,
+        timestamp,
+        device_id: device_id.clone(),
+        undoes: None,
+        undoes_device: None,
+        source,
+    };
+
+    let before = to_objects(before)?;
+    let after = to_objects(after)?;
+    let mut entries = Vec::new();
+
+    for (id, new_job) in &after {
+        match before.iter().find(|(old_id, _)| old_id == id) {
+            None => entries.push(entry(JournalOp::Create, id, None, None, Some(Value::Object(new_job.clone())))),
+            Some((_, old_job)) => {
+                let mut fields: Vec<&String> = old_job.keys().chain(new_job.keys()).collect();
+                fields.sort();
+                fields.dedup();
+                for field in fields {
+                    let (old, new) = (old_job.get(field), new_job.get(field));
+                    if old != new {
+                        entries.push(entry(JournalOp::Update, id, Some(field), old.cloned(), new.cloned()));
+                    }
+                }
+            }
+        }
+    }
+    for (id, old_job) in &before {
+        if !after.iter().any(|(new_id, _)| new_id == id) {
+            entries.push(entry(JournalOp::Delete, id, None, Some(Value::Object(old_job.clone())), None));
+        }
+    }
+    Ok(entries)
+}
+
+fn to_objects(jobs: &[Job]) -> Result<Vec<(String, Map<String, Value>)>> {
+    jobs.iter()
+        .map(|job| match serde_json::to_value(job)? {
+            Value::Object(map) => Ok((job.id.to_string(), map)),
+            _ => bail!("Job did not serialize to an object"),
+        })
+        .collect()
+}
+
+/// Append the changes between the two snapshots. The first write also records a
+/// create for everything that already existed, so replay has a starting point.
+pub fn record(before: &[Job], after: &[Job], source: Source) -> Result<()> {
+    let path = journal_path()?;
+    let mut entries = if path.exists() { Vec::new() } else { diff(&[], before, None)? };
+    entries.extend(diff(before, after, Some(source))?);
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    append(&path, &entries)
+}
+
+/// Mark the save `undone_device` made at `undone` as reverted. `reverted_at`
+/// is when the changes that reverted it were recorded, so they aren't undone
+/// in turn.
+pub fn record_undo(undone: DateTime<Utc>, undone_device: &str, reverted_at: DateTime<Utc>) -> Result<()> {
+    let entry = JournalEntry {
+        op: JournalOp::Undo,
+        job_id: String::new(),
+        field: None,
+        old: None,
+        new: None,
+        timestamp: reverted_at,
+        device_id: device_id()?,
+        undoes: Some(undone),
+        undoes_device: Some(undone_device.to_string()),
+        source: Some(Source::Undo),
+    };
+    append(&journal_path()?, &[entry])
+}
+
+fn append(path: &Path, entries: &[JournalEntry]) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .context("Failed to open journal.jsonl")?;
+    for entry in entries {
+        let line = serde_json::to_string(entry).context("Failed to serialize journal entry")?;
+        writeln!(file, "{}", line).context("Failed to write journal.jsonl")?;
+    }
+    Ok(())
+}
+
+pub fn read_journal() -> Result<Vec<JournalEntry>> {
+    let path = journal_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).context("Failed to read journal.jsonl")?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line).with_context(|| format!("Bad journal entry on line {}", i + 1))
+        })
+        .collect()
+}
+
+/// Rebuild the job list from scratch by applying every entry in order
+pub fn replay(entries: &[JournalEntry]) -> Result<Vec<Job>> {
+    let mut jobs: Vec<(String, Map<String, Value>)> = Vec::new();
+    for entry in entries {
+        let position = jobs.iter().position(|(id, _)| same_id(id, &entry.job_id));
+        match (&entry.op, position) {
+            (JournalOp::Create, None) => {
+                if let Some(Value::Object(job)) = &entry.new {
+                    jobs.push((entry.job_id.clone(), job.clone()));
+                }
+            }
+            (JournalOp::Update, Some(i)) => {
+                if let Some(field) = &entry.field {
+                    match &entry.new {
+                        Some(value) => jobs[i].1.insert(field.clone(), value.clone()),
+                        None => jobs[i].1.remove(field),
+                    };
+                }
+            }
+            (JournalOp::Delete, Some(i)) => {
+                jobs.remove(i);
+            }
+            // Updates to jobs we never saw created, or double creates. Skip them
+            // rather than give up on the whole replay. Undo markers change
+            // nothing themselves, the entries before them did.
+            _ => {}
+        }
+    }
+
+    jobs.into_iter()
+        .map(|(id, job)| {
+            serde_json::from_value(Value::Object(job)).with_context(|| format!("Replayed job {} is invalid", id))
+        })
+        .collect()
+}
+
+/// When a field last changed and what changed it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blame {
+    pub field: String,
+    pub at: DateTime<Utc>,
+    pub source: Option<Source>,
+}
+
+impl Blame {
+    /// "2026-10-12 14:03, bulk set"
+    pub fn describe(&self) -> String {
+        let source = self.source.map_or("unknown", Source::label);
+        format!("{}, {}", self.at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"), source)
+    }
+}
+
+/// The last change to each field of job `job_id`, newest first. Fields
+/// that haven't changed since the job was created aren't listed, and
+/// neither are the BOOKKEEPING ones every save bumps.
+pub fn blame(entries: &[JournalEntry], job_id: &str) -> Vec<Blame> {
+    let mut last: Vec<Blame> = Vec::new();
+    for entry in entries.iter().filter(|e| e.op == JournalOp::Update && same_id(&e.job_id, job_id)) {
+        let Some(field) = entry.field.as_deref().filter(|f| !BOOKKEEPING.contains(f)) else { continue };
+        last.retain(|b| b.field != field);
+        last.push(Blame { field: field.to_string(), at: entry.timestamp, source: entry.source });
+    }
+    last.reverse();
+    last
+}
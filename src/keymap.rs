@@ -0,0 +1,284 @@
+//! Normal-mode key bindings. Defaults match the footer help; config.toml can
+//! rebind any action:
+//!
+//! ```toml
+//! [keymap]
+//! down = ["j", "Down"]
+//! up = ["k", "Up"]
+//! delete = ["x"]
+//! ```
+//!
+//! Keys are a single character, a named key (Enter, Esc, Tab, Up, Down, Left,
+//! Right, PageUp, PageDown, Home, End, Backspace, Space) or either of those
+//! with a `ctrl-` prefix.
+
+use anyhow::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Down,
+    Up,
+    Add,
+    FinishDraft,
+    EditLink,
+    Note,
+    EditNotes,
+    Description,
+    CycleStatus,
+    CloseStatus,
+    CyclePriority,
+    Star,
+    Sort,
+    StatusHistory,
+    Delete,
+    Archive,
+    Pipeline,
+    OpenLink,
+    AddLink,
+    Files,
+    Answers,
+    Network,
+    AsOf,
+    CompanyHistory,
+    EditCooldown,
+    Cooldowns,
+    EditDeadline,
+    ReplyBy,
+    EditApplied,
+    FollowUp,
+    EditStart,
+    Salary,
+    Location,
+    Timezone,
+    ReqId,
+    Tags,
+    Skills,
+    TagFilter,
+    Agenda,
+    Inbox,
+    Assessment,
+    Agency,
+    Source,
+    Resume,
+    CoverLetter,
+    Contact,
+    Engagement,
+    Interview,
+    Expense,
+    Transcript,
+    Briefing,
+    Expenses,
+    Relocation,
+    Offers,
+    Onboarding,
+    QrCode,
+    LoopTemplate,
+    CompleteStage,
+    ReopenStage,
+    Filter,
+    BulkEdit,
+    Details,
+    SwitchFocus,
+    Stats,
+    Settings,
+    Usage,
+    Availability,
+    Back,
+}
+
+impl Action {
+    /// Every action with its config name, default keys and footer label, in
+    /// footer order. Actions without a label are left out of the footer.
+    pub const ALL: &'static [(Action, &'static str, &'static [&'static str], &'static str)] = &[
+        (Action::Add, "add", &["a"], "Add"),
+        (Action::FinishDraft, "finish_draft", &["f"], "Finish Draft"),
+        (Action::EditLink, "edit_link", &["e"], "Edit Link"),
+        (Action::Salary, "salary", &["P"], "Salary"),
+        (Action::Location, "location", &["L"], "Location"),
+        (Action::Timezone, "timezone", &["G"], "Timezone"),
+        (Action::ReqId, "req_id", &["#"], "Req ID"),
+        (Action::Note, "note", &["N"], "Note"),
+        (Action::EditNotes, "edit_notes", &["n"], "Notes"),
+        (Action::Description, "description", &["J"], "Posting Text"),
+        (Action::Delete, "delete", &["d"], "Delete"),
+        (Action::Archive, "archive", &["z"], "Archive"),
+        (Action::Pipeline, "pipeline", &["Z"], "Active/Closed"),
+        (Action::CycleStatus, "cycle_status", &["Enter"], "Change Status"),
+        (Action::CloseStatus, "close_status", &["-"], "Close"),
+        (Action::StatusHistory, "status_history", &["y"], "Timeline"),
+        (Action::CyclePriority, "cycle_priority", &["!"], "Priority"),
+        (Action::Star, "star", &["*"], "Star"),
+        (Action::Sort, "sort", &["="], "Sort"),
+        (Action::OpenLink, "open_link", &["o"], "Open Link"),
+        (Action::AddLink, "add_link", &["+"], "Add Link"),
+        (Action::Files, "files", &["%"], "Files"),
+        (Action::Answers, "answers", &["?"], "Form Answers"),
+        (Action::Network, "network", &["&"], "Network"),
+        (Action::AsOf, "as_of", &["<"], "As Of"),
+        (Action::QrCode, "qr_code", &["Q"], "QR"),
+        (Action::CompanyHistory, "company_history", &["h"], "Company History"),
+        (Action::EditCooldown, "edit_cooldown", &["c"], "Cool-down"),
+        (Action::Cooldowns, "cooldowns", &["C"], "Cool-downs"),
+        (Action::EditDeadline, "edit_deadline", &["w"], "Deadline"),
+        (Action::ReplyBy, "reply_by", &["~"], "Reply By"),
+        (Action::EditApplied, "edit_applied", &["Y"], "Applied Date"),
+        (Action::FollowUp, "follow_up", &["F"], "Follow Up"),
+        (Action::Assessment, "assessment", &["O"], "OA"),
+        (Action::Agenda, "agenda", &["D"], "Agenda"),
+        (Action::Inbox, "inbox", &["m"], "Inbox"),
+        (Action::Agency, "agency", &["A"], "Agency"),
+        (Action::Source, "source", &["g"], "Source"),
+        (Action::Resume, "resume", &["V"], "Resume"),
+        (Action::CoverLetter, "cover_letter", &["K"], "Letter"),
+        (Action::Contact, "contact", &["r"], "Contact"),
+        (Action::Engagement, "engagement", &["E"], "Type/Rate"),
+        (Action::Interview, "interview", &["I"], "Interview"),
+        (Action::Expense, "expense", &["X"], "Expense"),
+        (Action::Transcript, "transcript", &["U"], "Transcript"),
+        (Action::Briefing, "briefing", &["p"], "Briefing"),
+        (Action::Expenses, "expenses", &["$"], "Owed"),
+        (Action::Relocation, "relocation", &["R"], "Relocation"),
+        (Action::EditStart, "edit_start", &["S"], "Start Date"),
+        (Action::Offers, "offers", &["M"], "Compare Offers"),
+        (Action::Onboarding, "onboarding", &["b"], "Onboarding"),
+        (Action::LoopTemplate, "loop_template", &["l"], "Loop"),
+        (Action::CompleteStage, "complete_stage", &["]"], "Stage Done"),
+        (Action::ReopenStage, "reopen_stage", &["["], "Stage Undo"),
+        (Action::Filter, "filter", &["/"], "Filter"),
+        (Action::Tags, "tags", &["t"], "Tags"),
+        (Action::Skills, "skills", &["^"], "Skills"),
+        (Action::TagFilter, "tag_filter", &["T"], "Filter by Tag"),
+        (Action::BulkEdit, "bulk_edit", &["B"], "Bulk Edit"),
+        (Action::Details, "details", &["v"], "Details"),
+        (Action::Stats, "stats", &["s"], "Stats"),
+        (Action::Settings, "settings", &[","], "Settings"),
+        (Action::Usage, "usage", &["u"], "My Usage"),
+        (Action::Availability, "availability", &["@"], "Copy Availability"),
+        (Action::Quit, "quit", &["q"], "Quit"),
+        (Action::Down, "down", &["Down"], ""),
+        (Action::Up, "up", &["Up"], ""),
+        (Action::SwitchFocus, "switch_focus", &["Tab"], ""),
+        (Action::Back, "back", &["Esc"], ""),
+    ];
+
+    pub fn name(self) -> &'static str {
+        Action::ALL.iter().find(|(a, ..)| *a == self).map(|(_, name, ..)| *name).unwrap_or("")
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        // Its name from before it showed closed jobs as well as archived ones
+        let name = if name == "show_archived" { "pipeline" } else { name };
+        Action::ALL.iter().find(|(_, n, ..)| *n == name).map(|(a, ..)| *a)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl KeyBinding {
+    pub fn parse(spec: &str) -> Result<KeyBinding> {
+        let (ctrl, key) = match spec.strip_prefix("ctrl-").or_else(|| spec.strip_prefix("Ctrl-")) {
+            Some(rest) if !rest.is_empty() => (true, rest),
+            _ => (false, spec),
+        };
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "enter" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "backspace" => KeyCode::Backspace,
+                "space" => KeyCode::Char(' '),
+                _ => bail!("Unknown key '{}'", spec),
+            },
+        };
+        Ok(KeyBinding { code, ctrl })
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        self.code == key.code && self.ctrl == key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// How the footer shows it, e.g. "'a'" or "Enter"
+    pub fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) if !self.ctrl => format!("'{}'", c),
+            KeyCode::Char(c) => c.to_string(),
+            other => format!("{:?}", other),
+        };
+        if self.ctrl { format!("Ctrl-{}", key) } else { key }
+    }
+}
+
+pub struct Keymap {
+    bindings: Vec<(KeyBinding, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Keymap::from_config(&BTreeMap::new()).expect("default keymap is valid")
+    }
+}
+
+impl Keymap {
+    /// Defaults with the config's overrides applied. An action listed in the
+    /// config loses its default keys. Two actions on one key is an error.
+    pub fn from_config(overrides: &BTreeMap<String, Vec<String>>) -> Result<Keymap> {
+        for name in overrides.keys() {
+            if Action::from_name(name).is_none() {
+                bail!("Unknown action '{}' in [keymap]", name);
+            }
+        }
+
+        let mut bindings: Vec<(KeyBinding, Action)> = Vec::new();
+        for (action, name, defaults, _) in Action::ALL {
+            let keys: Vec<&str> = match overrides.get(*name) {
+                Some(keys) => keys.iter().map(String::as_str).collect(),
+                None => defaults.to_vec(),
+            };
+            for key in keys {
+                let binding = KeyBinding::parse(key)?;
+                if let Some((_, other)) = bindings.iter().find(|(b, _)| *b == binding) {
+                    bail!("Key '{}' is bound to both {} and {}", key, other.name(), name);
+                }
+                bindings.push((binding, *action));
+            }
+        }
+        Ok(Keymap { bindings })
+    }
+
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings.iter().find(|(b, _)| b.matches(key)).map(|(_, a)| *a)
+    }
+
+    /// First key bound to the action, for help text
+    pub fn key_for(&self, action: Action) -> Option<KeyBinding> {
+        self.bindings.iter().find(|(_, a)| *a == action).map(|(b, _)| *b)
+    }
+
+    /// " 'a': Add | 'e': Edit Link | ... " from whatever is bound
+    pub fn help(&self) -> String {
+        let parts: Vec<String> = Action::ALL
+            .iter()
+            .filter(|(.., label)| !label.is_empty())
+            .filter_map(|(action, .., label)| Some(format!("{}: {}", self.key_for(*action)?.label(), label)))
+            .collect();
+        format!(" {} ", parts.join(" | "))
+    }
+}
@@ -0,0 +1,51 @@
+pub mod answers;
+pub mod app;
+pub mod asof;
+pub mod availability;
+pub mod briefing;
+pub mod bulk;
+pub mod chart;
+pub mod cli;
+pub mod companies;
+pub mod complete;
+pub mod config;
+pub mod contract;
+pub mod currency;
+pub mod debrief;
+pub mod evergreen;
+pub mod export;
+pub mod filter;
+pub mod fingerprint;
+pub mod fixtures;
+pub mod inbox;
+pub mod ipc;
+pub mod journal;
+pub mod keymap;
+pub mod metrics;
+pub mod models;
+pub mod network;
+pub mod offers;
+pub mod outreach;
+pub mod posting;
+pub mod preset;
+pub mod references;
+pub mod reminders;
+pub mod runway;
+pub mod salary;
+pub mod schedule;
+pub mod scope;
+pub mod settings;
+pub mod skills;
+pub mod spell;
+pub mod statuses;
+pub mod storage;
+pub mod textarea;
+pub mod theme;
+pub mod timezone;
+pub mod transcript;
+pub mod transparency;
+pub mod travel;
+pub mod ui;
+pub mod undo;
+pub mod usage;
+pub mod validate;
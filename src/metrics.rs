@@ -0,0 +1,345 @@
+use crate::config::{EvergreenConfig, HealthConfig, MetricsConfig};
+use crate::evergreen::is_evergreen;
+use crate::models::{Job, Pipeline, RejectionStage, Status};
+use anyhow::{bail, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use std::collections::HashSet;
+
+/// Public holidays for the configured locale plus any extra dates from the config
+pub struct HolidayCalendar {
+    locale: String,
+    extra: HashSet<NaiveDate>,
+}
+
+impl HolidayCalendar {
+    pub fn from_config(config: &MetricsConfig) -> Result<Self> {
+        let locale = config
+            .holiday_locale
+            .clone()
+            .unwrap_or_else(|| "none".to_string())
+            .to_lowercase();
+        if !["us", "uk", "none"].contains(&locale.as_str()) {
+            bail!("Unknown holiday_locale '{}' (expected us, uk or none)", locale);
+        }
+        Ok(Self {
+            locale,
+            extra: config.holidays.iter().copied().collect(),
+        })
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.extra.contains(&date) || self.locale_holidays(date.year()).contains(&date)
+    }
+
+    fn locale_holidays(&self, year: i32) -> Vec<NaiveDate> {
+        let date = |m, d| NaiveDate::from_ymd_opt(year, m, d).unwrap();
+        match self.locale.as_str() {
+            "us" => vec![
+                date(1, 1),
+                nth_weekday(year, 1, Weekday::Mon, 3),  // MLK Day
+                nth_weekday(year, 2, Weekday::Mon, 3),  // Presidents' Day
+                last_weekday(year, 5, Weekday::Mon),    // Memorial Day
+                date(6, 19),
+                date(7, 4),
+                nth_weekday(year, 9, Weekday::Mon, 1),  // Labor Day
+                nth_weekday(year, 11, Weekday::Thu, 4), // Thanksgiving
+                date(12, 25),
+            ],
+            "uk" => {
+                let easter = easter_sunday(year);
+                vec![
+                    date(1, 1),
+                    easter - Duration::days(2), // Good Friday
+                    easter + Duration::days(1), // Easter Monday
+                    nth_weekday(year, 5, Weekday::Mon, 1),
+                    last_weekday(year, 5, Weekday::Mon),
+                    last_weekday(year, 8, Weekday::Mon),
+                    date(12, 25),
+                    date(12, 26),
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Weekdays between the two dates that aren't holidays. The start day isn't counted,
+/// so applying Friday and hearing back Monday is 1 business day.
+pub fn business_days_between(start: NaiveDate, end: NaiveDate, calendar: &HolidayCalendar) -> i64 {
+    let mut days = 0;
+    let mut date = start;
+    while date < end {
+        date += Duration::days(1);
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !calendar.is_holiday(date) {
+            days += 1;
+        }
+    }
+    days
+}
+
+/// Days from applying to the first response, for every job that got one
+pub fn response_times(jobs: &[Job], business_days: bool, calendar: &HolidayCalendar) -> Vec<i64> {
+    jobs.iter()
+        .filter_map(|job| {
+            let start = job.date_applied.date_naive();
+            let end = job.responded_at?.date_naive();
+            Some(if business_days {
+                business_days_between(start, end, calendar)
+            } else {
+                (end - start).num_days()
+            })
+        })
+        .collect()
+}
+
+/// Share of replies a job's wait is measured against
+const USUAL_SHARE: usize = 80;
+
+/// Fewer replies than this and there's nothing to measure against
+const MIN_REPLIES: usize = 5;
+
+/// How long a job has gone without a reply, next to how long the ones that
+/// did reply took
+pub struct ResponseWait {
+    pub day: i64,     // Days since applying
+    pub usual: i64,   // USUAL_SHARE of replies came within this many
+    pub longest: i64, // The slowest reply yet
+    pub business_days: bool,
+}
+
+impl ResponseWait {
+    /// Waited longer than most replies took, time to follow up or let it go
+    pub fn past_usual(&self) -> bool {
+        self.day > self.usual
+    }
+
+    /// "80% of companies that replied did so within 9 days, this one is at
+    /// day 14, worth a follow-up"
+    pub fn label(&self) -> String {
+        let unit = if self.business_days { "business days" } else { "days" };
+        let verdict = match self.day {
+            day if day > self.longest => ", longer than any reply so far",
+            day if day > self.usual => ", worth a follow-up",
+            _ => "",
+        };
+        format!(
+            "{}% of companies that replied did so within {} {}, this one is at day {}{}",
+            USUAL_SHARE, self.usual, unit, self.day, verdict
+        )
+    }
+}
+
+/// Where `job`, still waiting on a first reply, stands against the reply
+/// times in `history`. None for jobs that have heard back or gone
+/// elsewhere, and while there are too few replies to go on.
+pub fn response_wait(
+    job: &Job,
+    history: &[Job],
+    business_days: bool,
+    calendar: &HolidayCalendar,
+    today: NaiveDate,
+) -> Option<ResponseWait> {
+    if job.status != Status::Applied || job.responded_at.is_some() || job.draft {
+        return None;
+    }
+    let mut times = response_times(history, business_days, calendar);
+    if times.len() < MIN_REPLIES {
+        return None;
+    }
+    times.sort();
+    let start = job.date_applied.date_naive();
+    let day = if business_days { business_days_between(start, today, calendar) } else { (today - start).num_days() };
+    Some(ResponseWait {
+        day,
+        usual: times[(times.len() * USUAL_SHARE).div_ceil(100) - 1],
+        longest: *times.last()?,
+        business_days,
+    })
+}
+
+/// Rejections by how far they got, in pipeline order, then those with no
+/// stage recorded. Stages nobody was rejected at are left out.
+pub fn rejection_stages(jobs: &[Job]) -> Vec<(&'static str, usize)> {
+    let rejected: Vec<&Job> = jobs.iter().filter(|j| j.status == Status::Rejected).collect();
+    let mut counts: Vec<(&'static str, usize)> = RejectionStage::ALL
+        .iter()
+        .map(|stage| (stage.label(), rejected.iter().filter(|j| j.rejected_stage == Some(*stage)).count()))
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    let unrecorded = rejected.iter().filter(|j| j.rejected_stage.is_none()).count();
+    if unrecorded > 0 {
+        counts.push(("not recorded", unrecorded));
+    }
+    counts
+}
+
+/// A number in the stats view, and the list filter that shows the jobs
+/// behind it. None clears the filter, for the total.
+pub struct Figure {
+    pub label: String,
+    pub filter: Option<String>,
+    pub group: FigureGroup, // The row it's shown on
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FigureGroup {
+    Total,
+    Pipeline, // Active and closed
+    Status,
+    Rejected, // Where rejections happened
+    Health,
+}
+
+/// The stats view's numbers in the order they're shown, so the view can
+/// step through them: the total, active and closed, each status, where
+/// rejections happened, then the health signals
+pub fn figures(jobs: &[Job], health: &PipelineHealth) -> Vec<Figure> {
+    let mut figures = vec![Figure { label: format!("Total {}", jobs.len()), filter: None, group: FigureGroup::Total }];
+    figures.extend([Pipeline::Active, Pipeline::Closed].map(|side| Figure {
+        label: format!("{:?} {}", side, jobs.iter().filter(|j| j.pipeline() == side).count()),
+        filter: Some(format!("pipeline:{}", side.label())),
+        group: FigureGroup::Pipeline,
+    }));
+    figures.extend(Status::ALL.iter().map(|status| Figure {
+        label: format!("{:?} {}", status, jobs.iter().filter(|j| j.status == *status).count()),
+        filter: Some(format!("status:{:?}", status).to_lowercase()),
+        group: FigureGroup::Status,
+    }));
+    figures.extend(rejection_stages(jobs).into_iter().map(|(stage, count)| Figure {
+        label: format!("{} {}", stage, count),
+        filter: Some(match RejectionStage::parse(stage) {
+            Some(_) => format!("rejected:\"{}\"", stage.to_lowercase()),
+            None => "rejected:none".to_string(),
+        }),
+        group: FigureGroup::Rejected,
+    }));
+    figures.extend(health.signals.iter().map(|signal| Figure {
+        label: signal.name.to_string(),
+        filter: Some(signal.filter.clone()),
+        group: FigureGroup::Health,
+    }));
+    figures
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).unwrap()
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+/// Anonymous Gregorian algorithm
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+/// One ingredient of the health score
+pub struct HealthSignal {
+    pub name: &'static str,
+    pub score: f64, // 0.0 to 1.0
+    pub weight: f64,
+    pub detail: String,
+    pub filter: String, // Lists the jobs it counted, see filter.rs
+}
+
+pub struct PipelineHealth {
+    pub score: f64, // 0 to 100
+    pub signals: Vec<HealthSignal>,
+}
+
+impl PipelineHealth {
+    /// Signals well short of their target, worst first
+    pub fn drags(&self) -> Vec<&HealthSignal> {
+        let mut drags: Vec<&HealthSignal> = self
+            .signals
+            .iter()
+            .filter(|s| s.weight > 0.0 && s.score < 0.7)
+            .collect();
+        drags.sort_by(|a, b| (a.score * a.weight).total_cmp(&(b.score * b.weight)));
+        drags
+    }
+}
+
+/// Rough "how's the search going" number from a few signals: enough processes
+/// open, not too many gone quiet, steady applications, and interviews happening.
+/// Evergreen postings only count for part of an open process.
+pub fn pipeline_health(jobs: &[Job], config: &HealthConfig, evergreen: &EvergreenConfig, now: DateTime<Utc>) -> PipelineHealth {
+    let ratio = |value: f64, target: u32| if target == 0 { 1.0 } else { (value / target as f64).min(1.0) };
+    let active: Vec<&Job> = jobs
+        .iter()
+        .filter(|j| matches!(j.status, Status::Applied | Status::Interviewing | Status::Offer))
+        .collect();
+    let standing = active.iter().filter(|j| is_evergreen(j, evergreen)).count();
+    let open = (active.len() - standing) as f64 + standing as f64 * evergreen.counts_as.clamp(0.0, 1.0);
+    let open_detail = match standing {
+        0 => format!("{} open, target {}", active.len(), config.active_target),
+        n => format!("{} open ({} likely evergreen), target {}", active.len(), n, config.active_target),
+    };
+
+    // Last thing that happened on a job, as far as the fields can tell
+    let stale_cutoff = now - Duration::days(config.stale_days as i64);
+    let stale = active
+        .iter()
+        .filter(|j| j.responded_at.unwrap_or(j.date_applied).max(j.date_applied) < stale_cutoff)
+        .count();
+    let staleness = if active.is_empty() { 1.0 } else { 1.0 - stale as f64 / active.len() as f64 };
+
+    let this_week = jobs.iter().filter(|j| j.date_applied > now - Duration::days(7)).count();
+    let interviewing = jobs.iter().filter(|j| j.status == Status::Interviewing).count();
+    let open_filter = "(status:applied OR status:interviewing OR status:offer)";
+
+    let signals = vec![
+        HealthSignal {
+            name: "Active processes",
+            score: ratio(open, config.active_target),
+            weight: config.weights.active,
+            detail: open_detail,
+            filter: open_filter.to_string(),
+        },
+        HealthSignal {
+            name: "Freshness",
+            score: staleness,
+            weight: config.weights.staleness,
+            detail: format!("{} of {} open with no news in {} days", stale, active.len(), config.stale_days),
+            filter: format!("{} quiet:{}", open_filter, config.stale_days),
+        },
+        HealthSignal {
+            name: "Weekly volume",
+            score: ratio(this_week as f64, config.weekly_target),
+            weight: config.weights.volume,
+            detail: format!("{} applied in the last 7 days, target {}", this_week, config.weekly_target),
+            filter: format!("applied>={}", (now - Duration::days(7)).date_naive()),
+        },
+        HealthSignal {
+            name: "Interviews",
+            score: ratio(interviewing as f64, config.interview_target),
+            weight: config.weights.interviews,
+            detail: format!("{} interviewing, target {}", interviewing, config.interview_target),
+            filter: "status:interviewing".to_string(),
+        },
+    ];
+
+    let total_weight: f64 = signals.iter().map(|s| s.weight.max(0.0)).sum();
+    let score = if total_weight == 0.0 {
+        0.0
+    } else {
+        signals.iter().map(|s| s.score * s.weight.max(0.0)).sum::<f64>() / total_weight * 100.0
+    };
+    PipelineHealth { score, signals }
+}
@@ -1,53 +1,1715 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc, Weekday};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use url::Url;
+use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+use crate::answers::Answer;
+use crate::config::{InterviewConfig, LoopTemplate};
+use crate::contract::{ContractTerms, Engagement};
+use crate::debrief::{self, Debrief};
+use crate::salary;
+use crate::transparency::PayDisclosure;
+use crate::travel::{self, Travel};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum Status {
     Applied,
     Interviewing,
     Offer,
+    Accepted,
     Rejected,
     Ghosted,
+    Withdrawn, // I pulled out
+}
+
+/// Which side of the search a job is on: still going somewhere, or done
+/// with, whether that ended in a job or not
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pipeline {
+    Active,
+    Closed,
+}
+
+impl Pipeline {
+    pub fn label(self) -> &'static str {
+        match self {
+            Pipeline::Active => "active",
+            Pipeline::Closed => "closed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Pipeline> {
+        match s.trim().to_lowercase().as_str() {
+            "active" | "open" => Some(Pipeline::Active),
+            "closed" => Some(Pipeline::Closed),
+            _ => None,
+        }
+    }
+}
+
+/// One step of an interview loop, e.g. "Phone screen"
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Stage {
+    pub name: String,
+    pub done: bool,
+    #[serde(default)]
+    pub kind: StageKind,
+}
+
+/// Online assessments get their own kind since they come with a deadline
+/// and nobody to talk to, unlike the interviews around them
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StageKind {
+    #[default]
+    Interview,
+    Assessment,
+}
+
+/// Where the work happens day to day
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkMode {
+    Remote,
+    Hybrid,
+    Onsite,
+}
+
+impl WorkMode {
+    pub fn parse(text: &str) -> Option<WorkMode> {
+        match text.trim().to_lowercase().replace(['-', ' '], "").as_str() {
+            "remote" | "wfh" => Some(WorkMode::Remote),
+            "hybrid" => Some(WorkMode::Hybrid),
+            "onsite" | "office" | "inoffice" => Some(WorkMode::Onsite),
+            _ => None,
+        }
+    }
+}
+
+/// "remote", "hybrid" or "onsite", the way `parse` reads it back
+impl fmt::Display for WorkMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WorkMode::Remote => "remote",
+            WorkMode::Hybrid => "hybrid",
+            WorkMode::Onsite => "onsite",
+        })
+    }
+}
+
+/// How much I want the job. Ordered so sorting puts High first.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Priority {
+    High,
+    #[default]
+    Medium,
+    Low,
+}
+
+impl Priority {
+    pub fn parse(text: &str) -> Option<Priority> {
+        match text.trim().to_lowercase().as_str() {
+            "high" | "h" => Some(Priority::High),
+            "medium" | "med" | "m" | "normal" => Some(Priority::Medium),
+            "low" | "l" => Some(Priority::Low),
+            _ => None,
+        }
+    }
+
+    /// Medium, High, Low and around again, so one press marks a top target
+    pub fn next(self) -> Priority {
+        match self {
+            Priority::Medium => Priority::High,
+            Priority::High => Priority::Low,
+            Priority::Low => Priority::Medium,
+        }
+    }
+}
+
+/// How far a rejected application got, for seeing where I fall out of pipelines
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionStage {
+    Resume,
+    Phone,
+    Assessment,
+    Onsite,
+}
+
+impl RejectionStage {
+    pub const ALL: [RejectionStage; 4] = [RejectionStage::Resume, RejectionStage::Phone, RejectionStage::Assessment, RejectionStage::Onsite];
+
+    pub fn parse(text: &str) -> Option<RejectionStage> {
+        match text.trim().to_lowercase().replace(['-', ' '], "").as_str() {
+            "resume" | "resumescreen" | "cv" | "screen" | "application" => Some(RejectionStage::Resume),
+            "phone" | "phonescreen" | "recruiter" | "call" => Some(RejectionStage::Phone),
+            "oa" | "assessment" | "takehome" | "test" => Some(RejectionStage::Assessment),
+            "onsite" | "final" | "finalround" | "loop" => Some(RejectionStage::Onsite),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RejectionStage::Resume => "Resume screen",
+            RejectionStage::Phone => "Phone screen",
+            RejectionStage::Assessment => "Assessment",
+            RejectionStage::Onsite => "Onsite",
+        }
+    }
+}
+
+/// Whether an application wants a cover letter, and whether it went out
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LetterStatus {
+    #[default]
+    NotNeeded,
+    Needed,
+    Sent,
+}
+
+impl LetterStatus {
+    pub fn parse(text: &str) -> Option<LetterStatus> {
+        match text.trim().to_lowercase().as_str() {
+            "none" | "no" | "not-needed" => Some(LetterStatus::NotNeeded),
+            "needed" | "required" | "todo" => Some(LetterStatus::Needed),
+            "sent" | "done" => Some(LetterStatus::Sent),
+            _ => None,
+        }
+    }
+}
+
+/// Ways of saying no letter is wanted, checked before the first word so
+/// "not needed" isn't taken for a file
+const NO_LETTER: &[&str] = &["not needed", "not required", "no letter", "no need", "not wanted"];
+
+/// "needed", "sent ~/letters/acme.pdf" or just the file into the status
+/// and file. A file on its own means it was sent.
+pub fn parse_cover_letter(input: &str) -> (LetterStatus, Option<String>) {
+    let input = input.trim();
+    let words = input.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    if input.is_empty() || NO_LETTER.contains(&words.as_str()) {
+        return (LetterStatus::NotNeeded, None);
+    }
+    let (first, rest) = input.split_once(' ').unwrap_or((input, ""));
+    let file = Some(rest.trim().to_string()).filter(|f| !f.is_empty());
+    match LetterStatus::parse(first) {
+        Some(LetterStatus::NotNeeded) => (LetterStatus::NotNeeded, None),
+        Some(status) => (status, file),
+        None => (LetterStatus::Sent, Some(input.to_string())),
+    }
+}
+
+/// A path ("~/cv/backend-v3.pdf") that isn't there anymore. Plain labels
+/// like "v3" are never missing.
+fn missing_file(path: &str) -> bool {
+    let path = path.trim();
+    path.contains('/') && !home_path(path).exists()
+}
+
+/// `path` with a leading "~/" pointing into the home directory
+pub fn home_path(path: &str) -> std::path::PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => std::path::Path::new(&home).join(rest),
+        _ => std::path::PathBuf::from(path),
+    }
+}
+
+/// A path typed in to keep on a job, as it's stored: "~/" left as is,
+/// anything else made absolute. None when there's no file there.
+pub fn attachment_path(input: &str) -> Option<String> {
+    let input = input.trim();
+    if input.is_empty() || !home_path(input).exists() {
+        return None;
+    }
+    if input.starts_with("~/") || std::path::Path::new(input).is_absolute() {
+        return Some(input.to_string());
+    }
+    std::path::absolute(input).ok().map(|path| path.display().to_string())
+}
+
+/// "offer.pdf (~/Downloads/offer.pdf)", or "(not found)" after it once
+/// it's been moved
+pub fn describe_file(path: &str) -> String {
+    let name = std::path::Path::new(path).file_name().map_or(path.into(), |name| name.to_string_lossy());
+    let missing = if home_path(path).exists() { "" } else { " (not found)" };
+    format!("{} ({}){}", name, path, missing)
+}
+
+/// When I applied: "2026-10-01", "today", "yesterday", or days and weeks
+/// back like "3d" or "2w ago". None when it doesn't parse.
+pub fn parse_applied(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(date);
+    }
+    let ago = input.strip_suffix("ago").unwrap_or(&input).trim();
+    let days: u64 = match ago {
+        "today" => 0,
+        "yesterday" => 1,
+        _ => match (ago.strip_suffix('d'), ago.strip_suffix('w')) {
+            (Some(days), _) => days.trim().parse().ok()?,
+            (_, Some(weeks)) => weeks.trim().parse::<u64>().ok()? * 7,
+            _ => return None,
+        },
+    };
+    today.checked_sub_days(chrono::Days::new(days))
+}
+
+/// When they said I'd hear back: "2026-03-03", "mar 3" or "March 3"
+/// (the next one), a weekday ("friday", the next one), "tomorrow", or days
+/// and weeks ahead like "3d" or "in 2w". None when it doesn't parse.
+pub fn parse_reply_by(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(date);
+    }
+    for format in ["%b %d %Y", "%B %d %Y"] {
+        if let Ok(date) = NaiveDate::parse_from_str(&format!("{} {}", input, today.year()), format) {
+            return Some(if date < today { date.with_year(today.year() + 1)? } else { date });
+        }
+    }
+    if let Ok(weekday) = input.parse::<Weekday>() {
+        return (1..=7).map(|n| today + chrono::Days::new(n)).find(|date| date.weekday() == weekday);
+    }
+    let ahead = input.strip_prefix("in ").unwrap_or(&input).trim();
+    let days: u64 = match ahead {
+        "today" => 0,
+        "tomorrow" => 1,
+        _ => match (ahead.strip_suffix('d'), ahead.strip_suffix('w')) {
+            (Some(days), _) => days.trim().parse().ok()?,
+            (_, Some(weeks)) => weeks.trim().parse::<u64>().ok()? * 7,
+            _ => return None,
+        },
+    };
+    today.checked_add_days(chrono::Days::new(days))
+}
+
+/// The usual places a job comes from, spelled one way so they group in
+/// filters and charts. Anything else is kept as typed.
+pub const SOURCES: [&str; 4] = ["LinkedIn", "Referral", "Company site", "Recruiter outreach"];
+
+/// "li", "referred", "careers" and so on into one of `SOURCES`. None when empty.
+pub fn parse_source(input: &str) -> Option<String> {
+    let input = input.trim();
+    let source = match input.to_lowercase().as_str() {
+        "" => return None,
+        "linkedin" | "li" => "LinkedIn",
+        "referral" | "referred" | "ref" => "Referral",
+        "company site" | "company" | "site" | "careers" | "careers page" | "direct" => "Company site",
+        "recruiter outreach" | "recruiter" | "outreach" | "inbound" => "Recruiter outreach",
+        _ => input,
+    };
+    Some(source.to_string())
+}
+
+/// "Berlin, hybrid", "Berlin hybrid", "remote" or just "Berlin" into the
+/// location and work mode
+pub fn parse_location(input: &str) -> (String, Option<WorkMode>) {
+    let input = input.trim();
+    if let Some(mode) = WorkMode::parse(input) {
+        return (String::new(), Some(mode));
+    }
+    for separator in [',', ' '] {
+        if let Some((place, mode)) = input.rsplit_once(separator)
+            && let Some(mode) = WorkMode::parse(mode)
+        {
+            return (place.trim().trim_end_matches(',').to_string(), Some(mode));
+        }
+    }
+    (input.to_string(), None)
+}
+
+/// An online assessment invitation. They usually expire a week or so after
+/// the email arrives, whether or not the loop has an OA stage.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Assessment {
+    pub platform: String, // HackerRank, Codility, CodeSignal...
+    pub received: NaiveDate,
+    #[serde(default)]
+    pub expires: Option<NaiveDate>,
+    #[serde(default)]
+    pub completed: Option<NaiveDate>,
+}
+
+/// A scheduled or past interview, e.g. the onsite on 2026-10-20
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Interview {
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub start: Option<NaiveTime>,
+    #[serde(default)]
+    pub end: Option<NaiveTime>, // Only with a start; None runs for [interviews] length_minutes
+    pub name: String,
+    #[serde(default)]
+    pub expenses: Vec<Expense>,
+    #[serde(default)]
+    pub interviewers: Vec<String>,
+    #[serde(default)]
+    pub debrief: Option<Debrief>, // None until I've been asked about it, see debrief.rs
+    #[serde(default)]
+    pub attachment: Option<PathBuf>, // Transcript or recording, see transcript.rs
+    #[serde(default)]
+    pub transcript: String, // Text of a transcript attachment, kept for search
+    #[serde(default)]
+    pub travel: Option<Travel>, // Onsites somewhere I have to get to, see travel.rs
+}
+
+/// One status change, e.g. Applied -> Interviewing
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StatusChange {
+    pub at: DateTime<Utc>,
+    pub from: String, // Status labels, custom statuses by their own name
+    pub to: String,
+}
+
+/// Money spent getting to an interview, and whether the company paid it back
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Expense {
+    pub description: String,
+    pub amount: f64,
+    #[serde(default)]
+    pub reimbursed: bool,
+}
+
+/// A link kept on a job besides the posting
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NamedLink {
+    pub name: String,
+    pub url: String,
+}
+
+impl NamedLink {
+    /// "Status page https://careers.acme.com/me": the link is the last
+    /// word, everything before it the name. None without both.
+    pub fn parse(input: &str) -> Option<NamedLink> {
+        let (name, url) = input.trim().rsplit_once(char::is_whitespace)?;
+        let (name, url) = (name.trim(), url.trim());
+        (!name.is_empty() && !url.is_empty()).then(|| NamedLink { name: name.to_string(), url: url.to_string() })
+    }
+}
+
+/// Somewhere else the same posting turned up, see fingerprint.rs
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Listing {
+    pub source: Option<String>, // "LinkedIn", "Hacker News", as in Job::source
+    pub link: String,
+    pub seen: NaiveDate,
+}
+
+/// Who to talk to about the job, usually the recruiter
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Contact {
+    pub name: String,
+    pub email: String,
+    pub phone: String,
+    pub linkedin: String, // Full profile URL
+}
+
+impl Contact {
+    pub fn is_empty(&self) -> bool {
+        self == &Contact::default()
+    }
+
+    /// "Dana Lee <dana@acme.com>, +1 555 0100", whatever's filled in, or
+    /// the LinkedIn URL when that's all there is
+    pub fn label(&self) -> String {
+        let who = match (self.name.is_empty(), self.email.is_empty()) {
+            (false, false) => format!("{} <{}>", self.name, self.email),
+            (false, true) => self.name.clone(),
+            (true, _) => self.email.clone(),
+        };
+        let parts: Vec<&str> = [who.as_str(), self.phone.as_str()].into_iter().filter(|p| !p.is_empty()).collect();
+        if parts.is_empty() { self.linkedin.clone() } else { parts.join(", ") }
+    }
+
+    /// Lowercase text of every field, for the `contact:` filter
+    pub fn search_text(&self) -> String {
+        [&self.name, &self.email, &self.phone, &self.linkedin].map(|f| f.to_lowercase()).join(" ")
+    }
+}
+
+/// A profile URL from "linkedin.com/in/dana", a full URL, "in/dana" or just "dana"
+pub fn linkedin_url(input: &str) -> String {
+    let input = input.trim().trim_end_matches('/');
+    let input = input.strip_prefix("in/").unwrap_or(input);
+    if input.is_empty() || input.starts_with("http://") || input.starts_with("https://") {
+        input.to_string()
+    } else if input.contains("linkedin.com") {
+        format!("https://{}", input)
+    } else {
+        format!("https://www.linkedin.com/in/{}", input.trim_start_matches('@'))
+    }
+}
+
+/// What moving for the job would involve
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Relocation {
+    pub city: String,
+    pub package: Option<f64>,     // What the company offers towards the move
+    pub moving_cost: Option<f64>, // My estimate of what it'll actually cost
+}
+
+impl Relocation {
+    /// "Seattle, 10000, 6500": city, package and moving cost. Either number
+    /// can be left empty, and "10,000" counts as ten thousand.
+    pub fn parse(input: &str) -> Option<Relocation> {
+        let (city, amounts) = input.split_once(',').unwrap_or((input, ""));
+        let city = Some(city.trim()).filter(|c| !c.is_empty())?.to_string();
+        let amounts = without_thousands_commas(amounts);
+        let mut parts = amounts.split(',').map(str::trim);
+        let mut amount = || -> Option<Option<f64>> {
+            match parts.next() {
+                None | Some("") => Some(None),
+                Some(text) => text.replace(['$', '£', '€', '_'], "").parse().ok().map(Some),
+            }
+        };
+        let package = amount()?;
+        let moving_cost = amount()?;
+        Some(Relocation { city, package, moving_cost })
+    }
+
+    /// The line `parse` reads back
+    pub fn to_input(&self) -> String {
+        let amount = |a: Option<f64>| a.map(|a| a.to_string()).unwrap_or_default();
+        format!("{}, {}, {}", self.city, amount(self.package), amount(self.moving_cost))
+    }
+
+    /// Package minus moving cost: what I'm left with (or out of pocket) after moving
+    pub fn net(&self) -> Option<f64> {
+        Some(self.package.unwrap_or(0.0) - self.moving_cost?)
+    }
+}
+
+/// "10,000, 6,500" as "10000, 6500". A comma with one to three digits before
+/// it and exactly three after groups thousands, any other separates.
+fn without_thousands_commas(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut digits = 0; // Since the last non-digit or grouping comma
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ',' && (1..=3).contains(&digits) && chars[i + 1..].iter().take_while(|c| c.is_ascii_digit()).count() == 3 {
+            digits = 0;
+            continue;
+        }
+        digits = if c.is_ascii_digit() { digits + 1 } else { 0 };
+        out.push(c);
+    }
+    out
+}
+
+impl Interview {
+    pub fn new(date: NaiveDate, name: &str) -> Interview {
+        Interview {
+            date,
+            start: None,
+            end: None,
+            name: name.to_string(),
+            expenses: Vec::new(),
+            interviewers: Vec::new(),
+            debrief: None,
+            attachment: None,
+            transcript: String::new(),
+            travel: None,
+        }
+    }
+
+    /// "2026-10-20 Onsite", just "Onsite" for today, and either can end
+    /// with "with Ana Ruiz, Bo Chen" to name the interviewers. A time like
+    /// "14:00" or "14:00-15:30" can follow the date.
+    pub fn parse(input: &str, today: NaiveDate) -> Interview {
+        let (input, interviewers) = match input.trim().split_once(" with ") {
+            Some((input, names)) => (input, names.split(',').map(str::trim).filter(|n| !n.is_empty()).collect()),
+            None => (input.trim(), Vec::new()),
+        };
+        let (first, rest) = input.split_once(' ').unwrap_or((input, ""));
+        let (date, input) = match NaiveDate::parse_from_str(first, "%Y-%m-%d") {
+            Ok(date) => (date, rest.trim()),
+            Err(_) => (today, input),
+        };
+        let (first, rest) = input.split_once(' ').unwrap_or((input, ""));
+        let mut interview = match parse_times(first) {
+            Some((start, end)) => {
+                let mut interview = Interview::new(date, rest.trim());
+                interview.start = Some(start);
+                interview.end = end;
+                interview
+            }
+            None => Interview::new(date, input),
+        };
+        interview.interviewers = interviewers.into_iter().map(String::from).collect();
+        interview
+    }
+
+    /// "14:00-15:30", "14:00" without an end, or None without a start
+    pub fn time_label(&self) -> Option<String> {
+        let start = self.start?.format("%H:%M");
+        Some(match self.end {
+            Some(end) => format!("{}-{}", start, end.format("%H:%M")),
+            None => start.to_string(),
+        })
+    }
+
+    /// "2026-10-20 14:00-15:30 Onsite", the way it was typed
+    pub fn when(&self) -> String {
+        match self.time_label() {
+            Some(time) => format!("{} {} {}", self.date, time, self.name),
+            None => format!("{} {}", self.date, self.name),
+        }
+    }
+
+    /// Whether it's over by `now`: past its end, or the next day when it
+    /// has no start time to go by
+    pub fn is_over(&self, now: NaiveDateTime, length_minutes: u32) -> bool {
+        match self.span(length_minutes) {
+            Some((_, end)) => end <= now,
+            None => self.date < now.date(),
+        }
+    }
+
+    /// When it starts and ends, if it has a start time
+    pub fn span(&self, length_minutes: u32) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let start = self.date.and_time(self.start?);
+        let end = match self.end {
+            Some(end) if end > self.start? => self.date.and_time(end),
+            _ => start + TimeDelta::minutes(length_minutes as i64),
+        };
+        Some((start, end))
+    }
+}
+
+/// "14:00" or "14:00-15:30"
+fn parse_times(input: &str) -> Option<(NaiveTime, Option<NaiveTime>)> {
+    let time = |s: &str| NaiveTime::parse_from_str(s, "%H:%M").ok();
+    match input.split_once('-') {
+        Some((start, end)) => Some((time(start)?, Some(time(end)?))),
+        None => Some((time(input)?, None)),
+    }
+}
+
+impl Expense {
+    /// "Taxi to the office 42.50": the amount is the last word
+    pub fn parse(input: &str) -> Option<Expense> {
+        let (description, amount) = input.trim().rsplit_once(' ')?;
+        let amount: f64 = amount.trim_start_matches(|c: char| !c.is_ascii_digit()).parse().ok()?;
+        Some(Expense { description: description.trim().to_string(), amount, reimbursed: false })
+    }
+}
+
+impl Assessment {
+    /// "HackerRank 2026-10-31" or "Codility 7d": the platform, then an
+    /// optional expiry as a date or a number of days from `today`
+    pub fn parse_invite(input: &str, today: NaiveDate) -> (String, Option<NaiveDate>) {
+        let input = input.trim();
+        let (rest, last) = input.rsplit_once(' ').unwrap_or(("", input));
+        let expires = NaiveDate::parse_from_str(last, "%Y-%m-%d").ok().or_else(|| {
+            let days: u64 = last.strip_suffix('d')?.parse().ok()?;
+            today.checked_add_days(chrono::Days::new(days))
+        });
+        match expires {
+            Some(date) => (rest.trim().to_string(), Some(date)),
+            None => (input.to_string(), None),
+        }
+    }
+}
+
+/// A job's id: a random UUID, so it stays the same whatever else is added
+/// or deleted and other tools can hold on to it. Files from before ids were
+/// UUIDs have small numbers instead, read as `JobId::legacy`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(from = "StoredId", into = "Uuid")]
+pub struct JobId(Uuid);
+
+/// What an id can look like on disk
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredId {
+    Legacy(u64),
+    Uuid(Uuid),
+}
+
+impl From<StoredId> for JobId {
+    fn from(stored: StoredId) -> JobId {
+        match stored {
+            StoredId::Legacy(n) => JobId::legacy(n),
+            StoredId::Uuid(uuid) => JobId(uuid),
+        }
+    }
+}
+
+impl From<JobId> for Uuid {
+    fn from(id: JobId) -> Uuid {
+        id.0
+    }
+}
+
+impl JobId {
+    #[allow(clippy::new_without_default)] // A default id would be the same one every time
+    pub fn new() -> JobId {
+        JobId(Uuid::new_v4())
+    }
+
+    /// The UUID for what used to be job number `n`. It starts with the
+    /// short ID the number had, so short IDs don't change when a file is
+    /// first read.
+    pub fn legacy(n: u64) -> JobId {
+        // splitmix64 so neighbouring numbers don't share a prefix
+        let mut x = n.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^= x >> 31;
+        JobId(Uuid::from_u128(((x as u32 as u128) << 96) | n as u128))
+    }
+
+    /// The first 8 hex digits, shown in the list and accepted by the CLI
+    pub fn short(&self) -> String {
+        format!("{:08x}", (self.0.as_u128() >> 96) as u32)
+    }
+}
+
+/// How many hex digits of the id tell every one of `jobs` apart, four at
+/// the least. Four collide once there are a few hundred jobs, and a list
+/// that shows them has to give `career-cli show` enough to go on.
+pub fn short_id_len<'a>(jobs: impl IntoIterator<Item = &'a Job>) -> usize {
+    let mut ids: Vec<u128> = jobs.into_iter().map(|job| job.id.0.as_u128()).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    let shared = ids.windows(2).map(|pair| (pair[0] ^ pair[1]).leading_zeros() as usize / 4).max().unwrap_or(0);
+    (shared + 1).clamp(4, 32)
+}
+
+/// How many columns `Job::id_prefix(digits)` takes: the digits, plus the
+/// hyphens it runs past after the eighth, twelfth, sixteenth and twentieth
+pub fn id_width(digits: usize) -> usize {
+    digits + [8, 12, 16, 20].iter().filter(|&&hyphen| digits > hyphen).count()
+}
+
+impl fmt::Display for JobId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A UUID, or an old job number as written in journals from before UUIDs
+impl FromStr for JobId {
+    type Err = uuid::Error;
+
+    fn from_str(text: &str) -> Result<JobId, uuid::Error> {
+        match text.trim().parse::<u64>() {
+            Ok(n) => Ok(JobId::legacy(n)),
+            Err(_) => Uuid::parse_str(text.trim()).map(JobId),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Job {
-    pub id: usize,
+    pub id: JobId,
     pub company: String,
     pub role: String,
     #[serde(default)]
     pub post_link: String,
+    #[serde(default)]
+    pub req_id: Option<String>, // The requisition ID ATS portals ask for, e.g. "R-10234"
+    #[serde(default)]
+    pub links: Vec<NamedLink>, // Besides the posting, e.g. the portal's status page or the take-home repo
+    #[serde(default)]
+    pub files: Vec<String>, // Paths kept with it, e.g. the take-home brief or the offer PDF
+    #[serde(default)]
+    pub answers: Vec<Answer>, // What I put on the application form, see answers.rs
     pub status: Status,
+    #[serde(default)]
+    pub custom_status: Option<String>, // Name from [[statuses]] in config.toml, see statuses.rs
+    #[serde(default)]
+    pub history: Vec<StatusChange>, // Oldest first, empty for jobs still on their first status
     pub notes: String,
     pub date_applied: DateTime<Utc>,
+    #[serde(default)]
+    pub responded_at: Option<DateTime<Utc>>, // First time the status moved past Applied
+    #[serde(default)]
+    pub rejected_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub rejected_stage: Option<RejectionStage>, // How far it got, asked for when it's rejected
+    #[serde(default)]
+    pub rejection_reason: String, // What they gave as the reason, if anything
+    #[serde(default)]
+    pub cooldown_months: Option<u32>, // How long until they'll look at me again
+    #[serde(default)]
+    pub loop_template: Option<String>,
+    #[serde(default)]
+    pub stages: Vec<Stage>,
+    #[serde(default)]
+    pub cycle: Option<String>, // Hiring season, e.g. "Summer 2026 Internship" or "New Grad 2026"
+    #[serde(default)]
+    pub deadline: Option<NaiveDate>, // Next thing due: application close, offer decision
+    #[serde(default)]
+    pub reply_by: Option<NaiveDate>, // When they said I'd hear back, until the status moves on
+    #[serde(default)]
+    pub assessments: Vec<Assessment>,
+    #[serde(default)]
+    pub contact: Contact,
+    #[serde(default)]
+    pub agency: Option<String>, // Third-party recruiter who submitted me, None if I applied directly
+    #[serde(default)]
+    pub source: Option<String>, // Where I found it, see parse_source
+    #[serde(default)]
+    pub resume_version: String, // File or label of the resume I sent, e.g. "backend-v3.pdf"
+    #[serde(default)]
+    pub cover_letter: Option<String>, // File or label of the cover letter, like resume_version
+    #[serde(default)]
+    pub letter_status: LetterStatus,
+    #[serde(default)]
+    pub interviews: Vec<Interview>,
+    #[serde(default)]
+    pub relocation: Option<Relocation>, // None when no move is needed
+    #[serde(default)]
+    pub onboarding: Option<Onboarding>, // Set up once the offer is accepted
+    #[serde(default)]
+    pub engagement: Engagement,
+    #[serde(default)]
+    pub contract: Option<ContractTerms>, // Rate, length and start, for contract and freelance work
+    #[serde(default)]
+    pub desired_start: Option<NaiveDate>, // The start date the offer asks for
+    #[serde(default)]
+    pub location: String, // City, or empty when unknown or fully remote
+    #[serde(default)]
+    pub work_mode: Option<WorkMode>,
+    #[serde(default)]
+    pub timezone: Option<String>, // IANA name like "Europe/Berlin", for their business hours, see timezone.rs
+    #[serde(default)]
+    pub salary_min: Option<u32>, // Advertised range, yearly
+    #[serde(default)]
+    pub salary_max: Option<u32>,
+    #[serde(default)]
+    pub salary_currency: Option<String>, // ISO code like "GBP", None when the posting didn't say
+    #[serde(default)]
+    pub pay_disclosure: Option<PayDisclosure>, // What the posting said about pay where it has to, see transparency.rs
+    #[serde(default)]
+    pub tags: Vec<String>, // Lowercase, without the '#', see parse_tags
+    #[serde(default)]
+    pub skills: Vec<String>, // What the posting asks for, lowercase, see skills.rs
+    #[serde(default)]
+    pub follow_up: Option<DateTime<Utc>>, // When to nudge them if I haven't heard back
+    #[serde(default)]
+    pub priority: Priority,
+    #[serde(default)]
+    pub draft: bool, // Saved halfway through adding, finished with App::start_finish_draft
+    #[serde(default)]
+    pub archived: bool, // Closed and out of the way, hidden from the list unless asked for
+    #[serde(default)]
+    pub starred: bool, // One I'm especially keen on, marked ★ in the list
+    #[serde(default)]
+    pub description: String, // The posting's text, see posting.rs
+    #[serde(default)]
+    pub sightings: Vec<NaiveDate>, // Days the posting was seen still up, oldest first, see evergreen.rs
+    #[serde(default)]
+    pub listings: Vec<Listing>, // The same posting found elsewhere, see fingerprint.rs
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>, // First saved, see storage::merge. None for jobs from before it was kept
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>, // Last saved with a change
+    #[serde(default)]
+    pub revision: u64, // Bumped on every saved change, see storage::merge
 }
 
+/// Company, role, link, salary and location, the prompts of the add wizard
+pub const DRAFT_FIELDS: usize = 5;
+
+/// What's left between accepting and the first day
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Onboarding {
+    pub start_date: Option<NaiveDate>,
+    pub checklist: Vec<ChecklistItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChecklistItem {
+    pub name: String,
+    #[serde(default)]
+    pub done: bool,
+    #[serde(default)]
+    pub due: Option<NaiveDate>,
+}
+
+/// The paperwork nearly every offer comes with
+pub const ONBOARDING_CHECKLIST: [&str; 3] = ["Background check", "Reference checks", "Contract signed"];
+
+impl Default for Onboarding {
+    fn default() -> Self {
+        Onboarding {
+            start_date: None,
+            checklist: ONBOARDING_CHECKLIST
+                .iter()
+                .map(|name| ChecklistItem { name: name.to_string(), done: false, due: None })
+                .collect(),
+        }
+    }
+}
+
+impl Onboarding {
+    /// (done, total)
+    pub fn progress(&self) -> (usize, usize) {
+        (self.checklist.iter().filter(|i| i.done).count(), self.checklist.len())
+    }
+}
+
+/// Most companies ask you to wait 6-12 months after a rejection
+pub const DEFAULT_COOLDOWN_MONTHS: u32 = 6;
+
 impl Status {
+    /// One step further along: Applied, Interviewing, Offer, Accepted.
+    /// Terminal statuses stay put, so Enter on an offer can't roll it
+    /// over into a rejection.
     pub fn next(&self) -> Self {
         match self {
             Status::Applied => Status::Interviewing,
             Status::Interviewing => Status::Offer,
-            Status::Offer => Status::Rejected, // Or maybe stay at Offer?
-            Status::Rejected => Status::Ghosted,
-            Status::Ghosted => Status::Applied,
+            Status::Offer => Status::Accepted,
+            Status::Accepted | Status::Rejected | Status::Ghosted | Status::Withdrawn => self.clone(),
         }
     }
+
+    /// Nothing comes after it: I took the job, or it's over one way or another
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Status::Accepted | Status::Rejected | Status::Ghosted | Status::Withdrawn)
+    }
+
+    /// Out of the running without a job at the end of it
+    pub fn is_dropped(&self) -> bool {
+        matches!(self, Status::Rejected | Status::Ghosted | Status::Withdrawn)
+    }
+
+    pub fn pipeline(&self) -> Pipeline {
+        match self.is_terminal() {
+            true => Pipeline::Closed,
+            false => Pipeline::Active,
+        }
+    }
+
+    pub const ALL: [Status; 7] = [
+        Status::Applied,
+        Status::Interviewing,
+        Status::Offer,
+        Status::Accepted,
+        Status::Rejected,
+        Status::Ghosted,
+        Status::Withdrawn,
+    ];
+
+    /// Case-insensitive, so "ghosted" and "Ghosted" both work on the command line
+    pub fn parse(name: &str) -> Option<Status> {
+        Status::ALL
+            .into_iter()
+            .find(|status| format!("{:?}", status).eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+impl Stage {
+    /// Names like "OA" or "Online assessment: HackerRank" are assessments,
+    /// everything else an interview
+    pub fn new(name: &str) -> Self {
+        let lower = name.trim().to_lowercase();
+        let assessment = lower == "oa"
+            || lower.starts_with("oa:")
+            || lower.starts_with("oa ")
+            || lower.contains("online assessment");
+        let kind = if assessment { StageKind::Assessment } else { StageKind::Interview };
+        Stage { name: name.to_string(), done: false, kind }
+    }
 }
 
 impl Job {
-    pub fn new(id: usize, company: String, role: String, post_link: String) -> Self {
+    pub fn new(id: JobId, company: String, role: String, post_link: String) -> Self {
         Self {
             id,
             company,
             role,
             post_link,
+            req_id: None,
             status: Status::Applied,
+            custom_status: None,
+            history: Vec::new(),
             notes: String::new(),
             date_applied: Utc::now(),
+            responded_at: None,
+            rejected_at: None,
+            rejected_stage: None,
+            rejection_reason: String::new(),
+            cooldown_months: None,
+            loop_template: None,
+            stages: Vec::new(),
+            cycle: None,
+            deadline: None,
+            reply_by: None,
+            assessments: Vec::new(),
+            contact: Contact::default(),
+            agency: None,
+            source: None,
+            resume_version: String::new(),
+            cover_letter: None,
+            letter_status: LetterStatus::NotNeeded,
+            interviews: Vec::new(),
+            relocation: None,
+            onboarding: None,
+            engagement: Engagement::FullTime,
+            contract: None,
+            desired_start: None,
+            location: String::new(),
+            work_mode: None,
+            timezone: None,
+            links: Vec::new(),
+            files: Vec::new(),
+            answers: Vec::new(),
+            salary_min: None,
+            salary_max: None,
+            salary_currency: None,
+            pay_disclosure: None,
+            tags: Vec::new(),
+            skills: Vec::new(),
+            follow_up: None,
+            priority: Priority::default(),
+            draft: false,
+            archived: false,
+            starred: false,
+            description: String::new(),
+            sightings: Vec::new(),
+            listings: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            revision: 0,
+        }
+    }
+
+    /// A job from `career-cli add`, keeping the posting text and the pay
+    /// read out of it when it has any
+    pub fn from_posting(id: JobId, company: String, role: String, link: String, posting: &str) -> Job {
+        let mut job = Job::new(id, company, role, link);
+        job.description = posting.trim().to_string();
+        if let Some(pay) = salary::extract(posting) {
+            let (min, max) = pay.yearly();
+            (job.salary_min, job.salary_max) = (Some(min), Some(max));
+            job.salary_currency = Some(pay.currency.to_string());
+        }
+        job
+    }
+
+    /// Every link to open, the posting first as "Posting" when there is one
+    pub fn all_links(&self) -> Vec<(&str, &str)> {
+        let posting = Some(("Posting", self.post_link.as_str())).filter(|(_, url)| !url.trim().is_empty());
+        posting.into_iter().chain(self.links.iter().map(|l| (l.name.as_str(), l.url.as_str()))).collect()
+    }
+
+    /// Their timezone, if it's set and still a known one
+    pub fn tz(&self) -> Option<chrono_tz::Tz> {
+        self.timezone.as_deref()?.parse().ok()
+    }
+
+    /// When it was last changed, as far as anyone knows: the last saved
+    /// change, else when it was added, else when I applied
+    pub fn touched(&self) -> DateTime<Utc> {
+        self.updated_at.or(self.created_at).unwrap_or(self.date_applied)
+    }
+
+    /// Hex prefix of the id, shown in the list and accepted by the CLI
+    pub fn short_id(&self) -> String {
+        self.id.short()
+    }
+
+    /// The first `digits` hex digits of the id, as `resolve` reads them back.
+    /// Pair with `short_id_len` so the prefix is one no other job shares.
+    pub fn id_prefix(&self, digits: usize) -> String {
+        let id = self.id.to_string();
+        let end = id.char_indices().filter(|(_, c)| *c != '-').nth(digits).map_or(id.len(), |(at, _)| at);
+        id[..end].trim_end_matches('-').to_string()
+    }
+
+    /// Append a dated line to the notes, e.g. "[2025-03-01 14:05] Recruiter called"
+    pub fn append_note(&mut self, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        if !self.notes.is_empty() && !self.notes.ends_with('\n') {
+            self.notes.push('\n');
         }
+        let stamp = Local::now().format("%Y-%m-%d %H:%M");
+        self.notes.push_str(&format!("[{}] {}", stamp, text));
     }
 
     pub fn cycle_status(&mut self) {
-        self.status = self.status.next();
+        self.set_status(self.status.next());
+    }
+
+    /// The custom status name when there is one, else the built-in's
+    pub fn status_label(&self) -> String {
+        self.custom_status.clone().unwrap_or_else(|| format!("{:?}", self.status))
+    }
+
+    /// Change status, stamping the response/rejection dates along the way
+    pub fn set_status(&mut self, status: Status) {
+        self.change_status(status, None);
+    }
+
+    /// Change to a custom status that counts as `status`. Built-in names
+    /// aren't stored twice.
+    pub fn set_custom_status(&mut self, name: &str, status: Status) {
+        let builtin = Status::parse(name).as_ref() == Some(&status);
+        self.change_status(status, (!builtin).then(|| name.to_string()));
+    }
+
+    fn change_status(&mut self, status: Status, custom: Option<String>) {
+        let from = self.status_label();
+        self.status = status;
+        self.custom_status = custom;
+        let to = self.status_label();
+        // Moving on is the reply they said was coming, so it isn't owed anymore
+        if from != to {
+            self.history.push(StatusChange { at: Utc::now(), from, to });
+            self.reply_by = None;
+        }
+        if self.responded_at.is_none() && !matches!(self.status, Status::Applied | Status::Ghosted | Status::Withdrawn) {
+            self.responded_at = Some(Utc::now());
+        }
+        if let Status::Rejected = self.status {
+            self.rejected_at = Some(Utc::now());
+            self.cooldown_months.get_or_insert(DEFAULT_COOLDOWN_MONTHS);
+        }
+        if let Status::Accepted = self.status {
+            let start = self.offered_start();
+            self.onboarding.get_or_insert_with(|| Onboarding { start_date: start, ..Default::default() });
+        }
+    }
+
+    /// The start date the offer asks for, falling back to the contract's
+    pub fn offered_start(&self) -> Option<NaiveDate> {
+        self.desired_start.or(self.contract.as_ref().and_then(|c| c.start))
+    }
+
+    /// Replace the expected stages with the template's. Nothing is marked done.
+    pub fn apply_template(&mut self, template: &LoopTemplate) {
+        self.loop_template = Some(template.name.clone());
+        self.stages = template
+            .stages
+            .iter()
+            .map(|name| Stage::new(name))
+            .collect();
+    }
+
+    /// The next stage if it's an online assessment I still have to take
+    pub fn pending_assessment(&self) -> Option<&Stage> {
+        self.stages.iter().find(|s| !s.done).filter(|s| s.kind == StageKind::Assessment)
+    }
+
+    /// Add an expense to the latest interview that has already happened
+    /// (or the next one, if none has). False when no interview is logged.
+    pub fn add_expense(&mut self, expense: Expense, today: NaiveDate) -> bool {
+        match self.latest_interview_mut(today) {
+            Some(interview) => {
+                interview.expenses.push(expense);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The most recent interview that has happened, or the first one if none has yet
+    pub fn latest_interview_mut(&mut self, today: NaiveDate) -> Option<&mut Interview> {
+        match self.interviews.iter().rposition(|i| i.date <= today) {
+            Some(i) => self.interviews.get_mut(i),
+            None => self.interviews.first_mut(),
+        }
+    }
+
+    /// Log an interview, keeping them in date order
+    pub fn add_interview(&mut self, interview: Interview) {
+        let at = self.interviews.partition_point(|i| i.date <= interview.date);
+        self.interviews.insert(at, interview);
+    }
+
+    /// Switch engagement type. Terms only stay on contract and freelance work.
+    pub fn set_engagement(&mut self, engagement: Engagement, terms: Option<ContractTerms>) {
+        self.engagement = engagement;
+        self.contract = terms.filter(|_| engagement.is_contract());
+    }
+
+    /// "Berlin (hybrid)", "Remote" or "Berlin", None when neither is known
+    pub fn location_label(&self) -> Option<String> {
+        match (self.location.as_str(), self.work_mode) {
+            ("", None) => None,
+            ("", Some(mode)) => {
+                let mode = mode.to_string();
+                Some(mode[..1].to_uppercase() + &mode[1..])
+            }
+            (place, None) => Some(place.to_string()),
+            (place, Some(mode)) => Some(format!("{} ({})", place, mode)),
+        }
+    }
+
+    /// The line `parse_location` reads back, for prefilling the editor
+    pub fn location_input(&self) -> String {
+        let mode = self.work_mode.map(|m| m.to_string());
+        match (self.location.as_str(), mode) {
+            ("", mode) => mode.unwrap_or_default(),
+            (place, None) => place.to_string(),
+            (place, Some(mode)) => format!("{}, {}", place, mode),
+        }
+    }
+
+    /// The line `salary::parse` reads back, for prefilling the editor
+    pub fn salary_input(&self) -> String {
+        let range = match (self.salary_min, self.salary_max) {
+            (Some(min), Some(max)) if min != max => format!("{}-{}", min, max),
+            (min, max) => min.or(max).map(|n| n.to_string()).unwrap_or_default(),
+        };
+        match &self.salary_currency {
+            Some(currency) if !range.is_empty() => format!("{}{}", salary::symbol(currency), range),
+            _ => range,
+        }
+    }
+
+    /// An open job whose follow-up is due today or already past
+    pub fn follow_up_due(&self, today: NaiveDate) -> bool {
+        self.is_open() && self.follow_up.is_some_and(|at| at.date_naive() <= today)
+    }
+
+    /// Why a follow-up queued for `queued_for` shouldn't go out `now`: the
+    /// job closed, or the follow-up was moved or cleared since. None when it
+    /// should, however late the at job runs.
+    pub fn follow_up_held(&self, queued_for: DateTime<Utc>, now: DateTime<Utc>) -> Option<String> {
+        if !self.is_open() {
+            return Some(format!("it's {} now", self.status_label()));
+        }
+        match self.follow_up {
+            None => Some("the follow-up was cleared".to_string()),
+            Some(at) if at != queued_for => Some(format!("the follow-up moved to {}", at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"))),
+            Some(at) if at > now => Some("it isn't due yet".to_string()),
+            Some(_) => None,
+        }
+    }
+
+    /// An open job I was told I'd hear back about before today
+    pub fn reply_overdue(&self, today: NaiveDate) -> bool {
+        self.is_open() && self.reply_by.is_some_and(|date| date < today)
+    }
+
+    /// "by Mar 3" while a reply is expected, "late Mar 3" once it's
+    /// overdue, short enough for the list's status column
+    pub fn reply_label(&self, today: NaiveDate) -> Option<String> {
+        let date = self.reply_by.filter(|_| self.is_open())?;
+        if self.reply_overdue(today) {
+            Some(format!("late {}", date.format("%b %-d")))
+        } else {
+            Some(format!("by {}", date.format("%b %-d")))
+        }
+    }
+
+    /// A resume recorded as a path that isn't there anymore
+    pub fn resume_missing(&self) -> bool {
+        missing_file(&self.resume_version)
+    }
+
+    /// The line `parse_cover_letter` reads back, for prefilling the editor
+    pub fn cover_letter_input(&self) -> String {
+        let status = match self.letter_status {
+            LetterStatus::NotNeeded => return String::new(),
+            LetterStatus::Needed => "needed",
+            LetterStatus::Sent => "sent",
+        };
+        match &self.cover_letter {
+            Some(file) => format!("{} {}", status, file),
+            None => status.to_string(),
+        }
+    }
+
+    /// "Needed", "Sent" or "Sent, ~/letters/acme.pdf (file not found)",
+    /// None when the application doesn't want one
+    pub fn cover_letter_label(&self) -> Option<String> {
+        let status = match self.letter_status {
+            LetterStatus::NotNeeded => return None,
+            LetterStatus::Needed => "Needed",
+            LetterStatus::Sent => "Sent",
+        };
+        match &self.cover_letter {
+            Some(file) => {
+                let missing = if missing_file(file) { " (file not found)" } else { "" };
+                Some(format!("{}, {}{}", status, file, missing))
+            }
+            None => Some(status.to_string()),
+        }
+    }
+
+    /// The add prompts a draft left empty, e.g. ["link", "salary"]
+    pub fn missing_fields(&self) -> Vec<&'static str> {
+        let filled = [
+            ("company", !self.company.trim().is_empty()),
+            ("role", !self.role.trim().is_empty()),
+            ("link", !self.post_link.is_empty()),
+            ("salary", self.salary_min.is_some() || self.salary_max.is_some()),
+            ("location", self.location_label().is_some()),
+        ];
+        filled.into_iter().filter(|(_, filled)| !filled).map(|(name, _)| name).collect()
+    }
+
+    /// "Draft 3/5", how many of the add prompts are filled in
+    pub fn draft_label(&self) -> String {
+        format!("Draft {}/{}", DRAFT_FIELDS - self.missing_fields().len(), DRAFT_FIELDS)
+    }
+
+    /// "Phone screen: wanted more Go", or whichever half was recorded
+    pub fn rejection_label(&self) -> Option<String> {
+        let reason = self.rejection_reason.trim();
+        match (self.rejected_stage, reason.is_empty()) {
+            (Some(stage), true) => Some(stage.label().to_string()),
+            (Some(stage), false) => Some(format!("{}: {}", stage.label(), reason)),
+            (None, false) => Some(reason.to_string()),
+            (None, true) => None,
+        }
+    }
+
+    /// "120k-150k", "from 120k" or "up to 150k", as "£70k" when the
+    /// currency is known
+    pub fn salary_label(&self) -> Option<String> {
+        let symbol = self.salary_currency.as_deref().map(salary::symbol).unwrap_or_default();
+        let k = |amount: u32| if amount.is_multiple_of(1000) { format!("{}k", amount / 1000) } else { amount.to_string() };
+        match (self.salary_min, self.salary_max) {
+            (Some(min), Some(max)) if min == max => Some(format!("{}{}", symbol, k(min))),
+            (Some(min), Some(max)) => Some(format!("{}{}-{}", symbol, k(min), k(max))),
+            (Some(min), None) => Some(format!("from {}{}", symbol, k(min))),
+            (None, Some(max)) => Some(format!("up to {}{}", symbol, k(max))),
+            (None, None) => None,
+        }
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.trim_start_matches('#').to_lowercase();
+        self.tags.contains(&tag)
+    }
+
+    /// Active until its status is terminal, see `Status::pipeline`
+    pub fn pipeline(&self) -> Pipeline {
+        self.status.pipeline()
+    }
+
+    /// Still in play, so its deadline matters: on the active side of the
+    /// search, where an accepted offer isn't
+    pub fn is_open(&self) -> bool {
+        self.pipeline() == Pipeline::Active
+    }
+
+    /// Mark the first pending stage as done. Finishing an OA stage also
+    /// closes the oldest open OA invitation.
+    pub fn complete_next_stage(&mut self) {
+        if let Some(stage) = self.stages.iter_mut().find(|s| !s.done) {
+            stage.done = true;
+            if stage.kind == StageKind::Assessment
+                && let Some(assessment) = self.assessments.iter_mut().find(|a| a.completed.is_none())
+            {
+                assessment.completed = Some(Local::now().date_naive());
+            }
+        }
+    }
+
+    /// Record an OA invitation that arrived today
+    pub fn add_assessment(&mut self, platform: &str, expires: Option<NaiveDate>) {
+        self.assessments.push(Assessment {
+            platform: platform.trim().to_string(),
+            received: Local::now().date_naive(),
+            expires,
+            completed: None,
+        });
+    }
+
+    /// Mark the oldest open OA as taken, ticking off the loop's OA stage with
+    /// it. False when there was nothing open.
+    pub fn complete_assessment(&mut self) -> bool {
+        let Some(assessment) = self.assessments.iter_mut().find(|a| a.completed.is_none()) else {
+            return false;
+        };
+        assessment.completed = Some(Local::now().date_naive());
+        if let Some(stage) = self.stages.iter_mut().find(|s| !s.done)
+            && stage.kind == StageKind::Assessment
+        {
+            stage.done = true;
+        }
+        true
+    }
+
+    /// Undo the last completed stage
+    pub fn reopen_last_stage(&mut self) {
+        if let Some(stage) = self.stages.iter_mut().rev().find(|s| s.done) {
+            stage.done = false;
+        }
+    }
+
+    /// (completed, total), or None when no loop is set up
+    pub fn stage_progress(&self) -> Option<(usize, usize)> {
+        if self.stages.is_empty() {
+            return None;
+        }
+        let done = self.stages.iter().filter(|s| s.done).count();
+        Some((done, self.stages.len()))
+    }
+
+    /// How far into the process this is, 0.0 to 1.0. Uses the loop stages when there
+    /// are any, otherwise a rough guess from the status.
+    pub fn pipeline_progress(&self) -> f64 {
+        if let Some((done, total)) = self.stage_progress() {
+            return done as f64 / total as f64;
+        }
+        match self.status {
+            Status::Applied => 0.2,
+            Status::Interviewing => 0.5,
+            Status::Offer | Status::Accepted => 1.0,
+            Status::Rejected | Status::Ghosted | Status::Withdrawn => 0.0,
+        }
+    }
+
+    /// When the company's reapplication cool-down ends, if it's a rejection we track
+    pub fn reapply_after(&self) -> Option<DateTime<Utc>> {
+        if !matches!(self.status, Status::Rejected) {
+            return None;
+        }
+        let months = self.cooldown_months?;
+        self.rejected_at?.checked_add_months(Months::new(months))
+    }
+
+    /// Short domain of the post link, e.g. "boards.greenhouse.io/acme" -> "greenhouse.io"
+    pub fn link_domain(&self) -> Option<String> {
+        domain_of(&self.post_link)
+    }
+
+    /// Where it probably came from, to prefill the source prompt: the
+    /// agency if one submitted me, else the job board in the link. Links
+    /// to anything else are usually the company's own careers page.
+    pub fn guess_source(&self) -> Option<String> {
+        if self.agency.is_some() {
+            return Some("Recruiter outreach".to_string());
+        }
+        let domain = self.link_domain()?;
+        let board = match domain.split('.').next().unwrap_or_default() {
+            "linkedin" => "LinkedIn",
+            "indeed" => "Indeed",
+            "glassdoor" => "Glassdoor",
+            "wellfound" => "Wellfound",
+            "ycombinator" => "Hacker News",
+            _ => "Company site",
+        };
+        Some(board.to_string())
+    }
+}
+
+pub fn domain_of(link: &str) -> Option<String> {
+    let link = link.trim();
+    if link.is_empty() {
+        return None;
+    }
+
+    // People often paste links without the scheme
+    let parsed = Url::parse(link)
+        .or_else(|_| Url::parse(&format!("https://{}", link)))
+        .ok()?;
+    let host = parsed.host_str()?.trim_start_matches("www.");
+
+    let labels: Vec<&str> = host.split('.').filter(|l| !l.is_empty()).collect();
+    if labels.len() <= 2 {
+        return Some(labels.join("."));
+    }
+
+    // Keep one extra label for country suffixes like co.uk or com.au
+    let n = labels.len();
+    let keep = if labels[n - 1].len() == 2 && labels[n - 2].len() <= 3 { 3 } else { 2 };
+    Some(labels[n - keep..].join("."))
+}
+
+/// "120k-150k", "$120,000 – 150,000", "120k to 150k" or a single "150k"
+/// into (min, max). Empty input clears the range, None means it didn't
+/// parse or is more than fits.
+pub fn parse_salary(input: &str) -> Option<(Option<u32>, Option<u32>)> {
+    let amount = |text: &str| -> Option<u32> {
+        let text = text.trim().to_lowercase();
+        let (digits, scale) = match text.strip_suffix('k') {
+            Some(rest) => (rest, 1000.0),
+            None => (text.as_str(), 1.0),
+        };
+        let digits: String = digits.chars().filter(|c| c.is_ascii_digit() || *c == '.').collect();
+        // As u64 first, which anything too big for a u32 still overflows
+        u32::try_from(digits.parse::<f64>().ok().map(|n| (n * scale).round() as u64)?).ok()
+    };
+    let input = input.trim();
+    if input.is_empty() {
+        return Some((None, None));
+    }
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let range = match words.iter().position(|word| word.eq_ignore_ascii_case("to")) {
+        Some(at) => Some((words[..at].join(" "), words[at + 1..].join(" "))),
+        None => input.split_once(['-', '–', '—']).map(|(min, max)| (min.to_string(), max.to_string())),
+    };
+    match range {
+        Some((min, max)) => {
+            let (min, max) = (amount(&min)?, amount(&max)?);
+            (min <= max).then_some((Some(min), Some(max)))
+        }
+        None => amount(input).map(|n| (Some(n), Some(n))),
+    }
+}
+
+/// "#Remote, referral dream-company" into ["remote", "referral", "dream-company"],
+/// keeping the first of any repeats
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for word in input.split(|c: char| c.is_whitespace() || c == ',') {
+        let tag = word.trim_start_matches('#').to_lowercase();
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Lowercase and drop punctuation and legal suffixes so "Google LLC" matches "google"
+pub fn normalize_company(name: &str) -> String {
+    const SUFFIXES: [&str; 8] = ["inc", "llc", "ltd", "limited", "corp", "corporation", "co", "gmbh"];
+
+    let cleaned: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let mut words: Vec<&str> = cleaned.split_whitespace().collect();
+    while words.len() > 1 && SUFFIXES.contains(words.last().unwrap()) {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+/// Every application to `company`, archived cohorts (named) and the current
+/// search ("current") together, oldest first
+pub fn company_history<'a>(cohorts: &'a [(String, Vec<Job>)], jobs: &'a [Job], company: &str) -> Vec<(&'a str, &'a Job)> {
+    let key = normalize_company(company);
+
+    // Normalizing is the slow part and the same names come up over and
+    // over, so only do it once per spelling
+    let mut seen: HashMap<&str, bool> = HashMap::new();
+    let mut rows: Vec<(&str, &Job)> = cohorts
+        .iter()
+        .flat_map(|(name, jobs)| jobs.iter().map(move |j| (name.as_str(), j)))
+        .chain(jobs.iter().map(|j| ("current", j)))
+        .filter(|(_, j)| {
+            *seen
+                .entry(j.company.as_str())
+                .or_insert_with(|| normalize_company(&j.company) == key)
+        })
+        .collect();
+    rows.sort_by_key(|(_, j)| j.date_applied);
+    rows
+}
+
+/// Rejections with a cool-down, soonest first. Skips companies I've already reapplied to.
+pub fn reapply_windows(jobs: &[Job]) -> Vec<(&Job, DateTime<Utc>)> {
+    let mut windows: Vec<(&Job, DateTime<Utc>)> = jobs
+        .iter()
+        .filter_map(|job| job.reapply_after().map(|date| (job, date)))
+        .filter(|(job, _)| {
+            let key = normalize_company(&job.company);
+            !jobs.iter().any(|other| {
+                normalize_company(&other.company) == key && other.date_applied > job.date_applied
+            })
+        })
+        .collect();
+    windows.sort_by_key(|(_, date)| *date);
+    windows
+}
+
+/// Open submissions to the same company by a different agency. Two agencies
+/// putting me forward means a fee dispute, and companies often drop both.
+pub fn double_submissions<'a>(jobs: &'a [Job], job: &Job) -> Vec<&'a Job> {
+    let Some(agency) = &job.agency else {
+        return Vec::new();
+    };
+    let key = normalize_company(&job.company);
+    jobs.iter()
+        .filter(|other| other.id != job.id && other.is_open())
+        .filter(|other| other.agency.as_ref().is_some_and(|a| !a.eq_ignore_ascii_case(agency)))
+        .filter(|other| normalize_company(&other.company) == key)
+        .collect()
+}
+
+/// An interview already booked too close to a new one
+pub struct Clash<'a> {
+    pub job: &'a Job,
+    pub interview: &'a Interview,
+    pub gap: Option<i64>, // Minutes between them, negative when they overlap; None when either has no time
+}
+
+impl Clash<'_> {
+    /// "overlaps Globex Onsite 14:00-15:00", "15 min after Globex ..." or
+    /// "same day as Globex Onsite"
+    pub fn describe(&self) -> String {
+        let what = match self.interview.time_label() {
+            Some(time) => format!("{} {} {}", self.job.company, self.interview.name, time),
+            None => format!("{} {}", self.job.company, self.interview.name),
+        };
+        match self.gap {
+            Some(gap) if gap < 0 => format!("overlaps {}", what),
+            Some(gap) => format!("{} min from {}", gap, what),
+            None => format!("same day as {}", what),
+        }
+    }
+}
+
+/// Interviews on open jobs that overlap `new` or leave less than the
+/// buffer between them. When either has no time, the same day is enough,
+/// since there's no telling.
+pub fn interview_clashes<'a>(jobs: &'a [Job], new: &Interview, config: &InterviewConfig) -> Vec<Clash<'a>> {
+    let length = config.length_minutes;
+    let mut clashes = Vec::new();
+    for job in jobs.iter().filter(|j| j.is_open()) {
+        for interview in job.interviews.iter().filter(|i| i.date == new.date) {
+            let gap = match (new.span(length), interview.span(length)) {
+                (Some((start, end)), Some((other_start, other_end))) => {
+                    if start < other_end && other_start < end {
+                        -1
+                    } else {
+                        (start - other_end).num_minutes().max((other_start - end).num_minutes())
+                    }
+                }
+                _ => {
+                    clashes.push(Clash { job, interview, gap: None });
+                    continue;
+                }
+            };
+            if gap < config.buffer_minutes as i64 {
+                clashes.push(Clash { job, interview, gap: Some(gap) });
+            }
+        }
+    }
+    clashes
+}
+
+/// An expense the company still owes me back
+pub struct Outstanding<'a> {
+    pub job: &'a Job,
+    pub interview: &'a Interview,
+    pub expense: &'a Expense,
+    pub position: (usize, usize, usize), // job, interview and expense index, for marking it paid
+}
+
+/// Unreimbursed expenses across all jobs, oldest interview first
+pub fn outstanding_expenses(jobs: &[Job]) -> Vec<Outstanding<'_>> {
+    let mut items = Vec::new();
+    for (j, job) in jobs.iter().enumerate() {
+        for (i, interview) in job.interviews.iter().enumerate() {
+            for (e, expense) in interview.expenses.iter().enumerate().filter(|(_, e)| !e.reimbursed) {
+                items.push(Outstanding { job, interview, expense, position: (j, i, e) });
+            }
+        }
+    }
+    items.sort_by_key(|item| item.interview.date);
+    items
+}
+
+/// Something due on a date, for the agenda
+pub struct Due<'a> {
+    pub job: &'a Job,
+    pub date: NaiveDate,
+    pub kind: DueKind<'a>,
+}
+
+pub enum DueKind<'a> {
+    Deadline,
+    Assessment(&'a Assessment),
+    Onboarding(&'a ChecklistItem),
+    StartDate,
+    FollowUp,
+    Reply,
+    Travel(&'a Interview),
+}
+
+impl Due<'_> {
+    /// "OA (HackerRank)", "Background check" or "Deadline"
+    pub fn label(&self) -> String {
+        match self.kind {
+            DueKind::Deadline => "Deadline".to_string(),
+            DueKind::Assessment(assessment) if assessment.platform.is_empty() => "OA".to_string(),
+            DueKind::Assessment(assessment) => format!("OA ({})", assessment.platform),
+            DueKind::Onboarding(item) => item.name.clone(),
+            DueKind::StartDate => "First day".to_string(),
+            DueKind::FollowUp => "Follow up".to_string(),
+            DueKind::Reply => "Hear back".to_string(),
+            DueKind::Travel(interview) => format!("Travel: {}", interview.name),
+        }
+    }
+
+    pub fn is_assessment(&self) -> bool {
+        matches!(self.kind, DueKind::Assessment(_))
+    }
+}
+
+/// Deadlines, follow-ups and promised replies of open jobs, expiry dates of untaken
+/// OAs, onboarding paperwork and start dates, soonest first. Past dates are kept so a missed one
+/// still shows up.
+pub fn agenda(jobs: &[Job]) -> Vec<Due<'_>> {
+    let mut items: Vec<Due> = Vec::new();
+    for job in jobs.iter().filter(|job| job.is_open()) {
+        if let Some(date) = job.deadline {
+            items.push(Due { job, date, kind: DueKind::Deadline });
+        }
+        if let Some(date) = debrief::follow_up(job) {
+            items.push(Due { job, date, kind: DueKind::FollowUp });
+        }
+        if let Some(at) = job.follow_up {
+            items.push(Due { job, date: at.date_naive(), kind: DueKind::FollowUp });
+        }
+        if let Some(date) = job.reply_by {
+            items.push(Due { job, date, kind: DueKind::Reply });
+        }
+        // Until it's been debriefed, when the trip is over
+        for interview in job.interviews.iter().filter(|i| i.debrief.is_none()) {
+            if let Some(date) = travel::travel_day(interview) {
+                items.push(Due { job, date, kind: DueKind::Travel(interview) });
+            }
+        }
+        for assessment in job.assessments.iter().filter(|a| a.completed.is_none()) {
+            if let Some(date) = assessment.expires {
+                items.push(Due { job, date, kind: DueKind::Assessment(assessment) });
+            }
+        }
+    }
+    // An accepted offer is closed, but there's still the paperwork
+    for job in jobs.iter().filter(|job| job.status == Status::Accepted) {
+        let Some(onboarding) = &job.onboarding else { continue };
+        for item in onboarding.checklist.iter().filter(|i| !i.done) {
+            if let Some(date) = item.due {
+                items.push(Due { job, date, kind: DueKind::Onboarding(item) });
+            }
+        }
+        if let Some(date) = onboarding.start_date {
+            items.push(Due { job, date, kind: DueKind::StartDate });
+        }
+    }
+    items.sort_by_key(|due| due.date);
+    items
+}
+
+/// Untaken OAs expiring within `days` of `today`. Once one has expired
+/// there's nothing left to warn about, the agenda still shows it.
+pub fn expiring_assessments(jobs: &[Job], today: NaiveDate, days: u32) -> Vec<Due<'_>> {
+    agenda(jobs)
+        .into_iter()
+        .filter(|due| due.is_assessment() && (0..=days as i64).contains(&(due.date - today).num_days()))
+        .collect()
+}
+
+/// Older files can contain duplicate ids, give the later copies fresh ones
+pub fn fix_duplicate_ids(jobs: &mut [Job]) {
+    let mut seen = std::collections::HashSet::new();
+    for job in jobs.iter_mut() {
+        if !seen.insert(job.id) {
+            job.id = JobId::new();
+        }
     }
 }
@@ -0,0 +1,127 @@
+//! My network, kept in network.json next to the jobs: people I know, where
+//! they work, how we met and when we last talked. Linking someone to a job
+//! puts them on it in the detail pane, so the friend at Acme comes to mind
+//! when the Acme application does.
+
+use crate::models::{normalize_company, Job, JobId};
+use crate::storage::get_data_dir;
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Person {
+    pub name: String,
+    #[serde(default)]
+    pub company: String,
+    #[serde(default)]
+    pub met: String, // "RustConf 2025", "worked together at Initech"
+    #[serde(default)]
+    pub last_contact: Option<NaiveDate>,
+    #[serde(default)]
+    pub jobs: Vec<JobId>, // Applications they're connected to
+}
+
+impl Person {
+    pub fn new(name: &str, company: &str, met: &str) -> Person {
+        Person {
+            name: name.trim().to_string(),
+            company: company.trim().to_string(),
+            met: met.trim().to_string(),
+            last_contact: None,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// "Dana Lee, Acme, met at RustConf 2025": the name, then where they
+    /// work and how we met when given. None without a name.
+    pub fn parse(input: &str) -> Option<Person> {
+        let mut parts = input.splitn(3, ',').map(str::trim);
+        let name = parts.next().filter(|name| !name.is_empty())?;
+        let company = parts.next().unwrap_or_default();
+        let met = parts.next().unwrap_or_default();
+        Some(Person::new(name, company, met))
+    }
+
+    /// "Dana Lee (Acme)", or just the name when I don't know where they work
+    pub fn label(&self) -> String {
+        match self.company.is_empty() {
+            true => self.name.clone(),
+            false => format!("{} ({})", self.name, self.company),
+        }
+    }
+
+    /// "talked 12 days ago", "talked today" or "never talked"
+    pub fn last_contact_label(&self, today: NaiveDate) -> String {
+        match self.last_contact.map(|date| (today - date).num_days()) {
+            None => "never talked".to_string(),
+            Some(0) => "talked today".to_string(),
+            Some(1) => "talked yesterday".to_string(),
+            Some(days) => format!("talked {} days ago", days),
+        }
+    }
+
+    /// Link them to `job`, or unlink them if they already are. Returns
+    /// whether they're linked now.
+    pub fn toggle_job(&mut self, job: JobId) -> bool {
+        match self.jobs.iter().position(|&id| id == job) {
+            Some(i) => {
+                self.jobs.remove(i);
+                false
+            }
+            None => {
+                self.jobs.push(job);
+                true
+            }
+        }
+    }
+}
+
+/// The people linked to `job`
+pub fn linked<'a>(people: &'a [Person], job: &Job) -> Vec<&'a Person> {
+    people.iter().filter(|p| p.jobs.contains(&job.id)).collect()
+}
+
+/// The people who work where `job` is and aren't linked to it yet, worth
+/// a word before applying
+pub fn at_company<'a>(people: &'a [Person], job: &Job) -> Vec<&'a Person> {
+    let company = normalize_company(&job.company);
+    people
+        .iter()
+        .filter(|p| !p.jobs.contains(&job.id) && !company.is_empty() && normalize_company(&p.company) == company)
+        .collect()
+}
+
+pub fn network_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("network.json"))
+}
+
+pub fn load_network() -> Result<Vec<Person>> {
+    let path = network_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).context("Failed to read network.json")?;
+    serde_json::from_str(&content).context("Failed to parse network.json")
+}
+
+pub fn save_network(people: &[Person]) -> Result<()> {
+    let json = serde_json::to_string_pretty(people).context("Failed to serialize network")?;
+    fs::write(network_path()?, json).context("Failed to write network.json")
+}
+
+/// The person whose name starts with `query`, ignoring case
+pub fn find(people: &[Person], query: &str) -> Result<usize> {
+    let query = query.trim().to_lowercase();
+    let matches: Vec<usize> = (0..people.len()).filter(|&i| people[i].name.to_lowercase().starts_with(&query)).collect();
+    match matches.as_slice() {
+        [i] => Ok(*i),
+        [] => bail!("No one named '{}' in the network", query),
+        _ => {
+            let names: Vec<&str> = matches.iter().map(|&i| people[i].name.as_str()).collect();
+            bail!("'{}' could be {}", query, names.join(" or "))
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Side-by-side comparison of the jobs at Offer: one column per offer, one
+//! row per thing worth comparing.
+//!
+//! Each offer's start date is also worked back through my notice period to
+//! the last day I can resign and the last day I can say yes. Offers that
+//! need an answer at different times are flagged, since answering the
+//! earlier one means giving up on waiting for the other.
+
+use crate::config::{CurrencyConfig, NoticeConfig};
+use crate::currency;
+use crate::models::{Job, Status};
+use chrono::{Days, NaiveDate};
+
+pub struct Matrix {
+    pub columns: Vec<String>,                   // "Company - Role" per offer
+    pub rows: Vec<(String, Vec<String>)>, // Label, then a cell per offer
+    pub conflicts: Vec<String>,                 // Timeline problems, printed under the table
+}
+
+/// The dates an offer's start date implies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timeline {
+    pub start: NaiveDate,
+    pub resign_by: NaiveDate,
+    pub decide_by: NaiveDate, // Buffer before resigning, or the offer's own deadline if that's sooner
+}
+
+pub fn timeline(job: &Job, notice: &NoticeConfig) -> Option<Timeline> {
+    let start = job.offered_start()?;
+    let resign_by = start - Days::new(notice.period_weeks as u64 * 7);
+    let mut decide_by = resign_by - Days::new(notice.decision_buffer_days as u64);
+    if let Some(deadline) = job.deadline {
+        decide_by = decide_by.min(deadline);
+    }
+    Some(Timeline { start, resign_by, decide_by })
+}
+
+/// Offers whose dates can't all be met: notice I can no longer give in time,
+/// and answers due before a competing offer's
+pub fn conflicts(jobs: &[Job], notice: &NoticeConfig, today: NaiveDate) -> Vec<String> {
+    let mut offers: Vec<(&Job, Timeline)> = jobs
+        .iter()
+        .filter(|j| j.status == Status::Offer)
+        .filter_map(|j| Some((j, timeline(j, notice)?)))
+        .collect();
+    offers.sort_by_key(|(_, t)| t.decide_by);
+
+    let mut conflicts = Vec::new();
+    for (job, t) in &offers {
+        if t.resign_by < today {
+            conflicts.push(format!(
+                "{}: too late to give {} weeks' notice before {}, ask to start later",
+                job.company, notice.period_weeks, t.start
+            ));
+        } else if t.decide_by < today {
+            conflicts.push(format!("{}: the answer was due {}", job.company, t.decide_by));
+        }
+    }
+    for pair in offers.windows(2) {
+        let ((first, a), (second, b)) = (pair[0], pair[1]);
+        if a.decide_by < b.decide_by && a.decide_by >= today {
+            conflicts.push(format!(
+                "{} needs an answer by {}, {} days before {} ({}): ask {} for more time",
+                first.company,
+                a.decide_by,
+                (b.decide_by - a.decide_by).num_days(),
+                second.company,
+                b.decide_by,
+                first.company
+            ));
+        }
+    }
+    conflicts
+}
+
+/// The pay rows compare as they were offered, then all in the base currency
+pub fn comparison(jobs: &[Job], notice: &NoticeConfig, currency: &CurrencyConfig, today: NaiveDate) -> Matrix {
+    let offers: Vec<&Job> = jobs.iter().filter(|j| j.status == Status::Offer).collect();
+    let cell = |f: &dyn Fn(&Job) -> Option<String>| -> Vec<String> {
+        offers.iter().map(|job| f(job).unwrap_or_else(|| "-".to_string())).collect()
+    };
+    let money = |amount: f64| format!("{:.0}", amount);
+
+    let pay_in_base = format!("Pay in {}", currency.base);
+    let rows = vec![
+        ("Type", cell(&|job| Some(job.engagement.to_string()))),
+        ("Salary", cell(&|job| job.salary_label())),
+        ("Rate", cell(&|job| job.contract.as_ref()?.rate_label())),
+        (pay_in_base.as_str(), cell(&|job| currency::normalized(job, currency))),
+        ("Length", cell(&|job| Some(format!("{} months", job.contract.as_ref()?.duration_months?)))),
+        ("Start", cell(&|job| Some(job.offered_start()?.to_string()))),
+        ("Resign by", cell(&|job| Some(timeline(job, notice)?.resign_by.to_string()))),
+        ("Decide by", cell(&|job| Some(timeline(job, notice)?.decide_by.to_string()))),
+        ("City", cell(&|job| Some(job.relocation.as_ref()?.city.clone()))),
+        ("Relocation", cell(&|job| job.relocation.as_ref()?.package.map(money))),
+        ("Moving cost", cell(&|job| job.relocation.as_ref()?.moving_cost.map(money))),
+        ("Net of move", cell(&|job| job.relocation.as_ref()?.net().map(money))),
+        ("Agency", cell(&|job| job.agency.clone())),
+    ];
+    let rows = rows.into_iter().map(|(label, cells)| (label.to_string(), cells)).collect();
+    Matrix {
+        columns: offers.iter().map(|job| format!("{} - {}", job.company, job.role)).collect(),
+        rows,
+        conflicts: conflicts(jobs, notice, today),
+    }
+}
+
+impl Matrix {
+    /// Plain text table with columns padded to fit, for the CLI and the TUI popup
+    pub fn lines(&self) -> Vec<String> {
+        let label_width = self.rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..self.columns.len())
+            .map(|c| {
+                self.rows
+                    .iter()
+                    .map(|(_, cells)| cells[c].chars().count())
+                    .chain(std::iter::once(self.columns[c].chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let row = |label: &str, cells: &[String]| {
+            let cells: Vec<String> = cells.iter().zip(&widths).map(|(cell, w)| format!("{:<w$}", cell, w = w)).collect();
+            format!("{:<label_width$}  {}", label, cells.join(" | "), label_width = label_width).trim_end().to_string()
+        };
+
+        let mut lines = vec![row("", &self.columns)];
+        lines.extend(self.rows.iter().map(|(label, cells)| row(label, cells)));
+        if !self.conflicts.is_empty() {
+            lines.push(String::new());
+            lines.extend(self.conflicts.iter().map(|c| format!("! {}", c)));
+        }
+        lines
+    }
+}
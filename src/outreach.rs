@@ -0,0 +1,149 @@
+//! Cold outreach, kept in outreach.json next to the jobs: people I wrote to
+//! at companies I'd like to work at, before there's an application to
+//! track. Each message goes from sent to a reply, maybe a referral, and
+//! maybe an application, and `funnel` counts how many make each step.
+
+use crate::models::{normalize_company, Job, JobId};
+use crate::storage::get_data_dir;
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reply {
+    #[default]
+    Waiting,
+    Replied,
+    Declined, // Answered, but no
+}
+
+/// One message to one person
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Outreach {
+    pub company: String,
+    pub contact: String, // "Dana Lee, eng manager"
+    pub sent: NaiveDate,
+    #[serde(default)]
+    pub reply: Reply,
+    #[serde(default)]
+    pub referred: Option<NaiveDate>, // When they put me forward
+    #[serde(default)]
+    pub job_id: Option<JobId>, // The application it led to
+}
+
+impl Outreach {
+    pub fn new(company: &str, contact: &str, sent: NaiveDate) -> Outreach {
+        Outreach {
+            company: company.trim().to_string(),
+            contact: contact.trim().to_string(),
+            sent,
+            reply: Reply::Waiting,
+            referred: None,
+            job_id: None,
+        }
+    }
+
+    /// The application it led to: the one linked, else one at the same
+    /// company sent on or after the message
+    pub fn application<'a>(&self, jobs: &'a [Job]) -> Option<&'a Job> {
+        match self.job_id {
+            Some(id) => jobs.iter().find(|j| j.id == id),
+            None => jobs.iter().find(|j| {
+                normalize_company(&j.company) == normalize_company(&self.company) && j.date_applied.date_naive() >= self.sent
+            }),
+        }
+    }
+
+    /// "replied, referred 2026-10-12, applied" for the list. Like the
+    /// funnel, a referral or application means they replied.
+    pub fn progress(&self, jobs: &[Job]) -> String {
+        let application = self.application(jobs);
+        let answered = self.referred.is_some() || application.is_some();
+        let mut steps = vec![match self.reply {
+            Reply::Waiting if !answered => "no reply yet".to_string(),
+            Reply::Waiting | Reply::Replied => "replied".to_string(),
+            Reply::Declined => "declined".to_string(),
+        }];
+        steps.extend(self.referred.map(|date| format!("referred {}", date)));
+        steps.extend(application.map(|job| format!("applied ({})", job.short_id())));
+        steps.join(", ")
+    }
+}
+
+/// How many messages made it to each step. A referral or application
+/// counts as a reply even when the reply itself wasn't recorded.
+pub struct Funnel {
+    pub sent: usize,
+    pub replied: usize,
+    pub referred: usize,
+    pub applied: usize,
+}
+
+impl Funnel {
+    /// "12 sent -> 5 replied (42%) -> 3 referred (25%) -> 2 applied (17%)",
+    /// each share out of those sent
+    pub fn label(&self) -> String {
+        let share = |n: usize| (n * 100).checked_div(self.sent).unwrap_or(0);
+        format!(
+            "{} sent -> {} replied ({}%) -> {} referred ({}%) -> {} applied ({}%)",
+            self.sent,
+            self.replied,
+            share(self.replied),
+            self.referred,
+            share(self.referred),
+            self.applied,
+            share(self.applied)
+        )
+    }
+}
+
+pub fn funnel(outreach: &[Outreach], jobs: &[Job]) -> Funnel {
+    let applied = |o: &&Outreach| o.application(jobs).is_some();
+    Funnel {
+        sent: outreach.len(),
+        replied: outreach
+            .iter()
+            .filter(|o| o.reply != Reply::Waiting || o.referred.is_some() || applied(o))
+            .count(),
+        referred: outreach.iter().filter(|o| o.referred.is_some()).count(),
+        applied: outreach.iter().filter(applied).count(),
+    }
+}
+
+pub fn outreach_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("outreach.json"))
+}
+
+pub fn load_outreach() -> Result<Vec<Outreach>> {
+    let path = outreach_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).context("Failed to read outreach.json")?;
+    serde_json::from_str(&content).context("Failed to parse outreach.json")
+}
+
+pub fn save_outreach(outreach: &[Outreach]) -> Result<()> {
+    let json = serde_json::to_string_pretty(outreach).context("Failed to serialize outreach")?;
+    fs::write(outreach_path()?, json).context("Failed to write outreach.json")
+}
+
+/// The message to a company ("google llc" finds Google) or a contact whose
+/// name starts with `query`, ignoring case
+pub fn find(outreach: &[Outreach], query: &str) -> Result<usize> {
+    let company = normalize_company(query);
+    let query = query.trim().to_lowercase();
+    let matches: Vec<usize> = (0..outreach.len())
+        .filter(|&i| normalize_company(&outreach[i].company) == company || outreach[i].contact.to_lowercase().starts_with(&query))
+        .collect();
+    match matches.as_slice() {
+        [i] => Ok(*i),
+        [] => bail!("No outreach to '{}'", query),
+        _ => {
+            let names: Vec<String> = matches.iter().map(|&i| format!("{} at {}", outreach[i].contact, outreach[i].company)).collect();
+            bail!("'{}' could be {}, name the contact", query, names.join(" or "))
+        }
+    }
+}
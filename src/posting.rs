@@ -0,0 +1,157 @@
+//! The posting's text, kept on the job so the requirements I applied
+//! against are still there after the listing comes down. Pasted with 'J'
+//! in the TUI or `career-cli description <id> --stdin`, or fetched from the
+//! link with `--fetch`, which needs curl. Reapplying to a company, the
+//! new posting can be held up against the last one to see whether the
+//! role really changed.
+
+use crate::models::Job;
+use anyhow::{bail, Context, Result};
+use std::process::Command;
+
+/// Download the page at `link` and boil it down to text
+pub fn fetch(link: &str) -> Result<String> {
+    if link.trim().is_empty() {
+        bail!("No link to fetch the posting from");
+    }
+    let output = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location", "--max-time", "20", "--", link.trim()])
+        .output()
+        .context("Failed to run curl, is it installed?")?;
+    if !output.status.success() {
+        bail!("Couldn't fetch {}: {}", link.trim(), String::from_utf8_lossy(&output.stderr).trim());
+    }
+    let text = html_to_text(&String::from_utf8_lossy(&output.stdout));
+    if text.is_empty() {
+        bail!("{} has no text, it may need a browser to show the posting", link.trim());
+    }
+    Ok(text)
+}
+
+/// Readable text out of a page: tags dropped, scripts and styles with
+/// them, a line break for each block element, the common entities decoded
+/// and blank runs squeezed to one empty line
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim().to_ascii_lowercase();
+        rest = &rest[start + end + 1..];
+        let name: String = tag.trim_start_matches('/').chars().take_while(|c| c.is_ascii_alphanumeric()).collect();
+        if !tag.starts_with('/') && matches!(name.as_str(), "script" | "style" | "head" | "noscript") {
+            let close = format!("</{}", name);
+            // ASCII only, so offsets into the lowercased copy are offsets into `rest`
+            rest = match rest.to_ascii_lowercase().find(&close) {
+                Some(at) => &rest[at..],
+                None => "",
+            };
+            continue;
+        }
+        if name == "li" {
+            // Each item starts its own line, so closing one adds nothing
+            if !tag.starts_with('/') {
+                text.push_str("\n- ");
+            }
+        } else if matches!(
+            name.as_str(),
+            "br" | "p" | "div" | "ul" | "ol" | "tr" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "section" | "article"
+        ) {
+            text.push('\n');
+        }
+    }
+    text.push_str(rest);
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in decode_entities(&text).lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() && lines.last().is_none_or(|l| l.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&rsquo;", "'")
+        .replace("&mdash;", "-")
+        .replace("&ndash;", "-")
+        .replace("&bull;", "-")
+        .replace("&amp;", "&")
+}
+
+/// A line of `diff`, by which side has it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Same,
+    Added,
+    Removed,
+}
+
+/// Line diff of two posting texts, `old` to `new`, in reading order.
+/// Blank lines and spacing don't count, so a reflowed copy of the same
+/// posting comes out unchanged.
+pub fn diff<'a>(old: &'a str, new: &'a str) -> Vec<(Change, &'a str)> {
+    let lines = |text: &'a str| -> Vec<&'a str> { text.lines().map(str::trim).filter(|l| !l.is_empty()).collect() };
+    let (old, new) = (lines(old), lines(new));
+
+    // Longest common subsequence, filled from the end so it reads forwards
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut changes = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            changes.push((Change::Same, new[j]));
+            (i, j) = (i + 1, j + 1);
+        } else if j < new.len() && (i == old.len() || common[i][j + 1] >= common[i + 1][j]) {
+            changes.push((Change::Added, new[j]));
+            j += 1;
+        } else {
+            changes.push((Change::Removed, old[i]));
+            i += 1;
+        }
+    }
+    changes
+}
+
+/// "same text" or "3 lines added, 1 removed"
+pub fn summary(changes: &[(Change, &str)]) -> String {
+    let count = |kind: Change| changes.iter().filter(|(c, _)| *c == kind).count();
+    match (count(Change::Added), count(Change::Removed)) {
+        (0, 0) => "same text".to_string(),
+        (added, removed) => format!("{} line{} added, {} removed", added, if added == 1 { "" } else { "s" }, removed),
+    }
+}
+
+/// The latest earlier application in `history` (as from
+/// `models::company_history`, oldest first) that kept its posting text,
+/// to hold `job`'s posting up against
+pub fn earlier<'a>(history: &[(&'a str, &'a Job)], job: &Job) -> Option<(&'a str, &'a Job)> {
+    history
+        .iter()
+        .rev()
+        .find(|(_, other)| other.id != job.id && other.date_applied <= job.date_applied && !other.description.is_empty())
+        .copied()
+}
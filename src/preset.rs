@@ -0,0 +1,145 @@
+//! Shareable setups: theme, keymap and loop templates in one TOML file.
+//! Storage and spell-check paths are machine specific and never included.
+
+use crate::config::{Config, LoopTemplate, ThemeConfig};
+use crate::keymap::{Action, Keymap};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Preset {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keymap: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_templates: Option<Vec<LoopTemplate>>,
+}
+
+/// Presets that ship with the binary, importable by name
+const BUILTIN: &[(&str, &str)] = &[("vim", VIM_PRESET)];
+
+const VIM_PRESET: &str = r#"# hjkl navigation, x to delete, i to add, : for filters
+[keymap]
+down = ["j", "Down"]
+up = ["k", "Up"]
+add = ["i"]
+delete = ["x"]
+company_history = ["H"]
+loop_template = ["L"]
+location = ["W"]
+filter = ["/", ":"]
+details = ["l", "v"]
+back = ["Esc", "ctrl-c"]
+"#;
+
+impl Preset {
+    /// Everything shareable from the current config, with the keymap spelled
+    /// out in full so the preset doesn't depend on this version's defaults
+    pub fn from_config(config: &Config) -> Preset {
+        let keymap = Action::ALL
+            .iter()
+            .map(|(_, name, defaults, _)| {
+                let keys = config
+                    .keymap
+                    .get(*name)
+                    .cloned()
+                    .unwrap_or_else(|| defaults.iter().map(|k| k.to_string()).collect());
+                (name.to_string(), keys)
+            })
+            .collect();
+        Preset {
+            theme: Some(config.theme.clone()),
+            keymap: Some(keymap),
+            loop_templates: Some(config.templates()),
+        }
+    }
+
+    /// A built-in preset by name, or a TOML file
+    pub fn load(source: &str) -> Result<Preset> {
+        let content = match BUILTIN.iter().find(|(name, _)| *name == source) {
+            Some((_, content)) => content.to_string(),
+            None => fs::read_to_string(Path::new(source))
+                .with_context(|| format!("No built-in preset or file named '{}'", source))?,
+        };
+        let preset: Preset = toml::from_str(&content).with_context(|| format!("'{}' is not a valid preset", source))?;
+        if let Some(keymap) = &preset.keymap {
+            Keymap::from_config(keymap).with_context(|| format!("Bad keymap in '{}'", source))?;
+        }
+        Ok(preset)
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self).context("Failed to serialize preset")
+    }
+
+    /// The config with this preset's sections swapped in
+    pub fn apply(&self, config: &Config) -> Config {
+        let mut config = config.clone();
+        if let Some(theme) = &self.theme {
+            config.theme = theme.clone();
+        }
+        if let Some(keymap) = &self.keymap {
+            config.keymap = keymap.clone();
+        }
+        if let Some(templates) = &self.loop_templates {
+            config.loop_templates = templates.clone();
+        }
+        config
+    }
+}
+
+pub fn builtin_names() -> Vec<&'static str> {
+    BUILTIN.iter().map(|(name, _)| *name).collect()
+}
+
+/// "+ added", "- removed" and "~ changed" lines between two configs, one per setting
+pub fn diff(old: &Config, new: &Config) -> Result<Vec<String>> {
+    let flat = |config: &Config| -> Result<BTreeMap<String, String>> {
+        let mut out = BTreeMap::new();
+        flatten("", &toml::Value::try_from(config)?, &mut out);
+        Ok(out)
+    };
+    // Defaults and explicit values are the same thing, compare what takes effect
+    let (old, new) = (flat(&with_effective_keymap(old))?, flat(&with_effective_keymap(new))?);
+
+    let mut lines = Vec::new();
+    for (key, value) in &new {
+        match old.get(key) {
+            None => lines.push(format!("+ {} = {}", key, value)),
+            Some(before) if before != value => lines.push(format!("~ {}: {} -> {}", key, before, value)),
+            _ => {}
+        }
+    }
+    for (key, value) in &old {
+        if !new.contains_key(key) {
+            lines.push(format!("- {} = {}", key, value));
+        }
+    }
+    Ok(lines)
+}
+
+fn with_effective_keymap(config: &Config) -> Config {
+    let mut config = config.clone();
+    config.keymap = Preset::from_config(&config).keymap.unwrap_or_default();
+    config.loop_templates = config.templates();
+    config
+}
+
+fn flatten(prefix: &str, value: &toml::Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten(&path, value, out);
+            }
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
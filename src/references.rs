@@ -0,0 +1,122 @@
+//! People who vouch for me, kept in references.json next to the jobs. Each
+//! one remembers which applications they were given to, so I don't wear out
+//! a reference by handing them to every process at once.
+
+use crate::models::{Job, JobId};
+use crate::storage::get_data_dir;
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub name: String,
+    #[serde(default)]
+    pub relationship: String, // "Manager at Initech"
+    #[serde(default)]
+    pub contact: String,
+    #[serde(default)]
+    pub given: Vec<Given>,
+    #[serde(default)]
+    pub asked_on: Option<NaiveDate>, // Last time I checked they're happy to take another call
+}
+
+/// One application the reference was passed on to
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Given {
+    pub job_id: JobId,
+    pub company: String,
+    pub date: NaiveDate,
+}
+
+/// Open processes a reference can be in before I should check with them again
+pub const CONCURRENT_LIMIT: usize = 2;
+
+impl Reference {
+    pub fn new(name: &str, relationship: &str, contact: &str) -> Reference {
+        Reference {
+            name: name.trim().to_string(),
+            relationship: relationship.trim().to_string(),
+            contact: contact.trim().to_string(),
+            given: Vec::new(),
+            asked_on: None,
+        }
+    }
+
+    pub fn last_used(&self) -> Option<NaiveDate> {
+        self.given.iter().map(|g| g.date).max()
+    }
+
+    /// Companies they were given to whose process is still running
+    pub fn active_uses<'a>(&'a self, jobs: &[Job]) -> Vec<&'a Given> {
+        self.given
+            .iter()
+            .filter(|g| {
+                jobs.iter().any(|j| j.id == g.job_id && j.is_open())
+            })
+            .collect()
+    }
+
+    /// A warning if giving them to `job` would put them in a third concurrent
+    /// process and I haven't asked them since they were last given out
+    pub fn overuse_warning(&self, jobs: &[Job], job: &Job) -> Option<String> {
+        let active: Vec<&Given> = self.active_uses(jobs).into_iter().filter(|g| g.job_id != job.id).collect();
+        if active.len() < CONCURRENT_LIMIT {
+            return None;
+        }
+        let latest = active.iter().map(|g| g.date).max();
+        if self.asked_on.is_some() && self.asked_on >= latest {
+            return None;
+        }
+        let companies: Vec<&str> = active.iter().map(|g| g.company.as_str()).collect();
+        Some(format!(
+            "{} is already a reference for {}, ask them before adding {}",
+            self.name,
+            companies.join(", "),
+            job.company
+        ))
+    }
+
+    /// Record handing them to `job`. Giving them twice to the same job is a no-op.
+    pub fn give(&mut self, job: &Job, today: NaiveDate) {
+        if !self.given.iter().any(|g| g.job_id == job.id) {
+            self.given.push(Given { job_id: job.id, company: job.company.clone(), date: today });
+        }
+    }
+}
+
+pub fn references_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("references.json"))
+}
+
+pub fn load_references() -> Result<Vec<Reference>> {
+    let path = references_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).context("Failed to read references.json")?;
+    serde_json::from_str(&content).context("Failed to parse references.json")
+}
+
+pub fn save_references(references: &[Reference]) -> Result<()> {
+    let json = serde_json::to_string_pretty(references).context("Failed to serialize references")?;
+    fs::write(references_path()?, json).context("Failed to write references.json")
+}
+
+/// The reference whose name starts with `query`, ignoring case
+pub fn find(references: &[Reference], query: &str) -> Result<usize> {
+    let query = query.trim().to_lowercase();
+    let matches: Vec<usize> = (0..references.len())
+        .filter(|&i| references[i].name.to_lowercase().starts_with(&query))
+        .collect();
+    match matches.as_slice() {
+        [i] => Ok(*i),
+        [] => bail!("No reference named '{}'", query),
+        _ => {
+            let names: Vec<&str> = matches.iter().map(|&i| references[i].name.as_str()).collect();
+            bail!("'{}' could be {}", query, names.join(" or "))
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Paperwork that comes with being between jobs: certifying for unemployment
+//! every week, the COBRA election deadline. They aren't tied to any job, so
+//! they live in config.toml and show up beside the job deadlines in the
+//! agenda rather than among them.
+//!
+//! ```toml
+//! [[reminders]]
+//! name = "Unemployment certification"
+//! due = "2026-10-19"
+//! every = "1w"            # d, w or m; leave out for a one-off
+//! until = "2027-04-01"    # optional, stops repeating after this
+//!
+//! [[reminders]]
+//! name = "COBRA election"
+//! due = "2026-12-15"
+//! ```
+
+use anyhow::{bail, Context, Result};
+use chrono::{Days, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AdminReminder {
+    pub name: String,
+    pub due: NaiveDate, // The first time, or the only time for a one-off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Interval>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<NaiveDate>,
+}
+
+/// "1w", "14d", "1m"
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub enum Interval {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+}
+
+impl Interval {
+    pub fn parse(text: &str) -> Result<Interval> {
+        let text = text.trim().to_lowercase();
+        let split = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+        let (count, unit) = text.split_at(split);
+        let count: u32 = count.parse().ok().filter(|n| *n > 0).context("Intervals look like 1w, 14d or 1m")?;
+        Ok(match unit {
+            "d" | "day" | "days" => Interval::Days(count),
+            "w" | "week" | "weeks" => Interval::Weeks(count),
+            "m" | "month" | "months" => Interval::Months(count),
+            _ => bail!("Intervals look like 1w, 14d or 1m, got '{}'", text),
+        })
+    }
+
+    /// `date` moved on by this interval `times` times, counted from the
+    /// start so month ends don't drift
+    fn after(self, date: NaiveDate, times: u32) -> Option<NaiveDate> {
+        match self {
+            Interval::Days(n) => date.checked_add_days(Days::new(n as u64 * times as u64)),
+            Interval::Weeks(n) => date.checked_add_days(Days::new(n as u64 * 7 * times as u64)),
+            Interval::Months(n) => date.checked_add_months(Months::new(n * times)),
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Interval::Days(n) => write!(f, "{}d", n),
+            Interval::Weeks(n) => write!(f, "{}w", n),
+            Interval::Months(n) => write!(f, "{}m", n),
+        }
+    }
+}
+
+impl TryFrom<String> for Interval {
+    type Error = anyhow::Error;
+
+    fn try_from(text: String) -> Result<Interval> {
+        Interval::parse(&text)
+    }
+}
+
+impl From<Interval> for String {
+    fn from(interval: Interval) -> String {
+        interval.to_string()
+    }
+}
+
+impl AdminReminder {
+    /// The next time it's due, today included. None once a repeating one
+    /// is over. A one-off stays due, late, until it's removed, so one that
+    /// came and went while nothing was running still gets its notification.
+    pub fn next_due(&self, today: NaiveDate) -> Option<NaiveDate> {
+        let Some(every) = self.every else {
+            return Some(self.due);
+        };
+        let next = (0..).map_while(|n| every.after(self.due, n)).find(|date| *date >= today)?;
+        let over = self.until.is_some_and(|until| next > until);
+        (!over).then_some(next)
+    }
+
+    /// "every 1w", or empty for a one-off
+    pub fn describe(&self) -> String {
+        self.every.map(|every| format!("every {}", every)).unwrap_or_default()
+    }
+}
+
+/// Each reminder's next due date, soonest first
+pub fn upcoming(reminders: &[AdminReminder], today: NaiveDate) -> Vec<(NaiveDate, &AdminReminder)> {
+    let mut upcoming: Vec<(NaiveDate, &AdminReminder)> =
+        reminders.iter().filter_map(|r| Some((r.next_due(today)?, r))).collect();
+    upcoming.sort_by_key(|(date, r)| (*date, r.name.clone()));
+    upcoming
+}
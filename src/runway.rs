@@ -0,0 +1,67 @@
+//! How long the savings last, and what that means for how fast I should be
+//! applying. Configured under [runway] in config.toml.
+
+use crate::config::RunwayConfig;
+use crate::models::{Job, Status};
+use chrono::{Days, Duration, NaiveDate, NaiveTime};
+
+/// Assumed when there's no offer yet to learn the real ratio from
+pub const DEFAULT_APPLICATIONS_PER_OFFER: f64 = 30.0;
+
+const DAYS_PER_MONTH: f64 = 30.44;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Runway {
+    pub ends: NaiveDate,
+    pub weeks: f64,                  // Left from today, 0 once the money's gone
+    pub pace: f64,                   // Applications a week over the last four weeks
+    pub applications_per_offer: f64, // From my own history when there's an offer in it
+    pub needed: f64,                 // Applications a week to land an offer before `ends`
+}
+
+/// None until a monthly burn is configured
+pub fn runway(jobs: &[Job], config: &RunwayConfig, today: NaiveDate) -> Option<Runway> {
+    if config.monthly_burn <= 0.0 {
+        return None;
+    }
+    // Savings aren't touched while severance is still paying out
+    let burn_from = config.severance_end.map_or(today, |end| end.max(today));
+    let days = (config.savings.max(0.0) / config.monthly_burn * DAYS_PER_MONTH) as u64;
+    let ends = burn_from + Days::new(days);
+    let weeks = (ends - today).num_days() as f64 / 7.0;
+
+    let since = (today - Duration::weeks(4)).and_time(NaiveTime::MIN).and_utc();
+    let pace = jobs.iter().filter(|j| j.date_applied >= since).count() as f64 / 4.0;
+
+    let offers = jobs.iter().filter(|j| matches!(j.status, Status::Offer | Status::Accepted)).count();
+    let applications_per_offer = if offers == 0 {
+        DEFAULT_APPLICATIONS_PER_OFFER
+    } else {
+        jobs.len() as f64 / offers as f64
+    };
+    // Applications sent in the last few weeks won't turn into an offer in time
+    let window = (weeks - config.hiring_weeks as f64).max(1.0);
+    Some(Runway { ends, weeks, pace, applications_per_offer, needed: applications_per_offer / window })
+}
+
+impl Runway {
+    pub fn on_track(&self) -> bool {
+        self.pace >= self.needed
+    }
+
+    /// Summary for the stats screens
+    pub fn lines(&self) -> Vec<String> {
+        let verdict = if self.on_track() {
+            "on track".to_string()
+        } else {
+            format!("apply faster, {:.1}/week needed", self.needed)
+        };
+        vec![
+            format!("Runway: {:.0} weeks, until {}", self.weeks, self.ends),
+            format!(
+                "Pace: {:.1}/week applied, {} (1 offer per {:.0} applications)",
+                self.pace, verdict, self.applications_per_offer
+            ),
+        ]
+    }
+}
@@ -0,0 +1,187 @@
+//! Pulling the pay out of a pasted posting: "$150k–$180k", "£70,000 per
+//! annum", "USD 45-60 an hour", "between €60.000 and €75.000". Only amounts
+//! with a currency count, so a "401k" or "10,000 employees" in the same text
+//! doesn't get picked up. Hourly and monthly pay is turned yearly, since that
+//! is what `salary_min` and `salary_max` hold.
+
+use crate::models;
+
+/// Full-time hours in a year, for hourly postings
+const HOURS_PER_YEAR: f64 = 2080.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Hour,
+    Month,
+    Year,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extracted {
+    pub min: u32,
+    pub max: u32,
+    pub currency: &'static str, // ISO code, e.g. "USD"
+    pub per: Period,
+}
+
+impl Extracted {
+    /// The range as yearly amounts
+    pub fn yearly(&self) -> (u32, u32) {
+        let scale = match self.per {
+            Period::Hour => HOURS_PER_YEAR,
+            Period::Month => 12.0,
+            Period::Year => 1.0,
+        };
+        ((self.min as f64 * scale).round() as u32, (self.max as f64 * scale).round() as u32)
+    }
+}
+
+/// What the salary prompt takes: a plain "120k-150k" as before, or pasted
+/// text with a currency in it. Some((None, None, None)) when empty.
+pub fn parse(input: &str) -> Option<(Option<u32>, Option<u32>, Option<String>)> {
+    if let Some(found) = extract(input) {
+        let (min, max) = found.yearly();
+        return Some((Some(min), Some(max), Some(found.currency.to_string())));
+    }
+    let (min, max) = models::parse_salary(input)?;
+    Some((min, max, None))
+}
+
+/// "$" for USD and so on, the code and a space for the rest
+pub fn symbol(currency: &str) -> String {
+    match currency {
+        "USD" => "$".to_string(),
+        "GBP" => "£".to_string(),
+        "EUR" => "€".to_string(),
+        other => format!("{} ", other),
+    }
+}
+
+struct Amount {
+    value: f64,
+    k: bool, // Had a 'k' after it
+    currency: Option<&'static str>,
+    start: usize,
+    end: usize,
+}
+
+/// The first range (or single amount) with a currency in `text`
+pub fn extract(text: &str) -> Option<Extracted> {
+    let amounts = amounts(text);
+    for (i, first) in amounts.iter().enumerate() {
+        let second = amounts.get(i + 1).filter(|next| {
+            // Less the currency in front of the second one, as in "$150k–$180k"
+            let between = text[first.end..next.start]
+                .trim_end()
+                .trim_end_matches(|c: char| matches!(c, '$' | '£' | '€') || c.is_ascii_uppercase())
+                .trim()
+                .to_lowercase();
+            ["-", "–", "—", "to", "and"].contains(&between.as_str())
+        });
+        let Some(currency) = first.currency.or(second.and_then(|s| s.currency)) else { continue };
+
+        let (mut min, mut max) = (first.value, second.map_or(first.value, |s| s.value));
+        // "$150-180k" puts the k on the second number only
+        if second.is_some_and(|s| s.k) && !first.k && min < 1000.0 {
+            min *= 1000.0;
+        }
+        if min > max {
+            (min, max) = (max, min);
+        }
+        let end = second.map_or(first.end, |s| s.end);
+        let after: String = text[end..].chars().take(30).collect::<String>().to_lowercase();
+        let says = |words: &[&str]| words.iter().any(|word| after.contains(word));
+        // Sizes settle it when the words around are about something else, like "40 hours a week"
+        let per = if says(&["hour", "/hr", "/h"]) && max < 1000.0 {
+            Period::Hour
+        } else if says(&["month", "/mo"]) && max < 100_000.0 {
+            Period::Month
+        } else if max < 1000.0 && !says(&["annum", "year", "/yr", "annual"]) {
+            // Nobody is paid 60 a year
+            Period::Hour
+        } else {
+            Period::Year
+        };
+        return Some(Extracted { min: min.round() as u32, max: max.round() as u32, currency, per });
+    }
+    None
+}
+
+/// Every number in `text`, with a currency when a symbol or code sits
+/// right before or after it
+fn amounts(text: &str) -> Vec<Amount> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        let after_number = i > 0 && (chars[i - 1].1.is_ascii_digit() || chars[i - 1].1.is_alphabetic());
+        if !c.is_ascii_digit() || after_number {
+            i += 1;
+            continue;
+        }
+        let mut j = i;
+        while j < chars.len() && (chars[j].1.is_ascii_digit() || matches!(chars[j].1, ',' | '.')) {
+            j += 1;
+        }
+        // A trailing separator ends the sentence rather than the number
+        while j > i && matches!(chars[j - 1].1, ',' | '.') {
+            j -= 1;
+        }
+        let digits: String = chars[i..j].iter().map(|(_, c)| c).collect();
+        let mut value = number(&digits);
+        let mut end = chars.get(j).map_or(text.len(), |(at, _)| *at);
+        let k = chars.get(j).is_some_and(|(_, c)| matches!(c, 'k' | 'K'))
+            && !chars.get(j + 1).is_some_and(|(_, c)| c.is_alphabetic());
+        if k {
+            value *= 1000.0;
+            j += 1;
+            end = chars.get(j).map_or(text.len(), |(at, _)| *at);
+        }
+        let currency = currency_before(&text[..start]).or_else(|| currency_after(&text[end..]));
+        found.push(Amount { value, k, currency, start, end });
+        i = j.max(i + 1);
+    }
+    found
+}
+
+/// "70,000" and "60.000" are thousands, "62.5" and "62,5" are not, and
+/// "150,000.00" is both
+fn number(digits: &str) -> f64 {
+    let mut groups: Vec<&str> = digits.split([',', '.']).collect();
+    let cents = if groups.len() > 2 && groups.last().is_some_and(|g| g.len() == 2) { groups.pop() } else { None };
+    if groups.len() > 1 && groups[1..].iter().all(|g| g.len() == 3) {
+        let whole: f64 = groups.concat().parse().unwrap_or(0.0);
+        return whole + cents.and_then(|c| c.parse::<f64>().ok()).unwrap_or(0.0) / 100.0;
+    }
+    digits.replace(',', ".").parse().unwrap_or(0.0)
+}
+
+/// The currency written in front of an amount, as in "£650" or "CHF650"
+pub fn currency_prefix(amount: &str) -> Option<&'static str> {
+    let start = amount.find(|c: char| c.is_ascii_digit())?;
+    currency_before(&amount[..start]).filter(|_| start > 0)
+}
+
+fn currency_before(text: &str) -> Option<&'static str> {
+    let text = text.trim_end();
+    for (marker, code) in [("CA$", "CAD"), ("C$", "CAD"), ("AU$", "AUD"), ("A$", "AUD"), ("US$", "USD"), ("$", "USD"), ("£", "GBP"), ("€", "EUR")] {
+        if text.ends_with(marker) {
+            return Some(code);
+        }
+    }
+    let word = text.rsplit(|c: char| !c.is_alphabetic()).next()?;
+    code(word)
+}
+
+fn currency_after(text: &str) -> Option<&'static str> {
+    let text = text.trim_start();
+    if text.starts_with('€') {
+        return Some("EUR");
+    }
+    code(text.split(|c: char| !c.is_alphabetic()).next()?)
+}
+
+fn code(word: &str) -> Option<&'static str> {
+    ["USD", "GBP", "EUR", "CAD", "AUD", "CHF"].into_iter().find(|code| *code == word)
+}
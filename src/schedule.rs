@@ -0,0 +1,91 @@
+//! Follow-ups at a set time, e.g. "nudge the recruiter Thursday 9am their
+//! time". The time goes on the job as its follow-up, and an `at` job runs
+//! `career-cli follow-up <id> --send <time>` then, which pops a notification and
+//! opens an email draft to the job's contact. Nothing to keep running, but
+//! atd has to be, as it is on most Linux and macOS machines once enabled.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// "thu 9am", "thursday 09:30", "tomorrow 2pm" or "2026-10-22 9am", in
+/// `tz` when the job has one and my own time otherwise. A weekday is the
+/// next one still to come, so "thu 9am" on a Thursday afternoon is next
+/// week. None when it doesn't parse or is already past.
+pub fn parse_when(input: &str, tz: Option<Tz>, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let input = input.trim().to_lowercase();
+    let (day, time) = input.split_once(' ')?;
+    let time = parse_time(time.trim())?;
+    let today = match tz {
+        Some(tz) => now.with_timezone(&tz).date_naive(),
+        None => now.with_timezone(&Local).date_naive(),
+    };
+    let to_utc = |date: NaiveDate| -> Option<DateTime<Utc>> {
+        let local = date.and_time(time);
+        match tz {
+            Some(tz) => tz.from_local_datetime(&local).earliest().map(|at| at.with_timezone(&Utc)),
+            None => Local.from_local_datetime(&local).earliest().map(|at| at.with_timezone(&Utc)),
+        }
+    };
+    let at = match day {
+        "today" => to_utc(today)?,
+        "tomorrow" => to_utc(today + Days::new(1))?,
+        _ => match (NaiveDate::parse_from_str(day, "%Y-%m-%d"), day.parse::<Weekday>()) {
+            (Ok(date), _) => to_utc(date)?,
+            (_, Ok(weekday)) => (0..8)
+                .map(|n| today + Days::new(n))
+                .filter(|date| date.weekday() == weekday)
+                .filter_map(to_utc)
+                .find(|at| *at > now)?,
+            _ => return None,
+        },
+    };
+    (at > now).then_some(at)
+}
+
+/// "9am", "9:30am", "2pm" or "14:00"
+fn parse_time(input: &str) -> Option<NaiveTime> {
+    if let Ok(time) = NaiveTime::parse_from_str(input, "%H:%M") {
+        return Some(time);
+    }
+    let (clock, pm) = match (input.strip_suffix("am"), input.strip_suffix("pm")) {
+        (Some(clock), _) => (clock, false),
+        (_, Some(clock)) => (clock, true),
+        _ => return None,
+    };
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse().ok()?),
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+    NaiveTime::from_hms_opt(hour % 12 + if pm { 12 } else { 0 }, minute, 0)
+}
+
+/// Queue `career-cli follow-up <id> --send <when>` with at for `when`,
+/// returning what at said about the job it made. Moving or clearing the
+/// follow-up leaves it queued; `--send` checks the job still wants it for
+/// `when`, so `id` has to be saved with it first.
+pub fn queue(id: &str, when: DateTime<Utc>) -> Result<String> {
+    let binary = std::env::current_exe().context("Can't find the career-cli binary")?;
+    let command = format!("'{}' follow-up {} --send {}\n", binary.display(), id, when.to_rfc3339());
+    let stamp = when.with_timezone(&Local).format("%Y%m%d%H%M").to_string();
+    let mut child = Command::new("at")
+        .args(["-t", &stamp])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run at, is it installed (and atd running)?")?;
+    child.stdin.take().context("No stdin for at")?.write_all(command.as_bytes())?;
+    let output = child.wait_with_output()?;
+    // at reports the job on stderr, e.g. "job 12 at Thu Oct 22 09:00:00 2026"
+    let said = String::from_utf8_lossy(&output.stderr).lines().last().unwrap_or_default().trim().to_string();
+    if !output.status.success() {
+        bail!("at refused the follow-up: {}", said);
+    }
+    Ok(said)
+}
@@ -0,0 +1,154 @@
+//! Which jobs stats, charts and exports count. Test entries, abandoned drafts
+//! and old searches all drag the response rate around without saying so, so
+//! each kind is a switch with a default in config.toml that any of those
+//! commands can flip with `--include` or `--exclude`, and whatever is left
+//! out gets a line saying so.
+//!
+//! ```toml
+//! [stats]
+//! archived = false   # past searches in archive/, and jobs archived in the list
+//! trashed = false    # jobs tagged #trash
+//! withdrawn = true   # Withdrawn jobs, or a custom status by that name
+//! drafts = false     # half-added jobs, not applied to yet
+//! exclude = "tag:test OR company:example"   # anything else, as a filter
+//! ```
+
+use crate::filter::Filter;
+use crate::models::{Job, Status};
+use crate::statuses::StatusDef;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct Scope {
+    pub archived: bool,
+    pub trashed: bool,
+    pub withdrawn: bool,
+    pub drafts: bool,
+    pub exclude: Option<String>,
+}
+
+impl Default for Scope {
+    fn default() -> Self {
+        Self { archived: false, trashed: false, withdrawn: true, drafts: false, exclude: None }
+    }
+}
+
+/// Tag that keeps a job in the list but out of the numbers
+pub const TRASH_TAG: &str = "trash";
+
+pub fn is_trashed(job: &Job) -> bool {
+    job.tags.iter().any(|t| t == TRASH_TAG)
+}
+
+pub fn is_withdrawn(job: &Job) -> bool {
+    job.status == Status::Withdrawn || job.status_label().eq_ignore_ascii_case("withdrawn")
+}
+
+/// The jobs a command counts, and how many it left out of each kind
+pub struct Selection {
+    pub jobs: Vec<Job>,
+    pub left_out: Vec<(&'static str, usize)>,
+}
+
+impl Selection {
+    /// "Left out 2 trashed, 14 archived"
+    pub fn note(&self) -> Option<String> {
+        let parts: Vec<String> = self
+            .left_out
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(kind, count)| format!("{} {}", count, kind))
+            .collect();
+        if parts.is_empty() {
+            return None;
+        }
+        Some(format!("Left out {}", parts.join(", ")))
+    }
+}
+
+impl Scope {
+    /// Checked on startup, like the other config sections
+    pub fn validate(&self, statuses: &[StatusDef]) -> Result<()> {
+        self.filter(statuses).map(|_| ())
+    }
+
+    fn filter(&self, statuses: &[StatusDef]) -> Result<Option<Filter>> {
+        match self.exclude.as_deref().map(str::trim).filter(|e| !e.is_empty()) {
+            Some(source) => Filter::parse(source, statuses).map(Some).context("Bad [stats] exclude in config.toml"),
+            None => Ok(None),
+        }
+    }
+
+    /// Take `--include <kinds>` and `--exclude <kinds>` off the command's
+    /// arguments, kinds as "archived,trashed", "drafts" or "all". The
+    /// rest is returned for the command to parse as before.
+    pub fn with_flags(mut self, args: &[String]) -> Result<(Scope, Vec<String>)> {
+        let mut rest = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let include = match arg.as_str() {
+                "--include" => true,
+                "--exclude" => false,
+                _ => {
+                    rest.push(arg.clone());
+                    continue;
+                }
+            };
+            let Some(kinds) = args.next() else {
+                bail!("{} needs archived, trashed, withdrawn, drafts or all", arg);
+            };
+            for kind in kinds.split(',').map(str::trim) {
+                match kind {
+                    "archived" => self.archived = include,
+                    "trashed" | "trash" => self.trashed = include,
+                    "withdrawn" => self.withdrawn = include,
+                    "drafts" | "draft" => self.drafts = include,
+                    "all" => (self.archived, self.trashed, self.withdrawn, self.drafts) = (include, include, include, include),
+                    other => bail!("Unknown kind '{}' (archived, trashed, withdrawn, drafts or all)", other),
+                }
+            }
+        }
+        Ok((self, rest))
+    }
+
+    /// `jobs` plus the archived `cohorts` when they're in, minus whatever
+    /// is switched off or matches the exclude filter
+    pub fn select(&self, jobs: &[Job], cohorts: &[(String, Vec<Job>)], statuses: &[StatusDef]) -> Result<Selection> {
+        let filter = self.filter(statuses)?;
+        let mut archived: usize = cohorts.iter().map(|(_, jobs)| jobs.len()).sum();
+        if self.archived {
+            archived = 0;
+        }
+        let candidates = jobs.iter().chain(cohorts.iter().flat_map(|(_, jobs)| jobs).filter(|_| self.archived));
+        let (mut trashed, mut withdrawn, mut drafts, mut excluded) = (0, 0, 0, 0);
+        let mut selected = Vec::new();
+        for job in candidates {
+            // Archived in the list ('Z' in the TUI) counts like an archived cohort
+            if !self.archived && job.archived {
+                archived += 1;
+            } else if !self.trashed && is_trashed(job) {
+                trashed += 1;
+            } else if !self.withdrawn && is_withdrawn(job) {
+                withdrawn += 1;
+            } else if !self.drafts && job.draft {
+                drafts += 1;
+            } else if filter.as_ref().is_some_and(|f| f.matches(job)) {
+                excluded += 1;
+            } else {
+                selected.push(job.clone());
+            }
+        }
+        Ok(Selection {
+            jobs: selected,
+            left_out: vec![
+                ("archived", archived),
+                ("trashed", trashed),
+                ("withdrawn", withdrawn),
+                ("drafts", drafts),
+                ("excluded by filter", excluded),
+            ],
+        })
+    }
+}
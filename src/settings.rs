@@ -0,0 +1,132 @@
+//! The settings screen (',' in the TUI): the config.toml options people
+//! actually change, edited in place. Each change is checked the way a
+//! hand-edited config is, takes effect straight away and is written back to
+//! config.toml, so nobody has to learn TOML to switch the colors.
+//!
+//! Everything else in config.toml (keymap, statuses, templates) is still
+//! edited by hand and survives a save here, less its comments.
+
+use crate::config::Config;
+use crate::storage::get_data_dir;
+use crate::theme::{ColorMode, GlyphStyle};
+use anyhow::{bail, Context, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    ColorMode,
+    Glyphs,
+    Sort,
+    StaleDays,
+    WeeklyGoal,
+    BusinessDays,
+    DataDir,
+}
+
+impl Setting {
+    /// In the order the screen lists them
+    pub const ALL: [Setting; 7] = [
+        Setting::ColorMode,
+        Setting::Glyphs,
+        Setting::Sort,
+        Setting::StaleDays,
+        Setting::WeeklyGoal,
+        Setting::BusinessDays,
+        Setting::DataDir,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Setting::ColorMode => "Colors",
+            Setting::Glyphs => "Status glyphs",
+            Setting::Sort => "Default sort",
+            Setting::StaleDays => "Stale after (days)",
+            Setting::WeeklyGoal => "Weekly goal (applications)",
+            Setting::BusinessDays => "Count business days",
+            Setting::DataDir => "Data folder",
+        }
+    }
+
+    /// The current value, the way config.toml spells it
+    pub fn value(self, config: &Config) -> String {
+        match self {
+            Setting::ColorMode => match config.theme.color_mode {
+                ColorMode::Auto => "auto",
+                ColorMode::Basic => "16",
+                ColorMode::Ansi256 => "256",
+                ColorMode::TrueColor => "truecolor",
+            }
+            .to_string(),
+            Setting::Glyphs => format!("{:?}", config.theme.glyphs).to_lowercase(),
+            Setting::Sort => format!("{:?}", config.list.sort).to_lowercase(),
+            Setting::StaleDays => config.metrics.health.stale_days.to_string(),
+            Setting::WeeklyGoal => config.metrics.health.weekly_target.to_string(),
+            Setting::BusinessDays => if config.metrics.business_days { "yes" } else { "no" }.to_string(),
+            Setting::DataDir => get_data_dir().map_or_else(|e| format!("{:#}", e), |dir| dir.display().to_string()),
+        }
+    }
+
+    /// Whether Enter steps to the next choice instead of asking for a value
+    pub fn cycles(self) -> bool {
+        matches!(self, Setting::ColorMode | Setting::Glyphs | Setting::Sort | Setting::BusinessDays)
+    }
+
+    /// What the prompt asks for settings that take a typed value
+    pub fn prompt(self) -> &'static str {
+        match self {
+            Setting::StaleDays => " Open jobs with no news for this many days count as stale (1-365) ",
+            Setting::WeeklyGoal => " Applications to aim for each week (0 to leave it out of the health score) ",
+            _ => " Value ",
+        }
+    }
+
+    /// Step `config` to this setting's next choice
+    pub fn cycle(self, config: &mut Config) {
+        match self {
+            Setting::ColorMode => {
+                config.theme.color_mode = match config.theme.color_mode {
+                    ColorMode::Auto => ColorMode::Basic,
+                    ColorMode::Basic => ColorMode::Ansi256,
+                    ColorMode::Ansi256 => ColorMode::TrueColor,
+                    ColorMode::TrueColor => ColorMode::Auto,
+                }
+            }
+            Setting::Glyphs => {
+                config.theme.glyphs = match config.theme.glyphs {
+                    GlyphStyle::Off => GlyphStyle::Auto,
+                    GlyphStyle::Auto => GlyphStyle::Ascii,
+                    GlyphStyle::Ascii => GlyphStyle::Unicode,
+                    GlyphStyle::Unicode => GlyphStyle::Nerd,
+                    GlyphStyle::Nerd => GlyphStyle::Off,
+                }
+            }
+            Setting::Sort => {
+                config.list.sort = config.list.sort.next()
+            }
+            Setting::BusinessDays => config.metrics.business_days = !config.metrics.business_days,
+            Setting::StaleDays | Setting::WeeklyGoal | Setting::DataDir => {}
+        }
+    }
+
+    /// Set a typed value on `config`, or say why it won't do
+    pub fn set(self, config: &mut Config, input: &str) -> Result<()> {
+        let number = || input.trim().parse::<u32>().with_context(|| format!("'{}' isn't a whole number", input.trim()));
+        match self {
+            Setting::StaleDays => {
+                let days = number()?;
+                if !(1..=365).contains(&days) {
+                    bail!("Stale after is 1 to 365 days");
+                }
+                config.metrics.health.stale_days = days;
+            }
+            Setting::WeeklyGoal => {
+                let goal = number()?;
+                if goal > 100 {
+                    bail!("More than 100 applications a week is a typo");
+                }
+                config.metrics.health.weekly_target = goal;
+            }
+            _ => bail!("{} isn't typed in", self.label()),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+//! How well I fit a job: the skills it asks for against the ones I have,
+//! listed under [skills] in config.toml. The list can sort by it, so the
+//! applications where I match best come up first.
+
+use crate::config::SkillsConfig;
+use crate::models::Job;
+
+/// The skills a job asks for, split into the ones I have and the ones I don't
+pub struct Fit<'a> {
+    pub matched: Vec<&'a str>,
+    pub missing: Vec<&'a str>,
+}
+
+impl Fit<'_> {
+    /// Share of the asked-for skills I have, 0 to 100, rounded
+    pub fn score(&self) -> u32 {
+        let asked = self.matched.len() + self.missing.len();
+        (self.matched.len() * 100 + asked / 2).checked_div(asked).unwrap_or(0) as u32
+    }
+
+    /// "67% (2 of 3), missing kafka" for the detail pane and `show`
+    pub fn label(&self) -> String {
+        let asked = self.matched.len() + self.missing.len();
+        let missing = if self.missing.is_empty() { String::new() } else { format!(", missing {}", self.missing.join(", ")) };
+        format!("{}% ({} of {}){}", self.score(), self.matched.len(), asked, missing)
+    }
+}
+
+/// "Rust, PostgreSQL,machine learning" into ["rust", "postgresql",
+/// "machine learning"]. Commas split, so skills can be more than a word.
+pub fn parse(input: &str) -> Vec<String> {
+    let mut skills: Vec<String> = Vec::new();
+    for skill in input.split(',') {
+        let skill = skill.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        if !skill.is_empty() && !skills.contains(&skill) {
+            skills.push(skill);
+        }
+    }
+    skills
+}
+
+/// How `job` fits, None when it lists no skills or I haven't listed mine
+pub fn fit<'a>(job: &'a Job, config: &SkillsConfig) -> Option<Fit<'a>> {
+    if job.skills.is_empty() || config.have.is_empty() {
+        return None;
+    }
+    let have = |skill: &str| config.have.iter().any(|mine| mine.trim().eq_ignore_ascii_case(skill));
+    let (matched, missing) = job.skills.iter().map(String::as_str).partition(|skill| have(skill));
+    Some(Fit { matched, missing })
+}
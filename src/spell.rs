@@ -0,0 +1,123 @@
+use crate::config::SpellcheckConfig;
+use crate::models::Job;
+use crate::storage::get_data_dir;
+use std::collections::HashSet;
+use std::fs;
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// Word list from a hunspell .dic file. The affix rules aren't applied, so
+/// common English endings are stripped before giving up on a word instead.
+pub struct Dictionary {
+    words: HashSet<String>,
+}
+
+const SUFFIXES: [&str; 8] = ["'s", "s", "es", "ed", "d", "ing", "ly", "er"];
+
+impl Dictionary {
+    /// None when spell-check is off or no dictionary for the language is installed
+    pub fn load(config: &SpellcheckConfig) -> Option<Dictionary> {
+        if !config.enabled {
+            return None;
+        }
+        let content = candidates(config)
+            .into_iter()
+            .find_map(|path| fs::read_to_string(path).ok())?;
+        Some(Dictionary::from_dic(&content))
+    }
+
+    /// Parse .dic contents: an optional count on the first line, then "word/FLAGS" per line
+    pub fn from_dic(content: &str) -> Dictionary {
+        let words = content
+            .lines()
+            .filter_map(|line| line.split('/').next())
+            .map(|word| word.trim().to_lowercase())
+            .filter(|word| !word.is_empty() && !word.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        Dictionary { words }
+    }
+
+    /// Company names and roles I've already typed are never typos
+    pub fn learn_from(&mut self, jobs: &[Job]) {
+        for text in jobs.iter().flat_map(|job| [&job.company, &job.role]) {
+            for range in words(text) {
+                self.words.insert(text[range].to_lowercase());
+            }
+        }
+    }
+
+    pub fn is_correct(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        if self.words.contains(&word) {
+            return true;
+        }
+        SUFFIXES.iter().any(|suffix| {
+            word.strip_suffix(suffix)
+                .is_some_and(|stem| stem.len() > 2 && (self.words.contains(stem) || self.words.contains(&format!("{}e", stem))))
+        })
+    }
+
+    /// Byte ranges of words in `text` that aren't in the dictionary
+    pub fn misspelled(&self, text: &str) -> Vec<Range<usize>> {
+        words(text)
+            .filter(|range| should_check(text, range))
+            .filter(|range| !self.is_correct(&text[range.clone()]))
+            .collect()
+    }
+}
+
+/// Ranges of each run of letters and apostrophes
+fn words(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut start = None;
+    text.char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+        .filter_map(move |(i, c)| {
+            let in_word = c.is_alphabetic() || (c == '\'' && start.is_some());
+            match (in_word, start) {
+                (true, None) => {
+                    start = Some(i);
+                    None
+                }
+                (false, Some(s)) => {
+                    start = None;
+                    Some(s..i)
+                }
+                _ => None,
+            }
+        })
+        .map(move |range| {
+            // Trailing apostrophe as in "companies'"
+            let end = if text[range.clone()].ends_with('\'') { range.end - 1 } else { range.end };
+            range.start..end
+        })
+}
+
+/// Skip the things a dictionary can't know: short words, ACRONYMS and
+/// words glued to digits or dots like "k8s" or "example.com"
+fn should_check(text: &str, range: &Range<usize>) -> bool {
+    let word = &text[range.clone()];
+    let glued = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit() || matches!(c, '.' | '/' | '@' | '_' | ':'));
+    let before = text[..range.start].chars().next_back();
+    let after = text[range.end..].chars().next();
+    // A sentence-ending period is fine, "example.com" isn't
+    let after_glued = glued(after) && !(after == Some('.') && text[range.end + 1..].chars().next().is_none_or(char::is_whitespace));
+
+    word.chars().count() > 2 && !word.chars().all(|c| c.is_uppercase()) && !glued(before) && !after_glued
+}
+
+fn candidates(config: &SpellcheckConfig) -> Vec<PathBuf> {
+    if let Some(path) = &config.dictionary {
+        return vec![path.clone()];
+    }
+    let file = format!("{}.dic", config.language);
+    let mut paths = Vec::new();
+    if let Ok(dir) = get_data_dir() {
+        paths.push(dir.join("dictionaries").join(&file));
+    }
+    paths.push(PathBuf::from("/usr/share/hunspell").join(&file));
+    paths.push(PathBuf::from("/usr/share/myspell").join(&file));
+    if config.language.starts_with("en") {
+        paths.push(PathBuf::from("/usr/share/dict/words"));
+    }
+    paths
+}
@@ -0,0 +1,128 @@
+//! My own pipeline steps in place of the built-in statuses. Each one counts
+//! as one of the built-ins, so stats, cool-downs and offers keep working,
+//! and the list order is the order Enter moves a job forward through.
+//! Built-in names can be listed too, to recolor them or put them in the
+//! cycle. Entries that close a job, like a "Declined" that counts as
+//! Rejected, are reached with '-' ahead of the built-in ones.
+//!
+//! ```toml
+//! [[statuses]]
+//! name = "Applied"
+//!
+//! [[statuses]]
+//! name = "Phone Screen"
+//! counts_as = "Interviewing"
+//! color = "cyan"             # a color name, "#5fafd7" or a 256-palette index
+//!
+//! [[statuses]]
+//! name = "Onsite"
+//! counts_as = "Interviewing"
+//!
+//! [[statuses]]
+//! name = "Negotiating"
+//! counts_as = "Offer"
+//! ```
+//!
+//! Jobs store the built-in status plus the custom name, so taking a status
+//! out of the config just shows those jobs under their built-in one again.
+
+use crate::models::{Job, Status};
+use anyhow::{bail, Context, Result};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StatusDef {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counts_as: Option<Status>, // Optional for built-in names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+impl StatusDef {
+    /// The built-in status this one stands for
+    pub fn category(&self) -> Option<Status> {
+        self.counts_as.clone().or_else(|| Status::parse(&self.name))
+    }
+
+    /// None when no color is set, or it doesn't parse (`validate` catches that)
+    pub fn color(&self) -> Option<Color> {
+        Color::from_str(self.color.as_deref()?).ok()
+    }
+}
+
+/// Checked once on startup so a typo in config.toml doesn't turn into a
+/// status nobody can reach
+pub fn validate(defs: &[StatusDef]) -> Result<()> {
+    for (i, def) in defs.iter().enumerate() {
+        if def.name.trim().is_empty() {
+            bail!("Custom status {} has no name", i + 1);
+        }
+        if def.category().is_none() {
+            bail!("Custom status '{}' needs counts_as, one of Applied, Interviewing, Offer, Accepted, Rejected, Ghosted or Withdrawn", def.name);
+        }
+        if let Some(color) = &def.color {
+            Color::from_str(color).ok().with_context(|| format!("Unknown color '{}' for status '{}'", color, def.name))?;
+        }
+        if defs[..i].iter().any(|other| other.name.eq_ignore_ascii_case(&def.name)) {
+            bail!("Status '{}' is defined twice", def.name);
+        }
+    }
+    Ok(())
+}
+
+pub fn find<'a>(defs: &'a [StatusDef], name: &str) -> Option<&'a StatusDef> {
+    defs.iter().find(|def| def.name.eq_ignore_ascii_case(name.trim()))
+}
+
+/// The definition the job's status comes from, if there is one
+pub fn current<'a>(defs: &'a [StatusDef], job: &Job) -> Option<&'a StatusDef> {
+    find(defs, &job.status_label()).filter(|def| def.category().as_ref() == Some(&job.status))
+}
+
+/// Where Enter takes the job: the next entry that moves it forward, never
+/// one that closes it. Jobs on a status the list doesn't have yet (the
+/// config changed, or bulk edit set a built-in) pick up after the first
+/// entry that counts as the same thing. None past the end of the list, and
+/// for terminal statuses, which the built-in cycle handles.
+pub fn next<'a>(defs: &'a [StatusDef], job: &Job) -> Option<&'a StatusDef> {
+    if job.status.is_terminal() {
+        return None;
+    }
+    let position = match current(defs, job) {
+        Some(def) => defs.iter().position(|d| std::ptr::eq(d, def)),
+        None => defs.iter().position(|d| d.category().as_ref() == Some(&job.status)),
+    };
+    let after = position.map_or(0, |i| i + 1);
+    defs[after..].iter().find(|d| d.category().is_some_and(|status| !status.is_dropped()))
+}
+
+/// What '-' does: the entries that close a job in list order, then the
+/// built-in Rejected, Ghosted and Withdrawn the list doesn't name, then
+/// back to Applied. Open jobs start at the first of them.
+pub fn close(defs: &[StatusDef], job: &mut Job) {
+    let closing: Vec<&StatusDef> = defs.iter().filter(|def| def.category().is_some_and(|status| status.is_dropped())).collect();
+    let builtins: Vec<Status> = [Status::Rejected, Status::Ghosted, Status::Withdrawn]
+        .into_iter()
+        .filter(|status| !closing.iter().any(|def| Status::parse(&def.name).as_ref() == Some(status)))
+        .collect();
+    let at = match current(defs, job).and_then(|def| closing.iter().position(|d| std::ptr::eq(*d, def))) {
+        Some(i) => i + 1,
+        None if job.status.is_dropped() => closing.len() + builtins.iter().position(|s| *s == job.status).map_or(0, |i| i + 1),
+        None => 0,
+    };
+    match (closing.get(at), at.checked_sub(closing.len()).and_then(|i| builtins.get(i))) {
+        (Some(def), _) => apply(job, def),
+        (None, Some(status)) => job.set_status(status.clone()),
+        (None, None) => job.set_status(Status::Applied),
+    }
+}
+
+/// Move the job to `def`, with the same bookkeeping as a built-in change
+pub fn apply(job: &mut Job, def: &StatusDef) {
+    if let Some(category) = def.category() {
+        job.set_custom_status(&def.name, category);
+    }
+}
@@ -1,12 +1,27 @@
-use crate::models::Job;
-use anyhow::{Context, Result};
+use crate::companies::{self, Company};
+use crate::config::{self, load_config, Config, StorageLayout};
+use crate::usage::{self, UsageEvent};
+use crate::inbox;
+use crate::journal::{self, JournalEntry, Source, BOOKKEEPING};
+use crate::models::{fix_duplicate_ids, Job, JobId};
+use crate::network::{self, Person};
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use serde_json::{Map, Value};
 use directories::UserDirs;
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 /// Helper to determine where to store the file safely
 /// Mac/Linux: ~/Documents/career-cli/jobs.json
 fn get_db_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("jobs.json"))
+}
+
+/// Mac/Linux: ~/Documents/career-cli
+pub fn get_data_dir() -> Result<PathBuf> {
     let user_dirs = UserDirs::new()
         .context("Could not determine home directory")?;
     let documents_dir = user_dirs
@@ -20,33 +35,613 @@ fn get_db_path() -> Result<PathBuf> {
             .context("Failed to create data directory")?;
     }
 
-    Ok(data_dir.join("jobs.json"))
+    Ok(data_dir)
+}
+
+/// Where and how the job list is persisted
+pub trait Storage {
+    fn load(&self) -> Result<Vec<Job>>;
+    fn save(&self, jobs: &[Job]) -> Result<()>;
+
+    /// Called after a successful save with the previous and new contents
+    fn record_changes(&self, _before: &[Job], _after: &[Job], _source: Source) -> Result<()> {
+        Ok(())
+    }
+
+    /// Everything `record_changes` wrote, oldest first
+    fn journal(&self) -> Result<Vec<JournalEntry>> {
+        Ok(Vec::new())
+    }
+
+    /// Imported jobs waiting for review, see inbox.rs
+    fn inbox(&self) -> Result<Vec<Job>> {
+        Ok(Vec::new())
+    }
+
+    fn save_inbox(&self, _pending: &[Job]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Company records the jobs refer to, see companies.rs
+    fn companies(&self) -> Result<Vec<Company>> {
+        Ok(Vec::new())
+    }
+
+    fn save_companies(&self, _companies: &[Company]) -> Result<()> {
+        Ok(())
+    }
+
+    /// People I know, see network.rs
+    fn network(&self) -> Result<Vec<Person>> {
+        Ok(Vec::new())
+    }
+
+    fn save_network(&self, _people: &[Person]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Write config.toml after a change on the settings screen, see settings.rs
+    fn save_config(&self, _config: &Config) -> Result<()> {
+        Ok(())
+    }
+
+    /// How I've used the tool, see usage.rs
+    fn usage(&self) -> Result<Vec<UsageEvent>> {
+        Ok(Vec::new())
+    }
+
+    fn record_usage(&self, _events: &[UsageEvent]) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Keeps everything in memory. For tests, and for tools embedding the tracker
+/// that handle persistence themselves. Clones share the same jobs.
+#[derive(Clone, Default)]
+pub struct MemoryStorage {
+    jobs: Arc<Mutex<Vec<Job>>>,
+    inbox: Arc<Mutex<Vec<Job>>>,
+    companies: Arc<Mutex<Vec<Company>>>,
+    network: Arc<Mutex<Vec<Person>>>,
+    config: Arc<Mutex<Option<Config>>>,
+    usage: Arc<Mutex<Vec<UsageEvent>>>,
+}
+
+impl MemoryStorage {
+    pub fn new(jobs: Vec<Job>) -> Self {
+        Self {
+            jobs: Arc::new(Mutex::new(jobs)),
+            inbox: Arc::default(),
+            companies: Arc::default(),
+            network: Arc::default(),
+            config: Arc::default(),
+            usage: Arc::default(),
+        }
+    }
+
+    /// With imported jobs waiting for review
+    pub fn with_inbox(self, pending: Vec<Job>) -> Self {
+        *self.inbox.lock().unwrap() = pending;
+        self
+    }
+
+    pub fn inbox_jobs(&self) -> Vec<Job> {
+        self.inbox.lock().unwrap().clone()
+    }
+
+    /// With company records already on file
+    pub fn with_companies(self, companies: Vec<Company>) -> Self {
+        *self.companies.lock().unwrap() = companies;
+        self
+    }
+
+    pub fn company_records(&self) -> Vec<Company> {
+        self.companies.lock().unwrap().clone()
+    }
+
+    /// With people already in the network
+    pub fn with_network(self, people: Vec<Person>) -> Self {
+        *self.network.lock().unwrap() = people;
+        self
+    }
+
+    pub fn people(&self) -> Vec<Person> {
+        self.network.lock().unwrap().clone()
+    }
+
+    /// The config last written from the settings screen, if any
+    pub fn saved_config(&self) -> Option<Config> {
+        self.config.lock().unwrap().clone()
+    }
+
+    pub fn usage_events(&self) -> Vec<UsageEvent> {
+        self.usage.lock().unwrap().clone()
+    }
+
+    /// What's currently "on disk"
+    pub fn jobs(&self) -> Vec<Job> {
+        self.jobs.lock().unwrap().clone()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn load(&self) -> Result<Vec<Job>> {
+        Ok(self.jobs())
+    }
+
+    fn save(&self, jobs: &[Job]) -> Result<()> {
+        *self.jobs.lock().unwrap() = jobs.to_vec();
+        Ok(())
+    }
+
+    fn inbox(&self) -> Result<Vec<Job>> {
+        Ok(self.inbox_jobs())
+    }
+
+    fn save_inbox(&self, pending: &[Job]) -> Result<()> {
+        *self.inbox.lock().unwrap() = pending.to_vec();
+        Ok(())
+    }
+
+    fn companies(&self) -> Result<Vec<Company>> {
+        Ok(self.company_records())
+    }
+
+    fn save_companies(&self, companies: &[Company]) -> Result<()> {
+        *self.companies.lock().unwrap() = companies.to_vec();
+        Ok(())
+    }
+
+    fn network(&self) -> Result<Vec<Person>> {
+        Ok(self.people())
+    }
+
+    fn save_network(&self, people: &[Person]) -> Result<()> {
+        *self.network.lock().unwrap() = people.to_vec();
+        Ok(())
+    }
+
+    fn save_config(&self, config: &Config) -> Result<()> {
+        *self.config.lock().unwrap() = Some(config.clone());
+        Ok(())
+    }
+
+    fn usage(&self) -> Result<Vec<UsageEvent>> {
+        Ok(self.usage_events())
+    }
+
+    fn record_usage(&self, events: &[UsageEvent]) -> Result<()> {
+        self.usage.lock().unwrap().extend_from_slice(events);
+        Ok(())
+    }
+}
+
+/// Everything in one jobs.json (the default)
+pub struct JsonFileStorage {
+    path: PathBuf,
+}
+
+impl JsonFileStorage {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Storage for JsonFileStorage {
+    fn load(&self) -> Result<Vec<Job>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path)
+            .context("Failed to read jobs.json")?;
+
+        serde_json::from_str(&content)
+            .context("Failed to parse JSON")
+    }
+
+    fn save(&self, jobs: &[Job]) -> Result<()> {
+        let json = serde_json::to_string_pretty(jobs)
+            .context("Failed to serialize jobs")?;
+
+        fs::write(&self.path, json)
+            .context("Failed to write to jobs.json")?;
+
+        Ok(())
+    }
+
+    fn record_changes(&self, before: &[Job], after: &[Job], source: Source) -> Result<()> {
+        journal::record(before, after, source)
+    }
+
+    fn journal(&self) -> Result<Vec<JournalEntry>> {
+        journal::read_journal()
+    }
+
+    fn inbox(&self) -> Result<Vec<Job>> {
+        inbox::load_inbox()
+    }
+
+    fn save_inbox(&self, pending: &[Job]) -> Result<()> {
+        inbox::save_inbox(pending)
+    }
+
+    fn companies(&self) -> Result<Vec<Company>> {
+        companies::load_companies()
+    }
+
+    fn save_companies(&self, records: &[Company]) -> Result<()> {
+        companies::save_companies(records)
+    }
+
+    fn network(&self) -> Result<Vec<Person>> {
+        network::load_network()
+    }
+
+    fn save_network(&self, people: &[Person]) -> Result<()> {
+        network::save_network(people)
+    }
+
+    fn save_config(&self, config: &Config) -> Result<()> {
+        config::save_config(config)
+    }
+
+    fn usage(&self) -> Result<Vec<UsageEvent>> {
+        usage::read_usage()
+    }
+
+    fn record_usage(&self, events: &[UsageEvent]) -> Result<()> {
+        usage::append_usage(events)
+    }
+}
+
+/// One file per job in jobs/ plus jobs/index.json for the list order.
+/// Keeps git diffs small and makes editing a single job by hand safe.
+pub struct JobDirStorage {
+    dir: PathBuf,
+}
+
+impl JobDirStorage {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+const INDEX_FILE: &str = "index.json";
+
+impl Storage for JobDirStorage {
+    fn load(&self) -> Result<Vec<Job>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let index_path = self.dir.join(INDEX_FILE);
+        let mut names: Vec<String> = if index_path.exists() {
+            let content = fs::read_to_string(&index_path)
+                .context("Failed to read jobs/index.json")?;
+            serde_json::from_str(&content).context("Failed to parse jobs/index.json")?
+        } else {
+            Vec::new()
+        };
+
+        // Files added by hand that aren't in the index yet go at the end
+        let listed: HashSet<&String> = names.iter().collect();
+        let mut unlisted: Vec<String> = json_files(&self.dir)?
+            .into_iter()
+            .filter(|name| name != INDEX_FILE && !listed.contains(name))
+            .collect();
+        unlisted.sort();
+        names.extend(unlisted);
+
+        let mut jobs = Vec::new();
+        for name in names {
+            let path = self.dir.join(&name);
+            if !path.exists() {
+                continue;
+            }
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read jobs/{}", name))?;
+            let job: Job = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse jobs/{}", name))?;
+            jobs.push(job);
+        }
+        Ok(jobs)
+    }
+
+    fn save(&self, jobs: &[Job]) -> Result<()> {
+        fs::create_dir_all(&self.dir).context("Failed to create jobs directory")?;
+
+        // The whole id, since two jobs can share the eight digits the list shows
+        let mut names = Vec::new();
+        for job in jobs {
+            let name = format!("{}.json", job.id);
+            let path = self.dir.join(&name);
+            let json = serde_json::to_string_pretty(job).context("Failed to serialize job")?;
+
+            // Only touch files that changed so mtimes and editors stay calm
+            if fs::read_to_string(&path).ok().as_deref() != Some(json.as_str()) {
+                fs::write(&path, json).with_context(|| format!("Failed to write jobs/{}", name))?;
+            }
+            names.push(name);
+        }
+
+        let kept: HashSet<&String> = names.iter().collect();
+        for name in json_files(&self.dir)? {
+            if name != INDEX_FILE && !kept.contains(&name) {
+                fs::remove_file(self.dir.join(&name))
+                    .with_context(|| format!("Failed to remove jobs/{}", name))?;
+            }
+        }
+
+        let index = serde_json::to_string_pretty(&names).context("Failed to serialize index")?;
+        fs::write(self.dir.join(INDEX_FILE), index).context("Failed to write jobs/index.json")?;
+        Ok(())
+    }
+
+    fn record_changes(&self, before: &[Job], after: &[Job], source: Source) -> Result<()> {
+        journal::record(before, after, source)
+    }
+
+    fn journal(&self) -> Result<Vec<JournalEntry>> {
+        journal::read_journal()
+    }
+
+    fn inbox(&self) -> Result<Vec<Job>> {
+        inbox::load_inbox()
+    }
+
+    fn save_inbox(&self, pending: &[Job]) -> Result<()> {
+        inbox::save_inbox(pending)
+    }
+
+    fn companies(&self) -> Result<Vec<Company>> {
+        companies::load_companies()
+    }
+
+    fn save_companies(&self, records: &[Company]) -> Result<()> {
+        companies::save_companies(records)
+    }
+
+    fn network(&self) -> Result<Vec<Person>> {
+        network::load_network()
+    }
+
+    fn save_network(&self, people: &[Person]) -> Result<()> {
+        network::save_network(people)
+    }
+
+    fn save_config(&self, config: &Config) -> Result<()> {
+        config::save_config(config)
+    }
+
+    fn usage(&self) -> Result<Vec<UsageEvent>> {
+        usage::read_usage()
+    }
+
+    fn record_usage(&self, events: &[UsageEvent]) -> Result<()> {
+        usage::append_usage(events)
+    }
+}
+
+fn json_files(dir: &Path) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) == Some("json")
+            && let Some(name) = path.file_name()
+        {
+            names.push(name.to_string_lossy().to_string());
+        }
+    }
+    Ok(names)
+}
+
+pub fn storage_for(layout: StorageLayout) -> Result<Box<dyn Storage>> {
+    Ok(match layout {
+        StorageLayout::File => Box::new(JsonFileStorage::new(get_db_path()?)),
+        StorageLayout::Dir => Box::new(JobDirStorage::new(get_data_dir()?.join("jobs"))),
+    })
+}
+
+/// The storage picked in config.toml
+pub fn configured_storage() -> Result<Box<dyn Storage>> {
+    storage_for(load_config()?.storage.layout)
 }
 
 pub fn load_jobs() -> Result<Vec<Job>> {
-    let db_path = get_db_path()?;
+    load_jobs_from(configured_storage()?.as_ref())
+}
+
+pub fn load_jobs_from(storage: &dyn Storage) -> Result<Vec<Job>> {
+    let mut jobs = storage.load()?;
+    fix_duplicate_ids(&mut jobs);
+    Ok(jobs)
+}
+
+/// Save `jobs`, which were edited starting from `base` (what load_jobs returned).
+/// Changes written by someone else since then are merged in rather than
+/// overwritten, see `merge`. Returns the list that ended up on disk.
+pub fn save_jobs(base: &[Job], jobs: &[Job], source: Source) -> Result<Vec<Job>> {
+    save_jobs_to(configured_storage()?.as_ref(), base, jobs, source)
+}
+
+pub fn save_jobs_to(storage: &dyn Storage, base: &[Job], jobs: &[Job], source: Source) -> Result<Vec<Job>> {
+    // A corrupt file just means everything shows up as created
+    let on_disk = load_jobs_from(storage).unwrap_or_default();
 
-    if !db_path.exists() {
+    let merged = merge(base, jobs, &on_disk)?;
+    storage.save(&merged)?;
+    storage.record_changes(&on_disk, &merged, source)?;
+    Ok(merged)
+}
+
+/// Three-way merge of my edits (`base` -> `ours`) with whatever is on disk now.
+///
+/// Every job carries a revision that goes up each time it's saved with changes.
+/// If the disk copy still has the revision I started from, my version wins
+/// outright. If someone else saved it in between, their version is the starting
+/// point and only the fields I touched are applied on top; touching the same
+/// field as them is a conflict and nothing gets written. Edits win over deletes.
+/// Whatever gets written with a change has its `updated_at` set to now, and
+/// new jobs their `created_at` too.
+pub fn merge(base: &[Job], ours: &[Job], disk: &[Job]) -> Result<Vec<Job>> {
+    let now = Utc::now();
+    let find = |jobs: &[Job], id: JobId| jobs.iter().find(|j| j.id == id).cloned();
+    let mut merged: Vec<Job> = Vec::new();
+    let mut conflicts: Vec<String> = Vec::new();
+
+    for job in ours {
+        let base_job = find(base, job.id);
+        let disk_job = find(disk, job.id);
+
+        let Some(base_job) = base_job else {
+            // New on my side. If another writer also has this id, mine gets a fresh one.
+            let mut job = job.clone();
+            if disk_job.is_some() || merged.iter().any(|j| j.id == job.id) {
+                job.id = JobId::new();
+            }
+            job.revision = 1;
+            job.created_at.get_or_insert(now);
+            job.updated_at = Some(now);
+            merged.push(job);
+            continue;
+        };
+
+        let mine = fields(job)?;
+        let started_from = fields(&base_job)?;
+        if mine == started_from {
+            // Untouched here, take whatever is newest (gone if they deleted it)
+            merged.extend(disk_job);
+            continue;
+        }
+
+        match disk_job {
+            Some(disk_job) if disk_job.revision != base_job.revision => {
+                let mut theirs = fields(&disk_job)?;
+                for (field, value) in &mine {
+                    let original = started_from.get(field);
+                    if original == Some(value) {
+                        continue;
+                    }
+                    let current = theirs.get(field);
+                    if current == original || current == Some(value) {
+                        theirs.insert(field.clone(), value.clone());
+                    } else {
+                        conflicts.push(format!("{} - {}: '{}'", job.company, job.role, field));
+                    }
+                }
+                let mut job: Job = serde_json::from_value(Value::Object(theirs))
+                    .context("Failed to merge job")?;
+                job.revision = disk_job.revision + 1;
+                job.updated_at = Some(now);
+                merged.push(job);
+            }
+            _ => {
+                let mut job = job.clone();
+                job.revision = base_job.revision + 1;
+                job.updated_at = Some(now);
+                merged.push(job);
+            }
+        }
+    }
+
+    for disk_job in disk {
+        if merged.iter().any(|j| j.id == disk_job.id) {
+            continue;
+        }
+        match find(base, disk_job.id) {
+            // Created elsewhere
+            None => merged.push(disk_job.clone()),
+            // I deleted it, but someone else edited it since
+            Some(base_job) if disk_job.revision != base_job.revision => merged.push(disk_job.clone()),
+            Some(_) => {}
+        }
+    }
+
+    if !conflicts.is_empty() {
+        bail!(
+            "These fields were also changed by another session, nothing was saved:\n  {}",
+            conflicts.join("\n  ")
+        );
+    }
+    Ok(merged)
+}
+
+/// Names of the fields that differ between two versions of a job, as they
+/// appear in the journal
+pub fn changed_fields(before: &Job, after: &Job) -> Result<Vec<String>> {
+    let (before, after) = (fields(before)?, fields(after)?);
+    let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+    names.sort();
+    names.dedup();
+    Ok(names.into_iter().filter(|name| before.get(*name) != after.get(*name)).cloned().collect())
+}
+
+/// A job's fields as JSON, minus the ones saving keeps up by itself
+fn fields(job: &Job) -> Result<Map<String, Value>> {
+    match serde_json::to_value(job).context("Failed to serialize job")? {
+        Value::Object(mut map) => {
+            for field in BOOKKEEPING {
+                map.remove(*field);
+            }
+            Ok(map)
+        }
+        _ => bail!("Job did not serialize to an object"),
+    }
+}
+
+/// Old searches moved into archive/, one file per cohort (e.g. archive/2023.json).
+/// Each file is a JSON array of jobs, the same shape as jobs.json.
+/// Returned as (cohort name, jobs), sorted by name.
+pub fn load_archived_cohorts() -> Result<Vec<(String, Vec<Job>)>> {
+    let archive_dir = get_archive_dir()?;
+    if !archive_dir.exists() {
         return Ok(Vec::new());
     }
 
-    let content = fs::read_to_string(db_path)
-        .context("Failed to read jobs.json")?;
-    
-    let jobs: Vec<Job> = serde_json::from_str(&content)
-        .context("Failed to parse JSON")?;
+    let mut cohorts = Vec::new();
+    for entry in fs::read_dir(&archive_dir).context("Failed to read archive directory")? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let jobs: Vec<Job> = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        cohorts.push((name, jobs));
+    }
 
-    Ok(jobs)
+    cohorts.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(cohorts)
 }
 
-pub fn save_jobs(jobs: &[Job]) -> Result<()> {
-    let db_path = get_db_path()?;
+/// Add `jobs` to archive/<cohort>.json, replacing any with the same ID already there
+pub fn archive_cohort(cohort: &str, jobs: &[Job]) -> Result<()> {
+    if cohort.is_empty() || cohort.contains(['/', '\\']) || cohort.starts_with('.') {
+        bail!("A cohort name like 2025 or spring-2026, used as the file name");
+    }
+    let archive_dir = get_archive_dir()?;
+    fs::create_dir_all(&archive_dir).context("Failed to create archive directory")?;
+    let path = archive_dir.join(format!("{}.json", cohort));
 
-    let json = serde_json::to_string_pretty(jobs)
-        .context("Failed to serialize jobs")?;
-    
-    fs::write(db_path, json)
-        .context("Failed to write to jobs.json")?;
+    let mut archived: Vec<Job> = match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    archived.retain(|old| jobs.iter().all(|job| job.id != old.id));
+    archived.extend(jobs.iter().cloned());
+
+    let json = serde_json::to_string_pretty(&archived).context("Failed to serialize archive")?;
+    fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+}
 
-    Ok(())
+fn get_archive_dir() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("archive"))
 }
@@ -0,0 +1,122 @@
+//! A small multi-line text buffer for the notes editor. The cursor is a byte
+//! offset into the text and always sits on a char boundary.
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextArea {
+    pub text: String,
+    pub cursor: usize,
+}
+
+impl TextArea {
+    /// Cursor at the end, where I usually pick up writing
+    pub fn new(text: &str) -> TextArea {
+        TextArea { text: text.to_string(), cursor: text.len() }
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.text.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    pub fn backspace(&mut self) {
+        if let Some(c) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+            self.text.remove(self.cursor);
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.cursor < self.text.len() {
+            self.text.remove(self.cursor);
+        }
+    }
+
+    pub fn left(&mut self) {
+        if let Some(c) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+        }
+    }
+
+    pub fn right(&mut self) {
+        if let Some(c) = self.text[self.cursor..].chars().next() {
+            self.cursor += c.len_utf8();
+        }
+    }
+
+    pub fn home(&mut self) {
+        self.cursor = self.line_start(self.cursor);
+    }
+
+    pub fn end(&mut self) {
+        self.cursor = self.line_end(self.cursor);
+    }
+
+    pub fn up(&mut self) {
+        let start = self.line_start(self.cursor);
+        if start > 0 {
+            let column = self.text[start..self.cursor].chars().count();
+            self.cursor = self.line_start(start - 1);
+            self.move_to_column(column);
+        }
+    }
+
+    pub fn down(&mut self) {
+        let end = self.line_end(self.cursor);
+        if end < self.text.len() {
+            let column = self.text[self.line_start(self.cursor)..self.cursor].chars().count();
+            self.cursor = end + 1;
+            self.move_to_column(column);
+        }
+    }
+
+    /// Rows word-wrapped to `width` chars, and the cursor's (row, column) in them
+    pub fn wrap(&self, width: usize) -> (Vec<String>, (usize, usize)) {
+        let width = width.max(1);
+        let mut rows = Vec::new();
+        let mut cursor = (0, 0);
+        let mut offset = 0;
+        for line in self.text.split('\n') {
+            let chars: Vec<(usize, char)> = line.char_indices().collect();
+            let byte_at = |i: usize| offset + chars.get(i).map_or(line.len(), |(b, _)| *b);
+            let mut start = 0;
+            loop {
+                let mut end = (start + width).min(chars.len());
+                // Break after the last space that fits, unless the word is wider than the row
+                if end < chars.len()
+                    && let Some(space) = (start..end).rev().find(|&i| chars[i].1 == ' ')
+                {
+                    end = space + 1;
+                }
+                let last = end >= chars.len();
+                let (from, to) = (byte_at(start), byte_at(end));
+                if self.cursor >= from && (self.cursor < to || (last && self.cursor == to)) {
+                    cursor = (rows.len(), self.text[from..self.cursor].chars().count());
+                }
+                rows.push(chars[start..end].iter().map(|(_, c)| c).collect());
+                if last {
+                    break;
+                }
+                start = end;
+            }
+            offset += line.len() + 1;
+        }
+        (rows, cursor)
+    }
+
+    fn line_start(&self, at: usize) -> usize {
+        self.text[..at].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self, at: usize) -> usize {
+        self.text[at..].find('\n').map_or(self.text.len(), |i| at + i)
+    }
+
+    /// Within the cursor's line, clamped to its length
+    fn move_to_column(&mut self, column: usize) {
+        let end = self.line_end(self.cursor);
+        self.cursor = self.text[self.cursor..end]
+            .char_indices()
+            .nth(column)
+            .map_or(end, |(i, _)| self.cursor + i);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::config::ThemeConfig;
+use crate::models::Status;
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+
+/// How many colors the terminal can show
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    #[serde(rename = "16")]
+    Basic,
+    #[serde(rename = "256")]
+    Ansi256,
+    #[serde(alias = "24bit")]
+    TrueColor,
+}
+
+impl ColorMode {
+    /// Resolve `Auto` from the environment. Most terminals set COLORTERM when they
+    /// do 24-bit color, and TERM ends in -256color for the 256 palette. Anything
+    /// else (linux console, old SSH setups) gets the basic 16.
+    pub fn resolve(self) -> Self {
+        if self != ColorMode::Auto {
+            return self;
+        }
+        let colorterm = env::var("COLORTERM").unwrap_or_default().to_lowercase();
+        let term = env::var("TERM").unwrap_or_default().to_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorMode::TrueColor
+        } else if term.contains("256color") {
+            ColorMode::Ansi256
+        } else {
+            ColorMode::Basic
+        }
+    }
+}
+
+/// Which symbols to use for the one-cell status column
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum GlyphStyle {
+    #[default]
+    Off,
+    Auto,
+    Ascii,
+    Unicode,
+    Nerd,
+}
+
+impl GlyphStyle {
+    /// There's no way to ask the terminal for its font, so `Auto` only picks
+    /// nerd glyphs when NERD_FONT is set, and falls back to ASCII unless the
+    /// locale is UTF-8.
+    pub fn resolve(self) -> Self {
+        if self != GlyphStyle::Auto {
+            return self;
+        }
+        if env::var("NERD_FONT").is_ok_and(|v| !v.is_empty() && v != "0") {
+            return GlyphStyle::Nerd;
+        }
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .find_map(|name| env::var(name).ok().filter(|v| !v.is_empty()))
+            .unwrap_or_default()
+            .to_lowercase();
+        if locale.contains("utf-8") || locale.contains("utf8") {
+            GlyphStyle::Unicode
+        } else {
+            GlyphStyle::Ascii
+        }
+    }
+}
+
+pub struct Theme {
+    pub mode: ColorMode,
+    pub glyphs: GlyphStyle,
+    glyph_overrides: HashMap<String, char>,
+}
+
+impl Theme {
+    pub fn new(mode: ColorMode) -> Self {
+        Self { mode: mode.resolve(), glyphs: GlyphStyle::Off, glyph_overrides: HashMap::new() }
+    }
+
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        // Only the first character of an override is used, the column is one cell wide
+        let glyph_overrides = config
+            .status_glyphs
+            .iter()
+            .filter_map(|(status, glyph)| Some((status.to_lowercase(), glyph.chars().next()?)))
+            .collect();
+        Self {
+            glyphs: config.glyphs.resolve(),
+            glyph_overrides,
+            ..Theme::new(config.color_mode)
+        }
+    }
+
+    /// Symbol for the status column, None when the column is off
+    pub fn status_glyph(&self, status: &Status) -> Option<char> {
+        if self.glyphs == GlyphStyle::Off {
+            return None;
+        }
+        if let Some(glyph) = self.glyph_overrides.get(&format!("{:?}", status).to_lowercase()) {
+            return Some(*glyph);
+        }
+        Some(match (self.glyphs, status) {
+            (GlyphStyle::Nerd, Status::Applied) => '\u{f1d8}',      // nf-fa-paper_plane
+            (GlyphStyle::Nerd, Status::Interviewing) => '\u{f086}', // nf-fa-comments
+            (GlyphStyle::Nerd, Status::Offer) => '\u{f091}',        // nf-fa-trophy
+            (GlyphStyle::Nerd, Status::Accepted) => '\u{f00c}',     // nf-fa-check
+            (GlyphStyle::Nerd, Status::Rejected) => '\u{f057}',     // nf-fa-times_circle
+            (GlyphStyle::Nerd, Status::Ghosted) => '\u{f2ac}',      // nf-fa-snapchat_ghost
+            (GlyphStyle::Nerd, Status::Withdrawn) => '\u{f08b}',    // nf-fa-sign_out
+            (GlyphStyle::Unicode, Status::Applied) => '•',
+            (GlyphStyle::Unicode, Status::Interviewing) => '◆',
+            (GlyphStyle::Unicode, Status::Offer) => '★',
+            (GlyphStyle::Unicode, Status::Accepted) => '✓',
+            (GlyphStyle::Unicode, Status::Rejected) => '✗',
+            (GlyphStyle::Unicode, Status::Ghosted) => '○',
+            (GlyphStyle::Unicode, Status::Withdrawn) => '↩',
+            (_, Status::Applied) => '.',
+            (_, Status::Interviewing) => '~',
+            (_, Status::Offer) => '$',
+            (_, Status::Accepted) => '+',
+            (_, Status::Rejected) => 'x',
+            (_, Status::Ghosted) => '?',
+            (_, Status::Withdrawn) => '-',
+        })
+    }
+
+    /// The mark in front of a starred job's company, '*' for ASCII
+    pub fn star_glyph(&self) -> char {
+        match self.glyphs {
+            GlyphStyle::Ascii => '*',
+            GlyphStyle::Nerd => '\u{f005}', // nf-fa-star
+            GlyphStyle::Off | GlyphStyle::Auto | GlyphStyle::Unicode => '★',
+        }
+    }
+
+    pub fn status_color(&self, status: &Status) -> Color {
+        match self.mode {
+            // DarkGray is "bright black" in the 16 color palette and disappears on
+            // dark backgrounds, so ghosted gets magenta instead
+            ColorMode::Basic | ColorMode::Auto => match status {
+                Status::Applied => Color::White,
+                Status::Interviewing => Color::Yellow,
+                Status::Offer => Color::Green,
+                Status::Accepted => Color::Cyan,
+                Status::Rejected => Color::Red,
+                Status::Ghosted => Color::Magenta,
+                Status::Withdrawn => Color::Blue,
+            },
+            ColorMode::Ansi256 => match status {
+                Status::Applied => Color::Indexed(252),
+                Status::Interviewing => Color::Indexed(220),
+                Status::Offer => Color::Indexed(78),
+                Status::Accepted => Color::Indexed(80),
+                Status::Rejected => Color::Indexed(203),
+                Status::Ghosted => Color::Indexed(245),
+                Status::Withdrawn => Color::Indexed(146),
+            },
+            ColorMode::TrueColor => match status {
+                Status::Applied => Color::Rgb(220, 220, 220),
+                Status::Interviewing => Color::Rgb(240, 190, 60),
+                Status::Offer => Color::Rgb(90, 200, 120),
+                Status::Accepted => Color::Rgb(80, 200, 210),
+                Status::Rejected => Color::Rgb(235, 95, 90),
+                Status::Ghosted => Color::Rgb(140, 140, 150),
+                Status::Withdrawn => Color::Rgb(150, 160, 210),
+            },
+        }
+    }
+
+    /// Rows that need doing something about, like an overdue follow-up
+    pub fn warning(&self) -> Color {
+        match self.mode {
+            ColorMode::Basic | ColorMode::Auto => Color::LightRed,
+            ColorMode::Ansi256 => Color::Indexed(209),
+            ColorMode::TrueColor => Color::Rgb(255, 140, 80),
+        }
+    }
+
+    pub fn highlight(&self) -> Style {
+        let style = match self.mode {
+            ColorMode::Basic | ColorMode::Auto => Style::default().bg(Color::White).fg(Color::Black),
+            ColorMode::Ansi256 => Style::default().bg(Color::Indexed(238)).fg(Color::Indexed(255)),
+            ColorMode::TrueColor => Style::default().bg(Color::Rgb(60, 64, 80)).fg(Color::Rgb(250, 250, 250)),
+        };
+        style.add_modifier(Modifier::BOLD)
+    }
+}
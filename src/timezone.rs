@@ -0,0 +1,69 @@
+//! Where a company keeps its hours, so the detail pane can say whether
+//! it's their working day right now: worth knowing before sending a
+//! follow-up that would otherwise land at 3am. Set with 'G' in the TUI or
+//! `career-cli timezone <id> <zone>`.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Datelike, Days, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+use chrono_tz::{Tz, TZ_VARIANTS};
+
+/// Their working day, in their time
+pub const OPENS: u32 = 9;
+pub const CLOSES: u32 = 17;
+
+/// "Europe/Berlin", any case, or just the city: "berlin", "new york"
+pub fn parse(input: &str) -> Result<Tz> {
+    let input = input.trim();
+    let city = input.replace(' ', "_");
+    let found = TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(input))
+        .or_else(|| TZ_VARIANTS.iter().find(|tz| tz.name().rsplit('/').next().is_some_and(|c| c.eq_ignore_ascii_case(&city))));
+    match found {
+        Some(tz) => Ok(*tz),
+        None => bail!("Unknown timezone '{}', try Europe/Berlin, Berlin or UTC", input),
+    }
+}
+
+/// Whether it's their business hours at `now`, and when that changes
+pub enum Hours {
+    Open { closes: DateTime<Tz> },
+    Closed { opens: DateTime<Tz> },
+}
+
+pub fn hours(tz: Tz, now: DateTime<Utc>) -> Hours {
+    let local = now.with_timezone(&tz);
+    let weekday = |date: chrono::NaiveDate| !matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+    let at = |date: chrono::NaiveDate, hour: u32| {
+        let time = date.and_time(NaiveTime::from_hms_opt(hour, 0, 0).unwrap_or(NaiveTime::MIN));
+        tz.from_local_datetime(&time).earliest().unwrap_or(local)
+    };
+    let today = local.date_naive();
+    if weekday(today) && (OPENS..CLOSES).contains(&local.hour()) {
+        return Hours::Open { closes: at(today, CLOSES) };
+    }
+    let mut day = if local.hour() < OPENS { today } else { today + Days::new(1) };
+    while !weekday(day) {
+        day = day + Days::new(1);
+    }
+    Hours::Closed { opens: at(day, OPENS) }
+}
+
+/// "Tue 14:05 CET, business hours until 17:00" or "Sat 10:12 CET, outside
+/// business hours, they open Mon 09:00 (in 1d 22h)"
+pub fn describe(tz: Tz, now: DateTime<Utc>) -> String {
+    let local = now.with_timezone(&tz);
+    let clock = local.format("%a %H:%M %Z");
+    match hours(tz, now) {
+        Hours::Open { closes } => format!("{}, business hours until {}", clock, closes.format("%H:%M")),
+        Hours::Closed { opens } => {
+            let wait = opens.with_timezone(&Utc) - now;
+            let wait = match (wait.num_days(), wait.num_hours() % 24, wait.num_minutes() % 60) {
+                (0, 0, m) => format!("{}m", m),
+                (0, h, m) => format!("{}h {}m", h, m),
+                (d, h, _) => format!("{}d {}h", d, h),
+            };
+            format!("{}, outside business hours, they open {} (in {})", clock, opens.format("%a %H:%M"), wait)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Transcripts and recordings attached to interviews. Text transcripts
+//! (.txt, .md, .vtt, .srt) are read in when attached so they can be searched
+//! alongside the notes; for anything else only the path is kept.
+
+use crate::models::{Interview, Job};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Attachments read in as text, the rest are treated as recordings
+pub const TEXT_EXTENSIONS: [&str; 4] = ["txt", "md", "vtt", "srt"];
+
+/// Point the interview at `path`, reading in the text if it's a transcript
+pub fn attach(interview: &mut Interview, path: &Path) -> Result<()> {
+    let path = path.canonicalize().with_context(|| format!("Can't find {}", path.display()))?;
+    let is_text = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| TEXT_EXTENSIONS.contains(&e.to_lowercase().as_str()));
+    interview.transcript = if is_text {
+        clean(&fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?)
+    } else {
+        String::new()
+    };
+    interview.attachment = Some(path);
+    Ok(())
+}
+
+/// Subtitle files without their cue numbers and timings, just what was said
+pub fn clean(raw: &str) -> String {
+    raw.lines()
+        .map(str::trim)
+        .filter(|line| {
+            !line.is_empty()
+                && *line != "WEBVTT"
+                && !line.contains("-->")
+                && !line.chars().all(|c| c.is_ascii_digit())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A line that matched a search
+pub struct Hit<'a> {
+    pub job: &'a Job,
+    pub source: String, // "notes" or "Onsite transcript"
+    pub line: &'a str,
+}
+
+/// Lines in notes, postings and transcripts containing every word of `query`, ignoring case
+pub fn search<'a>(jobs: &'a [Job], query: &str) -> Vec<Hit<'a>> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+    let matches = |line: &str| {
+        let line = line.to_lowercase();
+        words.iter().all(|w| line.contains(w.as_str()))
+    };
+
+    let mut hits = Vec::new();
+    for job in jobs {
+        for line in job.notes.lines().filter(|l| matches(l)) {
+            hits.push(Hit { job, source: "notes".to_string(), line });
+        }
+        for line in job.description.lines().filter(|l| matches(l)) {
+            hits.push(Hit { job, source: "posting".to_string(), line });
+        }
+        for interview in &job.interviews {
+            for line in interview.transcript.lines().filter(|l| matches(l)) {
+                hits.push(Hit { job, source: format!("{} transcript", interview.name), line });
+            }
+        }
+    }
+    hits
+}
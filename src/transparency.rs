@@ -0,0 +1,145 @@
+//! Pay transparency: places where a posting has to include its pay range.
+//! Adding a job located in one asks what range the posting gave, and
+//! `career-cli transparency` reports the postings that gave none, by
+//! jurisdiction, for anyone who tracks that.
+
+use crate::models::Job;
+use crate::salary;
+use serde::{Deserialize, Serialize};
+
+/// What a posting in a pay-transparency jurisdiction said about pay
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PayDisclosure {
+    pub jurisdiction: String,
+    #[serde(default)]
+    pub range: Option<String>, // As posted, e.g. "$120k-150k". None when it gave none.
+}
+
+impl PayDisclosure {
+    /// "Colorado, posted $120k-150k" or "Colorado, no range posted"
+    pub fn label(&self) -> String {
+        match &self.range {
+            Some(range) => format!("{}, posted {}", self.jurisdiction, range),
+            None => format!("{}, no range posted", self.jurisdiction),
+        }
+    }
+}
+
+/// Jurisdictions with a range-in-the-posting law, and how a location in
+/// each is written: whole words, or its code after a comma
+const JURISDICTIONS: &[(&str, &[&str], &str)] = &[
+    ("California", &["california", "san francisco", "los angeles", "san diego", "san jose"], "ca"),
+    ("Colorado", &["colorado", "denver", "boulder"], "co"),
+    ("Washington", &["washington state", "seattle", "bellevue", "redmond"], "wa"),
+    ("New York", &["new york", "nyc", "brooklyn", "manhattan"], "ny"),
+    ("Illinois", &["illinois", "chicago"], "il"),
+    ("Maryland", &["maryland", "baltimore"], "md"),
+    ("Hawaii", &["hawaii", "honolulu"], "hi"),
+    ("Minnesota", &["minnesota", "minneapolis"], "mn"),
+    ("Massachusetts", &["massachusetts", "boston"], "ma"),
+    ("New Jersey", &["new jersey", "jersey city", "newark"], "nj"),
+    ("Vermont", &["vermont"], "vt"),
+    ("British Columbia", &["british columbia", "vancouver"], "bc"),
+];
+
+/// Every US state code (and DC), so "Newark, DE" reads as Delaware rather
+/// than the Newark in New Jersey
+const US_STATES: &[&str] = &[
+    "al", "ak", "az", "ar", "ca", "co", "ct", "de", "dc", "fl", "ga", "hi", "id", "il", "in", "ia", "ks", "ky", "la", "me", "md",
+    "ma", "mi", "mn", "ms", "mo", "mt", "ne", "nv", "nh", "nj", "nm", "ny", "nc", "nd", "oh", "ok", "or", "pa", "ri", "sc", "sd",
+    "tn", "tx", "ut", "vt", "va", "wa", "wv", "wi", "wy",
+];
+
+/// Codes of states with a law that are also country codes: "Toronto, CA"
+/// is Canada
+const COUNTRY_CODES: &[&str] = &["ca", "co", "il", "md", "mn", "ma"];
+
+/// The state (or province) code `location` gives after a comma, and whether
+/// it's sure to be one: followed by a ZIP code or the country, or last and
+/// not a country code too. "Denver, CO" could be Colombia.
+fn stated_code(location: &str) -> Option<(&str, bool)> {
+    let parts: Vec<&str> = location.split(',').map(str::trim).collect();
+    parts.iter().enumerate().skip(1).find_map(|(i, part)| {
+        let mut words = part.split_whitespace();
+        let code = words.next()?;
+        if !(US_STATES.contains(&code) || code == "bc") {
+            return None;
+        }
+        let zip = words.next().is_some_and(|zip| zip.len() == 5 && zip.chars().all(|c| c.is_ascii_digit()));
+        let in_us = parts[i + 1..].iter().any(|rest| matches!(*rest, "us" | "usa" | "united states"));
+        let last = i == parts.len() - 1;
+        (zip || in_us || last).then_some((code, zip || in_us || !COUNTRY_CODES.contains(&code)))
+    })
+}
+
+/// The pay-transparency jurisdiction `location` is in, if any:
+/// "Seattle, WA" and "Colorado (remote)" are both. A state code given
+/// outright wins over a city name, which several states share, and a city
+/// only counts with a code that could be a country when they agree.
+pub fn jurisdiction(location: &str) -> Option<&'static str> {
+    let location = location.to_lowercase();
+    let stated = stated_code(&location);
+    if let Some((code, true)) = stated {
+        return JURISDICTIONS.iter().find(|(.., c)| *c == code).map(|(name, ..)| *name);
+    }
+    let words: String = location.chars().map(|c| if c.is_alphanumeric() { c } else { ' ' }).collect();
+    let padded = format!(" {} ", words.split_whitespace().collect::<Vec<_>>().join(" "));
+    JURISDICTIONS
+        .iter()
+        .find(|(_, names, _)| names.iter().any(|name| padded.contains(&format!(" {} ", name))))
+        .filter(|(.., code)| stated.is_none_or(|(stated, _)| stated == *code))
+        .map(|(name, ..)| *name)
+}
+
+/// "Colorado $120k-150k" or "Colorado none", as typed at the add prompt:
+/// the jurisdiction, then the range when the posting gave one. None for an
+/// empty answer, Err for a range that doesn't read as pay.
+pub fn parse(input: &str) -> Option<Result<PayDisclosure, String>> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+    // Where ends at the first word that's pay, or "none"
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let split = words
+        .iter()
+        .position(|word| word.chars().any(|c| c.is_ascii_digit() || "$€£".contains(c)) || word.eq_ignore_ascii_case("none"))
+        .unwrap_or(words.len());
+    let (place, range) = words.split_at(split);
+    let jurisdiction = place.join(" ");
+    let range = range.join(" ");
+    if jurisdiction.is_empty() {
+        return Some(Err("Start with where, e.g. Colorado $120k-150k".to_string()));
+    }
+    let range = match range.as_str() {
+        "" => None,
+        none if none.eq_ignore_ascii_case("none") => None,
+        range => match salary::parse(range) {
+            Some((min, max, _)) if min.is_some() || max.is_some() => Some(range.to_string()),
+            _ => return Some(Err(format!("'{}' isn't a pay range, try $120k-150k or none", range))),
+        },
+    };
+    Some(Ok(PayDisclosure { jurisdiction, range }))
+}
+
+/// Per jurisdiction, the jobs recorded there and those whose posting gave
+/// no range, busiest first
+pub fn report(jobs: &[Job]) -> Vec<(String, Vec<&Job>, Vec<&Job>)> {
+    let mut rows: Vec<(String, Vec<&Job>, Vec<&Job>)> = Vec::new();
+    for job in jobs {
+        let Some(disclosure) = &job.pay_disclosure else { continue };
+        let at = match rows.iter().position(|(name, ..)| name.eq_ignore_ascii_case(&disclosure.jurisdiction)) {
+            Some(at) => at,
+            None => {
+                rows.push((disclosure.jurisdiction.clone(), Vec::new(), Vec::new()));
+                rows.len() - 1
+            }
+        };
+        rows[at].1.push(job);
+        if disclosure.range.is_none() {
+            rows[at].2.push(job);
+        }
+    }
+    rows.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+    rows
+}
@@ -0,0 +1,77 @@
+//! Getting to an onsite: where it is, what's booked to get there, and when
+//! to set off. Kept on the interview, shown in its briefing with a map
+//! link, and the day I travel stands out in the agenda. `career-cli agenda
+//! --notify` says when to be there by on the day.
+
+use crate::config::InterviewConfig;
+use crate::models::{parse_reply_by, Interview};
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct Travel {
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub bookings: Vec<String>, // "Flight BA117 ref X7KQ2P", "Hotel confirmation 48213"
+    #[serde(default)]
+    pub depart: Option<NaiveDate>, // When I set off, if it's before the day itself
+    #[serde(default)]
+    pub arrive_minutes: Option<u32>, // How early to be there, else [interviews] arrive_minutes
+}
+
+/// A maps search for `address`, which opens in the app on a phone
+pub fn map_link(address: &str) -> String {
+    let query: String = url::form_urlencoded::byte_serialize(address.trim().as_bytes()).collect();
+    format!("https://www.google.com/maps/search/?api=1&query={}", query)
+}
+
+/// The day I travel for `interview`: the day I set off, else the day itself
+pub fn travel_day(interview: &Interview) -> Option<NaiveDate> {
+    let travel = interview.travel.as_ref()?;
+    Some(travel.depart.filter(|d| *d < interview.date).unwrap_or(interview.date))
+}
+
+/// When to be at the door, the start less the arrival buffer. Can be the
+/// day before, for a 00:15 start
+pub fn arrive_by(interview: &Interview, config: &InterviewConfig) -> Option<NaiveDateTime> {
+    let minutes = interview.travel.as_ref()?.arrive_minutes.unwrap_or(config.arrive_minutes);
+    interview.date.and_time(interview.start?).checked_sub_signed(TimeDelta::minutes(minutes as i64))
+}
+
+/// "13:40", with the date too when that's not the day itself
+pub fn arrive_label(interview: &Interview, config: &InterviewConfig) -> Option<String> {
+    let by = arrive_by(interview, config)?;
+    let format = if by.date() == interview.date { "%H:%M" } else { "%Y-%m-%d %H:%M" };
+    Some(by.format(format).to_string())
+}
+
+/// Which of `interviews` `selector` means: one on that date ("2026-10-21",
+/// "thursday"), else one by stage name ("onsite"), the next such if there
+/// are a few
+pub fn find_interview(interviews: &[Interview], selector: &str, today: NaiveDate) -> Option<usize> {
+    if let Some(date) = parse_reply_by(selector, today) {
+        return interviews.iter().position(|interview| interview.date == date);
+    }
+    let named = |interview: &&Interview| interview.name.eq_ignore_ascii_case(selector.trim());
+    let upcoming = interviews.iter().filter(named).filter(|interview| interview.date >= today).min_by_key(|interview| interview.date);
+    let interview = upcoming.or_else(|| interviews.iter().filter(named).max_by_key(|interview| interview.date))?;
+    interviews.iter().position(|i| std::ptr::eq(i, interview))
+}
+
+/// The briefing's Travel section, empty for an interview with nothing to travel to
+pub fn lines(interview: &Interview, config: &InterviewConfig) -> Vec<String> {
+    let Some(travel) = &interview.travel else { return Vec::new() };
+    let mut lines = vec![String::new(), "## Travel".to_string()];
+    if !travel.address.is_empty() {
+        lines.push(format!("Address: {} ([map]({}))", travel.address, map_link(&travel.address)));
+    }
+    if let Some(depart) = travel.depart.filter(|d| *d < interview.date) {
+        lines.push(format!("Set off: {}", depart));
+    }
+    if let Some(by) = arrive_label(interview, config) {
+        lines.push(format!("Be there by {}", by));
+    }
+    lines.extend(travel.bookings.iter().map(|booking| format!("- {}", booking)));
+    lines
+}
@@ -0,0 +1,1584 @@
+use crate::app::{App, EditTarget, Focus, InputField, InputMode};
+use crate::config::SortOrder;
+use crate::metrics::FigureGroup;
+use crate::settings::Setting;
+use crate::spell::Dictionary;
+use crate::theme::GlyphStyle;
+use crate::{answers, asof, bulk, evergreen, fingerprint, metrics, models, network, offers, posting, reminders, runway, skills, statuses, timezone};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    widgets::{Block, Borders, Gauge, Paragraph, Wrap},
+};
+use ratatui::widgets::Clear;
+use ratatui::widgets::{List, ListItem, ListState};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+
+// Simple UI function to render a box
+pub fn ui(frame: &mut ratatui::Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(frame.size());
+
+    // --- NEW: STATS CALCULATION ---
+    let active_count = app.jobs.iter().filter(|j| j.pipeline() == models::Pipeline::Active).count();
+    let closed_count = app.jobs.len() - active_count;
+    let interview_count = app
+        .jobs
+        .iter()
+        .filter(|j| matches!(j.status, models::Status::Interviewing))
+        .count();
+    let offer_count = app
+        .jobs
+        .iter()
+        .filter(|j| matches!(j.status, models::Status::Offer))
+        .count();
+
+    // Create a dynamic title
+    let mut title_text = format!(
+        " Career Tracker | Active: {} | Closed: {} | Interviewing: {} | Offers: {} ",
+        active_count, closed_count, interview_count, offer_count
+    );
+    match app.pipeline {
+        Some(side) => title_text.push_str(&format!("| Showing {} ", side.label())),
+        None => title_text.push_str("| Showing all "),
+    }
+    if !app.inbox.is_empty() {
+        title_text.push_str(&format!("| {} to review ", app.inbox.len()));
+    }
+    let archived = app.jobs.iter().filter(|j| j.archived).count();
+    if archived > 0 {
+        let shown = if app.show_archived { "shown" } else { "hidden" };
+        title_text.push_str(&format!("| {} archived {} ", archived, shown));
+    }
+    match app.sort {
+        SortOrder::Added => {}
+        SortOrder::Priority => title_text.push_str("| Sorted by priority "),
+        SortOrder::Touched => title_text.push_str("| Sorted by last change "),
+        SortOrder::Fit => title_text.push_str("| Sorted by fit "),
+    }
+    if let Some(filter) = &app.filter {
+        title_text.push_str(&format!("| Filter: {} ({} shown) ", filter.as_str(), app.visible.len()));
+    }
+
+    // Detail pane on the right follows the selection
+    let (list_area, detail_area) = if app.detail_open {
+        let halves = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(chunks[0]);
+        (halves[0], Some(halves[1]))
+    } else {
+        (chunks[0], None)
+    };
+
+    // --- LIST RENDERING ---
+    // Only the rows that fit on screen get formatted, so a huge list costs the
+    // same per frame as a short one. We track the scroll offset ourselves.
+    // The glyph column takes a cell plus a space when it's on
+    let glyph_width = if app.theme.glyphs == GlyphStyle::Off { 0 } else { 2 };
+    // Short ids grow past four digits once four stop telling jobs apart
+    let id_len = models::short_id_len(&app.jobs);
+    let row_width = list_area.width.saturating_sub(2 + glyph_width + (models::id_width(id_len) - 4) as u16); // inside the borders
+    // Too narrow for four readable columns: two lines per job instead of truncating everything
+    let compact = is_compact(row_width);
+    let row_height = if compact { 2 } else { 1 };
+    let widths = column_widths(row_width);
+
+    let height = list_area.height.saturating_sub(2) as usize / row_height;
+    let selected = app.state.selected();
+    let mut offset = app.state.offset().min(app.visible.len().saturating_sub(height));
+    if let Some(selected) = selected {
+        if selected < offset {
+            offset = selected;
+        } else if height > 0 && selected >= offset + height {
+            offset = selected + 1 - height;
+        }
+    }
+    *app.state.offset_mut() = offset;
+
+    let today = chrono::Local::now().date_naive();
+    let items: Vec<ListItem> = app
+        .visible
+        .iter()
+        .skip(offset)
+        .take(height)
+        .map(|&i| {
+            let job = &app.jobs[i];
+            let color = statuses::current(&app.config.statuses, job).and_then(|def| def.color());
+            let color = if job.follow_up_due(today) || job.reply_overdue(today) { Some(app.theme.warning()) } else { color };
+            let mut style = Style::default().fg(color.unwrap_or_else(|| app.theme.status_color(&job.status)));
+            if job.draft {
+                style = style.add_modifier(Modifier::ITALIC);
+            }
+            // Top targets stand out, the long shots fade back
+            style = match job.priority {
+                models::Priority::High => style.add_modifier(Modifier::BOLD),
+                models::Priority::Medium => style,
+                models::Priority::Low => style.add_modifier(Modifier::DIM),
+            };
+            // Standing reqs fade back too, whatever their priority
+            let evergreen = evergreen::is_evergreen(job, &app.config.evergreen);
+            if evergreen {
+                style = style.add_modifier(Modifier::DIM);
+            }
+            let star = app.theme.star_glyph();
+            let glyph = match app.theme.status_glyph(&job.status) {
+                Some(glyph) => format!("{} ", glyph),
+                None => String::new(),
+            };
+            let content = if compact {
+                compact_row(job, &job.id_prefix(id_len), &glyph, star, evergreen, today, row_width as usize + glyph.chars().count())
+            } else {
+                Text::from(wide_row(job, &job.id_prefix(id_len), &glyph, star, evergreen, today, widths))
+            };
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title_text)) // Use new title
+        .highlight_style(app.theme.highlight())
+        .highlight_symbol(">> ");
+
+    let mut visible_state = ListState::default().with_selected(selected.map(|i| i - offset));
+    frame.render_stateful_widget(list, list_area, &mut visible_state);
+
+    if let Some(area) = detail_area {
+        render_detail(frame, app, area);
+    }
+
+    // --- FOOTER & POPUP (Same as before) ---
+    let footer_text = match app.input_mode {
+        InputMode::Normal => &app.keymap.help(),
+        InputMode::Editing => " Typing... Enter: Confirm | Esc: Cancel ",
+        InputMode::CompanyHistory => " 'n': Company Notes | 'w': Website | 'd': Posting Diff | Any other key: Close ",
+        InputMode::StatusHistory
+        | InputMode::Cooldowns
+        | InputMode::QrCode
+        | InputMode::Agenda
+        | InputMode::Offers
+        | InputMode::Usage
+        | InputMode::PostingDiff => " Press any key to close ",
+        InputMode::Stats => " Arrows: Choose a Number | Enter: List Those Jobs | Any other key: Close ",
+        InputMode::Briefing => " 'e': Export as Markdown | Any other key: Close ",
+        InputMode::TemplatePicker => " Up/Down: Choose | Enter: Apply | Esc: Cancel ",
+        InputMode::Expenses => " Up/Down: Choose | Enter: Mark Reimbursed | Esc: Close ",
+        InputMode::Inbox => " Up/Down: Choose | Enter: Accept | 'e': Edit, then Accept | 'd': Discard | Esc: Close ",
+        InputMode::Settings => " Up/Down: Choose | Enter: Change | Esc: Close ",
+        InputMode::Links => " Up/Down: Choose | Enter: Open | 'n': New Link | 'd': Remove | Esc: Close ",
+        InputMode::Files => " Up/Down: Choose | Enter: Open | 'n': Attach Another | 'd': Drop | Esc: Close ",
+        InputMode::Answers => " Up/Down: Choose | Enter: Edit | 'n': New Answer | 'd': Drop | Esc: Close ",
+        InputMode::AsOf => " Up/Down: Scroll | Left/Right: Day Before/After | PgUp/PgDn: Week | Enter: Pick Date | Esc: Back to Now ",
+        InputMode::Network => " Up/Down: Choose | Enter: Link/Unlink Selected Job | 'c': Talked Today | 'n': New Person | 'd': Remove | Esc: Close ",
+        InputMode::Onboarding => " Up/Down: Choose | Enter: Done/Undo | 'u': Due Date | 'n': New Item | 's': Start Date | Esc: Close ",
+        InputMode::Notes => " Typing... Enter: New Line | Arrows/Home/End: Move | Ctrl-S: Save | Esc: Discard ",
+        InputMode::BulkPreview => " 'y': Apply to all listed | Any other key: Cancel ",
+        InputMode::DraftPrompt => " 's': Save as Draft | 'd': Discard | Esc: Keep Typing ",
+        InputMode::SaveFailed => " 'r': Retry | 'a': Save Elsewhere | 'c': Copy JSON | 'D': Quit Without Saving | Esc: Keep Working ",
+    };
+    // Messages from a prompt show inside it instead, see below
+    let footer = match app.message.as_ref().filter(|_| !matches!(app.input_mode, InputMode::Editing)) {
+        Some(message) => Paragraph::new(message.as_str()).style(Style::default().fg(Color::Yellow)),
+        None => Paragraph::new(footer_text),
+    };
+    let footer = footer
+        .block(Block::default().borders(Borders::TOP));
+    frame.render_widget(footer, chunks[1]);
+
+    // Stays up behind the save-draft question so it's clear what would be kept
+    if let InputMode::Editing | InputMode::DraftPrompt = app.input_mode {
+        let area = centered_rect(60, 20, frame.size());
+        frame.render_widget(Clear, area);
+        
+        let title = match app.input_field {
+            InputField::Company => " Enter Company Name ",
+            InputField::Role => " Enter Role Title ",
+            InputField::Link => match app.edit_target {
+                EditTarget::Existing(_) => " Edit Job Link ",
+                EditTarget::New => " Enter Job Link (optional) ",
+            },
+            InputField::Note => " Add Note ",
+            InputField::Cooldown => " Reapply Cool-down (months, empty to clear) ",
+            InputField::Deadline => " Deadline, e.g. 2026-10-31 (empty to clear) ",
+            InputField::ReplyBy => " When they said I'd hear back: 2026-03-03, mar 3, friday or 2w (empty to clear) ",
+            InputField::Applied => " Applied on, e.g. 2026-10-01, yesterday or 3d ago ",
+            InputField::FollowUp => " Follow up on, e.g. 2026-10-31, 5d or 1w, or thu 9am (their time) for a reminder then (empty to clear) ",
+            InputField::Salary => match app.edit_target {
+                EditTarget::Existing(_) => " Salary, e.g. 120k-150k, or paste the pay line from the posting (empty to clear) ",
+                EditTarget::New => " Salary, e.g. 120k-150k, or paste the pay line from the posting (optional) ",
+            },
+            InputField::Location => match app.edit_target {
+                EditTarget::Existing(_) => " Location and work mode, e.g. Berlin, hybrid or remote (empty to clear) ",
+                EditTarget::New => " Location and work mode, e.g. Berlin, hybrid or remote (optional) ",
+            },
+            InputField::PayDisclosure => " Postings here must give a pay range: where, then the range posted, e.g. Colorado $120k-150k or Colorado none (empty to skip) ",
+            InputField::Transcript => " Transcript or recording for the latest interview (file path) ",
+            InputField::Tags => " Tags, e.g. remote referral dream-company (empty to clear) ",
+            InputField::Skills => " Skills it asks for, comma-separated, e.g. rust, postgresql, machine learning (Tab completes) ",
+            InputField::DesiredStart => " Start date the offer asks for, e.g. 2026-12-01 (empty to clear) ",
+            InputField::NamedLink => " Another link: a name, then the URL, e.g. Take-home https://github.com/me/acme-task ",
+            InputField::Attachment => " File to keep with the job, e.g. ~/Downloads/acme-offer.pdf ",
+            InputField::AsOf => " Show the list as it stood on, e.g. 2026-01-15, yesterday or 3w ago ",
+            InputField::Person => " Someone I know: name, where they work, how we met, e.g. Dana Lee, Acme, RustConf 2025 ",
+            InputField::Answer => " A question from the form, then my answer, e.g. Why us? Their tooling (Tab completes) ",
+            InputField::Timezone => " Their timezone, e.g. Europe/Berlin or just Berlin (empty to clear) ",
+            InputField::ReqId => " Requisition ID from the posting or portal, e.g. R-10234 (empty to clear) ",
+            InputField::Engagement => " Engagement, e.g. contract 650/day 6m 2026-11-01 or full-time ",
+            InputField::Interview => " Interview: date, time, name and who, e.g. 2026-10-20 14:00-15:00 Onsite with Ana, Bo (no date means today) ",
+            InputField::Expense => " Expense for the latest interview, e.g. Taxi to the office 42.50 ",
+            InputField::Relocation => " Relocation: city, package, moving cost, e.g. Seattle, 10000, 6500 (empty if no move) ",
+            InputField::StartDate => " First day, e.g. 2026-11-02 (empty to clear) ",
+            InputField::ChecklistItem => " New onboarding item, e.g. Equipment shipped ",
+            InputField::ChecklistDue => " Due date for this item, e.g. 2026-10-25 (empty to clear) ",
+            InputField::ContactName => " Contact: name (1/4) ",
+            InputField::ContactEmail => " Contact: email (2/4) ",
+            InputField::ContactPhone => " Contact: phone (3/4) ",
+            InputField::ContactLinkedin => " Contact: LinkedIn profile or handle (4/4) ",
+            InputField::CoverLetter => " Cover letter: needed, sent, or the file I sent, e.g. sent ~/letters/acme.pdf (empty if none) ",
+            InputField::Resume => " Resume I sent, a file like ~/cv/backend-v3.pdf or a label like v3 (empty to clear) ",
+            InputField::Source => " Where did it come from? e.g. LinkedIn, referral, company site, recruiter (Tab completes, empty to skip) ",
+            InputField::CompanyNotes => " Notes on the company, shared by every role there (empty to clear) ",
+            InputField::CompanyWebsite => " Company website, e.g. acme.com (empty to clear) ",
+            InputField::Agency => " Recruiting agency that submitted me (empty if I applied directly) ",
+            InputField::Assessment => " OA invite: platform and expiry, e.g. HackerRank 2026-10-31 or Codility 7d ('done' once taken) ",
+            InputField::DebriefRating => " How did it go, 1-5? (Esc to skip the debrief) ",
+            InputField::DebriefQuestions => " What did they ask? ",
+            InputField::DebriefNextSteps => " Next steps they mentioned ",
+            InputField::DebriefFollowUp => " Follow up if I hear nothing by, e.g. 2026-10-31 or 5d (empty for no reminder) ",
+            InputField::Filter => " Filter, e.g. status:applied AND applied<2024-12-01 (empty to clear) ",
+            InputField::BulkSet => " Set on every listed job, e.g. status=Ghosted ",
+            InputField::SavePath => " Save jobs JSON to ",
+            InputField::RejectionStage => " Rejected at? resume, phone, oa or onsite (empty to skip) ",
+            InputField::RejectionReason => " Reason they gave, if any (empty to skip) ",
+            InputField::Setting => app.picked_setting().map_or(" Value ", Setting::prompt),
+        };
+
+        // Show the rest of the Tab completion greyed out after the cursor
+        let typed = Style::default().fg(Color::Yellow);
+        let mut spans = match (&app.input_field, &app.dictionary) {
+            (InputField::Note | InputField::Role, Some(dictionary)) => {
+                spell_checked(&app.input_buffer, dictionary, typed)
+            }
+            _ => vec![Span::styled(app.input_buffer.as_str(), typed)],
+        };
+        let suggestion = app.suggestion();
+        if let Some(rest) = suggestion.as_deref().and_then(|s| s.get(app.input_buffer.trim_start().len()..)) {
+            spans.push(Span::styled(rest, Style::default().fg(Color::DarkGray)));
+        }
+        let title = if suggestion.is_some() { format!("{}(Tab to complete) ", title) } else { title.to_string() };
+        let title = match (app.debrief_heading(), app.draft_heading()) {
+            (Some(heading), _) => format!(" Debrief {}:{}", heading, title),
+            (_, Some(heading)) => format!(" Finish draft {}:{}", heading, title),
+            _ => title,
+        };
+        // What's wrong with the input, from the last Enter or live from validate.rs
+        let error = match &app.message {
+            Some(message) => Some(message.trim().to_string()),
+            None if !app.input_buffer.trim().is_empty() => app.input_error(),
+            None => None,
+        };
+        let mut lines = vec![Line::from(spans)];
+        lines.extend(error.map(|error| Line::styled(error, Style::default().fg(Color::Red))));
+        let input_block = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title));
+            
+        frame.render_widget(input_block, area);
+    }
+
+    if let InputMode::CompanyHistory = app.input_mode {
+        render_company_history(frame, app);
+    }
+    if let InputMode::StatusHistory = app.input_mode {
+        render_status_history(frame, app);
+    }
+    if let InputMode::Cooldowns = app.input_mode {
+        render_cooldowns(frame, app);
+    }
+    if let InputMode::Agenda = app.input_mode {
+        render_agenda(frame, app);
+    }
+    if let InputMode::Onboarding = app.input_mode {
+        render_onboarding(frame, app);
+    }
+    if let InputMode::Files = app.input_mode {
+        render_files(frame, app);
+    }
+    if let InputMode::Answers = app.input_mode {
+        render_answers(frame, app);
+    }
+    if let InputMode::Network = app.input_mode {
+        render_network(frame, app);
+    }
+    if let InputMode::AsOf = app.input_mode {
+        render_as_of(frame, app);
+    }
+    if let InputMode::Links = app.input_mode {
+        render_links(frame, app);
+    }
+    if let InputMode::PostingDiff = app.input_mode {
+        render_posting_diff(frame, app);
+    }
+    if let InputMode::Notes = app.input_mode {
+        render_notes_editor(frame, app);
+    }
+    if let InputMode::Offers = app.input_mode {
+        render_offers(frame, app);
+    }
+    if let InputMode::Briefing = app.input_mode {
+        render_briefing(frame, app);
+    }
+    if let InputMode::Expenses = app.input_mode {
+        render_expenses(frame, app);
+    }
+    if let InputMode::Inbox = app.input_mode {
+        render_inbox(frame, app);
+    }
+    if let InputMode::TemplatePicker = app.input_mode {
+        render_template_picker(frame, app);
+    }
+    if let InputMode::Settings = app.input_mode {
+        render_settings(frame, app);
+    }
+    if let InputMode::Usage = app.input_mode {
+        render_usage(frame, app);
+    }
+    #[cfg(feature = "qr")]
+    if let InputMode::QrCode = app.input_mode {
+        render_qr_code(frame, app);
+    }
+    if let InputMode::BulkPreview = app.input_mode {
+        render_bulk_preview(frame, app);
+    }
+    if let InputMode::SaveFailed = app.input_mode {
+        render_save_failed(frame, app);
+    }
+    if let InputMode::Stats = app.input_mode {
+        render_stats(frame, app);
+    }
+}
+
+/// Everything about the selected job. Tab moves the arrow keys here to scroll long notes.
+fn render_detail(frame: &mut ratatui::Frame, app: &App, area: ratatui::layout::Rect) {
+    let focused = app.focus == Focus::Detail;
+    let border = if focused { Style::default().fg(Color::Cyan) } else { Style::default() };
+    let title = if focused { " Details (Tab/Esc: back to list) " } else { " Details (Tab: scroll) " };
+    let block = Block::default().borders(Borders::ALL).border_style(border).title(title);
+
+    let Some(job) = app.selected_index().and_then(|i| app.jobs.get(i)) else {
+        frame.render_widget(Paragraph::new(" Nothing selected").block(block), area);
+        return;
+    };
+
+    let label = Style::default().add_modifier(Modifier::BOLD);
+    let field = |name: &'static str, value: String| Line::from(vec![Span::styled(format!("{:<9}", name), label), Span::raw(value)]);
+    let mut lines = vec![
+        field("Company", job.company.clone()),
+        field("Role", job.role.clone()),
+        field("Status", if job.archived { format!("{} (archived)", status_text(job)) } else { status_text(job) }),
+        field("Applied", job.date_applied.format("%Y-%m-%d").to_string()),
+        field("ID", job.short_id()),
+    ];
+    if let Some(req_id) = &job.req_id {
+        lines.push(field("Req ID", req_id.clone()));
+    }
+    let stamp = |at: chrono::DateTime<chrono::Utc>| at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string();
+    if let Some(created) = job.created_at {
+        lines.push(field("Created", stamp(created)));
+    }
+    if let Some(updated) = job.updated_at.filter(|u| Some(*u) != job.created_at) {
+        lines.push(field("Updated", stamp(updated)));
+    }
+    if let Some(rejection) = job.rejection_label() {
+        lines.push(field("Rejected", rejection));
+    }
+    if let Some(evergreen) = evergreen::label(job, &app.config.evergreen) {
+        lines.push(field("Posting", evergreen));
+    }
+    if job.priority != models::Priority::Medium {
+        lines.push(field("Priority", format!("{:?}", job.priority)));
+    }
+    if job.draft {
+        lines.push(field("Missing", format!("{} ('f' to finish)", job.missing_fields().join(", "))));
+    }
+    if !job.post_link.is_empty() {
+        lines.push(field("Link", job.post_link.clone()));
+    }
+    for link in &job.links {
+        lines.push(Line::from(vec![Span::styled(format!("{:<9}", ""), label), Span::raw(format!("{}: {}", link.name, link.url))]));
+    }
+    for listing in &job.listings {
+        lines.push(field("Listed", fingerprint::describe(listing)));
+    }
+    for path in &job.files {
+        lines.push(field("File", models::describe_file(path)));
+    }
+    let people: Vec<String> = network::linked(&app.network, job).iter().map(|p| p.label()).collect();
+    if !people.is_empty() {
+        lines.push(field("People", people.join(", ")));
+    }
+    // Someone I know works there, in case I haven't thought of asking them
+    let there: Vec<&str> = network::at_company(&app.network, job).iter().map(|p| p.name.as_str()).collect();
+    if !there.is_empty() {
+        lines.push(field("Knows", format!("{} works there", there.join(", "))));
+    }
+    if !job.answers.is_empty() {
+        let questions: Vec<&str> = job.answers.iter().map(|a| a.question.as_str()).collect();
+        lines.push(field("Answered", questions.join(", ")));
+    }
+    if job.engagement.is_contract() {
+        let terms = job.contract.as_ref().map(|t| t.describe()).unwrap_or_default();
+        lines.push(field("Type", format!("{} {}", job.engagement, terms).trim().to_string()));
+    }
+    if let Some(location) = job.location_label() {
+        lines.push(field("Location", location));
+    }
+    if let Some(tz) = job.tz() {
+        lines.push(field("Timezone", format!("{}, {}", tz.name(), timezone::describe(tz, chrono::Utc::now()))));
+    }
+    if let Some(salary) = job.salary_label() {
+        lines.push(field("Salary", salary));
+    }
+    if let Some(disclosure) = &job.pay_disclosure {
+        lines.push(field("Pay law", disclosure.label()));
+    }
+    if !job.contact.is_empty() {
+        let label = job.contact.label();
+        let url_too = !job.contact.linkedin.is_empty() && label != job.contact.linkedin;
+        lines.push(field("Contact", label));
+        if url_too {
+            lines.push(field("", job.contact.linkedin.clone()));
+        }
+    }
+    if let Some(agency) = &job.agency {
+        lines.push(field("Agency", agency.clone()));
+    }
+    if let Some(source) = &job.source {
+        lines.push(field("Source", source.clone()));
+    }
+    if !job.resume_version.is_empty() {
+        let missing = if job.resume_missing() { " (file not found)" } else { "" };
+        lines.push(field("Resume", format!("{}{}", job.resume_version, missing)));
+    }
+    if let Some(letter) = job.cover_letter_label() {
+        lines.push(field("Letter", letter));
+    }
+    let references: Vec<&str> = app
+        .references
+        .iter()
+        .filter(|r| r.given.iter().any(|g| g.job_id == job.id))
+        .map(|r| r.name.as_str())
+        .collect();
+    if !references.is_empty() {
+        lines.push(field("Refs", references.join(", ")));
+    }
+    if let Some(onboarding) = &job.onboarding {
+        let (done, total) = onboarding.progress();
+        let start = onboarding.start_date.map(|d| format!(", starts {}", d)).unwrap_or_default();
+        lines.push(field("Onboard", format!("{}/{} done{}", done, total, start)));
+    }
+    if let Some(relocation) = &job.relocation {
+        let mut parts = vec![relocation.city.clone()];
+        parts.extend(relocation.package.map(|p| format!("package {:.0}", p)));
+        parts.extend(relocation.moving_cost.map(|c| format!("moving {:.0}", c)));
+        lines.push(field("Move", parts.join(", ")));
+    }
+    if !job.tags.is_empty() {
+        let tags: Vec<String> = job.tags.iter().map(|t| format!("#{}", t)).collect();
+        lines.push(field("Tags", tags.join(" ")));
+    }
+    if !job.skills.is_empty() {
+        lines.push(field("Skills", job.skills.join(", ")));
+    }
+    if let Some(fit) = skills::fit(job, &app.config.skills) {
+        lines.push(field("Fit", fit.label()));
+    }
+    if let Some(cycle) = &job.cycle {
+        lines.push(field("Cycle", cycle.clone()));
+    }
+    if let Some(date) = job.deadline {
+        lines.push(field("Deadline", date.format("%Y-%m-%d").to_string()));
+    }
+    if let Some(date) = job.reply_by {
+        let late = if job.reply_overdue(chrono::Local::now().date_naive()) { " (no reply yet)" } else { "" };
+        lines.push(field("Reply by", format!("{}{}", date.format("%Y-%m-%d"), late)));
+    }
+    if let Some(wait) = app.response_wait(job, chrono::Local::now().date_naive()) {
+        let line = field("Waiting", wait.label());
+        lines.push(if wait.past_usual() { line.style(Style::default().fg(app.theme.warning())) } else { line });
+    }
+    if let Some(at) = job.follow_up {
+        let today = chrono::Local::now().date_naive();
+        let late = match at.date_naive() {
+            date if !job.follow_up_due(today) || date > today => "",
+            date if date == today => " (today)",
+            _ => " (overdue)",
+        };
+        lines.push(field("Nudge", format!("{}{}", at.format("%Y-%m-%d"), late)));
+    }
+    if let Some(t) = offers::timeline(job, &app.config.notice).filter(|_| job.status == models::Status::Offer) {
+        lines.push(field("Start", format!("{}, resign by {}, decide by {}", t.start, t.resign_by, t.decide_by)));
+    }
+    if let Some(date) = job.reapply_after() {
+        lines.push(field("Reapply", date.format("%Y-%m-%d").to_string()));
+    }
+    if !job.stages.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(job.loop_template.clone().unwrap_or_else(|| "Loop".to_string()), label));
+        for stage in &job.stages {
+            let kind = if stage.kind == models::StageKind::Assessment { " (OA)" } else { "" };
+            lines.push(Line::from(format!(" [{}] {}{}", if stage.done { "x" } else { " " }, stage.name, kind)));
+        }
+    }
+    if !job.interviews.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled("Interviews", label));
+        for interview in &job.interviews {
+            let attached = match interview.attachment.as_ref().and_then(|p| p.file_name()) {
+                Some(file) => format!("  [{}]", file.to_string_lossy()),
+                None => String::new(),
+            };
+            let with = if interview.interviewers.is_empty() {
+                String::new()
+            } else {
+                format!(" with {}", interview.interviewers.join(", "))
+            };
+            lines.push(Line::from(format!(" {}{}{}", interview.when(), with, attached)));
+            if let Some(debrief) = interview.debrief.as_ref().map(|d| d.summary()).filter(|s| !s.is_empty()) {
+                lines.push(Line::from(format!("   Debrief: {}", debrief)));
+            }
+            for expense in &interview.expenses {
+                let paid = if expense.reimbursed { "reimbursed" } else { "owed" };
+                lines.push(Line::from(format!("   {} {:.2} ({})", expense.description, expense.amount, paid)));
+            }
+        }
+    }
+    if !job.assessments.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled("Online assessments", label));
+        for assessment in &job.assessments {
+            let status = match (assessment.completed, assessment.expires) {
+                (Some(date), _) => format!("taken {}", date),
+                (None, Some(date)) => format!("expires {}", date),
+                (None, None) => "no expiry".to_string(),
+            };
+            let done = if assessment.completed.is_some() { "x" } else { " " };
+            lines.push(Line::from(format!(" [{}] {} (received {}, {})", done, assessment.platform, assessment.received, status)));
+        }
+    }
+    if let Some(company) = app.company_record(job).filter(|c| !c.website.is_empty() || !c.notes.is_empty()) {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(format!("About {} ('h' to edit)", company.name), label));
+        if !company.website.is_empty() {
+            lines.push(Line::from(company.website.clone()));
+        }
+        lines.extend(company.notes.lines().map(|l| Line::from(l.to_string())));
+    }
+    if !job.notes.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled("Notes", label));
+        lines.extend(job.notes.lines().map(|l| Line::from(l.to_string())));
+    }
+    if !job.description.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled("Posting ('J' to edit)", label));
+        lines.extend(job.description.lines().map(|l| Line::from(l.to_string())));
+    }
+    let blame = app.field_blame(job);
+    if !blame.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled("Last changed", label));
+        let width = blame.iter().map(|(field, _)| field.len()).max().unwrap_or(0);
+        for (field, when) in blame {
+            lines.push(Line::from(format!(" {:<width$}  {}", field, when, width = width)));
+        }
+    }
+
+    let detail = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.detail_scroll, 0));
+    frame.render_widget(detail, area);
+}
+
+fn status_text(job: &models::Job) -> String {
+    if job.draft {
+        return job.draft_label();
+    }
+    match job.stage_progress() {
+        Some((done, total)) => format!("{} {}/{}", job.status_label(), done, total),
+        None => job.status_label(),
+    }
+}
+
+/// The status column: the status, then when they said I'd hear back
+fn list_status(job: &models::Job, today: chrono::NaiveDate) -> String {
+    match job.reply_label(today) {
+        Some(reply) => format!("{}, {}", status_text(job), reply),
+        None => status_text(job),
+    }
+}
+
+/// Company, marked with a '!' for high priority so it shows without color
+/// too, and `star` when it's starred
+fn company_text(job: &models::Job, star: char) -> String {
+    let company = match job.priority {
+        models::Priority::High => format!("! {}", job.company),
+        _ => job.company.clone(),
+    };
+    if job.starred { format!("{} {}", star, company) } else { company }
+}
+
+/// Role, with the rate for contract work since that's what gets compared,
+/// and "(evergreen)" when the posting never seems to come down
+fn role_text(job: &models::Job, evergreen: bool) -> String {
+    let mut text = match job.contract.as_ref().and_then(|t| t.rate_label()).or_else(|| job.salary_label()) {
+        Some(pay) => format!("{} ({})", job.role, pay),
+        None => job.role.clone(),
+    };
+    for tag in &job.tags {
+        text.push_str(&format!(" #{}", tag));
+    }
+    if evergreen {
+        text.push_str(" (evergreen)");
+    }
+    text
+}
+
+/// One line: id, glyph, progress, then company | role | where | status columns.
+/// "Where" is the location and work mode, or the posting's domain until those are known.
+fn wide_row(job: &models::Job, id: &str, glyph: &str, star: char, evergreen: bool, today: chrono::NaiveDate, widths: (usize, usize, usize, usize)) -> String {
+    let (company_width, role_width, link_width, status_width) = widths;
+    let link_display = match job.location_label().or_else(|| job.link_domain()) {
+        Some(place) => truncate(&place, link_width),
+        None => "-".to_string(),
+    };
+
+    // Using format! macro to align columns slightly
+    format!(
+        " {} {}{} {:<company_width$} | {:<role_width$} | {:<link_width$} | {:<status_width$}",
+        id,
+        glyph,
+        progress_bar(job.pipeline_progress(), PROGRESS_WIDTH),
+        truncate(&company_text(job, star), company_width),
+        truncate(&role_text(job, evergreen), role_width),
+        link_display,
+        truncate(&list_status(job, today), status_width),
+        company_width = company_width,
+        role_width = role_width,
+        link_width = link_width,
+        status_width = status_width,
+    )
+}
+
+/// Two lines: company and role on top, status, date and domain underneath,
+/// both lined up after the progress bar
+fn compact_row(job: &models::Job, id: &str, glyph: &str, star: char, evergreen: bool, today: chrono::NaiveDate, width: usize) -> Text<'static> {
+    let lead = format!(" {} {}{} ", id, glyph, progress_bar(job.pipeline_progress(), PROGRESS_WIDTH));
+    let indent = lead.chars().count();
+    let room = width.saturating_sub(3 + indent); // minus the ">> " highlight
+
+    let mut details = vec![list_status(job, today), job.date_applied.format("%b %-d").to_string()];
+    details.extend(job.deadline.map(|d| d.format("due %b %-d").to_string()));
+    details.extend(job.location_label().or_else(|| job.link_domain()));
+    Text::from(vec![
+        Line::from(format!("{}{}", lead, truncate(&format!("{} · {}", company_text(job, star), role_text(job, evergreen)), room))),
+        Line::from(format!("{}{}", " ".repeat(indent), truncate(&details.join(" · "), room))),
+    ])
+}
+
+/// `text` with misspelled words underlined in red
+fn spell_checked<'a>(text: &'a str, dictionary: &Dictionary, style: Style) -> Vec<Span<'a>> {
+    let typo = Style::default().fg(Color::Red).add_modifier(Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for range in dictionary.misspelled(text) {
+        spans.push(Span::styled(&text[last..range.start], style));
+        spans.push(Span::styled(&text[range.clone()], typo));
+        last = range.end;
+    }
+    spans.push(Span::styled(&text[last..], style));
+    spans
+}
+
+/// Counts, response times and the pipeline health gauge with what's holding it back
+fn render_stats(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(70, 70, frame.size());
+    frame.render_widget(Clear, area);
+    let block = Block::default().borders(Borders::ALL).title(" Stats ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let jobs = app.counted_jobs();
+    let health = metrics::pipeline_health(jobs, &app.config.metrics.health, &app.config.evergreen, chrono::Utc::now());
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Length(3), Constraint::Min(0)])
+        .split(inner);
+
+    // Counts and response times. Each count is a figure the arrows step
+    // through, with the picked one reversed.
+    let figures = &metrics::figures(jobs, &health);
+    let picked = app.picker_state.selected().unwrap_or(0);
+    let pick = |i: usize| {
+        if i == picked { Style::default().add_modifier(Modifier::REVERSED) } else { Style::default() }
+    };
+    let in_group = |group: FigureGroup| (0..figures.len()).filter(move |&i| figures[i].group == group);
+    let row = |lead: &str, groups: &[FigureGroup]| -> Line {
+        let mut spans = vec![Span::raw(lead.to_string())];
+        for (n, i) in groups.iter().flat_map(|&group| in_group(group)).enumerate() {
+            if n > 0 {
+                spans.push(Span::raw(" | "));
+            }
+            spans.push(Span::styled(figures[i].label.clone(), pick(i)));
+        }
+        Line::from(spans)
+    };
+    let business_days = app.config.metrics.business_days;
+    let mut times = app
+        .holiday_calendar()
+        .map(|calendar| metrics::response_times(jobs, business_days, calendar))
+        .unwrap_or_default();
+    times.sort();
+    let response = match times.get(times.len() / 2) {
+        Some(median) => format!(
+            " Responses: {} of {}, median {} {}",
+            times.len(),
+            jobs.len(),
+            median,
+            if business_days { "business days" } else { "days" }
+        ),
+        None => " Responses: none yet".to_string(),
+    };
+    let mut summary = vec![
+        row(" ", &[FigureGroup::Total, FigureGroup::Pipeline]),
+        row(" ", &[FigureGroup::Status]),
+        Line::from(response),
+    ];
+    if in_group(FigureGroup::Rejected).next().is_some() {
+        summary.push(row(" Rejected at: ", &[FigureGroup::Rejected]));
+    }
+    frame.render_widget(Paragraph::new(summary), rows[0]);
+
+    let color = match health.score {
+        s if s >= 70.0 => Color::Green,
+        s if s >= 40.0 => Color::Yellow,
+        _ => Color::Red,
+    };
+    let gauge = Gauge::default()
+        .block(Block::default().borders(Borders::TOP).title(" Pipeline health "))
+        .gauge_style(Style::default().fg(color))
+        .percent(health.score.round().clamp(0.0, 100.0) as u16)
+        .label(format!("{:.0}/100", health.score));
+    frame.render_widget(gauge, rows[1]);
+
+    let drags = health.drags();
+    let first_signal = in_group(FigureGroup::Health).next().unwrap_or(figures.len());
+    let mut lines: Vec<Line> = health
+        .signals
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| {
+            let marker = if drags.iter().any(|d| d.name == signal.name) { "!" } else { " " };
+            Line::from(vec![
+                Span::raw(format!(" {} ", marker)),
+                Span::styled(format!("{:<17}", signal.name), pick(first_signal + i)),
+                Span::raw(format!(
+                    " {} {:>3.0}%  {}",
+                    progress_bar(signal.score, 10),
+                    signal.score * 100.0,
+                    signal.detail
+                )),
+            ])
+        })
+        .collect();
+    if let Some(note) = app.counted_note() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(format!(" {}, see [stats] in config.toml", note), Style::default().fg(Color::DarkGray)));
+    }
+    if let Some(worst) = drags.first() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!(" Biggest drag: {} ({})", worst.name, worst.detail),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    // Weeks of money left, colored by how close it's getting
+    if let Some(runway) = runway::runway(jobs, &app.config.runway, chrono::Local::now().date_naive()) {
+        let color = match runway.weeks {
+            w if w < 8.0 => Color::Red,
+            w if w < 16.0 || !runway.on_track() => Color::Yellow,
+            _ => Color::Green,
+        };
+        lines.push(Line::from(""));
+        lines.extend(runway.lines().into_iter().map(|l| Line::styled(format!(" {}", l), Style::default().fg(color))));
+    }
+    frame.render_widget(Paragraph::new(lines), rows[2]);
+}
+
+/// Saving on quit didn't work. Nothing is lost until one of these is picked.
+fn render_save_failed(frame: &mut ratatui::Frame, app: &App) {
+    let screen = frame.size();
+    let width = (screen.width * 7 / 10).max(40).min(screen.width);
+    let height = 13.min(screen.height);
+    let area = ratatui::layout::Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, area);
+
+    let error = app.save_error.as_deref().unwrap_or("Unknown error");
+    let lines = vec![
+        Line::from(format!(" {}", error)),
+        Line::from(""),
+        Line::from(" Your changes are still here. Pick what to do with them:"),
+        Line::from(""),
+        Line::from("   r    Try saving again"),
+        Line::from("   a    Save to a different file"),
+        Line::from("   c    Copy everything as JSON to the clipboard"),
+        Line::from("   D    Quit without saving"),
+        Line::from("   Esc  Keep working"),
+    ];
+    let dialog = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red))
+                .title(" Save failed "),
+        );
+    frame.render_widget(dialog, area);
+}
+
+/// Every row a bulk edit is about to touch, with old -> new values
+fn render_bulk_preview(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(80, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = bulk::preview(&app.jobs, &app.visible, &app.bulk)
+        .into_iter()
+        .map(|line| ListItem::new(format!(" {}", line)))
+        .collect();
+    let title = format!(" Apply to {} jobs? (y/N) ", app.visible.len());
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(list, area);
+}
+
+/// Scan the posting link with a phone. Half blocks fit two QR rows per terminal line.
+#[cfg(feature = "qr")]
+fn render_qr_code(frame: &mut ratatui::Frame, app: &App) {
+    let Some(job) = app.selected_index().and_then(|i| app.jobs.get(i)) else {
+        return;
+    };
+    let text = match qrcode::QrCode::new(job.post_link.trim().as_bytes()) {
+        // Terminals are usually dark, so draw light modules as blocks
+        Ok(code) => code
+            .render::<qrcode::render::unicode::Dense1x2>()
+            .dark_color(qrcode::render::unicode::Dense1x2::Light)
+            .light_color(qrcode::render::unicode::Dense1x2::Dark)
+            .build(),
+        Err(err) => format!("Can't encode this link: {}", err),
+    };
+
+    let screen = frame.size();
+    let width = (text.lines().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 2).min(screen.width);
+    let height = (text.lines().count() as u16 + 2).min(screen.height);
+    let area = ratatui::layout::Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, area);
+
+    let title = format!(" {} ", job.link_domain().unwrap_or_default());
+    let qr = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(qr, area);
+}
+
+/// Checklist from accepting to the first day
+/// The notes editor, wrapped to the popup and scrolled to keep the cursor in view
+fn render_notes_editor(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(70, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let inner_width = area.width.saturating_sub(2).max(1);
+    let inner_height = area.height.saturating_sub(2).max(1);
+    let (rows, (row, column)) = app.notes_editor.wrap(inner_width as usize);
+    let scroll = (row as u16).saturating_sub(inner_height - 1);
+
+    let typed = Style::default().fg(Color::Yellow);
+    let lines: Vec<Line> = rows
+        .iter()
+        .map(|text| match &app.dictionary {
+            Some(dictionary) => Line::from(spell_checked(text, dictionary, typed)),
+            None => Line::from(Span::styled(text.as_str(), typed)),
+        })
+        .collect();
+    let company = match app.edit_target {
+        EditTarget::Existing(i) => app.jobs.get(i).map(|j| j.company.as_str()).unwrap_or_default(),
+        EditTarget::New => "",
+    };
+    let notes = Paragraph::new(lines)
+        .scroll((scroll, 0))
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " {}: {} ",
+            if app.editing_description { "Posting text" } else { "Notes" },
+            company
+        )));
+    frame.render_widget(notes, area);
+    frame.set_cursor(
+        area.x + 1 + (column as u16).min(inner_width - 1),
+        area.y + 1 + row as u16 - scroll,
+    );
+}
+
+/// The job's links by name, to pick one to open
+fn render_links(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(60, 40, frame.size());
+    frame.render_widget(Clear, area);
+
+    let Some(job) = app.selected_index().map(|i| &app.jobs[i]) else {
+        return;
+    };
+    let links = job.all_links();
+    let width = links.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    let items: Vec<ListItem> = links.iter().map(|(name, url)| ListItem::new(format!(" {:<width$}  {}", name, url, width = width))).collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!(" Links: {} - {} ", job.company, job.role)))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// The job's files, to pick one to open
+fn render_files(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(60, 40, frame.size());
+    frame.render_widget(Clear, area);
+
+    let Some(job) = app.selected_index().map(|i| &app.jobs[i]) else {
+        return;
+    };
+    let items: Vec<ListItem> = job.files.iter().map(|path| ListItem::new(format!(" {}", models::describe_file(path)))).collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!(" Files: {} - {} ", job.company, job.role)))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// The job's form answers, each under its question, with what I said
+/// differently to the same question elsewhere
+fn render_answers(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(70, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let Some(job) = app.selected_index().map(|i| &app.jobs[i]) else {
+        return;
+    };
+    let items: Vec<ListItem> = job
+        .answers
+        .iter()
+        .map(|answer| {
+            let mut lines = vec![
+                Line::from(Span::styled(format!(" {}", answer.question), Style::default().add_modifier(Modifier::BOLD))),
+                Line::from(format!("   {}", answer.answer)),
+            ];
+            for (other, earlier) in answers::given(&app.jobs, job, &answer.question) {
+                if !earlier.answer.eq_ignore_ascii_case(&answer.answer) {
+                    let said = format!("   {} got: {}", other.company, earlier.answer);
+                    lines.push(Line::from(Span::styled(said, Style::default().fg(app.theme.warning()))));
+                }
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!(" Form Answers: {} - {} ", job.company, job.role)))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// The list as it stood at the end of a past day
+fn render_as_of(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(80, 80, frame.size());
+    frame.render_widget(Clear, area);
+
+    let Some((date, jobs)) = &app.as_of else {
+        return;
+    };
+    let items: Vec<ListItem> = jobs
+        .iter()
+        .map(|job| {
+            let color = statuses::current(&app.config.statuses, job).and_then(|def| def.color());
+            let color = color.unwrap_or_else(|| app.theme.status_color(&job.status));
+            ListItem::new(format!(
+                " {} | {:<18} | {:<24} | {}",
+                job.date_applied.format("%Y-%m-%d"),
+                truncate(&job.company, 18),
+                truncate(&job.role, 24),
+                if job.draft { job.draft_label() } else { job.status_label() },
+            ))
+            .style(Style::default().fg(color))
+        })
+        .collect();
+
+    let title = format!(" As of {}, {} ", date.format("%a %Y-%m-%d"), asof::summary(jobs));
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// Everyone in my network, with the selected job's links marked
+fn render_network(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(80, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let today = chrono::Local::now().date_naive();
+    let job = app.selected_index().map(|i| &app.jobs[i]);
+    let items: Vec<ListItem> = app
+        .network
+        .iter()
+        .map(|person| {
+            let linked = job.is_some_and(|job| person.jobs.contains(&job.id));
+            let line = format!(
+                " {} {:<20} | {:<16} | {:<24} | {} | {} job(s)",
+                if linked { "*" } else { " " },
+                truncate(&person.name, 20),
+                truncate(&person.company, 16),
+                truncate(&person.met, 24),
+                person.last_contact_label(today),
+                person.jobs.len()
+            );
+            ListItem::new(line)
+        })
+        .collect();
+
+    let title = match job {
+        Some(job) => format!(" Network ({} people, * linked to {} - {}) ", app.network.len(), job.company, job.role),
+        None => format!(" Network ({} people) ", app.network.len()),
+    };
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+fn render_onboarding(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(60, 50, frame.size());
+    frame.render_widget(Clear, area);
+
+    let Some(job) = app.selected_index().map(|i| &app.jobs[i]) else {
+        return;
+    };
+    let onboarding = job.onboarding.clone().unwrap_or_default();
+    let today = chrono::Local::now().date_naive();
+    let items: Vec<ListItem> = onboarding
+        .checklist
+        .iter()
+        .map(|item| {
+            let due = item.due.map(|d| format!("  due {}", d)).unwrap_or_default();
+            let style = match item.due {
+                Some(date) if !item.done && date < today => Style::default().fg(Color::Red),
+                _ if item.done => Style::default().fg(Color::DarkGray),
+                _ => Style::default(),
+            };
+            ListItem::new(format!(" [{}] {}{}", if item.done { "x" } else { " " }, item.name, due)).style(style)
+        })
+        .collect();
+
+    let (done, total) = onboarding.progress();
+    let start = match onboarding.start_date {
+        Some(date) => format!("starts {}", date),
+        None => "no start date yet".to_string(),
+    };
+    let title = format!(" Onboarding: {} ({}/{} done, {}) ", job.company, done, total, start);
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// Offers side by side, one column each
+/// The briefing's Markdown, with the headings in bold
+fn render_briefing(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(80, 90, frame.size());
+    frame.render_widget(Clear, area);
+    let lines: Vec<Line> = app
+        .briefing()
+        .into_iter()
+        .map(|line| match line.strip_prefix("## ").or_else(|| line.strip_prefix("# ")) {
+            Some(heading) => Line::styled(format!(" {}", heading), Style::default().add_modifier(Modifier::BOLD)),
+            None => Line::raw(format!(" {}", line.replace("**", ""))),
+        })
+        .collect();
+    let briefing = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(" Briefing "));
+    frame.render_widget(briefing, area);
+}
+
+fn render_posting_diff(frame: &mut ratatui::Frame, app: &App) {
+    let Some((earlier, changes)) = app.posting_diff() else { return };
+    let area = centered_rect(80, 90, frame.size());
+    frame.render_widget(Clear, area);
+    let lines: Vec<Line> = changes
+        .iter()
+        .map(|(change, line)| match change {
+            posting::Change::Added => Line::styled(format!(" + {}", line), Style::default().fg(Color::Green)),
+            posting::Change::Removed => Line::styled(format!(" - {}", line), Style::default().fg(Color::Red)),
+            posting::Change::Same => Line::styled(format!("   {}", line), Style::default().fg(Color::DarkGray)),
+        })
+        .collect();
+    let title = format!(
+        " Posting vs {} ({}): {} ",
+        earlier.role,
+        earlier.date_applied.format("%Y-%m-%d"),
+        posting::summary(&changes)
+    );
+    let diff = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(diff, area);
+}
+
+fn render_offers(frame: &mut ratatui::Frame, app: &App) {
+    let matrix = offers::comparison(&app.jobs, &app.config.notice, &app.config.currency, chrono::Local::now().date_naive());
+    let screen = frame.size();
+    let width = (screen.width * 9 / 10).min(screen.width);
+    let lines = matrix.lines();
+    let height = (lines.len() as u16 + 2).min(screen.height);
+    let area = ratatui::layout::Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, area);
+
+    let lines: Vec<Line> = lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let style = if i == 0 {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else if line.starts_with('!') {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            Line::styled(format!(" {}", line), style)
+        })
+        .collect();
+    let matrix = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Compare Offers "));
+    frame.render_widget(matrix, area);
+}
+
+/// Expenses still to be paid back, with the total owed in the title
+fn render_expenses(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(80, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let outstanding = models::outstanding_expenses(&app.jobs);
+    let total: f64 = outstanding.iter().map(|o| o.expense.amount).sum();
+    let items: Vec<ListItem> = outstanding
+        .iter()
+        .map(|o| {
+            ListItem::new(format!(
+                " {} | {:<18} | {:<16} | {:<24} | {:>9.2}",
+                o.interview.date,
+                truncate(&o.job.company, 18),
+                truncate(&o.interview.name, 16),
+                truncate(&o.expense.description, 24),
+                o.expense.amount,
+            ))
+        })
+        .collect();
+
+    let title = format!(" Outstanding Reimbursements ({} items, {:.2} owed) ", outstanding.len(), total);
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// Imports waiting to be accepted into the list or discarded
+fn render_inbox(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(80, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = app
+        .inbox
+        .iter()
+        .map(|job| {
+            ListItem::new(format!(
+                " {} | {:<18} | {:<24} | {:<12} | {}",
+                job.date_applied.format("%Y-%m-%d"),
+                truncate(&job.company, 18),
+                truncate(&job.role, 24),
+                job.status_label(),
+                models::domain_of(&job.post_link).unwrap_or_default(),
+            ))
+        })
+        .collect();
+
+    let title = format!(" Review Imports ({} waiting) ", app.inbox.len());
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+fn render_template_picker(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(60, 40, frame.size());
+    frame.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = app
+        .config
+        .templates()
+        .iter()
+        .map(|t| ListItem::new(format!(" {} ({} stages): {}", t.name, t.stages.len(), t.stages.join(" → "))))
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" Apply Interview Loop "))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// The settings screen, each with its current value. Changes are written to config.toml.
+fn render_settings(frame: &mut ratatui::Frame, app: &mut App) {
+    let area = centered_rect(70, 50, frame.size());
+    frame.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = Setting::ALL
+        .iter()
+        .map(|setting| {
+            let item = ListItem::new(format!(" {:<28} {}", setting.label(), setting.value(&app.config)));
+            if *setting == Setting::DataDir { item.style(Style::default().fg(Color::DarkGray)) } else { item }
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" Settings (saved to config.toml) "))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol(">> ");
+    frame.render_stateful_widget(list, area, &mut app.picker_state);
+}
+
+/// How I've been using the tool, from usage.jsonl. Warnings in yellow.
+fn render_usage(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(70, 60, frame.size());
+    frame.render_widget(Clear, area);
+    let Some(summary) = &app.usage else { return };
+
+    let warning = Style::default().fg(Color::Yellow);
+    let mut lines: Vec<Line> = summary.warnings().into_iter().map(|w| Line::styled(format!(" {}", w), warning)).collect();
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+    }
+    lines.extend(summary.lines().into_iter().map(|l| Line::from(format!(" {}", l))));
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(" How I Use career-cli (kept on this machine) "));
+    frame.render_widget(paragraph, area);
+}
+
+/// Every status the selected job has been through, with how long it sat in each
+fn render_status_history(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(70, 50, frame.size());
+    frame.render_widget(Clear, area);
+    let Some(job) = app.selected_index().and_then(|i| app.jobs.get(i)) else {
+        return;
+    };
+
+    let format = |at: chrono::DateTime<chrono::Utc>| at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M");
+    let mut items = vec![ListItem::new(format!(" {}  Applied", format(job.date_applied)))];
+    // Jobs older than the history only know where they are now
+    let first = job.history.first().map_or_else(|| job.status_label(), |change| change.from.clone());
+    let mut since = Some(job.date_applied);
+    if first != "Applied" {
+        items.push(ListItem::new(format!(" {:<16}  Applied -> {} (not recorded)", "?", first)));
+        since = None;
+    }
+    for change in &job.history {
+        let stay = since.map(|since| format!(" ({} days in {})", (change.at - since).num_days(), change.from));
+        items.push(ListItem::new(format!(
+            " {}  {} -> {}{}",
+            format(change.at),
+            change.from,
+            change.to,
+            stay.unwrap_or_default()
+        )));
+        since = Some(change.at);
+    }
+
+    let title = format!(" Timeline: {} - {} ", job.company, job.role);
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(list, area);
+}
+
+fn render_cooldowns(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(70, 50, frame.size());
+    frame.render_widget(Clear, area);
+
+    let now = chrono::Utc::now();
+    let items: Vec<ListItem> = models::reapply_windows(&app.jobs)
+        .into_iter()
+        .map(|(job, date)| {
+            let (when, style) = if date <= now {
+                ("eligible now".to_string(), Style::default().fg(Color::Green))
+            } else {
+                (format!("in {} days", (date - now).num_days()), Style::default())
+            };
+            ListItem::new(format!(
+                " {} | {:<24} | {:<24} | {}",
+                date.format("%Y-%m-%d"),
+                truncate(&job.company, 24),
+                truncate(&job.role, 24),
+                when,
+            ))
+            .style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" Reapply Eligibility "));
+    frame.render_widget(list, area);
+}
+
+/// Deadlines and OA expiries, soonest first. Overdue in red, the coming
+/// three days in yellow, and OAs about to expire in bold.
+fn render_agenda(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(80, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let today = chrono::Local::now().date_naive();
+    let warning_days = app.config.season.oa_warning_days as i64;
+    let agenda = models::agenda(&app.jobs);
+    let items: Vec<ListItem> = agenda
+        .iter()
+        .map(|due| {
+            let days = (due.date - today).num_days();
+            let (when, mut style) = match days {
+                ..0 if due.is_assessment() => ("expired".to_string(), Style::default().fg(Color::Red)),
+                ..0 => (format!("{} days late", -days), Style::default().fg(Color::Red)),
+                0 => ("today".to_string(), Style::default().fg(Color::Yellow)),
+                1 => ("tomorrow".to_string(), Style::default().fg(Color::Yellow)),
+                2..=3 => (format!("in {} days", days), Style::default().fg(Color::Yellow)),
+                _ => (format!("in {} days", days), Style::default()),
+            };
+            if due.is_assessment() && days <= warning_days {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            // Travel days stand out, there's a bag to pack
+            if matches!(due.kind, models::DueKind::Travel(_)) && days >= 0 {
+                style = style.fg(Color::Cyan).add_modifier(Modifier::BOLD);
+            }
+            ListItem::new(format!(
+                " {} | {:<12} | {:<18} | {:<20} | {:<20} | {}",
+                due.date.format("%Y-%m-%d"),
+                when,
+                truncate(&due.label(), 18),
+                truncate(&due.job.company, 20),
+                truncate(&due.job.role, 20),
+                due.job.cycle.as_deref().unwrap_or(""),
+            ))
+            .style(style)
+        })
+        .collect();
+
+    // Admin paperwork after the job dates, set apart by a header row
+    let mut items = items;
+    let admin = reminders::upcoming(&app.config.reminders, today);
+    if !admin.is_empty() {
+        items.push(ListItem::new(" Admin").style(Style::default().add_modifier(Modifier::BOLD)));
+        for (date, reminder) in &admin {
+            let days = (*date - today).num_days();
+            let (when, style) = match days {
+                ..0 => (format!("{} days late", -days), Style::default().fg(app.theme.warning())),
+                0 => ("today".to_string(), Style::default().fg(Color::Yellow)),
+                1 => ("tomorrow".to_string(), Style::default().fg(Color::Yellow)),
+                _ => (format!("in {} days", days), Style::default()),
+            };
+            items.push(
+                ListItem::new(format!(
+                    " {} | {:<12} | {:<41} | {}",
+                    date.format("%Y-%m-%d"),
+                    when,
+                    truncate(&reminder.name, 41),
+                    reminder.describe()
+                ))
+                .style(style),
+            );
+        }
+    }
+
+    let title = format!(" Agenda ({}) ", agenda.len() + admin.len());
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(list, area);
+}
+
+fn render_company_history(frame: &mut ratatui::Frame, app: &App) {
+    let area = centered_rect(80, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let rows = app.company_history();
+    let job = app.selected_index().and_then(|i| app.jobs.get(i));
+    let company = job.map(|j| j.company.clone()).unwrap_or_default();
+
+    // What's on the company's record goes above its applications
+    let mut items: Vec<ListItem> = Vec::new();
+    if let Some(record) = job.and_then(|j| app.company_record(j)) {
+        if !record.website.is_empty() {
+            items.push(ListItem::new(format!(" Website: {}", record.website)));
+        }
+        items.extend(record.notes.lines().map(|line| ListItem::new(format!(" {}", line))));
+        if !items.is_empty() {
+            items.push(ListItem::new(""));
+        }
+    }
+    items.extend(rows
+        .iter()
+        .map(|(cohort, job)| {
+            // Last note line usually says how it ended ("rejected after onsite")
+            let outcome = job.notes.lines().last().unwrap_or("");
+            ListItem::new(format!(
+                " {:<10} {} | {:<24} | {:<12} | {}",
+                truncate(cohort, 10),
+                job.date_applied.format("%Y-%m-%d"),
+                truncate(&job.role, 24),
+                job.status_label(),
+                outcome,
+            ))
+        }));
+
+    let title = format!(" History: {} ({} applications) ", company, rows.len());
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(list, area);
+}
+
+// Helper to center a rect in the screen
+fn centered_rect(percent_x: u16, percent_y: u16, r: ratatui::layout::Rect) -> ratatui::layout::Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
+
+fn truncate(value: &str, max_len: usize) -> String {
+    if value.len() <= max_len {
+        return value.to_string();
+    }
+    if max_len <= 3 {
+        return value.chars().take(max_len).collect::<String>();
+    }
+    let mut truncated = value
+        .chars()
+        .take(max_len.saturating_sub(3))
+        .collect::<String>();
+    truncated.push_str("...");
+    truncated
+}
+
+const PROGRESS_WIDTH: usize = 5;
+
+/// e.g. 0.4 -> "▰▰▱▱▱"
+fn progress_bar(fraction: f64, width: usize) -> String {
+    let filled = ((fraction.clamp(0.0, 1.0) * width as f64).round() as usize).min(width);
+    format!("{}{}", "▰".repeat(filled), "▱".repeat(width - filled))
+}
+
+/// Characters on every row that aren't one of the four columns: the ">> "
+/// highlight, leading space, short id, progress bar and three " | " separators
+pub const ROW_OVERHEAD: usize = 3 + 9 + 7 + PROGRESS_WIDTH;
+
+/// Below this many cells for the columns the list switches to two-line rows
+const MIN_COLUMNS_WIDTH: usize = 42;
+
+pub fn is_compact(total_width: u16) -> bool {
+    (total_width as usize).saturating_sub(ROW_OVERHEAD) < MIN_COLUMNS_WIDTH
+}
+
+/// Widths for company, role, domain and status given the list's inner width.
+/// Never adds up to more than what's left after `ROW_OVERHEAD`.
+pub fn column_widths(total_width: u16) -> (usize, usize, usize, usize) {
+    let content_width = (total_width as usize).saturating_sub(ROW_OVERHEAD);
+
+    if content_width == 0 {
+        return (0, 0, 0, 0);
+    }
+
+    let min_company = 10usize;
+    let min_role = 10usize;
+    let min_link = 12usize;
+    let min_status = 10usize;
+    let min_total = min_company + min_role + min_link + min_status;
+
+    if content_width < min_total {
+        let weights = [4usize, 4usize, 3usize, 2usize];
+        let weight_sum: usize = weights.iter().sum();
+        let mut widths = [
+            (content_width * weights[0]) / weight_sum,
+            (content_width * weights[1]) / weight_sum,
+            (content_width * weights[2]) / weight_sum,
+            0,
+        ];
+        widths[3] = content_width - (widths[0] + widths[1] + widths[2]);
+
+        // Keep every column readable while there's room for it, then give back
+        // whatever the floor borrowed, domain first
+        let floor = if content_width >= 4 * 3 { 3 } else { 1.min(content_width / 4) };
+        for width in widths.iter_mut() {
+            *width = (*width).max(floor);
+        }
+        let mut overflow = widths.iter().sum::<usize>().saturating_sub(content_width);
+        for i in [2, 3, 1, 0] {
+            let take = overflow.min(widths[i] - floor);
+            widths[i] -= take;
+            overflow -= take;
+        }
+
+        return (widths[0], widths[1], widths[2], widths[3]);
+    }
+
+    // Domains are short, so give most of the extra room to company/role
+    let extra = content_width - min_total;
+    let company = min_company + (extra * 4 / 10);
+    let role = min_role + (extra * 4 / 10);
+    let mut link = (min_link + (extra / 10)).min(24);
+    let mut status = content_width.saturating_sub(company + role + link);
+
+    if status < min_status {
+        let deficit = min_status - status;
+        let take = deficit.min(link.saturating_sub(min_link));
+        link = link.saturating_sub(take);
+        status = content_width.saturating_sub(company + role + link);
+    }
+
+    (company, role, link, status)
+}
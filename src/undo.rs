@@ -0,0 +1,167 @@
+//! `career-cli undo`: take back the most recent save, whenever it happened.
+//! Every save is already in the journal as a group of entries sharing a
+//! timestamp and device, so undoing is applying one group backwards. The reverting
+//! changes are saved like any other, followed by an undo marker so the next
+//! undo goes one save further back instead of redoing.
+
+use crate::journal::{JournalEntry, JournalOp};
+use crate::journal::{same_id, BOOKKEEPING};
+use crate::models::{Job, JobId};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// The entries of one save
+pub struct Operation<'a> {
+    pub timestamp: DateTime<Utc>,
+    pub device_id: &'a str,
+    pub entries: Vec<&'a JournalEntry>,
+}
+
+/// The newest save that hasn't been undone and isn't itself an undo. The
+/// journal's first save is where replay starts from and is never offered.
+pub fn last_undoable(journal: &[JournalEntry]) -> Option<Operation<'_>> {
+    // A save is its time and the device that made it, since two devices can
+    // save at the same moment. Old markers didn't say whose save they undid
+    // (None), so those go by the time alone.
+    let mut skip: HashSet<(DateTime<Utc>, Option<&str>)> = HashSet::new();
+    for marker in journal.iter().filter(|e| e.op == JournalOp::Undo) {
+        skip.insert((marker.timestamp, Some(marker.device_id.as_str())));
+        skip.extend(marker.undoes.map(|undone| (undone, marker.undoes_device.as_deref())));
+    }
+
+    let mut groups: Vec<Operation> = Vec::new();
+    for entry in journal.iter().filter(|e| e.op != JournalOp::Undo) {
+        match groups.last_mut() {
+            Some(group) if group.timestamp == entry.timestamp && group.device_id == entry.device_id => group.entries.push(entry),
+            _ => groups.push(Operation { timestamp: entry.timestamp, device_id: &entry.device_id, entries: vec![entry] }),
+        }
+    }
+    groups
+        .into_iter()
+        .skip(1)
+        .rev()
+        .find(|group| !skip.contains(&(group.timestamp, Some(group.device_id))) && !skip.contains(&(group.timestamp, None)))
+}
+
+impl Operation<'_> {
+    /// What undoing it will do, one line per change, e.g.
+    /// "Acme - Engineer: status Rejected -> Interviewing"
+    pub fn describe(&self, jobs: &[Job]) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|e| !e.field.as_deref().is_some_and(|f| BOOKKEEPING.contains(&f)))
+            .map(|entry| {
+                let name = name(jobs, entry);
+                match entry.op {
+                    JournalOp::Create => format!("{}: remove, it was added then", name),
+                    JournalOp::Delete => format!("{}: bring back, it was deleted then", name),
+                    _ => format!(
+                        "{}: {} {} -> {}",
+                        name,
+                        entry.field.as_deref().unwrap_or("?"),
+                        short(entry.new.as_ref()),
+                        short(entry.old.as_ref())
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Apply the operation backwards to `jobs`. Changes that were edited
+    /// again since are left alone and come back as warnings.
+    pub fn revert(&self, jobs: &mut Vec<Job>) -> Result<Vec<String>> {
+        let mut warnings = Vec::new();
+        for entry in self.entries.iter().rev() {
+            let position = jobs.iter().position(|j| same_id(&j.id.to_string(), &entry.job_id));
+            match (&entry.op, position) {
+                (JournalOp::Create, Some(i)) => {
+                    if same_job(&jobs[i], entry.new.as_ref())? {
+                        jobs.remove(i);
+                    } else {
+                        warnings.push(format!("{} changed after it was added, kept it", name(jobs, entry)));
+                    }
+                }
+                (JournalOp::Delete, None) => {
+                    let Some(Value::Object(old)) = &entry.old else { continue };
+                    let mut job: Job = serde_json::from_value(Value::Object(old.clone())).context("Deleted job is unreadable")?;
+                    // Something else got its id in the meantime
+                    if jobs.iter().any(|j| j.id == job.id) {
+                        job.id = JobId::new();
+                    }
+                    jobs.push(job);
+                }
+                (JournalOp::Update, Some(i)) => {
+                    let Some(field) = entry.field.as_deref().filter(|f| !BOOKKEEPING.contains(f)) else { continue };
+                    let mut current = to_map(&jobs[i])?;
+                    if current.get(field) != entry.new.as_ref() {
+                        warnings.push(format!("{}: {} was changed again since, kept it", name(jobs, entry), field));
+                        continue;
+                    }
+                    match &entry.old {
+                        Some(old) => current.insert(field.to_string(), old.clone()),
+                        None => current.remove(field),
+                    };
+                    jobs[i] = serde_json::from_value(Value::Object(current)).context("Failed to undo the change")?;
+                }
+                (JournalOp::Update, None) => {
+                    warnings.push(format!("{} has been deleted since, nothing to undo", name(jobs, entry)));
+                }
+                _ => {}
+            }
+        }
+        Ok(warnings)
+    }
+}
+
+fn to_map(job: &Job) -> Result<Map<String, Value>> {
+    match serde_json::to_value(job).context("Failed to serialize job")? {
+        Value::Object(map) => Ok(map),
+        _ => bail!("Job did not serialize to an object"),
+    }
+}
+
+/// Whether the job is still as it was created, not counting BOOKKEEPING
+fn same_job(job: &Job, created: Option<&Value>) -> Result<bool> {
+    let Some(Value::Object(created)) = created else { return Ok(false) };
+    let mut current = to_map(job)?;
+    let mut created = created.clone();
+    for field in BOOKKEEPING {
+        current.remove(*field);
+        created.remove(*field);
+    }
+    Ok(current == created)
+}
+
+/// "Acme - Engineer", from the job now or from the journal for deleted ones
+fn name(jobs: &[Job], entry: &JournalEntry) -> String {
+    if let Some(job) = jobs.iter().find(|j| same_id(&j.id.to_string(), &entry.job_id)) {
+        return format!("{} - {}", job.company, job.role);
+    }
+    let text = |key: &str| {
+        entry
+            .old
+            .as_ref()
+            .or(entry.new.as_ref())
+            .and_then(|job| job.get(key)?.as_str().map(str::to_string))
+            .unwrap_or_default()
+    };
+    match (text("company"), text("role")) {
+        (company, role) if !company.is_empty() => format!("{} - {}", company, role),
+        _ => format!("job {}", entry.job_id),
+    }
+}
+
+/// A value short enough for one preview line
+fn short(value: Option<&Value>) -> String {
+    let text = match value {
+        None | Some(Value::Null) => "-".to_string(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    };
+    match text.char_indices().nth(40) {
+        Some((i, _)) => format!("{}...", &text[..i]),
+        None => text,
+    }
+}
@@ -0,0 +1,150 @@
+//! How I use the tool, kept in usage.jsonl in the data dir and nowhere
+//! else: when the TUI was opened, which commands ran (never their
+//! arguments) and which screens I looked at. Shown with 'u' in the TUI and
+//! `career-cli usage`, next to how many applications went out, because a
+//! week of checking the list without applying is worth noticing.
+//!
+//! Turned off with `[usage] track = false`; what's already recorded stays
+//! until usage.jsonl is deleted.
+
+use crate::models::Job;
+use crate::storage::get_data_dir;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Weeks the summary goes back
+const WEEKS_SHOWN: i64 = 8;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageKind {
+    Session, // The TUI was opened
+    Command, // A CLI command ran
+    View,    // A screen was opened in the TUI
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsageEvent {
+    pub at: DateTime<Utc>,
+    pub kind: UsageKind,
+    pub name: String,
+}
+
+impl UsageEvent {
+    pub fn now(kind: UsageKind, name: &str) -> UsageEvent {
+        UsageEvent { at: Utc::now(), kind, name: name.to_string() }
+    }
+}
+
+fn usage_path() -> Result<PathBuf> {
+    Ok(get_data_dir()?.join("usage.jsonl"))
+}
+
+pub fn append_usage(events: &[UsageEvent]) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(usage_path()?)
+        .context("Failed to open usage.jsonl")?;
+    for event in events {
+        let line = serde_json::to_string(event).context("Failed to serialize usage")?;
+        writeln!(file, "{}", line).context("Failed to write usage.jsonl")?;
+    }
+    Ok(())
+}
+
+/// Everything recorded, oldest first. Lines that don't read are skipped,
+/// it's only ever a summary.
+pub fn read_usage() -> Result<Vec<UsageEvent>> {
+    let path = usage_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).context("Failed to read usage.jsonl")?;
+    Ok(content.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+}
+
+/// What the usage screen shows
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub weeks: Vec<(NaiveDate, usize, usize)>, // Monday, sessions, applications; newest first
+    pub views: Vec<(String, usize)>,           // Most used first
+    pub commands: Vec<(String, usize)>,
+    pub days_away: Option<i64>, // Between the last two sessions
+}
+
+pub fn summarize(events: &[UsageEvent], jobs: &[Job], today: NaiveDate) -> Summary {
+    let this_week = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let weeks = (0..WEEKS_SHOWN)
+        .map(|back| {
+            let monday = this_week - Duration::weeks(back);
+            let within = |at: DateTime<Utc>| {
+                let day = at.date_naive();
+                day >= monday && day < monday + Duration::weeks(1)
+            };
+            let sessions = events.iter().filter(|e| e.kind == UsageKind::Session && within(e.at)).count();
+            let applied = jobs.iter().filter(|j| !j.draft && within(j.date_applied)).count();
+            (monday, sessions, applied)
+        })
+        .collect();
+
+    let mut sessions: Vec<NaiveDate> = events.iter().filter(|e| e.kind == UsageKind::Session).map(|e| e.at.date_naive()).collect();
+    sessions.sort();
+    let days_away = match sessions.as_slice() {
+        [.., before, last] => Some((*last - *before).num_days()),
+        _ => None,
+    };
+    Summary { weeks, views: ranked(events, UsageKind::View), commands: ranked(events, UsageKind::Command), days_away }
+}
+
+/// Names of `kind` by how often they come up
+fn ranked(events: &[UsageEvent], kind: UsageKind) -> Vec<(String, usize)> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for event in events.iter().filter(|e| e.kind == kind) {
+        *counts.entry(event.name.as_str()).or_default() += 1;
+    }
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().map(|(name, n)| (name.to_string(), n)).collect();
+    ranked.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+    ranked
+}
+
+impl Summary {
+    /// The last two weeks had sessions but no applications
+    pub fn avoiding(&self) -> bool {
+        let recent = &self.weeks[..2.min(self.weeks.len())];
+        recent.iter().map(|w| w.1).sum::<usize>() >= 3 && recent.iter().all(|w| w.2 == 0)
+    }
+
+    /// Things worth noticing, shown above the rest
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.avoiding() {
+            warnings.push("Opened plenty, applied nowhere in two weeks. Time to send one?".to_string());
+        }
+        if let Some(days) = self.days_away.filter(|d| *d >= 7) {
+            warnings.push(format!("Back after {} days away", days));
+        }
+        warnings
+    }
+
+    /// Week by week, then the most used screens and commands
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("{:<12} {:>8} {:>8}", "Week of", "Sessions", "Applied")];
+        for (monday, sessions, applied) in &self.weeks {
+            lines.push(format!("{:<12} {:>8} {:>8}", monday, sessions, applied));
+        }
+        let top = |counts: &[(String, usize)]| {
+            let shown: Vec<String> = counts.iter().take(6).map(|(name, n)| format!("{} {}", name, n)).collect();
+            if shown.is_empty() { "none yet".to_string() } else { shown.join(", ") }
+        };
+        lines.push(String::new());
+        lines.push(format!("Most used screens: {}", top(&self.views)));
+        lines.push(format!("Commands: {}", top(&self.commands)));
+        lines
+    }
+}
@@ -0,0 +1,82 @@
+//! Rules a job's fields have to follow. The edit prompts check what's typed
+//! against them as I go, and `career-cli doctor` runs them over everything
+//! already saved, since older entries and imports never went through the
+//! prompts.
+
+use crate::config::ValidationConfig;
+use crate::models::Job;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use url::Url;
+
+pub fn company(input: &str) -> Result<()> {
+    if input.trim().is_empty() {
+        bail!("Company can't be empty");
+    }
+    Ok(())
+}
+
+/// Empty is fine, the link is optional. Otherwise it has to parse, with or
+/// without the scheme, and have a real host.
+pub fn link(input: &str) -> Result<()> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(());
+    }
+    let parsed = Url::parse(input).or_else(|_| Url::parse(&format!("https://{}", input)));
+    match parsed.ok().filter(|url| matches!(url.scheme(), "http" | "https")).and_then(|url| url.host_str().map(str::to_string)) {
+        Some(host) if host.contains('.') || host == "localhost" => Ok(()),
+        _ => bail!("'{}' isn't a link", input),
+    }
+}
+
+/// Salary ends and contract rates
+pub fn pay(amounts: &[Option<f64>]) -> Result<()> {
+    if amounts.iter().flatten().any(|amount| *amount <= 0.0) {
+        bail!("Pay has to be more than 0");
+    }
+    Ok(())
+}
+
+/// Things that already happened, like applying or hearing back. Allowed
+/// in the future with `allow_future_dates`, for a clock that's behind.
+pub fn past(what: &str, on: NaiveDate, today: NaiveDate, config: &ValidationConfig) -> Result<()> {
+    if !config.allow_future_dates && on > today {
+        bail!("{} is in the future ({})", what, on.format("%Y-%m-%d"));
+    }
+    Ok(())
+}
+
+/// The day `at` fell on here, to hold against a local `today`. A change
+/// made this evening is already tomorrow in UTC west of Greenwich.
+fn local_day(at: DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&Local).date_naive()
+}
+
+/// Every rule `job` breaks, as "link: 'htps//x' isn't a link, ..."
+pub fn job(job: &Job, config: &ValidationConfig, today: NaiveDate) -> Vec<String> {
+    let salary = [job.salary_min, job.salary_max].map(|amount| amount.map(f64::from));
+    let rate = job.contract.as_ref().map(|terms| terms.rate);
+    let mut checks = vec![
+        ("link", link(&job.post_link)),
+        ("pay", pay(&salary)),
+        ("rate", pay(&[rate.flatten()])),
+        ("applied", past("Applied date", local_day(job.date_applied), today, config)),
+    ];
+    // Drafts are allowed to be missing their company until they're finished
+    if !job.draft {
+        checks.insert(0, ("company", company(&job.company)));
+    }
+    for (what, at) in [("Response", job.responded_at), ("Rejection", job.rejected_at)] {
+        if let Some(at) = at {
+            checks.push(("dates", past(&format!("{} date", what), local_day(at), today, config)));
+        }
+    }
+    for change in &job.history {
+        checks.push(("history", past(&format!("Change to {}", change.to), local_day(change.at), today, config)));
+    }
+    checks
+        .into_iter()
+        .filter_map(|(field, result)| result.err().map(|e| format!("{}: {}", field, e)))
+        .collect()
+}
@@ -0,0 +1,3009 @@
+use career_cli::app::App;
+use career_cli::config::Config;
+use career_cli::fixtures::{sample_jobs, JobBuilder};
+use career_cli::models::{JobId, Status};
+use career_cli::storage::{JobDirStorage, MemoryStorage, Storage};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+fn app_with(storage: &MemoryStorage) -> App {
+    App::new(Box::new(storage.clone()), Vec::new(), Config::default()).unwrap()
+}
+
+fn press(app: &mut App, code: KeyCode) {
+    app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c));
+    }
+    press(app, KeyCode::Enter);
+}
+
+#[test]
+fn add_form_saves_a_new_job() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Wayne Enterprises");
+    type_text(&mut app, "Security Engineer");
+    type_text(&mut app, "https://jobs.lever.co/wayne/1");
+    type_text(&mut app, "150k-120k");
+    assert!(app.message.is_some(), "min above max is rejected");
+    app.input_buffer.clear();
+    type_text(&mut app, "$120,000 - 150k");
+    type_text(&mut app, "Gotham, hybrid");
+    app.save().unwrap();
+
+    let saved = storage.jobs();
+    assert_eq!(saved.len(), 6);
+    let job = saved.last().unwrap();
+    assert_eq!(job.company, "Wayne Enterprises");
+    assert!(saved[..5].iter().all(|other| other.id != job.id));
+    assert_eq!(job.link_domain().as_deref(), Some("lever.co"));
+    assert_eq!((job.salary_min, job.salary_max), (Some(120_000), Some(150_000)));
+    assert_eq!(job.salary_label().as_deref(), Some("$120k-150k"));
+    assert_eq!(job.location_label().as_deref(), Some("Gotham (hybrid)"));
+}
+
+#[test]
+fn delete_removes_the_selected_job() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('d'));
+    app.save().unwrap();
+
+    let companies: Vec<String> = storage.jobs().into_iter().map(|j| j.company).collect();
+    assert_eq!(companies, ["Acme", "Initech", "Hooli", "Umbrella"]);
+}
+
+#[test]
+fn closing_as_rejected_starts_a_cooldown() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").status(Status::Accepted).build()]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('Z'));
+    press(&mut app, KeyCode::Char('-'));
+
+    let job = &app.jobs[0];
+    assert_eq!(job.status, Status::Rejected);
+    assert!(job.reapply_after().is_some());
+}
+
+#[test]
+fn enter_stops_at_a_terminal_status_and_minus_closes_or_reopens() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").status(Status::Interviewing).build()]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].status, Status::Accepted);
+    press(&mut app, KeyCode::Char('Z'));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].status, Status::Accepted, "an offer doesn't roll over into a rejection");
+    assert!(app.message.as_deref().unwrap().contains("final"));
+
+    press(&mut app, KeyCode::Char('-'));
+    press(&mut app, KeyCode::Esc); // Skips the rejection questions
+    press(&mut app, KeyCode::Char('-'));
+    press(&mut app, KeyCode::Char('-'));
+    assert_eq!(app.jobs[0].status, Status::Withdrawn);
+    assert!(app.jobs[0].status.is_terminal() && !app.jobs[0].is_open());
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].status, Status::Withdrawn);
+    assert!(career_cli::scope::is_withdrawn(&app.jobs[0]));
+    assert!(career_cli::filter::Filter::parse("status:withdrawn", &[]).unwrap().matches(&app.jobs[0]));
+
+    press(&mut app, KeyCode::Char('-'));
+    assert_eq!(app.jobs[0].status, Status::Applied);
+    let path: Vec<&str> = app.jobs[0].history.iter().map(|c| c.to.as_str()).collect();
+    assert_eq!(path, ["Offer", "Accepted", "Rejected", "Ghosted", "Withdrawn", "Applied"]);
+}
+
+#[test]
+fn minus_goes_through_my_closing_statuses_before_the_built_in_ones() {
+    let config: Config = toml::from_str(
+        r#"
+        [[statuses]]
+        name = "Declined"
+        counts_as = "Rejected"
+
+        [[statuses]]
+        name = "Ghosted"
+
+        [[statuses]]
+        name = "Position filled"
+        counts_as = "Withdrawn"
+        "#,
+    )
+    .unwrap();
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").status(Status::Interviewing).build()]);
+    let mut app = App::new(Box::new(storage.clone()), Vec::new(), config).unwrap();
+    press(&mut app, KeyCode::Char('Z'));
+    press(&mut app, KeyCode::Char('Z')); // Active and closed jobs both
+    app.state.select(Some(0));
+
+    press(&mut app, KeyCode::Char('-'));
+    assert_eq!((app.jobs[0].status_label(), &app.jobs[0].status), ("Declined".to_string(), &Status::Rejected));
+    press(&mut app, KeyCode::Esc); // Skips the rejection questions
+    for _ in 0..3 {
+        press(&mut app, KeyCode::Char('-'));
+    }
+    assert_eq!(app.jobs[0].status_label(), "Rejected", "then the built-ins the list doesn't name");
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('-'));
+    press(&mut app, KeyCode::Char('-'));
+    let path: Vec<&str> = app.jobs[0].history.iter().map(|c| c.to.as_str()).collect();
+    assert_eq!(path, ["Declined", "Ghosted", "Position filled", "Rejected", "Withdrawn", "Applied"]);
+
+    let figures: Vec<String> = app.stat_figures().into_iter().take(3).map(|f| f.label).collect();
+    assert_eq!(figures, ["Total 1", "Active 1", "Closed 0"]);
+}
+
+#[test]
+fn the_list_shows_active_jobs_until_toggled_to_closed_ones() {
+    use career_cli::models::Pipeline;
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    let companies = |app: &App| -> Vec<String> { app.visible.iter().map(|&i| app.jobs[i].company.clone()).collect() };
+
+    assert_eq!(companies(&app), ["Acme", "Globex", "Initech"]);
+    assert!(app.jobs.iter().all(|j| (j.pipeline() == Pipeline::Closed) == j.status.is_terminal()));
+
+    press(&mut app, KeyCode::Char('Z'));
+    assert_eq!(companies(&app), ["Hooli", "Umbrella"]);
+    press(&mut app, KeyCode::Char('Z'));
+    assert_eq!(app.visible.len(), 5);
+    press(&mut app, KeyCode::Char('Z'));
+    assert!(app.show_archived && app.message.as_deref().unwrap().contains("archived ones too"));
+    press(&mut app, KeyCode::Char('Z'));
+    assert_eq!((app.pipeline, app.show_archived), (Some(Pipeline::Active), false));
+
+    // Closing a job takes it off the active list, with a word on where it went
+    app.state.select(Some(1));
+    press(&mut app, KeyCode::Char('-'));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(companies(&app), ["Acme", "Initech"]);
+    app.state.select(Some(1));
+    press(&mut app, KeyCode::Enter);
+    assert!(app.message.as_deref().unwrap().contains("Initech - Platform Engineer is closed now"));
+    assert_eq!(companies(&app), ["Acme"]);
+
+    // A typed filter looks through both sides
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "company:hooli");
+    assert_eq!(app.pipeline, None);
+    assert_eq!(companies(&app), ["Hooli"]);
+
+    // A number in the stats view counts both sides, and so does the list it opens
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.pipeline, None);
+    assert_eq!(companies(&app), ["Acme"]);
+}
+
+#[test]
+fn quick_note_appends_a_line() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('N'));
+    type_text(&mut app, "Recruiter called");
+
+    assert!(app.jobs[0].notes.ends_with("] Recruiter called"));
+}
+
+#[test]
+fn saving_keeps_changes_from_another_session() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    // Another session appends a note to Globex while this one cycles Acme's status
+    let mut other = app_with(&storage);
+    other.jobs[1].append_note("Phone screen booked");
+    other.save().unwrap();
+
+    press(&mut app, KeyCode::Enter);
+    app.save().unwrap();
+
+    let saved = storage.jobs();
+    assert_eq!(saved[0].status, Status::Interviewing);
+    assert!(saved[1].notes.contains("Phone screen booked"));
+    assert_eq!(saved[0].revision, 1);
+    assert_eq!(saved[1].revision, 1);
+}
+
+#[test]
+fn saves_stamp_what_they_change_and_the_list_can_sort_by_it() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    assert!(app.jobs.iter().all(|j| j.created_at.is_none() && j.updated_at.is_none()));
+
+    app.jobs[2].append_note("Sent portfolio");
+    app.save().unwrap();
+    let saved = storage.jobs();
+    let stamped = saved[2].updated_at.expect("changed, so stamped");
+    assert!(saved[0].updated_at.is_none(), "untouched jobs keep theirs");
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Stark");
+    type_text(&mut app, "Engineer");
+    // Link, salary, location and source left empty
+    for _ in 0..4 {
+        press(&mut app, KeyCode::Enter);
+    }
+    // Sorted by priority, then by last change: the unsaved job first, then the note
+    press(&mut app, KeyCode::Char('='));
+    press(&mut app, KeyCode::Char('='));
+    assert_eq!(app.sort, career_cli::config::SortOrder::Touched);
+    let order: Vec<&str> = app.visible.iter().take(2).map(|&i| app.jobs[i].company.as_str()).collect();
+    assert_eq!(order, ["Stark", "Initech"]);
+
+    app.save().unwrap();
+    let stark = storage.jobs().into_iter().find(|j| j.company == "Stark").unwrap();
+    assert!(stark.created_at.is_some() && stark.created_at == stark.updated_at);
+    assert!(stark.created_at.unwrap() >= stamped);
+}
+
+#[test]
+fn saving_rejects_clashing_edits() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    let mut other = app_with(&storage);
+    other.jobs[0].notes = "theirs".to_string();
+    other.save().unwrap();
+
+    app.jobs[0].notes = "mine".to_string();
+    assert!(app.save().is_err());
+    assert_eq!(storage.jobs()[0].notes, "theirs");
+}
+
+#[test]
+fn bulk_edit_only_touches_the_filtered_rows_after_confirming() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "status:applied OR status:ghosted");
+    assert_eq!(app.visible.len(), 2);
+
+    // Anything but 'y' backs out
+    press(&mut app, KeyCode::Char('B'));
+    type_text(&mut app, "status=Rejected");
+    press(&mut app, KeyCode::Char('n'));
+    assert!(app.jobs.iter().all(|j| j.status != Status::Rejected || j.company == "Hooli"));
+
+    // Saved there and then, as a save of its own
+    press(&mut app, KeyCode::Char('B'));
+    type_text(&mut app, "status=Rejected");
+    press(&mut app, KeyCode::Char('y'));
+    assert_eq!(app.message.as_deref(), Some(" Updated 2 jobs "));
+
+    let statuses: Vec<Status> = storage.jobs().into_iter().map(|j| j.status).collect();
+    assert_eq!(
+        statuses,
+        [Status::Rejected, Status::Interviewing, Status::Offer, Status::Rejected, Status::Rejected]
+    );
+    // Rejected jobs drop out of the filter
+    assert!(app.visible.is_empty());
+}
+
+#[test]
+fn filter_values_can_hold_comparison_signs() {
+    use career_cli::filter::Filter;
+    let job = JobBuilder::new("Acme", "Engineer").notes("salary<150k, wants a=b testing").build();
+    assert!(Filter::parse("notes:salary<150k", &[]).unwrap().matches(&job));
+    assert!(Filter::parse("notes:a=b", &[]).unwrap().matches(&job));
+    assert!(Filter::parse("applied>=2000-01-01", &[]).unwrap().matches(&job));
+    assert!(Filter::parse("company<acme", &[]).is_err(), "only dates compare");
+}
+
+#[test]
+fn bulk_archiving_leaves_open_jobs_in_the_list() {
+    use career_cli::bulk::{preview, Assignment};
+    let archive = Assignment::parse("archived=yes").unwrap();
+    let mut open = JobBuilder::new("Acme", "Engineer").build();
+    assert!(!archive.apply(&mut open));
+    assert!(!open.archived, "only closed jobs can be archived, in bulk too");
+
+    let jobs = vec![open];
+    assert!(preview(&jobs, &[0], std::slice::from_ref(&archive))[0].contains("archived: no -> no, still open"));
+    let close_first = Assignment::parse_list("status=rejected, archived=yes").unwrap();
+    assert!(preview(&jobs, &[0], &close_first)[0].contains("archived: no -> yes"));
+}
+
+#[test]
+fn bulk_edit_adds_removes_and_replaces_tags() {
+    use career_cli::bulk::Assignment;
+    let mut job = JobBuilder::new("Acme", "Engineer").build();
+    job.tags = vec!["remote".to_string(), "referral".to_string()];
+
+    let add = Assignment::parse_list("tag+=#Dream remote").unwrap();
+    assert_eq!(add[0].describe(&job), "tags: #remote #referral -> #remote #referral #dream");
+    add[0].apply(&mut job);
+    Assignment::parse("tag-=referral").unwrap().apply(&mut job);
+    assert_eq!(job.tags, ["remote", "dream"]);
+    Assignment::parse("tags=onsite").unwrap().apply(&mut job);
+    assert_eq!(job.tags, ["onsite"]);
+    Assignment::parse("tags=").unwrap().apply(&mut job);
+    assert!(job.tags.is_empty());
+    assert!(Assignment::parse("tag+=").is_err());
+}
+
+#[test]
+fn tab_completes_known_companies_and_common_titles() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('a'));
+    for c in "glo".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(app.input_buffer, "Globex");
+    press(&mut app, KeyCode::Enter);
+
+    for c in "site r".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(app.input_buffer, "Site Reliability Engineer");
+}
+
+#[test]
+fn focused_detail_pane_scrolls_instead_of_moving_the_selection() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('v'));
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.selected_index(), Some(1));
+
+    press(&mut app, KeyCode::Tab);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.selected_index(), Some(1));
+    assert_eq!(app.detail_scroll, 2);
+
+    // Back in the list, moving on starts the next job's details at the top
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.selected_index(), Some(2));
+    assert_eq!(app.detail_scroll, 0);
+}
+
+#[test]
+fn session_summary_counts_what_changed_since_opening() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    assert_eq!(app.session_summary(), None);
+
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Wayne Enterprises");
+    type_text(&mut app, "Engineer");
+    type_text(&mut app, "");
+    type_text(&mut app, "");
+    type_text(&mut app, "");
+    app.save().unwrap();
+
+    assert_eq!(app.session_summary().as_deref(), Some("Added 1 job, 1 status change"));
+}
+
+#[test]
+fn session_summary_counts_follow_ups_and_names_the_next_interview() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    let in_three_days = chrono::Local::now().date_naive() + chrono::Duration::days(3);
+    app.jobs[0].follow_up = Some(chrono::Utc::now() + chrono::Duration::days(2));
+    app.jobs[0].interviews.push(career_cli::models::Interview::parse(
+        &format!("{} 14:00 Onsite", in_three_days),
+        in_three_days,
+    ));
+    let company = app.jobs[0].company.clone();
+    app.save().unwrap();
+
+    assert_eq!(
+        app.session_summary(),
+        Some(format!("1 follow-up scheduled - next interview: {}, {} 14:00", company, in_three_days.format("%A")))
+    );
+}
+
+/// Storage that can be read but never written, like a read-only disk
+struct ReadOnlyStorage;
+
+impl career_cli::storage::Storage for ReadOnlyStorage {
+    fn load(&self) -> anyhow::Result<Vec<career_cli::models::Job>> {
+        Ok(sample_jobs())
+    }
+
+    fn save(&self, _jobs: &[career_cli::models::Job]) -> anyhow::Result<()> {
+        anyhow::bail!("Read-only file system")
+    }
+}
+
+#[test]
+fn failed_save_on_quit_keeps_the_tui_open() {
+    let mut app = App::new(Box::new(ReadOnlyStorage), Vec::new(), Config::default()).unwrap();
+    press(&mut app, KeyCode::Enter);
+
+    press(&mut app, KeyCode::Char('q'));
+    assert!(!app.should_quit);
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::SaveFailed));
+    assert!(app.save_error.as_deref().unwrap().contains("Read-only"));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rescue.json");
+    press(&mut app, KeyCode::Char('a'));
+    app.input_buffer.clear();
+    type_text(&mut app, path.to_str().unwrap());
+    assert!(app.should_quit);
+
+    let rescued: Vec<career_cli::models::Job> = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(rescued[0].status, Status::Interviewing);
+}
+
+#[test]
+fn merge_keeps_jobs_both_sides_added() {
+    let base = vec![JobBuilder::new("Acme", "Engineer").id(1).build()];
+    let mut ours = base.clone();
+    ours.push(JobBuilder::new("Globex", "Engineer").id(2).build());
+    let mut disk = base.clone();
+    // The other session happened to pick the same id for its own new job
+    disk.push(JobBuilder::new("Initech", "Engineer").id(2).build());
+    disk.push(JobBuilder::new("Hooli", "Engineer").id(3).build());
+
+    let merged = career_cli::storage::merge(&base, &ours, &disk).unwrap();
+    let mut companies: Vec<&str> = merged.iter().map(|job| job.company.as_str()).collect();
+    companies.sort();
+    assert_eq!(companies, ["Acme", "Globex", "Hooli", "Initech"]);
+    let ids: std::collections::HashSet<JobId> = merged.iter().map(|job| job.id).collect();
+    assert_eq!(ids.len(), 4);
+}
+
+#[test]
+fn job_dir_keeps_jobs_whose_short_ids_match_apart() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = JobDirStorage::new(dir.path().join("jobs"));
+    let mut first = JobBuilder::new("Acme", "Engineer").build();
+    first.id = "3f2a1b4c-0000-4000-8000-000000000001".parse().unwrap();
+    let mut second = JobBuilder::new("Globex", "Engineer").build();
+    second.id = "3f2a1b4c-0000-4000-8000-000000000002".parse().unwrap();
+    assert_eq!(first.short_id(), second.short_id());
+
+    storage.save(&[first, second]).unwrap();
+    let loaded = storage.load().unwrap();
+    assert_eq!(loaded.iter().map(|job| job.company.as_str()).collect::<Vec<_>>(), ["Acme", "Globex"]);
+}
+
+#[test]
+fn keymap_from_config_rebinds_actions_and_rejects_clashes() {
+    let mut config = Config::default();
+    config.keymap.insert("down".into(), vec!["j".into()]);
+    let mut app = App::new(Box::new(MemoryStorage::new(sample_jobs())), Vec::new(), config.clone()).unwrap();
+
+    press(&mut app, KeyCode::Char('j'));
+    assert_eq!(app.selected_index(), Some(1));
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.selected_index(), Some(1));
+
+    config.keymap.insert("delete".into(), vec!["j".into()]);
+    let err = App::new(Box::new(MemoryStorage::new(sample_jobs())), Vec::new(), config).err().unwrap();
+    assert!(err.to_string().contains("bound to both"));
+}
+
+#[test]
+fn season_mode_tags_new_jobs_and_templates_start_with_an_oa() {
+    let mut config = Config::default();
+    config.season.enabled = true;
+    config.season.cycle = Some("Summer 2026 Internship".into());
+    let storage = MemoryStorage::new(Vec::new());
+    let mut app = App::new(Box::new(storage.clone()), Vec::new(), config).unwrap();
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Acme");
+    type_text(&mut app, "SWE Intern");
+    type_text(&mut app, "");
+    type_text(&mut app, "");
+    type_text(&mut app, "");
+    type_text(&mut app, "");
+    press(&mut app, KeyCode::Char('w'));
+    type_text(&mut app, "2026-10-31");
+
+    let internship = app.config.templates().into_iter().find(|t| t.name == "Internship").unwrap();
+    app.jobs[0].apply_template(&internship);
+    app.save().unwrap();
+
+    let job = &storage.jobs()[0];
+    assert_eq!(job.cycle.as_deref(), Some("Summer 2026 Internship"));
+    assert_eq!(job.deadline, chrono::NaiveDate::from_ymd_opt(2026, 10, 31));
+    assert_eq!(job.pending_assessment().unwrap().name, "OA");
+    assert!(career_cli::filter::Filter::parse("cycle:summer deadline<=2026-10-31", &[]).unwrap().matches(job));
+}
+
+#[test]
+fn oa_invites_show_on_the_agenda_until_taken() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "SWE Intern").stages(&["OA", "Recruiter call"], 0).build()]);
+    let mut app = app_with(&storage);
+    let today = chrono::Local::now().date_naive();
+
+    press(&mut app, KeyCode::Char('O'));
+    type_text(&mut app, "HackerRank 2d");
+    app.check_expiring_assessments();
+    assert!(app.message.as_deref().unwrap().contains("Acme in 2 days"));
+
+    let agenda = career_cli::models::agenda(&app.jobs);
+    assert_eq!(agenda.len(), 1);
+    assert_eq!(agenda[0].label(), "OA (HackerRank)");
+    assert_eq!(agenda[0].date, today + chrono::Days::new(2));
+
+    press(&mut app, KeyCode::Char('O'));
+    type_text(&mut app, "done");
+    app.save().unwrap();
+
+    let job = &storage.jobs()[0];
+    assert_eq!(job.assessments[0].completed, Some(today));
+    assert_eq!(job.stage_progress(), Some((1, 2)));
+    assert!(career_cli::models::agenda(&storage.jobs()).is_empty());
+}
+
+#[test]
+fn expired_oas_stop_warning() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "SWE Intern").build()]);
+    let mut app = app_with(&storage);
+    let today = chrono::Local::now().date_naive();
+
+    press(&mut app, KeyCode::Char('O'));
+    type_text(&mut app, &format!("HackerRank {}", today - chrono::Days::new(3)));
+    app.message = None;
+    app.check_expiring_assessments();
+    assert_eq!(app.message, None);
+    assert!(career_cli::models::expiring_assessments(&app.jobs, today, 3).is_empty());
+    assert_eq!(career_cli::models::agenda(&app.jobs).len(), 1);
+}
+
+#[test]
+fn second_agency_for_a_company_warns_of_double_submission() {
+    let mut hays = JobBuilder::new("Acme Inc", "Backend Engineer").id(1).build();
+    hays.agency = Some("Hays".into());
+    let storage = MemoryStorage::new(vec![hays, JobBuilder::new("Acme", "Platform Engineer").id(2).build()]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('A'));
+    type_text(&mut app, "Robert Half");
+    assert!(app.message.as_deref().unwrap().contains("already put forward by Hays"));
+
+    let direct = career_cli::filter::Filter::parse("agency:none", &[]).unwrap();
+    let via_hays = career_cli::filter::Filter::parse("agency:hays", &[]).unwrap();
+    assert!(!app.jobs.iter().any(|j| direct.matches(j)));
+    assert_eq!(app.jobs.iter().filter(|j| via_hays.matches(j)).count(), 1);
+
+    // Same agency twice is just a second role, not a conflict
+    press(&mut app, KeyCode::Char('A'));
+    app.input_buffer.clear();
+    type_text(&mut app, "hays");
+    assert!(app.message.is_none());
+}
+
+#[test]
+fn contract_terms_are_parsed_and_dropped_when_switching_back() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('E'));
+    app.input_buffer.clear();
+    type_text(&mut app, "contract 95/h 6m 2026-11-01");
+    let job = &app.jobs[0];
+    assert_eq!(job.engagement, career_cli::contract::Engagement::Contract);
+    let terms = job.contract.as_ref().unwrap();
+    assert_eq!(terms.day_rate(), Some(760.0));
+    assert_eq!(terms.describe(), "95/h, 6 months from 2026-11-01");
+    assert!(career_cli::filter::Filter::parse("type:contract", &[]).unwrap().matches(job));
+
+    press(&mut app, KeyCode::Char('E'));
+    app.input_buffer.clear();
+    type_text(&mut app, "full-time 95/h");
+    assert!(app.message.as_deref().unwrap().contains("don't take a rate"));
+    app.input_buffer = "full-time".into();
+    press(&mut app, KeyCode::Enter);
+    assert!(app.jobs[0].contract.is_none());
+}
+
+#[test]
+fn interview_expenses_stay_outstanding_until_reimbursed() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('X'));
+    assert!(app.message.as_deref().unwrap().contains("Log the interview first"));
+
+    press(&mut app, KeyCode::Char('I'));
+    type_text(&mut app, "2026-01-05 Onsite");
+    press(&mut app, KeyCode::Char('X'));
+    type_text(&mut app, "Train ticket $84.20");
+    press(&mut app, KeyCode::Char('X'));
+    type_text(&mut app, "Parking 12");
+
+    let outstanding = career_cli::models::outstanding_expenses(&app.jobs);
+    assert_eq!(outstanding.len(), 2);
+    let total: f64 = outstanding.iter().map(|o| o.expense.amount).sum();
+    assert!((total - 96.2).abs() < 1e-9, "{}", total);
+    assert_eq!(outstanding[0].interview.name, "Onsite");
+
+    press(&mut app, KeyCode::Char('$'));
+    press(&mut app, KeyCode::Enter);
+    app.save().unwrap();
+
+    let saved = storage.jobs();
+    let expenses = &saved[0].interviews[0].expenses;
+    assert!(expenses[0].reimbursed && !expenses[1].reimbursed);
+    assert_eq!(career_cli::models::outstanding_expenses(&saved).len(), 1);
+}
+
+#[test]
+fn relocation_shows_up_in_the_offer_matrix() {
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).status(Status::Offer).build(),
+        JobBuilder::new("Globex", "SRE").id(2).status(Status::Offer).build(),
+        JobBuilder::new("Initech", "Dev").id(3).build(),
+    ]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('R'));
+    type_text(&mut app, "Seattle, $10000, 6500");
+    let today = chrono::Local::now().date_naive();
+    let matrix = career_cli::offers::comparison(&app.jobs, &Default::default(), &Default::default(), today);
+
+    assert_eq!(matrix.columns, ["Acme - Engineer", "Globex - SRE"]);
+    let row = |label: &str| matrix.rows.iter().find(|(l, _)| *l == label).unwrap().1.clone();
+    assert_eq!(row("City"), ["Seattle", "-"]);
+    assert_eq!(row("Net of move"), ["3500", "-"]);
+    assert!(matrix.lines()[0].contains("Acme - Engineer | Globex - SRE"));
+}
+
+#[test]
+fn relocation_amounts_can_group_thousands() {
+    use career_cli::models::Relocation;
+    let relocation = Relocation::parse("Seattle, $10,000, 6,500").unwrap();
+    assert_eq!((relocation.city.as_str(), relocation.package, relocation.moving_cost), ("Seattle", Some(10000.0), Some(6500.0)));
+    let relocation = Relocation::parse("Seattle,10000,650").unwrap();
+    assert_eq!((relocation.package, relocation.moving_cost), (Some(10000.0), Some(650.0)));
+    assert_eq!(Relocation::parse("Seattle, , 1,250").unwrap().moving_cost, Some(1250.0));
+}
+
+#[test]
+fn offers_in_different_currencies_compare_in_one() {
+    let mut acme = JobBuilder::new("Acme", "Engineer").id(1).status(Status::Offer).build();
+    (acme.salary_min, acme.salary_max, acme.salary_currency) = (Some(80_000), Some(100_000), Some("GBP".into()));
+    let mut globex = JobBuilder::new("Globex", "SRE").id(2).status(Status::Offer).build();
+    (globex.engagement, globex.contract) = career_cli::contract::parse("contract €650/day 6m").unwrap();
+    let terms = globex.contract.as_ref().unwrap();
+    assert_eq!(terms.currency.as_deref(), Some("EUR"));
+    assert_eq!(career_cli::contract::to_input(globex.engagement, Some(terms)), "contract €650/day 6m");
+
+    let jobs = vec![acme, globex];
+    let today = chrono::Local::now().date_naive();
+    let pay = |currency: &career_cli::config::CurrencyConfig| {
+        let matrix = career_cli::offers::comparison(&jobs, &Default::default(), currency, today);
+        let label = format!("Pay in {}", currency.base);
+        matrix.rows.iter().find(|(l, _)| *l == label).unwrap().1.clone()
+    };
+    assert_eq!(pay(&Default::default()), ["$102k-127k", "$702/day"]);
+
+    // Rates from config win over the built-in table
+    let euros = career_cli::config::CurrencyConfig {
+        base: "EUR".into(),
+        rates: [("GBP".to_string(), 1.2)].into_iter().collect(),
+    };
+    assert_eq!(pay(&euros), ["€89k-111k", "€650/day"]);
+}
+
+#[test]
+fn accepting_an_offer_starts_the_onboarding_checklist() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").status(Status::Offer).build()]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].status, Status::Accepted);
+    press(&mut app, KeyCode::Char('Z')); // Accepted is closed, off the default list
+
+    press(&mut app, KeyCode::Char('b'));
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('u'));
+    type_text(&mut app, "2026-10-25");
+    press(&mut app, KeyCode::Char('s'));
+    type_text(&mut app, "2026-11-02");
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Onboarding));
+
+    let onboarding = app.jobs[0].onboarding.clone().unwrap();
+    assert_eq!(onboarding.progress(), (1, 3));
+    assert_eq!(onboarding.checklist[1].name, "Reference checks");
+
+    let agenda = career_cli::models::agenda(&app.jobs);
+    let labels: Vec<String> = agenda.iter().map(|due| due.label()).collect();
+    assert_eq!(labels, ["Reference checks", "First day"]);
+}
+
+#[test]
+fn a_reference_in_two_open_processes_needs_asking_before_a_third() {
+    use career_cli::references::Reference;
+    let jobs = vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        JobBuilder::new("Globex", "SRE").id(2).status(Status::Interviewing).build(),
+        JobBuilder::new("Initech", "Dev").id(3).build(),
+        JobBuilder::new("Hooli", "Dev").id(4).status(Status::Rejected).build(),
+    ];
+    let day = |d: u32| chrono::NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+    let mut ana = Reference::new("Ana", "Manager at Initech", "ana@initech.com");
+    ana.give(&jobs[3], day(1));
+    ana.give(&jobs[0], day(2));
+    assert!(ana.overuse_warning(&jobs, &jobs[2]).is_none(), "closed processes don't count");
+
+    ana.give(&jobs[1], day(5));
+    ana.give(&jobs[1], day(6));
+    assert_eq!(ana.given.len(), 3);
+    assert_eq!(ana.last_used(), Some(day(5)));
+    let warning = ana.overuse_warning(&jobs, &jobs[2]).unwrap();
+    assert!(warning.contains("Acme, Globex"), "{}", warning);
+    assert!(ana.overuse_warning(&jobs, &jobs[1]).is_none(), "giving again to a job they're on isn't a new process");
+
+    ana.asked_on = Some(day(4));
+    assert!(ana.overuse_warning(&jobs, &jobs[2]).is_some(), "asked before the last use");
+    ana.asked_on = Some(day(5));
+    assert!(ana.overuse_warning(&jobs, &jobs[2]).is_none());
+}
+
+#[test]
+fn cold_outreach_is_counted_through_to_the_applications_it_led_to() {
+    use career_cli::outreach::{find, funnel, Outreach, Reply};
+    let day = |d: u32| chrono::NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+    let at = |d: u32| day(d).and_hms_opt(9, 0, 0).unwrap().and_utc();
+    let jobs = vec![
+        JobBuilder::new("Acme Inc", "Engineer").applied_at(at(8)).build(),
+        JobBuilder::new("Globex", "SRE").applied_at(at(1)).build(),
+    ];
+    let mut outreach = vec![
+        Outreach::new("Acme", "Dana Lee", day(2)),
+        Outreach::new("Globex", "Bo", day(3)),
+        Outreach::new("Initech", "Cy", day(3)),
+        Outreach::new("Hooli", "Ed", day(4)),
+    ];
+    outreach[0].referred = Some(day(6));
+    outreach[2].reply = Reply::Declined;
+
+    // Acme's application came after the message, Globex's before it
+    assert!(outreach[0].application(&jobs).is_some());
+    assert!(outreach[1].application(&jobs).is_none());
+    assert_eq!(outreach[0].progress(&jobs), format!("replied, referred 2026-10-06, applied ({})", jobs[0].short_id()));
+    assert_eq!(outreach[1].progress(&jobs), "no reply yet");
+    assert_eq!(
+        funnel(&outreach, &jobs).label(),
+        "4 sent -> 2 replied (50%) -> 1 referred (25%) -> 1 applied (25%)"
+    );
+
+    assert_eq!(find(&outreach, "globex llc").unwrap(), 1);
+    assert_eq!(find(&outreach, "dana").unwrap(), 0);
+    outreach.push(Outreach::new("Acme", "Fay", day(5)));
+    assert!(find(&outreach, "acme").unwrap_err().to_string().contains("Dana Lee at Acme or Fay at Acme"));
+}
+
+#[test]
+fn notes_editor_edits_multiple_lines_and_only_saves_on_ctrl_s() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").notes("first line").build()]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('n'));
+    press(&mut app, KeyCode::Home);
+    for c in "Recruiter: Sam".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::End);
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('x'));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.jobs[0].notes, "first line", "Esc discards");
+
+    press(&mut app, KeyCode::Char('n'));
+    press(&mut app, KeyCode::Enter);
+    for c in "second  ".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Backspace);
+    let rows = ["first ", "lne", "second", "  "].map(String::from).to_vec();
+    assert_eq!(app.notes_editor.wrap(6), (rows, (1, 1)));
+    app.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    assert_eq!(app.jobs[0].notes, "first lne\nsecond");
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Normal));
+}
+
+#[test]
+fn posting_text_is_kept_on_the_job_and_searchable() {
+    use career_cli::models::Job;
+    let job = Job::from_posting(JobId::new(), "Acme".into(), "Engineer".into(), String::new(), "\nMust know Rust\nPay: $150k-$180k\n");
+    assert_eq!(job.description, "Must know Rust\nPay: $150k-$180k");
+    assert_eq!(job.salary_min, Some(150_000));
+
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Globex", "SRE").notes("rust meetup").build()]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('J'));
+    for c in "5+ years of Rust".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    app.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    assert_eq!(app.jobs[0].description, "5+ years of Rust");
+    assert_eq!(app.jobs[0].notes, "rust meetup", "notes are left alone");
+    let sources: Vec<String> = career_cli::transcript::search(&app.jobs, "rust").into_iter().map(|h| h.source).collect();
+    assert_eq!(sources, ["notes", "posting"]);
+
+    let html = "<html><head><title>x</title><style>p { color: red }</style></head><body>\
+                <h1>Backend Engineer</h1><p>You&rsquo;ll build   APIs.</p><ul><li>Rust</li><li>SQL &amp; Postgres</li></ul>\
+                <script>track()</script></body></html>";
+    assert_eq!(career_cli::posting::html_to_text(html), "Backend Engineer\n\nYou'll build APIs.\n\n- Rust\n- SQL & Postgres");
+}
+
+#[test]
+fn text_whose_case_changes_length_inside_a_script_is_skipped_cleanly() {
+    use career_cli::posting::html_to_text;
+    assert_eq!(html_to_text("<script>\u{212A}\u{20AC}</script><p>ok</p>"), "ok");
+    assert_eq!(html_to_text(&format!("<script>{}</script><p>ok</p>", "\u{130}".repeat(30))), "ok");
+}
+
+#[test]
+fn reapplying_shows_how_the_posting_changed_since_last_time() {
+    use career_cli::posting::{diff, summary, Change};
+    let changes = diff("Build APIs\n\nRust\nGo", "Build APIs\nRust\n  Kubernetes");
+    assert_eq!(
+        changes,
+        [(Change::Same, "Build APIs"), (Change::Same, "Rust"), (Change::Added, "Kubernetes"), (Change::Removed, "Go")]
+    );
+    assert_eq!(summary(&changes), "1 line added, 1 removed");
+    assert_eq!(summary(&diff("Rust\n\nGo", "Rust\nGo\n")), "same text");
+
+    let mut before = JobBuilder::new("Acme Inc", "Backend Engineer").id(1).status(Status::Rejected).applied_days_after_epoch(0).build();
+    before.description = "Build APIs\nRust".into();
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Backend Engineer").id(2).applied_days_after_epoch(200).build(),
+        before,
+    ]);
+    let mut app = app_with(&storage);
+    assert_eq!(app.jobs[app.selected_index().unwrap()].company, "Acme");
+
+    // Pasting the new posting holds it up against the old one right away
+    press(&mut app, KeyCode::Char('J'));
+    for c in "Build APIs".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    for c in "Rust".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    app.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::PostingDiff));
+    let (earlier, changes) = app.posting_diff().unwrap();
+    assert_eq!(earlier.company, "Acme Inc");
+    assert_eq!(summary(&changes), "same text", "the same req, reposted");
+
+    // And again from the company history
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('h'));
+    press(&mut app, KeyCode::Char('d'));
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::PostingDiff));
+}
+
+#[test]
+fn postings_that_never_come_down_are_marked_evergreen() {
+    use career_cli::evergreen::is_evergreen;
+    use career_cli::fingerprint::sift;
+    let config = career_cli::config::EvergreenConfig::default();
+    let now = chrono::Utc::now();
+    let today = now.date_naive();
+
+    // Seen again in an import: noted on the tracked job, not queued twice
+    let mut tracked = vec![JobBuilder::new("Acme", "Engineer").link("https://acme.com/jobs/1").build()];
+    tracked[0].sightings = vec![today - chrono::Days::new(120), today - chrono::Days::new(60)];
+    let incoming = vec![
+        JobBuilder::new("ACME Inc.", "engineer").build(),
+        JobBuilder::new("Globex", "SRE").build(),
+        JobBuilder::new("Globex", "SRE").build(),
+    ];
+    let (fresh, again) = sift(&mut tracked, &mut [], incoming, today);
+    assert_eq!((fresh.len(), again.len()), (1, 2));
+    assert_eq!(fresh[0].sightings, [today]);
+    assert!(is_evergreen(&tracked[0], &config));
+    assert!(!is_evergreen(&fresh[0], &config));
+
+    // It only counts for half an open process
+    let health = career_cli::config::HealthConfig { active_target: 2, ..Default::default() };
+    let score = |jobs: &[career_cli::models::Job]| {
+        let health = career_cli::metrics::pipeline_health(jobs, &health, &config, now);
+        let active = health.signals.into_iter().find(|s| s.name == "Active processes").unwrap();
+        (active.score, active.detail)
+    };
+    let jobs = vec![tracked[0].clone(), fresh[0].clone()];
+    assert_eq!(score(&jobs), (0.75, "2 open (1 likely evergreen), target 2".to_string()));
+
+    // Reapplying to a role I was turned down for is a new application
+    tracked[0].status = Status::Rejected;
+    let (reapplied, again) = sift(&mut tracked, &mut [], vec![JobBuilder::new("Acme", "Engineer").build()], today);
+    assert_eq!((reapplied.len(), again.len()), (1, 0));
+
+    // Reposted word for word: up since the last application
+    let mut before = JobBuilder::new("Initech", "Dev").id(1).status(Status::Rejected).applied_at(now - chrono::Duration::days(150)).build();
+    before.description = "Build things".into();
+    before.sightings = vec![today - chrono::Days::new(100)];
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Initech", "Dev").id(2).applied_at(now).build(), before]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('J'));
+    for c in "Build things".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    app.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    let job = &app.jobs[app.selected_index().unwrap()];
+    assert_eq!(job.sightings.len(), 3);
+    assert_eq!(career_cli::evergreen::label(job, &config).unwrap(), "likely evergreen, seen up 3 times over 5 months");
+}
+
+#[test]
+fn the_same_role_from_several_boards_is_tracked_once() {
+    use career_cli::fingerprint::{canonical_url, find, sift, title_key};
+    assert_eq!(
+        canonical_url("https://www.Acme.com/jobs/42/?utm_source=li&gh_jid=42&ref=hn#apply").as_deref(),
+        Some("acme.com/jobs/42?gh_jid=42")
+    );
+    assert_eq!(canonical_url("acme.com/jobs/42").as_deref(), Some("acme.com/jobs/42"));
+    assert_eq!(title_key("Acme Inc", "Sr. Backend Eng (Remote)"), title_key("acme", "Senior Backend Engineer"));
+
+    let mut tracked = vec![JobBuilder::new("Acme", "Senior Backend Engineer").link("https://boards.greenhouse.io/acme/jobs/42").build()];
+    let mut from_linkedin = JobBuilder::new("Acme Inc", "Sr Backend Engineer").link("https://linkedin.com/jobs/view/99?trk=x").build();
+    from_linkedin.source = Some("LinkedIn".into());
+    from_linkedin.description = "Own the billing platform".into();
+    (from_linkedin.salary_min, from_linkedin.salary_max) = (Some(150_000), Some(180_000));
+    let from_hn = JobBuilder::new("Acme", "Backend Engineer, Platform").link("https://boards.greenhouse.io/acme/jobs/42?utm_campaign=hn").build();
+    let today = chrono::Local::now().date_naive();
+    let (fresh, again) = sift(&mut tracked, &mut [], vec![from_linkedin, from_hn], today);
+    assert_eq!((fresh.len(), again.len()), (0, 2));
+    assert_eq!(again, ["Acme - Senior Backend Engineer, taking its description and salary", "Acme - Senior Backend Engineer"]);
+    assert_eq!((tracked[0].description.as_str(), tracked[0].salary_max), ("Own the billing platform", Some(180_000)), "what it lacked is kept");
+    let listings = &tracked[0].listings;
+    assert_eq!(listings.len(), 1, "the greenhouse link with a campaign tag is the one already kept");
+    assert_eq!(listings[0].source.as_deref(), Some("LinkedIn"));
+    assert_eq!(listings[0].link, "https://linkedin.com/jobs/view/99?trk=x");
+
+    // Reapplying after a rejection is a new application, unless it's the very same link
+    tracked[0].status = Status::Rejected;
+    assert!(find(&tracked, &JobBuilder::new("Acme", "Senior Backend Engineer").build()).is_none());
+    assert_eq!(find(&tracked, &JobBuilder::new("Acme", "SRE").link("https://linkedin.com/jobs/view/99").build()), Some(0));
+
+    // The add wizard asks before adding a repeat
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Globex", "Data Engineer").link("https://globex.com/careers/7").build()]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Globex");
+    type_text(&mut app, "Data Eng");
+    type_text(&mut app, "https://jobs.lever.co/globex/7");
+    assert!(app.message.as_deref().unwrap().contains("Looks like Globex - Data Engineer"));
+    assert!(matches!(app.input_field, career_cli::app::InputField::Link));
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.input_field, career_cli::app::InputField::Salary));
+}
+
+#[test]
+fn promised_reply_dates_are_flagged_once_past() {
+    use career_cli::models::parse_reply_by;
+    let date = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+    let saturday = date("2026-10-17");
+    assert_eq!(parse_reply_by("Oct 20", saturday), Some(date("2026-10-20")));
+    assert_eq!(parse_reply_by("march 3", saturday), Some(date("2027-03-03")), "already past this year");
+    assert_eq!(parse_reply_by("friday", saturday), Some(date("2026-10-23")));
+    assert_eq!(parse_reply_by("in 2w", saturday), Some(date("2026-10-31")));
+    assert_eq!(parse_reply_by("soonish", saturday), None);
+
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    let today = chrono::Local::now().date_naive();
+    press(&mut app, KeyCode::Char('~'));
+    type_text(&mut app, "3d");
+    let i = app.selected_index().unwrap();
+    assert_eq!(app.jobs[i].reply_by, Some(today + chrono::Days::new(3)));
+    assert!(app.jobs[i].reply_label(today).unwrap().starts_with("by "));
+
+    // A week on and still nothing
+    let later = today + chrono::Days::new(7);
+    assert!(app.jobs[i].reply_overdue(later));
+    assert!(app.jobs[i].reply_label(later).unwrap().starts_with("late "));
+    let agenda = career_cli::models::agenda(&app.jobs);
+    assert!(agenda.iter().any(|due| due.label() == "Hear back" && due.job.id == app.jobs[i].id));
+
+    // Hearing back moves the status on, and then nothing's owed
+    let mut answered = app.jobs[i].clone();
+    answered.set_status(Status::Interviewing);
+    assert_eq!(answered.reply_by, None);
+    assert!(!answered.reply_overdue(later) && answered.reply_label(later).is_none());
+
+    // Closed jobs aren't waiting on anything
+    app.jobs[i].status = Status::Rejected;
+    assert!(!app.jobs[i].reply_overdue(later));
+}
+
+#[test]
+fn waiting_jobs_are_measured_against_how_long_replies_usually_take() {
+    let applied = career_cli::fixtures::epoch();
+    let replied = |company: &str, days: i64| {
+        JobBuilder::new(company, "Engineer").status(Status::Rejected).applied_at(applied).responded_at(applied + chrono::Duration::days(days)).build()
+    };
+    let mut jobs: Vec<_> = [2, 3, 4, 5, 9].iter().enumerate().map(|(i, days)| replied(&format!("Co{}", i), *days)).collect();
+    jobs.push(JobBuilder::new("Acme", "Engineer").applied_at(applied).build());
+    let storage = MemoryStorage::new(jobs);
+    let app = app_with(&storage);
+    let day = |n: i64| (applied + chrono::Duration::days(n)).date_naive();
+
+    let waiting = &app.jobs[5];
+    let wait = app.response_wait(waiting, day(7)).unwrap();
+    assert_eq!(wait.label(), "80% of companies that replied did so within 5 days, this one is at day 7, worth a follow-up");
+    assert!(!app.response_wait(waiting, day(4)).unwrap().past_usual());
+    assert!(app.response_wait(waiting, day(14)).unwrap().label().ends_with("day 14, longer than any reply so far"));
+    assert!(app.response_wait(&app.jobs[0], day(7)).is_none(), "already heard back");
+
+    // Too few replies to go on
+    let storage = MemoryStorage::new(app.jobs[3..].to_vec());
+    let app = app_with(&storage);
+    assert!(app.response_wait(&app.jobs[2], day(7)).is_none());
+}
+
+#[test]
+fn numbers_in_the_stats_view_open_the_jobs_behind_them() {
+    let mut phone = JobBuilder::new("Initech", "Engineer").id(3).status(Status::Rejected).build();
+    phone.rejected_stage = Some(career_cli::models::RejectionStage::Phone);
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).status(Status::Ghosted).build(),
+        JobBuilder::new("Globex", "Engineer").id(2).status(Status::Ghosted).build(),
+        phone,
+        JobBuilder::new("Umbrella", "Engineer").id(4).status(Status::Rejected).build(),
+    ]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('s'));
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Stats));
+
+    // Total, active and closed, then the statuses in order: Ghosted is the eighth along
+    for _ in 0..8 {
+        press(&mut app, KeyCode::Down);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Normal));
+    let filter = app.filter.clone().unwrap();
+    assert_eq!(filter.as_str(), "status:ghosted");
+    assert_eq!(app.jobs.iter().filter(|j| filter.matches(j)).count(), 2);
+
+    // Next come where rejections happened, one stage recorded and one not
+    press(&mut app, KeyCode::Char('s'));
+    for _ in 0..11 {
+        press(&mut app, KeyCode::Right);
+    }
+    press(&mut app, KeyCode::Enter);
+    let filter = app.filter.clone().unwrap();
+    let companies: Vec<&str> = app.jobs.iter().filter(|j| filter.matches(j)).map(|j| j.company.as_str()).collect();
+    assert_eq!(companies, vec!["Umbrella"]);
+
+    // Up from the top wraps round to the last health signal
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.filter.as_ref().unwrap().as_str(), "status:interviewing");
+
+    // The total shows everything again
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Enter);
+    assert!(app.filter.is_none());
+}
+
+#[test]
+fn the_stats_view_shows_each_status_on_its_row_and_rejections_on_theirs() {
+    use ratatui::{backend::TestBackend, Terminal};
+    let mut phone = JobBuilder::new("Initech", "Engineer").id(3).status(Status::Rejected).build();
+    phone.rejected_stage = Some(career_cli::models::RejectionStage::Phone);
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        JobBuilder::new("Globex", "Engineer").id(2).status(Status::Withdrawn).build(),
+        phone,
+    ]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('s'));
+    let mut terminal = Terminal::new(TestBackend::new(200, 50)).unwrap();
+    terminal.draw(|frame| career_cli::ui::ui(frame, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let screen: Vec<String> = (0..buffer.area.height)
+        .map(|y| (0..buffer.area.width).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect();
+    let line = |text: &str| screen.iter().find(|line| line.contains(text)).cloned().unwrap_or_default();
+
+    assert!(line("Total 3").contains("Total 3 | Active 1 | Closed 2"), "{}", line("Total 3"));
+    let statuses = line("Applied 1");
+    assert!(statuses.contains("Applied 1 | Interviewing 0 | Offer 0 | Accepted 0 | Rejected 1 | Ghosted 0 | Withdrawn 1"), "{}", statuses);
+    let rejected = line("Rejected at:");
+    assert!(rejected.contains("Rejected at: Phone"), "{}", rejected);
+    assert!(!rejected.contains("Ghosted") && !rejected.contains("Withdrawn"));
+}
+
+#[test]
+fn requisition_ids_are_kept_and_filterable() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('#'));
+    type_text(&mut app, "  R-10234 ");
+    let i = app.selected_index().unwrap();
+    assert_eq!(app.jobs[i].req_id.as_deref(), Some("R-10234"));
+    let filter = career_cli::filter::Filter::parse("req:r-102", &[]).unwrap();
+    assert_eq!(app.jobs.iter().filter(|j| filter.matches(j)).count(), 1);
+
+    // The prompt starts from what's there, and empty clears it
+    press(&mut app, KeyCode::Char('#'));
+    assert_eq!(app.input_buffer, "R-10234");
+    app.input_buffer.clear();
+    press(&mut app, KeyCode::Enter);
+    assert!(app.jobs[i].req_id.is_none());
+}
+
+#[test]
+fn timezones_say_whether_its_their_business_hours() {
+    use career_cli::timezone::{describe, parse};
+    assert_eq!(parse("europe/berlin").unwrap().name(), "Europe/Berlin");
+    assert_eq!(parse("new york").unwrap().name(), "America/New_York");
+    assert!(parse("Atlantis").is_err());
+
+    let berlin = parse("Berlin").unwrap();
+    let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+    assert_eq!(describe(berlin, at("2026-10-20T12:05:00Z")), "Tue 14:05 CEST, business hours until 17:00");
+    assert_eq!(
+        describe(berlin, at("2026-10-17T08:12:00Z")),
+        "Sat 10:12 CEST, outside business hours, they open Mon 09:00 (in 1d 22h)"
+    );
+    assert_eq!(
+        describe(berlin, at("2026-10-20T06:30:00Z")),
+        "Tue 08:30 CEST, outside business hours, they open Tue 09:00 (in 30m)"
+    );
+
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('G'));
+    type_text(&mut app, "Mars");
+    assert!(app.message.as_deref().unwrap().contains("Unknown timezone"));
+    app.input_buffer.clear();
+    type_text(&mut app, "tokyo");
+    assert_eq!(app.jobs[0].timezone.as_deref(), Some("Asia/Tokyo"));
+    assert_eq!(app.jobs[0].tz(), Some(chrono_tz::Tz::Asia__Tokyo));
+}
+
+#[test]
+fn follow_ups_at_a_time_read_in_their_timezone() {
+    use career_cli::schedule::parse_when;
+    let berlin = Some(chrono_tz::Tz::Europe__Berlin);
+    let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+    // Saturday morning in Berlin
+    let now = at("2026-10-17T08:12:00Z");
+    assert_eq!(parse_when("thu 9am", berlin, now), Some(at("2026-10-22T07:00:00Z")));
+    assert_eq!(parse_when("Thursday 9:30am", berlin, now), Some(at("2026-10-22T07:30:00Z")));
+    assert_eq!(parse_when("2026-10-22 14:00", berlin, now), Some(at("2026-10-22T12:00:00Z")));
+    assert_eq!(parse_when("sat 11am", berlin, now), Some(at("2026-10-17T09:00:00Z")), "later today");
+    assert_eq!(parse_when("sat 9am", berlin, now), Some(at("2026-10-24T07:00:00Z")), "already past, so next week");
+    assert_eq!(parse_when("today 9am", berlin, now), None);
+    assert_eq!(parse_when("thu 13pm", berlin, now), None);
+    assert_eq!(parse_when("5d", berlin, now), None);
+}
+
+#[test]
+fn a_queued_follow_up_only_goes_out_while_still_set_for_then() {
+    let at = |s: &str| s.parse::<chrono::DateTime<chrono::Utc>>().unwrap();
+    let queued_for = at("2026-10-22T07:00:00Z");
+    let now = at("2026-10-22T07:00:20Z");
+    let mut job = JobBuilder::new("Acme", "Engineer").build();
+    job.follow_up = Some(queued_for);
+    assert_eq!(job.follow_up_held(queued_for, now), None);
+    assert_eq!(job.follow_up_held(queued_for, at("2026-10-22T09:30:00Z")), None, "at ran late");
+    assert!(job.follow_up_held(queued_for, at("2026-10-22T06:59:00Z")).unwrap().contains("isn't due"));
+
+    job.follow_up = Some(at("2026-10-23T07:00:00Z"));
+    assert!(job.follow_up_held(queued_for, now).unwrap().contains("moved to"));
+    job.follow_up = None;
+    assert!(job.follow_up_held(queued_for, now).unwrap().contains("cleared"));
+    job.follow_up = Some(queued_for);
+    job.set_status(Status::Rejected);
+    assert_eq!(job.follow_up_held(queued_for, now).as_deref(), Some("it's Rejected now"));
+}
+
+#[test]
+fn files_are_kept_with_a_job_and_picked_to_open() {
+    let dir = tempfile::tempdir().unwrap();
+    let offer = dir.path().join("acme-offer.pdf");
+    let brief = dir.path().join("take-home.md");
+    std::fs::write(&offer, "%PDF").unwrap();
+    std::fs::write(&brief, "# Task").unwrap();
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    // With none yet, '%' asks for one straight away
+    press(&mut app, KeyCode::Char('%'));
+    type_text(&mut app, &dir.path().join("missing.pdf").display().to_string());
+    assert!(app.message.as_deref().unwrap().starts_with(" No file at"));
+    app.input_buffer.clear();
+    type_text(&mut app, &offer.display().to_string());
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Files));
+    press(&mut app, KeyCode::Char('n'));
+    type_text(&mut app, &brief.display().to_string());
+    assert_eq!(app.jobs[0].files, [offer.display().to_string(), brief.display().to_string()]);
+    assert_eq!(app.picker_state.selected(), Some(1), "the new one is picked");
+
+    // Moved away, it's flagged and dropping it leaves the file alone
+    std::fs::remove_file(&brief).unwrap();
+    assert!(career_cli::models::describe_file(&app.jobs[0].files[1]).starts_with("take-home.md ("));
+    assert!(career_cli::models::describe_file(&app.jobs[0].files[1]).ends_with("(not found)"));
+    press(&mut app, KeyCode::Enter);
+    assert!(app.message.as_deref().unwrap().contains("isn't there anymore"));
+    press(&mut app, KeyCode::Char('d'));
+    assert_eq!(app.jobs[0].files, [offer.display().to_string()]);
+    assert!(offer.exists());
+    press(&mut app, KeyCode::Esc);
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[0].files, app.jobs[0].files);
+}
+
+#[test]
+fn form_answers_are_kept_per_job_and_offered_again_for_the_same_question() {
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").build(),
+        JobBuilder::new("Globex", "Engineer").build(),
+    ]);
+    let mut app = app_with(&storage);
+
+    // With none yet, '?' asks for one straight away
+    press(&mut app, KeyCode::Char('?'));
+    type_text(&mut app, "Why us");
+    assert!(app.message.as_deref().unwrap().contains("A question, then my answer"));
+    app.input_buffer.clear();
+    type_text(&mut app, "Why us? Your tooling team");
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Answers));
+    press(&mut app, KeyCode::Char('n'));
+    type_text(&mut app, "Salary expectations: 150k");
+    let questions: Vec<&str> = app.jobs[0].answers.iter().map(|a| a.question.as_str()).collect();
+    assert_eq!(questions, ["Why us?", "Salary expectations"]);
+    assert_eq!(app.picker_state.selected(), Some(1), "the new one is picked");
+
+    // Editing types the question out again, and changes that answer in place
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.input_buffer, "Salary expectations: 150k");
+    app.input_buffer = "Salary expectations: 160k".to_string();
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].answers.len(), 2);
+    assert_eq!(app.jobs[0].answers[1].answer, "160k");
+    press(&mut app, KeyCode::Esc);
+
+    // At another job the question completes, then what I said last time
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('?'));
+    for c in "sal".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    assert_eq!(app.suggestion().as_deref(), Some("Salary expectations"));
+    app.input_buffer = "Salary expectations:".to_string();
+    assert_eq!(app.suggestion().as_deref(), Some("Salary expectations: 160k"));
+    app.input_buffer = "Why us? The product".to_string();
+    press(&mut app, KeyCode::Enter);
+    let others = career_cli::answers::given(&app.jobs, &app.jobs[1], "why us");
+    assert_eq!(others.len(), 1);
+    assert_eq!((others[0].0.company.as_str(), others[0].1.answer.as_str()), ("Acme", "Your tooling team"));
+
+    let today = chrono::Local::now().date_naive();
+    let briefing = career_cli::briefing::briefing(&app.jobs[0], &[], &Default::default(), today);
+    let at = briefing.iter().position(|l| l == "## What I put on the form").unwrap();
+    assert_eq!(briefing[at + 1..at + 3], ["- Why us? Your tooling team", "- Salary expectations: 160k"]);
+
+    // Rewording the question still changes that answer, not adds another
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Char('?'));
+    press(&mut app, KeyCode::Enter);
+    app.input_buffer = "Why Acme? Your tooling team".to_string();
+    press(&mut app, KeyCode::Enter);
+    let questions: Vec<&str> = app.jobs[0].answers.iter().map(|a| a.question.as_str()).collect();
+    assert_eq!(questions, ["Why Acme?", "Salary expectations"]);
+    assert_eq!(app.picker_state.selected(), Some(0));
+    // Asking what another one asks leaves just the one
+    press(&mut app, KeyCode::Enter);
+    app.input_buffer = "Salary expectations? 170k".to_string();
+    press(&mut app, KeyCode::Enter);
+    let answers: Vec<String> = app.jobs[0].answers.iter().map(|a| a.input()).collect();
+    assert_eq!(answers, ["Salary expectations? 170k"]);
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[1].answers, app.jobs[1].answers);
+}
+
+#[test]
+fn people_in_my_network_are_kept_apart_from_jobs_and_linked_to_them() {
+    use career_cli::network::{at_company, linked, Person};
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").build(),
+        JobBuilder::new("Globex", "Engineer").build(),
+    ])
+    .with_network(vec![Person::new("Sam Park", "Globex Inc", "ex-colleague")]);
+    let mut app = app_with(&storage);
+    assert_eq!(app.network.len(), 1);
+
+    press(&mut app, KeyCode::Char('&'));
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Network));
+    press(&mut app, KeyCode::Char('n'));
+    type_text(&mut app, ", Acme");
+    assert!(app.message.as_deref().unwrap().contains("A name, then"));
+    app.input_buffer = "Dana Lee, Acme, RustConf 2025".to_string();
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Network));
+    assert_eq!(app.picker_state.selected(), Some(1), "the new one is picked");
+    assert_eq!(app.network[1].met, "RustConf 2025");
+
+    // Enter links the picked person to the selected job, and again unlinks
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.message.as_deref(), Some(" Dana Lee linked to Acme - Engineer "));
+    press(&mut app, KeyCode::Char('c'));
+    let today = chrono::Local::now().date_naive();
+    assert_eq!(app.network[1].last_contact_label(today), "talked today");
+    let names: Vec<&str> = linked(&app.network, &app.jobs[0]).iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["Dana Lee"]);
+    assert!(at_company(&app.network, &app.jobs[0]).is_empty(), "linked already");
+    let there: Vec<&str> = at_company(&app.network, &app.jobs[1]).iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(there, ["Sam Park"], "Globex Inc is Globex");
+
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Char('d'));
+    assert_eq!(app.network.len(), 1);
+    press(&mut app, KeyCode::Esc);
+    assert!(storage.people().len() == 1 && storage.people()[0].name == "Sam Park", "nothing written until a save");
+    app.save().unwrap();
+    assert_eq!(storage.people(), app.network);
+    assert_eq!(storage.people()[0].jobs, [app.jobs[0].id]);
+}
+
+#[test]
+fn jobs_keep_named_links_besides_the_posting() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('+'));
+    type_text(&mut app, "https://careers.acme.com/me");
+    assert!(app.message.as_deref().unwrap().contains("A name, then the link"));
+    app.input_buffer.clear();
+    type_text(&mut app, "Status page careers.acme.com/me");
+    press(&mut app, KeyCode::Char('+'));
+    type_text(&mut app, "Take-home repo https://github.com/me/acme-task");
+    assert_eq!(
+        app.jobs[0].all_links(),
+        [
+            ("Posting", "https://boards.greenhouse.io/acme/jobs/1"),
+            ("Status page", "careers.acme.com/me"),
+            ("Take-home repo", "https://github.com/me/acme-task"),
+        ]
+    );
+
+    // With more than the posting, 'o' asks which one
+    press(&mut app, KeyCode::Char('o'));
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Links));
+    press(&mut app, KeyCode::Char('d'));
+    assert!(app.message.as_deref().unwrap().contains("changed with 'e'"), "the posting stays");
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('d'));
+    assert_eq!(app.jobs[0].links.len(), 1);
+    assert_eq!(app.jobs[0].links[0].name, "Take-home repo");
+    press(&mut app, KeyCode::Esc);
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[0].links, app.jobs[0].links);
+}
+
+#[test]
+fn notice_period_works_back_to_resign_and_decide_dates() {
+    use career_cli::config::NoticeConfig;
+    let day = |m: u32, d: u32| chrono::NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+    let mut acme = JobBuilder::new("Acme", "Engineer").status(Status::Offer).build();
+    acme.desired_start = Some(day(12, 1));
+    let mut globex = JobBuilder::new("Globex", "SRE").id(2).status(Status::Offer).deadline(day(10, 20)).build();
+    globex.desired_start = Some(day(12, 15));
+    let jobs = vec![acme, globex];
+    let notice = NoticeConfig { period_weeks: 4, decision_buffer_days: 3 };
+
+    let t = career_cli::offers::timeline(&jobs[0], &notice).unwrap();
+    assert_eq!((t.resign_by, t.decide_by), (day(11, 3), day(10, 31)));
+    assert_eq!(career_cli::offers::timeline(&jobs[1], &notice).unwrap().decide_by, day(10, 20), "offer deadline comes first");
+
+    let conflicts = career_cli::offers::conflicts(&jobs, &notice, day(10, 16));
+    assert_eq!(conflicts.len(), 1);
+    assert!(conflicts[0].starts_with("Globex needs an answer by 2026-10-20, 11 days before Acme"), "{}", conflicts[0]);
+
+    let conflicts = career_cli::offers::conflicts(&jobs, &notice, day(11, 10));
+    assert_eq!(conflicts.len(), 2, "{:?}", conflicts);
+    assert_eq!(conflicts[0], "Globex: the answer was due 2026-10-20");
+    assert!(conflicts[1].starts_with("Acme: too late to give 4 weeks' notice"), "{:?}", conflicts);
+
+    let mut accepted = jobs[0].clone();
+    accepted.set_status(Status::Accepted);
+    assert_eq!(accepted.onboarding.unwrap().start_date, Some(day(12, 1)));
+}
+
+#[test]
+fn runway_starts_after_severance_and_sets_the_pace_needed() {
+    use career_cli::config::RunwayConfig;
+    use career_cli::runway::runway;
+    let day = |m: u32, d: u32| chrono::NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+    let today = day(10, 16);
+    let applied = |d: u32| day(10, d).and_hms_opt(9, 0, 0).unwrap().and_utc();
+    let mut jobs: Vec<_> = (1..=8).map(|i| JobBuilder::new("Acme", "Dev").id(i).applied_at(applied(i as u32)).build()).collect();
+    jobs[0].status = Status::Offer;
+
+    assert!(runway(&jobs, &RunwayConfig::default(), today).is_none(), "off until a burn is set");
+
+    let config = RunwayConfig { savings: 6000.0, monthly_burn: 3000.0, severance_end: None, hiring_weeks: 6 };
+    let r = runway(&jobs, &config, today).unwrap();
+    assert_eq!(r.ends, day(12, 15));
+    assert_eq!(r.pace, 2.0);
+    assert_eq!(r.applications_per_offer, 8.0);
+    assert!(!r.on_track(), "an offer within the ~2.6 weeks left after hiring takes {:.1}/week", r.needed);
+    let quick = RunwayConfig { hiring_weeks: 0, ..config.clone() };
+    assert!(runway(&jobs, &quick, today).unwrap().on_track());
+
+    let later = RunwayConfig { severance_end: Some(day(12, 31)), ..config };
+    assert_eq!(runway(&jobs, &later, today).unwrap().ends, chrono::NaiveDate::from_ymd_opt(2027, 3, 1).unwrap());
+
+    let broke = RunwayConfig { savings: 0.0, ..RunwayConfig { monthly_burn: 3000.0, ..Default::default() } };
+    let r = runway(&jobs, &broke, today).unwrap();
+    assert_eq!(r.weeks, 0.0);
+    assert!(!r.on_track());
+    assert!(r.lines()[1].contains("apply faster, 8.0/week needed"), "{:?}", r.lines());
+}
+
+#[test]
+fn tags_are_edited_from_the_list_and_narrow_the_filter() {
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        JobBuilder::new("Globex", "SRE").id(2).build(),
+    ]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('t'));
+    type_text(&mut app, "#Remote, referral remote");
+    assert_eq!(app.jobs[0].tags, ["remote", "referral"]);
+
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('t'));
+    press(&mut app, KeyCode::Char('r'));
+    press(&mut app, KeyCode::Char('e'));
+    press(&mut app, KeyCode::Char('m'));
+    press(&mut app, KeyCode::Tab);
+    type_text(&mut app, " dream-company");
+    assert_eq!(app.jobs[1].tags, ["remote", "dream-company"]);
+
+    press(&mut app, KeyCode::Char('T'));
+    type_text(&mut app, "referral");
+    assert_eq!(app.visible, [0]);
+
+    let filter = career_cli::filter::Filter::parse("tag:remote NOT #referral", &[]).unwrap();
+    let matching: Vec<&str> = app.jobs.iter().filter(|j| filter.matches(j)).map(|j| j.company.as_str()).collect();
+    assert_eq!(matching, ["Globex"]);
+    assert!(!career_cli::filter::Filter::parse("tag:rem", &[]).unwrap().matches(&app.jobs[1]), "tags match whole");
+}
+
+#[test]
+fn admin_reminders_repeat_from_their_first_date_until_they_stop() {
+    use career_cli::reminders::{upcoming, AdminReminder, Interval};
+    let day = |m: u32, d: u32| chrono::NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+    let config: career_cli::config::Config = toml::from_str(
+        r#"
+        [[reminders]]
+        name = "Unemployment certification"
+        due = "2026-10-05"
+        every = "1w"
+        until = "2026-10-31"
+
+        [[reminders]]
+        name = "COBRA election"
+        due = "2026-12-15"
+
+        [[reminders]]
+        name = "Rent"
+        due = "2026-01-31"
+        every = "1m"
+        "#,
+    )
+    .unwrap();
+    let certify = &config.reminders[0];
+    assert_eq!(certify.every, Some(Interval::Weeks(1)));
+    assert_eq!(certify.next_due(day(10, 12)), Some(day(10, 12)));
+    assert_eq!(certify.next_due(day(10, 13)), Some(day(10, 19)));
+    assert_eq!(certify.next_due(day(10, 27)), None, "the 11-02 one is past `until`");
+    assert_eq!(config.reminders[2].next_due(day(3, 1)), Some(day(3, 31)), "months count from the first date");
+
+    let names: Vec<&str> = upcoming(&config.reminders, day(10, 16)).iter().map(|(_, r)| r.name.as_str()).collect();
+    assert_eq!(names, ["Unemployment certification", "Rent", "COBRA election"]);
+    let names: Vec<&str> = upcoming(&config.reminders, day(12, 16)).iter().map(|(_, r)| r.name.as_str()).collect();
+    assert_eq!(names, ["COBRA election", "Rent"], "a missed one-off is still owed, a finished repeat isn't");
+    assert_eq!(config.reminders[1].next_due(day(12, 20)), Some(day(12, 15)));
+
+    let one_off = AdminReminder { name: "COBRA".into(), due: day(12, 15), every: None, until: None };
+    assert!(toml::to_string(&config).unwrap().contains("every = \"1w\""));
+    assert!(Interval::parse("fortnightly").is_err());
+    assert_eq!(one_off.describe(), "");
+}
+
+#[test]
+fn interview_transcripts_are_attached_and_searchable() {
+    let dir = tempfile::tempdir().unwrap();
+    let vtt = dir.path().join("onsite.vtt");
+    std::fs::write(&vtt, "WEBVTT\n\n1\n00:00:01.000 --> 00:00:04.000\nTell me about Kubernetes at scale\n\n2\n00:00:05.000 --> 00:00:09.000\nWe run forty clusters\n").unwrap();
+    let recording = dir.path().join("screen.mp4");
+    std::fs::write(&recording, [0u8; 4]).unwrap();
+
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).notes("Recruiter said clusters are a mess").build(),
+        JobBuilder::new("Globex", "SRE").id(2).build(),
+    ]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('U'));
+    assert!(app.message.is_some(), "needs an interview first");
+    press(&mut app, KeyCode::Char('I'));
+    type_text(&mut app, "2026-10-01 Onsite");
+    press(&mut app, KeyCode::Char('U'));
+    type_text(&mut app, vtt.to_str().unwrap());
+
+    let interview = &app.jobs[0].interviews[0];
+    assert_eq!(interview.transcript, "Tell me about Kubernetes at scale\nWe run forty clusters");
+    assert_eq!(interview.attachment.as_deref(), Some(vtt.canonicalize().unwrap().as_path()));
+
+    let hits = career_cli::transcript::search(&app.jobs, "CLUSTERS");
+    let sources: Vec<&str> = hits.iter().map(|h| h.source.as_str()).collect();
+    assert_eq!(sources, ["notes", "Onsite transcript"]);
+    assert_eq!(career_cli::transcript::search(&app.jobs, "forty clusters").len(), 1);
+    assert!(career_cli::filter::Filter::parse("transcript:kubernetes", &[]).unwrap().matches(&app.jobs[0]));
+
+    let mut interview = career_cli::models::Interview::new(chrono::NaiveDate::from_ymd_opt(2026, 10, 2).unwrap(), "Screen");
+    career_cli::transcript::attach(&mut interview, &recording).unwrap();
+    assert!(interview.transcript.is_empty() && interview.attachment.is_some(), "recordings keep only the path");
+    assert!(career_cli::transcript::attach(&mut interview, &dir.path().join("missing.txt")).is_err());
+}
+
+#[test]
+fn location_and_work_mode_filter_out_onsite_roles_elsewhere() {
+    use career_cli::models::{parse_location, WorkMode};
+    assert_eq!(parse_location("New York hybrid"), ("New York".to_string(), Some(WorkMode::Hybrid)));
+    assert_eq!(parse_location("Remote"), (String::new(), Some(WorkMode::Remote)));
+    assert_eq!(parse_location("Berlin, on-site"), ("Berlin".to_string(), Some(WorkMode::Onsite)));
+    assert_eq!(parse_location("Austin"), ("Austin".to_string(), None));
+
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        JobBuilder::new("Globex", "SRE").id(2).build(),
+        JobBuilder::new("Initech", "Dev").id(3).build(),
+        JobBuilder::new("Hooli", "Dev").id(4).build(),
+    ]);
+    let mut app = app_with(&storage);
+    for place in ["Berlin, onsite", "Munich onsite", "remote", "Munich, hybrid"] {
+        press(&mut app, KeyCode::Char('L'));
+        app.input_buffer.clear();
+        type_text(&mut app, place);
+        press(&mut app, KeyCode::Down);
+    }
+    assert_eq!(app.jobs[1].location_input(), "Munich, onsite");
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "NOT (mode:onsite NOT location:berlin)");
+    let shown: Vec<&str> = app.visible.iter().map(|&i| app.jobs[i].company.as_str()).collect();
+    assert_eq!(shown, ["Acme", "Initech", "Hooli"]);
+}
+
+#[test]
+fn briefing_pulls_interviewers_loop_and_past_answers_together() {
+    use career_cli::models::Interview;
+    let today = chrono::Local::now().date_naive();
+    let mut past = JobBuilder::new("ACME Inc.", "Frontend Engineer")
+        .status(Status::Rejected)
+        .applied_days_after_epoch(10)
+        .notes("[2025-03-01 10:00] Q: Why Acme? A: the logistics data\nFelt rushed")
+        .build();
+    past.interviews.push(Interview::parse("2025-03-02 Phone screen with ana ruiz", today));
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Backend Engineer")
+            .id(1)
+            .status(Status::Interviewing)
+            .stages(&["Screen", "Onsite", "Team match"], 1)
+            .notes("Recruiter: Dana\nq: ask about on-call")
+            .build(),
+    ]);
+    let mut app = App::new(Box::new(storage.clone()), vec![("spring-2025".to_string(), vec![past])], Config::default()).unwrap();
+
+    press(&mut app, KeyCode::Char('I'));
+    type_text(&mut app, &format!("{} Onsite with Ana Ruiz, Bo Chen", today + chrono::Days::new(2)));
+    assert_eq!(app.jobs[0].interviews[0].interviewers, ["Ana Ruiz", "Bo Chen"]);
+
+    press(&mut app, KeyCode::Char('p'));
+    let sheet = app.briefing().join("\n");
+    assert!(sheet.contains("**Onsite** on ") && sheet.contains("(in 2 days) with Ana Ruiz, Bo Chen"), "{}", sheet);
+    assert!(sheet.contains("1 of 3 stages done") && sheet.contains("- [ ] Team match"));
+    assert!(sheet.contains("- Ana Ruiz: met in Phone screen on 2025-03-02 (Frontend Engineer, spring-2025)"));
+    assert!(sheet.contains("- Bo Chen: first time"));
+    assert!(sheet.contains("- [2025-03-01 10:00] Q: Why Acme? A: the logistics data (Frontend Engineer, spring-2025)"));
+    assert!(sheet.contains("- q: ask about on-call"));
+    assert!(!sheet.contains("- Felt rushed"), "only Q:/A: lines from earlier applications");
+    assert!(sheet.contains("## Earlier applications\n- 2025-01-16 Frontend Engineer, Rejected (spring-2025)"), "{}", sheet);
+    press(&mut app, KeyCode::Esc);
+    assert!(app.message.is_none());
+}
+
+#[test]
+fn onsites_carry_travel_plans_into_the_briefing_and_agenda() {
+    use career_cli::models::{agenda, DueKind, Interview};
+    use career_cli::travel::Travel;
+    let today = chrono::Local::now().date_naive();
+    let onsite_day = today + chrono::Days::new(3);
+    let mut job = JobBuilder::new("Acme", "Backend Engineer").status(Status::Interviewing).build();
+    let mut onsite = Interview::parse(&format!("{} 14:00-17:00 Onsite", onsite_day), today);
+    onsite.travel = Some(Travel {
+        address: "1 Main St, Springfield".to_string(),
+        bookings: vec!["Flight BA117 ref X7KQ2P".to_string()],
+        depart: Some(onsite_day - chrono::Days::new(1)),
+        arrive_minutes: None,
+    });
+    job.interviews.push(Interview::parse(&format!("{} Phone screen", today + chrono::Days::new(1)), today));
+    job.interviews.push(onsite);
+    let storage = MemoryStorage::new(vec![job]);
+    let mut app = app_with(&storage);
+
+    // The travel day is its own agenda item, the day before the onsite
+    let due = agenda(&app.jobs);
+    let travel: Vec<_> = due.iter().filter(|d| matches!(d.kind, DueKind::Travel(_))).collect();
+    assert_eq!(travel.len(), 1);
+    assert_eq!(travel[0].date, onsite_day - chrono::Days::new(1));
+    assert_eq!(travel[0].label(), "Travel: Onsite");
+
+    // The phone screen's briefing has nothing to travel to, the onsite's does
+    assert!(!app.briefing().join("\n").contains("## Travel"));
+    app.jobs[0].interviews.remove(0);
+    let sheet = app.briefing().join("\n");
+    assert!(sheet.contains("Address: 1 Main St, Springfield ([map](https://www.google.com/maps/search/?api=1&query=1+Main+St%2C+Springfield))"), "{}", sheet);
+    assert!(sheet.contains("Be there by 13:40"), "[interviews] arrive_minutes, 20 by default");
+    assert!(sheet.contains("- Flight BA117 ref X7KQ2P"));
+
+    // Once it's debriefed the trip's over
+    app.jobs[0].interviews[0].debrief = Some(Default::default());
+    assert!(!agenda(&app.jobs).iter().any(|d| matches!(d.kind, DueKind::Travel(_))));
+}
+
+#[test]
+fn travel_picks_an_interview_by_date_or_stage_and_arrives_the_day_before_a_midnight_start() {
+    use career_cli::models::Interview;
+    use career_cli::travel::{arrive_label, find_interview, Travel};
+    let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    let interviews = vec![
+        Interview::parse("2026-10-12 Onsite", today),
+        Interview::parse("2026-10-20 Phone screen", today),
+        Interview::parse("2026-10-22 Onsite", today),
+        Interview::parse("2026-10-29 Onsite", today),
+    ];
+    assert_eq!(find_interview(&interviews, "2026-10-20", today), Some(1));
+    assert_eq!(find_interview(&interviews, "thursday", today), Some(2));
+    assert_eq!(find_interview(&interviews, "onsite", today), Some(2), "the next one called that");
+    assert_eq!(find_interview(&interviews, "Onsite", today + chrono::Days::new(30)), Some(3), "else the last");
+    assert_eq!(find_interview(&interviews, "2026-10-21", today), None);
+    assert_eq!(find_interview(&interviews, "final", today), None);
+
+    let config = Config::default().interviews;
+    let mut late = Interview::parse("2026-10-22 00:10 Onsite", today);
+    late.travel = Some(Travel::default());
+    assert_eq!(arrive_label(&late, &config).as_deref(), Some("2026-10-21 23:50"));
+    late.start = chrono::NaiveTime::from_hms_opt(14, 0, 0);
+    assert_eq!(arrive_label(&late, &config).as_deref(), Some("13:40"));
+}
+
+#[test]
+fn jobs_are_scored_by_how_well_my_skills_fit_and_can_sort_by_it() {
+    let config: Config = toml::from_str(
+        r#"
+        [skills]
+        have = ["Rust", "PostgreSQL", "kubernetes"]
+        "#,
+    )
+    .unwrap();
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").build(),
+        JobBuilder::new("Globex", "Engineer").build(),
+        JobBuilder::new("Initech", "Engineer").build(),
+    ]);
+    let mut app = App::new(Box::new(storage.clone()), Vec::new(), config).unwrap();
+
+    // Acme asks for two I have and one I don't; Tab completes from my list
+    press(&mut app, KeyCode::Char('^'));
+    app.input_buffer = "rust, Kafka, post".to_string();
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(app.input_buffer, "rust, Kafka, PostgreSQL");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].skills, ["rust", "kafka", "postgresql"]);
+    assert_eq!(app.message.as_deref(), Some(" Fit 67% (2 of 3), missing kafka "));
+
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('^'));
+    type_text(&mut app, "rust,  Kubernetes");
+    let fit = career_cli::skills::fit(&app.jobs[1], &app.config.skills).unwrap();
+    assert_eq!(fit.score(), 100);
+    assert!(career_cli::skills::fit(&app.jobs[2], &app.config.skills).is_none(), "nothing listed to score");
+
+    // Best fit first, unscored last
+    for _ in 0..3 {
+        press(&mut app, KeyCode::Char('='));
+    }
+    assert_eq!(app.sort, career_cli::config::SortOrder::Fit);
+    let order: Vec<&str> = app.visible.iter().map(|&i| app.jobs[i].company.as_str()).collect();
+    assert_eq!(order, ["Globex", "Acme", "Initech"]);
+}
+
+#[test]
+fn custom_statuses_cycle_in_config_order_and_count_as_builtins() {
+    let config: Config = toml::from_str(
+        r##"
+        [[statuses]]
+        name = "Applied"
+
+        [[statuses]]
+        name = "Phone Screen"
+        counts_as = "Interviewing"
+        color = "#5fafd7"
+
+        [[statuses]]
+        name = "Negotiating"
+        counts_as = "Offer"
+
+        [[statuses]]
+        name = "Rejected"
+        "##,
+    )
+    .unwrap();
+    // Jobs saved before custom statuses existed have no custom_status field
+    let old: serde_json::Value = serde_json::to_value(JobBuilder::new("Acme", "Engineer").id(1).build()).unwrap();
+    let mut old = old.as_object().unwrap().clone();
+    old.remove("custom_status");
+    let job: career_cli::models::Job = serde_json::from_value(old.into()).unwrap();
+    let storage = MemoryStorage::new(vec![job, JobBuilder::new("Globex", "SRE").id(2).status(Status::Ghosted).build()]);
+    let mut app = App::new(Box::new(storage.clone()), Vec::new(), config).unwrap();
+    press(&mut app, KeyCode::Char('Z'));
+    press(&mut app, KeyCode::Char('Z')); // Active and closed jobs both
+    app.state.select(Some(0));
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!((app.jobs[0].status_label(), &app.jobs[0].status), ("Phone Screen".to_string(), &Status::Interviewing));
+    assert!(app.jobs[0].responded_at.is_some());
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].status, Status::Offer);
+    // Rejected is listed but closes the job, so Enter goes on to Accepted
+    press(&mut app, KeyCode::Enter);
+    assert_eq!((app.jobs[0].status_label(), app.jobs[0].custom_status.clone()), ("Accepted".to_string(), None));
+    press(&mut app, KeyCode::Char('-'));
+    assert_eq!(app.jobs[0].status_label(), "Rejected");
+    press(&mut app, KeyCode::Esc); // Skips the rejection questions
+    for _ in 0..3 {
+        press(&mut app, KeyCode::Char('-'));
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].status_label(), "Phone Screen");
+
+    // Ghosted is over, so Enter leaves it be
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[1].status, Status::Ghosted);
+
+    let filter = career_cli::filter::Filter::parse("status:\"phone screen\"", &app.config.statuses).unwrap();
+    assert!(filter.matches(&app.jobs[0]) && !filter.matches(&app.jobs[1]));
+    assert!(career_cli::filter::Filter::parse("status:interviewing", &[]).unwrap().matches(&app.jobs[0]));
+    let typo = career_cli::filter::Filter::parse("status:\"phone scren\"", &app.config.statuses).unwrap_err();
+    assert!(typo.to_string().contains("Unknown status 'phone scren'"));
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[0].custom_status.as_deref(), Some("Phone Screen"));
+
+    let bad: Config = toml::from_str("[[statuses]]\nname = \"Onsite\"").unwrap();
+    assert!(App::new(Box::new(storage.clone()), Vec::new(), bad).is_err(), "custom names need counts_as");
+}
+
+#[test]
+fn builtin_presets_dont_collide_with_default_keys() {
+    for name in career_cli::preset::builtin_names() {
+        let preset = career_cli::preset::Preset::load(name).unwrap();
+        let config = preset.apply(&Config::default());
+        assert!(career_cli::keymap::Keymap::from_config(&config.keymap).is_ok(), "{}", name);
+    }
+}
+
+#[test]
+fn status_changes_are_recorded_in_the_job_history() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").id(1).build()]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('B'));
+    type_text(&mut app, "status=Offer");
+    press(&mut app, KeyCode::Char('y'));
+
+    let moves: Vec<(&str, &str)> = app.jobs[0].history.iter().map(|c| (c.from.as_str(), c.to.as_str())).collect();
+    assert_eq!(moves, [("Applied", "Interviewing"), ("Interviewing", "Offer")], "setting the same status again isn't a change");
+    assert!(app.jobs[0].history[0].at <= app.jobs[0].history[1].at);
+
+    press(&mut app, KeyCode::Char('y'));
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::StatusHistory));
+    press(&mut app, KeyCode::Esc);
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[0].history.len(), 2);
+}
+
+#[test]
+fn debrief_prompts_follow_an_interview_and_set_a_follow_up() {
+    use career_cli::models::{agenda, DueKind, Interview};
+    let today = chrono::Local::now().date_naive();
+    let mut acme = JobBuilder::new("Acme", "Engineer").id(1).status(Status::Interviewing).build();
+    acme.interviews.push(Interview::new(today - chrono::Days::new(1), "Onsite"));
+    acme.interviews.push(Interview::new(today + chrono::Days::new(3), "Team match"));
+    let mut globex = JobBuilder::new("Globex", "SRE").id(2).status(Status::Interviewing).build();
+    globex.interviews.push(Interview::new(today - chrono::Days::new(2), "Screen"));
+    globex.interviews.push(Interview::new(today - chrono::Days::new(30), "Too long ago"));
+    let storage = MemoryStorage::new(vec![acme, globex]);
+    let mut app = app_with(&storage);
+
+    // Oldest first, and the skipped one isn't asked about again
+    app.check_debriefs();
+    assert_eq!(app.debrief_heading().unwrap(), format!("Globex Screen ({})", today - chrono::Days::new(2)));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.jobs[1].interviews[0].debrief, Some(Default::default()));
+
+    app.check_debriefs();
+    assert_eq!(app.jobs[app.selected_index().unwrap()].company, "Acme");
+    type_text(&mut app, "7");
+    assert!(app.message.is_some(), "ratings go from 1 to 5");
+    app.input_buffer.clear();
+    type_text(&mut app, "4");
+    type_text(&mut app, "System design for a rate limiter");
+    type_text(&mut app, "Hear back within a week");
+    type_text(&mut app, "5d");
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Normal));
+    assert!(app.debrief_heading().is_none());
+
+    let debrief = app.jobs[0].interviews[0].debrief.clone().unwrap();
+    assert_eq!((debrief.rating, debrief.questions.as_str()), (Some(4), "System design for a rate limiter"));
+    assert_eq!(debrief.follow_up, Some(today + chrono::Days::new(5)));
+    assert!(agenda(&app.jobs).iter().all(|due| !matches!(due.kind, DueKind::FollowUp)), "a later interview is already booked");
+    app.jobs[0].interviews.pop();
+    assert!(agenda(&app.jobs).iter().any(|due| matches!(due.kind, DueKind::FollowUp) && due.label() == "Follow up"));
+    assert!(career_cli::debrief::pending(&app.jobs, chrono::Local::now().naive_local(), &Default::default()).is_none());
+
+    // Same-day interviews are asked about once they're over, not before
+    let day = chrono::NaiveDate::from_ymd_opt(2026, 10, 20).unwrap();
+    let at = |h: u32, m: u32| day.and_hms_opt(h, m, 0).unwrap();
+    let mut screen = Interview::new(day, "Screen");
+    screen.start = chrono::NaiveTime::from_hms_opt(14, 0, 0);
+    let mut initech = JobBuilder::new("Initech", "Dev").status(Status::Interviewing).build();
+    initech.interviews.push(screen);
+    let config = career_cli::config::InterviewConfig::default();
+    let pending = |jobs: &[career_cli::models::Job], now| career_cli::debrief::pending(jobs, now, &config);
+    assert_eq!(pending(&[initech.clone()], at(14, 30)), None, "still going for its usual hour");
+    assert_eq!(pending(&[initech.clone()], at(15, 0)), Some((0, 0)));
+    initech.interviews[0].end = chrono::NaiveTime::from_hms_opt(14, 20, 0);
+    assert_eq!(pending(&[initech.clone()], at(14, 30)), Some((0, 0)), "its own end time goes first");
+    initech.interviews[0].start = None;
+    assert_eq!(pending(&[initech.clone()], at(23, 0)), None, "no start time, so it waits for the next day");
+}
+
+#[test]
+fn the_list_can_be_seen_as_it_stood_on_a_past_day() {
+    use career_cli::asof::{as_of, summary};
+    use career_cli::journal::{JournalEntry, JournalOp};
+    use career_cli::models::{Job, StatusChange};
+    use chrono::TimeZone;
+    let day = |n: u32| chrono::NaiveDate::from_ymd_opt(2026, 3, n).unwrap();
+    let noon = |n: u32| chrono::Local.from_local_datetime(&day(n).and_hms_opt(12, 0, 0).unwrap()).unwrap().with_timezone(&chrono::Utc);
+    let entry = |op, job: &Job, field: Option<&str>, new: Option<serde_json::Value>, n| JournalEntry {
+        op,
+        job_id: job.id.to_string(),
+        field: field.map(str::to_string),
+        old: None,
+        new,
+        timestamp: noon(n),
+        device_id: "laptop".to_string(),
+        undoes: None,
+        undoes_device: None,
+        source: None,
+    };
+    let mut acme = JobBuilder::new("Acme", "Engineer").id(1).applied_at(noon(1)).build();
+    let globex = JobBuilder::new("Globex", "SRE").id(2).applied_at(noon(2)).build();
+    let journal = vec![
+        entry(JournalOp::Create, &acme, None, Some(serde_json::to_value(&acme).unwrap()), 1),
+        entry(JournalOp::Create, &globex, None, Some(serde_json::to_value(&globex).unwrap()), 2),
+        entry(JournalOp::Update, &acme, Some("status"), Some("Interviewing".into()), 3),
+        entry(JournalOp::Delete, &globex, None, None, 4),
+    ];
+    acme.status = Status::Interviewing;
+    // Added after the fact with an earlier applied date, and turned down since
+    let mut initech = JobBuilder::new("Initech", "Engineer").id(3).applied_at(noon(1)).status(Status::Rejected).build();
+    initech.history = vec![StatusChange { at: noon(5), from: "Applied".to_string(), to: "Rejected".to_string() }];
+    initech.rejected_at = Some(noon(5));
+    let now = vec![acme.clone(), initech.clone()];
+
+    let companies = |jobs: &[Job]| jobs.iter().map(|j| format!("{} {}", j.company, j.status_label())).collect::<Vec<_>>();
+    let before = as_of(&journal, &now, day(2), &[]).unwrap();
+    assert_eq!(companies(&before), ["Acme Applied", "Initech Applied", "Globex Applied"]);
+    assert!(before[1].rejected_at.is_none() && before[1].history.is_empty(), "wound back");
+    assert_eq!(summary(&before), "3 jobs: 3 Applied");
+    let after = as_of(&journal, &now, day(4), &[]).unwrap();
+    assert_eq!(companies(&after), ["Acme Interviewing", "Initech Applied"], "Globex was deleted that day");
+    assert!(as_of(&journal, &now, chrono::NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(), &[]).unwrap().is_empty());
+
+    // In the TUI, with nothing journaled yet, it comes from the status history
+    let storage = MemoryStorage::new(now);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('<'));
+    type_text(&mut app, "someday");
+    assert!(app.message.as_deref().unwrap().contains("Dates look like"));
+    app.input_buffer = "2026-03-05".to_string();
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::AsOf));
+    let (date, jobs) = app.as_of.as_ref().unwrap();
+    assert_eq!((*date, companies(jobs)), (day(5), vec!["Acme Interviewing".to_string(), "Initech Rejected".to_string()]));
+    press(&mut app, KeyCode::Left);
+    let (date, jobs) = app.as_of.as_ref().unwrap();
+    assert_eq!((*date, jobs[1].status_label()), (day(4), "Applied".to_string()));
+    press(&mut app, KeyCode::Esc);
+    assert!(app.as_of.is_none() && matches!(app.input_mode, career_cli::app::InputMode::Normal));
+    assert_eq!(app.jobs[1].status, Status::Rejected, "the list itself is untouched");
+}
+
+#[test]
+fn undo_walks_back_through_saves_in_the_journal() {
+    use career_cli::journal::{JournalEntry, JournalOp};
+    use career_cli::undo::last_undoable;
+    let at = |secs: i64| chrono::DateTime::from_timestamp(1_790_000_000 + secs, 0).unwrap();
+    let acme = JobBuilder::new("Acme", "Engineer").id(1).build();
+    let globex = JobBuilder::new("Globex", "SRE").id(2).status(Status::Rejected).build();
+    let entry = |op, job: &str, field: Option<&str>, old: Option<serde_json::Value>, new: Option<serde_json::Value>, secs| JournalEntry {
+        op,
+        job_id: job.to_string(),
+        field: field.map(str::to_string),
+        old,
+        new,
+        timestamp: at(secs),
+        device_id: "laptop".to_string(),
+        undoes: None,
+        undoes_device: None,
+        source: None,
+    };
+    let mut journal = vec![
+        // Where the journal starts, never undone
+        entry(JournalOp::Create, "1", None, None, Some(serde_json::to_value(&acme).unwrap()), 0),
+        entry(JournalOp::Create, "2", None, None, Some(serde_json::to_value(&globex).unwrap()), 0),
+        entry(JournalOp::Update, "2", Some("status"), Some("Interviewing".into()), Some("Rejected".into()), 10),
+        entry(JournalOp::Delete, "1", None, Some(serde_json::to_value(&acme).unwrap()), None, 20),
+    ];
+
+    // The delete comes back first
+    let mut jobs = vec![globex.clone()];
+    let delete = last_undoable(&journal).unwrap();
+    assert_eq!(delete.describe(&jobs), ["Acme - Engineer: bring back, it was deleted then"]);
+    assert!(delete.revert(&mut jobs).unwrap().is_empty());
+    assert_eq!(jobs.iter().map(|j| j.company.as_str()).collect::<Vec<_>>(), ["Globex", "Acme"]);
+    journal.push(entry(JournalOp::Create, "1", None, None, Some(serde_json::to_value(&acme).unwrap()), 30));
+    journal.push(JournalEntry { undoes: Some(at(20)), ..entry(JournalOp::Undo, "", None, None, None, 30) });
+
+    // Then the status change, not the undo's own re-create
+    let status = last_undoable(&journal).unwrap();
+    assert_eq!(status.timestamp, at(10));
+    assert_eq!(status.describe(&jobs), ["Globex - SRE: status Rejected -> Interviewing"]);
+    let mut edited = jobs.clone();
+    edited[0].status = Status::Ghosted;
+    assert_eq!(status.revert(&mut edited).unwrap().len(), 1, "edited again since, so it's kept");
+    assert_eq!(edited[0].status, Status::Ghosted);
+    status.revert(&mut jobs).unwrap();
+    assert_eq!(jobs[0].status, Status::Interviewing);
+
+    journal.push(JournalEntry { undoes: Some(at(10)), ..entry(JournalOp::Undo, "", None, None, None, 40) });
+    assert!(last_undoable(&journal).is_none());
+    assert_eq!(career_cli::journal::replay(&journal).unwrap().len(), 2, "replay ignores the markers");
+
+    // Two devices saving at the same moment made two saves, undone one at a time
+    let at_once = |device: &str| JournalEntry { device_id: device.to_string(), ..entry(JournalOp::Update, "2", Some("notes"), Some("".into()), Some(device.into()), 50) };
+    journal.extend([at_once("laptop"), at_once("desktop")]);
+    let desktop = last_undoable(&journal).unwrap();
+    assert_eq!((desktop.timestamp, desktop.device_id), (at(50), "desktop"));
+    journal.push(JournalEntry { undoes: Some(at(50)), undoes_device: Some("desktop".into()), ..entry(JournalOp::Undo, "", None, None, None, 60) });
+    let laptop = last_undoable(&journal).unwrap();
+    assert_eq!((laptop.timestamp, laptop.device_id), (at(50), "laptop"));
+}
+
+#[test]
+fn contact_prompts_walk_each_field_and_check_the_email() {
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        JobBuilder::new("Globex", "SRE").id(2).build(),
+    ]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('r'));
+    type_text(&mut app, "Dana Lee");
+    type_text(&mut app, "dana at acme");
+    assert!(app.message.as_deref().unwrap().contains("email"), "a bad email stays on the prompt");
+    app.input_buffer.clear();
+    type_text(&mut app, "dana@acme.com");
+    press(&mut app, KeyCode::Enter);
+    type_text(&mut app, "@danalee");
+
+    let contact = &app.jobs[0].contact;
+    assert_eq!(contact.label(), "Dana Lee <dana@acme.com>");
+    assert_eq!(contact.linkedin, "https://www.linkedin.com/in/danalee");
+    assert!(app.jobs[1].contact.is_empty());
+
+    let filter = career_cli::filter::Filter::parse("contact:dana", &[]).unwrap();
+    let matching: Vec<&str> = app.jobs.iter().filter(|j| filter.matches(j)).map(|j| j.company.as_str()).collect();
+    assert_eq!(matching, ["Acme"]);
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[0].contact.email, "dana@acme.com");
+}
+
+#[test]
+fn linkedin_values_become_profile_urls_and_stand_in_for_a_label() {
+    use career_cli::models::{linkedin_url, Contact};
+    assert_eq!(linkedin_url("in/dana-lee"), "https://www.linkedin.com/in/dana-lee");
+    assert_eq!(linkedin_url("in/dana-lee/"), "https://www.linkedin.com/in/dana-lee");
+    assert_eq!(linkedin_url("linkedin.com/in/dana-lee"), "https://linkedin.com/in/dana-lee");
+    assert_eq!(linkedin_url(""), "");
+
+    let contact = Contact { linkedin: linkedin_url("in/dana-lee"), ..Default::default() };
+    assert_eq!(contact.label(), "https://www.linkedin.com/in/dana-lee");
+    let contact = Contact { phone: "+1 555 0100".into(), ..contact };
+    assert_eq!(contact.label(), "+1 555 0100");
+}
+
+#[test]
+fn stats_scope_leaves_out_trash_and_archives_unless_included() {
+    use career_cli::scope::Scope;
+    let config: career_cli::config::Config = toml::from_str(
+        r#"
+        [stats]
+        withdrawn = false
+        exclude = "company:example"
+        "#,
+    )
+    .unwrap();
+    let mut withdrawn = JobBuilder::new("Globex", "SRE").id(2).status(Status::Rejected).build();
+    withdrawn.set_custom_status("Withdrawn", Status::Rejected);
+    let mut trashed = JobBuilder::new("Acme", "Test entry").id(3).build();
+    trashed.tags.push("trash".to_string());
+    let mut archived = JobBuilder::new("Hooli", "Old").id(5).build();
+    archived.archived = true;
+    let jobs = vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        withdrawn,
+        trashed,
+        JobBuilder::new("Example Corp", "Draft").id(4).build(),
+        archived,
+    ];
+    let cohorts = vec![("2024".to_string(), vec![JobBuilder::new("Initech", "Dev").id(1).build()])];
+
+    let selection = config.stats.select(&jobs, &cohorts, &[]).unwrap();
+    let counted: Vec<&str> = selection.jobs.iter().map(|j| j.role.as_str()).collect();
+    assert_eq!(counted, ["Engineer"]);
+    assert_eq!(selection.note().unwrap(), "Left out 2 archived, 1 trashed, 1 withdrawn, 1 excluded by filter");
+
+    let args: Vec<String> = ["--include", "archived,withdrawn", "--business-days"].map(String::from).to_vec();
+    let (scope, rest) = config.stats.clone().with_flags(&args).unwrap();
+    assert_eq!(rest, ["--business-days"]);
+    let selection = scope.select(&jobs, &cohorts, &[]).unwrap();
+    let counted: Vec<&str> = selection.jobs.iter().map(|j| j.company.as_str()).collect();
+    assert_eq!(counted, ["Acme", "Globex", "Hooli", "Initech"]);
+    assert!(Scope::default().with_flags(&["--include".to_string(), "deleted".to_string()]).is_err());
+}
+
+#[test]
+fn esc_while_adding_offers_a_draft_that_can_be_finished_later() {
+    use career_cli::app::InputMode;
+    let storage = MemoryStorage::new(vec![]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Acme");
+    press(&mut app, KeyCode::Esc);
+    assert!(matches!(app.input_mode, InputMode::DraftPrompt));
+    press(&mut app, KeyCode::Char('d'));
+    assert!(app.jobs.is_empty(), "discarding adds nothing");
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Acme");
+    type_text(&mut app, "Engineer");
+    for c in "https://acme.com/jobs/1".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Esc);
+    assert!(matches!(app.input_mode, InputMode::Editing), "Esc again goes back to typing");
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('s'));
+    assert_eq!(app.jobs.len(), 1);
+    assert!(app.jobs[0].draft);
+    assert_eq!(app.jobs[0].post_link, "https://acme.com/jobs/1", "the open prompt is kept too");
+    assert_eq!(app.jobs[0].draft_label(), "Draft 3/5");
+    assert_eq!(app.jobs[0].missing_fields(), ["salary", "location"]);
+    let selection = career_cli::scope::Scope::default().select(&app.jobs, &[], &[]).unwrap();
+    assert!(selection.jobs.is_empty(), "drafts stay out of the stats");
+
+    // Picks up at the first empty prompt, with the rest filled in
+    press(&mut app, KeyCode::Char('f'));
+    assert!(matches!(app.input_field, career_cli::app::InputField::Salary));
+    type_text(&mut app, "120k-150k");
+    type_text(&mut app, "Berlin, hybrid");
+    type_text(&mut app, "");
+    assert_eq!(app.jobs.len(), 1);
+    assert!(!app.jobs[0].draft);
+    assert_eq!(app.jobs[0].salary_label().unwrap(), "120k-150k");
+    press(&mut app, KeyCode::Char('f'));
+    assert!(matches!(app.input_mode, InputMode::Normal));
+    app.save().unwrap();
+    assert!(!storage.jobs()[0].draft);
+}
+
+#[test]
+fn priority_cycles_from_the_list_and_sorts_top_targets_first() {
+    use career_cli::models::Priority;
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        JobBuilder::new("Globex", "SRE").id(2).build(),
+        JobBuilder::new("Initech", "Dev").id(3).build(),
+    ]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('!'));
+    press(&mut app, KeyCode::Char('!'));
+    assert_eq!(app.jobs[0].priority, Priority::Low);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('!'));
+    assert_eq!(app.jobs[2].priority, Priority::High);
+
+    press(&mut app, KeyCode::Char('='));
+    let order: Vec<&str> = app.visible.iter().map(|&i| app.jobs[i].company.as_str()).collect();
+    assert_eq!(order, ["Initech", "Globex", "Acme"]);
+    assert_eq!(app.jobs[app.selected_index().unwrap()].company, "Initech", "the selection moves with the job");
+    press(&mut app, KeyCode::Char('='));
+    press(&mut app, KeyCode::Char('='));
+    assert_eq!(app.visible, [0, 1, 2]);
+
+    let filter = career_cli::filter::Filter::parse("priority:high", &[]).unwrap();
+    assert!(filter.matches(&app.jobs[2]) && !filter.matches(&app.jobs[1]));
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[0].priority, Priority::Low);
+}
+
+#[test]
+fn overdue_follow_ups_jump_to_the_top_of_the_list() {
+    use career_cli::models::{agenda, DueKind};
+    let today = chrono::Local::now().date_naive();
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").id(1).build(),
+        JobBuilder::new("Globex", "SRE").id(2).build(),
+        JobBuilder::new("Initech", "Dev").id(3).status(Status::Rejected).build(),
+    ]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('Z'));
+    press(&mut app, KeyCode::Char('Z')); // Active and closed jobs both
+    app.state.select(Some(0));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('F'));
+    type_text(&mut app, "someday");
+    assert!(app.message.is_some(), "stays on the prompt until it parses");
+    app.input_buffer.clear();
+    type_text(&mut app, "3d");
+    assert_eq!(app.jobs[1].follow_up.unwrap().date_naive(), today + chrono::Days::new(3));
+    assert_eq!(app.visible, [0, 1, 2], "not due yet");
+
+    app.jobs[1].follow_up = Some(chrono::Utc::now() - chrono::Duration::days(2));
+    app.jobs[2].follow_up = Some(chrono::Utc::now() - chrono::Duration::days(2));
+    app.refresh_view();
+    assert_eq!(app.visible, [1, 0, 2], "closed jobs don't need nudging");
+    assert!(agenda(&app.jobs).iter().any(|due| due.job.id == JobId::legacy(2) && matches!(due.kind, DueKind::FollowUp)));
+
+    press(&mut app, KeyCode::Char('F'));
+    assert_eq!(app.input_buffer, app.jobs[1].follow_up.unwrap().date_naive().to_string(), "the selection followed it up");
+    app.input_buffer.clear();
+    type_text(&mut app, "");
+    assert!(app.jobs.iter().all(|j| j.id == JobId::legacy(3) || j.follow_up.is_none()));
+}
+
+#[test]
+fn field_rules_block_bad_input_and_flag_saved_jobs() {
+    use career_cli::config::ValidationConfig;
+    use career_cli::validate;
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").id(1).build()]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "  ");
+    assert!(matches!(app.input_field, career_cli::app::InputField::Company), "no company, no moving on");
+    assert_eq!(app.message.as_deref(), Some(" Company can't be empty "));
+    app.input_buffer.clear();
+    type_text(&mut app, "Globex");
+    type_text(&mut app, "SRE");
+    for c in "htp:/nowhere".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    assert!(app.input_error().unwrap().contains("isn't a link"), "shown while typing");
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.input_field, career_cli::app::InputField::Link));
+    app.input_buffer.clear();
+    type_text(&mut app, "boards.greenhouse.io/globex/1");
+    type_text(&mut app, "0");
+    assert_eq!(app.message.as_deref(), Some(" Pay has to be more than 0 "));
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('d'));
+    assert_eq!(app.jobs.len(), 1);
+    assert!(career_cli::bulk::Assignment::parse("company=").is_err());
+
+    let today = chrono::Local::now().date_naive();
+    let mut job = JobBuilder::new("", "Dev").id(2).link("not a link").build();
+    job.date_applied = chrono::Utc::now() + chrono::Duration::days(3);
+    let problems = validate::job(&job, &ValidationConfig::default(), today);
+    assert_eq!(problems.len(), 3, "{:?}", problems);
+    assert!(problems[0].starts_with("company:") && problems[1].starts_with("link:") && problems[2].starts_with("applied:"));
+    let relaxed = ValidationConfig { allow_future_dates: true };
+    assert_eq!(validate::job(&job, &relaxed, today).len(), 2);
+    job.draft = true;
+    assert_eq!(validate::job(&job, &relaxed, today).len(), 1, "drafts can wait for their company");
+
+    let mut answered = JobBuilder::new("Acme", "Dev").build();
+    answered.set_status(Status::Interviewing);
+    answered.date_applied = chrono::Utc::now();
+    assert!(validate::job(&answered, &ValidationConfig::default(), today).is_empty(), "what happened just now is never in the future here");
+}
+
+#[test]
+fn resume_version_is_recorded_and_completes_from_earlier_ones() {
+    let mut acme = JobBuilder::new("Acme", "Engineer").id(1).build();
+    acme.resume_version = "backend-v3.pdf".to_string();
+    let storage = MemoryStorage::new(vec![acme, JobBuilder::new("Globex", "SRE").id(2).build()]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('V'));
+    for c in "back".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    assert_eq!(app.suggestion().as_deref(), Some("backend-v3.pdf"));
+    press(&mut app, KeyCode::Tab);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[1].resume_version, "backend-v3.pdf");
+    assert!(!app.jobs[1].resume_missing(), "labels aren't checked on disk");
+
+    press(&mut app, KeyCode::Char('V'));
+    app.input_buffer.clear();
+    type_text(&mut app, "/nonexistent/cv/sre.pdf");
+    assert!(app.jobs[1].resume_missing());
+    assert!(app.message.as_deref().unwrap().contains("no file"));
+    let filter = career_cli::filter::Filter::parse("resume:backend", &[]).unwrap();
+    assert!(filter.matches(&app.jobs[0]) && !filter.matches(&app.jobs[1]));
+}
+
+#[test]
+fn pasted_salary_text_is_read_into_a_yearly_range_with_its_currency() {
+    use career_cli::salary::{extract, Period};
+    let found = extract("Compensation: $150k–$180k base + equity").unwrap();
+    assert_eq!((found.min, found.max, found.currency, found.per), (150_000, 180_000, "USD", Period::Year));
+    assert_eq!(extract("£70,000 per annum, plus 401k matching").unwrap().yearly(), (70_000, 70_000));
+    let hourly = extract("USD 45-60 an hour, 40 hours a week").unwrap();
+    assert_eq!((hourly.per, hourly.yearly()), (Period::Hour, (93_600, 124_800)));
+    assert!(extract("10,000 employees and a 401k").is_none(), "amounts without a currency are skipped");
+
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").id(1).build()]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('P'));
+    type_text(&mut app, "Salary: between €60.000 and €75.000 gross per year");
+    assert_eq!(app.jobs[0].salary_label().as_deref(), Some("€60k-75k"));
+    press(&mut app, KeyCode::Char('P'));
+    assert_eq!(app.input_buffer, "€60000-75000");
+    app.input_buffer.clear();
+    type_text(&mut app, "80k");
+    assert_eq!(app.jobs[0].salary_label().as_deref(), Some("€80k"), "a bare amount keeps the currency");
+
+    use career_cli::models::parse_salary;
+    assert_eq!(parse_salary("120k–150k"), Some((Some(120_000), Some(150_000))));
+    assert_eq!(parse_salary("$120,000 — $150,000"), Some((Some(120_000), Some(150_000))));
+    assert_eq!(parse_salary("120k to 150k"), Some((Some(120_000), Some(150_000))));
+    assert_eq!(parse_salary("5000000k"), None, "more than fits isn't read as the most that does");
+}
+
+#[test]
+fn cover_letters_are_marked_needed_until_one_is_sent() {
+    use career_cli::models::LetterStatus;
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").id(1).build()]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('K'));
+    type_text(&mut app, "needed");
+    assert_eq!(app.jobs[0].letter_status, LetterStatus::Needed);
+    assert_eq!(app.jobs[0].cover_letter_label().as_deref(), Some("Needed"));
+    let still_needed = career_cli::filter::Filter::parse("letter:needed", &[]).unwrap();
+    assert!(still_needed.matches(&app.jobs[0]));
+
+    // A file on its own means it went out
+    press(&mut app, KeyCode::Char('K'));
+    assert_eq!(app.input_buffer, "needed");
+    app.input_buffer.clear();
+    type_text(&mut app, "/nonexistent/letters/acme.pdf");
+    assert_eq!(app.jobs[0].letter_status, LetterStatus::Sent);
+    assert_eq!(app.jobs[0].cover_letter_label().unwrap(), "Sent, /nonexistent/letters/acme.pdf (file not found)");
+    assert!(!still_needed.matches(&app.jobs[0]));
+    press(&mut app, KeyCode::Char('K'));
+    assert_eq!(app.input_buffer, "sent /nonexistent/letters/acme.pdf");
+    app.input_buffer.clear();
+    press(&mut app, KeyCode::Enter);
+    assert_eq!((app.jobs[0].letter_status, app.jobs[0].cover_letter.clone()), (LetterStatus::NotNeeded, None));
+    assert_eq!(career_cli::models::parse_cover_letter("Not  needed"), (LetterStatus::NotNeeded, None));
+    assert_eq!(career_cli::models::parse_cover_letter("not required"), (LetterStatus::NotNeeded, None));
+}
+
+#[test]
+fn field_blame_shows_the_last_change_to_each_field_and_what_made_it() {
+    use career_cli::journal::{blame, JournalEntry, JournalOp, Source};
+    let acme = JobBuilder::new("Acme", "Engineer").id(1).build();
+    let entry = |field: &str, secs: i64, source| JournalEntry {
+        op: JournalOp::Update,
+        job_id: "1".to_string(),
+        field: Some(field.to_string()),
+        old: None,
+        new: Some("x".into()),
+        timestamp: chrono::DateTime::from_timestamp(1_790_000_000 + secs, 0).unwrap(),
+        device_id: "laptop".to_string(),
+        undoes: None,
+        undoes_device: None,
+        source,
+    };
+    let journal = vec![
+        entry("status", 10, Some(Source::Edit)),
+        entry("revision", 10, Some(Source::Edit)),
+        entry("updated_at", 10, Some(Source::Edit)),
+        entry("post_link", 20, None),
+        entry("status", 30, Some(Source::Bulk)),
+    ];
+    let fields: Vec<(String, Option<Source>)> = blame(&journal, "1").into_iter().map(|b| (b.field, b.source)).collect();
+    assert_eq!(fields, [("status".to_string(), Some(Source::Bulk)), ("post_link".to_string(), None)]);
+
+    let storage = MemoryStorage::new(vec![acme]);
+    let mut app = app_with(&storage);
+    app.changes = journal;
+    press(&mut app, KeyCode::Char('e'));
+    app.input_buffer.clear();
+    type_text(&mut app, "https://acme.com/jobs/1");
+    let shown = app.field_blame(&app.jobs[0]);
+    assert_eq!(shown[0], ("post_link".to_string(), "not saved yet".to_string()));
+    assert_eq!(shown.len(), 2, "the saved link change is hidden behind the unsaved one");
+    assert!(shown[1].1.ends_with(", bulk set"), "{:?}", shown);
+}
+
+#[test]
+fn imports_wait_in_the_inbox_until_accepted_edited_or_discarded() {
+    use career_cli::app::InputMode;
+    let csv = "Company,Title,URL,Applied,Salary\n\
+               Acme,Engineer,https://acme.com/jobs/1,2026-09-01,$120k-140k\n\
+               \"Globex, Inc\",\"SRE \"\"on call\"\"\",,2026-09-02,\n\
+               ,Nobody,,,\n\
+               Initech,Dev,not a link,,\n\
+               Umbrella,Analyst,,2026-09-03,\n";
+    let (incoming, skipped) = career_cli::inbox::parse_csv(csv).unwrap();
+    assert_eq!(incoming.iter().map(|j| j.company.as_str()).collect::<Vec<_>>(), ["Acme", "Globex, Inc", "Umbrella"]);
+    assert_eq!(incoming[1].role, "SRE \"on call\"");
+    assert_eq!(incoming[0].salary_label().as_deref(), Some("$120k-140k"));
+    assert_eq!(skipped.len(), 2);
+    assert!(skipped[0].starts_with("line 4:") && skipped[1].starts_with("line 5:"), "{:?}", skipped);
+    let mut pending = Vec::new();
+    career_cli::inbox::queue(&mut pending, incoming);
+
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Hooli", "PM").id(1).build()]).with_inbox(pending);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('m'));
+    assert!(matches!(app.input_mode, InputMode::Inbox));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs.len(), 2);
+    assert_eq!(app.jobs[1].company, "Acme");
+    press(&mut app, KeyCode::Char('d'));
+    assert_eq!(app.jobs.len(), 2, "discarded, not added");
+
+    // Editing runs the add prompts over it and keeps the applied date
+    press(&mut app, KeyCode::Char('e'));
+    assert!(matches!(app.input_mode, InputMode::Editing));
+    assert_eq!(app.input_buffer, "Umbrella");
+    for _ in 0..3 {
+        press(&mut app, KeyCode::Enter);
+    }
+    type_text(&mut app, "90k");
+    type_text(&mut app, "remote");
+    let umbrella = &app.jobs[2];
+    assert_eq!((umbrella.salary_label().as_deref(), umbrella.draft), (Some("90k"), false));
+    assert_eq!(umbrella.date_applied.date_naive().to_string(), "2026-09-03");
+
+    assert_eq!(storage.inbox_jobs().len(), 3, "the inbox only changes on save");
+    app.save().unwrap();
+    assert!(storage.inbox_jobs().is_empty() && app.inbox.is_empty());
+    assert_eq!(storage.jobs().len(), 3);
+}
+
+#[test]
+fn jobs_in_pay_transparency_places_ask_for_the_posted_range() {
+    use career_cli::transparency::{jurisdiction, report};
+    assert_eq!(jurisdiction("Denver, CO"), Some("Colorado"));
+    assert_eq!(jurisdiction("NYC (hybrid)"), Some("New York"));
+    assert_eq!(jurisdiction("Costa Mesa"), None, "a state code only counts after a comma");
+    assert_eq!(jurisdiction("Berlin"), None);
+    assert_eq!(jurisdiction("Toronto, CA"), None, "CA last is Canada");
+    assert_eq!(jurisdiction("Oakland, CA 94607"), Some("California"));
+    assert_eq!(jurisdiction("Sacramento, CA, USA"), Some("California"));
+    assert_eq!(jurisdiction("Olympia, WA"), Some("Washington"));
+    assert_eq!(jurisdiction("Newark, DE"), None, "the city goes with the state given");
+    assert_eq!(jurisdiction("Newark, NJ"), Some("New Jersey"));
+    assert_eq!(jurisdiction("Newark, CA"), None, "not the one in New Jersey");
+    assert_eq!(jurisdiction("Vancouver, WA"), Some("Washington"));
+
+    let storage = MemoryStorage::new(Vec::new());
+    let mut app = app_with(&storage);
+    let add = |app: &mut App, company: &str, salary: &str, location: &str| {
+        press(app, KeyCode::Char('a'));
+        type_text(app, company);
+        type_text(app, "Engineer");
+        type_text(app, "");
+        type_text(app, salary);
+        type_text(app, location);
+    };
+
+    // The salary already typed is offered as the posted range
+    add(&mut app, "Acme", "$120k-150k", "Denver, CO");
+    assert!(matches!(app.input_field, career_cli::app::InputField::PayDisclosure));
+    assert_eq!(app.input_buffer, "Colorado $120k-150k");
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.input_field, career_cli::app::InputField::Source), "then on to the source");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].pay_disclosure.as_ref().unwrap().label(), "Colorado, posted $120k-150k");
+
+    add(&mut app, "Globex", "", "Boulder, CO");
+    app.input_buffer = "Colorado $competitive".to_string();
+    press(&mut app, KeyCode::Enter);
+    assert!(app.message.as_deref().unwrap().contains("isn't a pay range"));
+    app.input_buffer = "Colorado none".to_string();
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Enter);
+
+    // A range typed only here fills in the salary
+    add(&mut app, "Initech", "", "Seattle");
+    app.input_buffer = "Washington $90k-110k".to_string();
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[2].salary_label().as_deref(), Some("$90k-110k"));
+
+    // Elsewhere it goes straight to the source
+    add(&mut app, "Umbrella", "", "Berlin");
+    assert!(matches!(app.input_field, career_cli::app::InputField::Source));
+    press(&mut app, KeyCode::Enter);
+    assert!(app.jobs[3].pay_disclosure.is_none());
+
+    let report = report(&app.jobs);
+    let rows: Vec<(&str, usize, Vec<&str>)> = report
+        .iter()
+        .map(|(name, all, missing)| (name.as_str(), all.len(), missing.iter().map(|j| j.company.as_str()).collect()))
+        .collect();
+    assert_eq!(rows, [("Colorado", 2, vec!["Globex"]), ("Washington", 1, vec![])]);
+}
+
+#[test]
+fn adding_a_job_asks_where_it_came_from_with_a_guess_from_the_link() {
+    let storage = MemoryStorage::new(Vec::new());
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Acme");
+    type_text(&mut app, "Engineer");
+    type_text(&mut app, "https://www.linkedin.com/jobs/view/123");
+    type_text(&mut app, "");
+    type_text(&mut app, "remote");
+    assert!(matches!(app.input_field, career_cli::app::InputField::Source));
+    assert_eq!(app.input_buffer, "LinkedIn");
+    app.input_buffer.clear();
+    for c in "ref".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    assert_eq!(app.suggestion().as_deref(), Some("Referral"));
+    app.input_buffer.clear();
+    type_text(&mut app, "referred");
+    assert_eq!(app.jobs[0].source.as_deref(), Some("Referral"));
+
+    press(&mut app, KeyCode::Char('g'));
+    app.input_buffer.clear();
+    type_text(&mut app, "Meetup");
+    assert_eq!(app.jobs[0].source.as_deref(), Some("Meetup"), "other sources are kept as typed");
+    let filter = career_cli::filter::Filter::parse("source:meetup", &[]).unwrap();
+    assert!(filter.matches(&app.jobs[0]));
+    assert!(!career_cli::filter::Filter::parse("source:none", &[]).unwrap().matches(&app.jobs[0]));
+}
+
+#[test]
+fn roles_at_the_same_company_share_its_record() {
+    use career_cli::companies::Company;
+    let mut google = Company::new("Google");
+    google.notes = "Hiring freeze until Q1".to_string();
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Google", "SRE").build()]).with_companies(vec![google]);
+    let mut app = app_with(&storage);
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "google LLC");
+    assert_eq!(app.temp_company, "Google");
+    assert!(app.message.as_deref().is_some_and(|m| m.contains("Filed under Google")));
+    for _ in 0..5 {
+        type_text(&mut app, "");
+    }
+    let added = app.jobs.last().unwrap();
+    assert_eq!(added.company, "Google");
+    assert_eq!(app.company_record(added).map(|c| c.notes.as_str()), Some("Hiring freeze until Q1"));
+
+    // New companies get a record of their own, and notes edited from one role show on the other
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Initech Inc.");
+    for _ in 0..5 {
+        type_text(&mut app, "");
+    }
+    press(&mut app, KeyCode::Char('h'));
+    press(&mut app, KeyCode::Char('n'));
+    assert_eq!(app.input_buffer, "Hiring freeze until Q1");
+    app.input_buffer.clear();
+    type_text(&mut app, "Recruiter is Ana");
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::CompanyHistory));
+    app.save().unwrap();
+
+    let records = storage.company_records();
+    assert_eq!(records.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), ["Google", "Initech Inc."]);
+    assert_eq!(records[0].notes, "Recruiter is Ana");
+}
+
+#[test]
+fn csv_exports_read_back_through_import() {
+    let mut job = JobBuilder::new("Initech, Inc.", "Dev").status(Status::Interviewing).notes("Said \"soon\"\nsecond line").build();
+    (job.salary_min, job.salary_max, job.salary_currency) = (Some(90_000), Some(110_000), Some("EUR".to_string()));
+    (job.location, job.work_mode) = career_cli::models::parse_location("Berlin, hybrid");
+    job.source = Some("Referral".to_string());
+    let csv = career_cli::export::to_csv(&[job.clone()]);
+
+    let (imported, skipped) = career_cli::inbox::parse_csv(&csv).unwrap();
+    assert!(skipped.is_empty(), "{:?}", skipped);
+    let back = &imported[0];
+    assert_eq!((back.company.as_str(), back.role.as_str(), back.status.clone()), ("Initech, Inc.", "Dev", Status::Interviewing));
+    assert_eq!((back.salary_min, back.salary_max, back.salary_currency.as_deref()), (Some(90_000), Some(110_000), Some("EUR")));
+    assert_eq!(back.location_label(), job.location_label());
+    assert_eq!(back.date_applied.date_naive(), job.date_applied.date_naive());
+    assert_eq!((back.source.as_deref(), back.notes.as_str()), (Some("Referral"), job.notes.as_str()));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("jobs.csv");
+    assert!(career_cli::export::write_if_changed(&path, &csv).unwrap());
+    assert!(!career_cli::export::write_if_changed(&path, &csv).unwrap(), "an unchanged copy isn't rewritten");
+
+    let mut screened = JobBuilder::new("Acme", "Engineer").build();
+    screened.set_custom_status("Phone screen", Status::Interviewing);
+    let (imported, skipped) = career_cli::inbox::parse_csv(&career_cli::export::to_csv(&[screened])).unwrap();
+    assert!(skipped.is_empty(), "a custom status comes back as the one it counts as: {:?}", skipped);
+    assert_eq!(imported[0].status, Status::Interviewing);
+}
+
+#[test]
+fn archived_jobs_leave_the_list_until_shown() {
+    let storage = MemoryStorage::new(vec![
+        JobBuilder::new("Acme", "Engineer").status(Status::Rejected).build(),
+        JobBuilder::new("Globex", "SRE").id(2).build(),
+    ]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('Z'));
+    press(&mut app, KeyCode::Char('Z')); // Active and closed jobs both
+    app.state.select(Some(0));
+
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('z'));
+    assert!(!app.jobs[1].archived, "open applications stay in the list");
+
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Char('z'));
+    assert!(app.jobs[0].archived);
+    assert_eq!(app.visible, vec![1]);
+    assert_eq!(app.selected_index(), Some(1));
+
+    press(&mut app, KeyCode::Char('Z'));
+    assert_eq!(app.visible, vec![0, 1]);
+    app.save().unwrap();
+    assert!(storage.jobs()[0].archived, "archiving keeps the job");
+
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Char('z'));
+    assert!(!app.jobs[0].archived, "'z' again brings it back");
+}
+
+#[cfg(unix)]
+#[test]
+fn commands_hand_their_change_to_the_open_tui() {
+    use career_cli::ipc::{send_to, Request, Server};
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tui.sock");
+    assert_eq!(send_to(&path, &Request::Note { job: "1".into(), text: "hi".into() }).unwrap(), None, "nothing open, do it directly");
+
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").build()]);
+    let mut app = app_with(&storage);
+    let server = Server::bind(&path).unwrap();
+    assert!(Server::bind(&path).is_err(), "only one TUI at a time");
+
+    let client_path = path.clone();
+    let client = std::thread::spawn(move || {
+        let add = Request::Add {
+            company: "acme inc".into(),
+            role: "SRE".into(),
+            link: String::new(),
+            posting: "Pay: $100k-$120k".into(),
+            separate: false,
+        };
+        let added = send_to(&client_path, &add).unwrap();
+        let missing = send_to(&client_path, &Request::Note { job: "ffff".into(), text: "hi".into() });
+        (added, missing.map_err(|e| e.to_string()))
+    });
+    while !client.is_finished() {
+        server.serve(|request| app.handle_request(request));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let (added, missing) = client.join().unwrap();
+    assert_eq!(added.as_deref(), Some("Added Acme - SRE (in the open TUI)"));
+    assert!(missing.unwrap_err().contains("No job matches"));
+
+    let saved = storage.jobs();
+    assert_eq!(saved.len(), 2, "saved as it came in");
+    assert_eq!((saved[1].salary_min, saved[1].salary_max), (Some(100_000), Some(120_000)));
+    assert_eq!(app.visible.len(), 2);
+
+    drop(server);
+    assert!(!path.exists(), "the socket goes with the TUI");
+}
+
+#[test]
+fn handed_over_changes_save_alone_and_leave_nothing_behind_when_they_fail() {
+    use career_cli::ipc::Request;
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").build()]);
+    let mut app = app_with(&storage);
+    app.jobs[0].role = "Staff Engineer".into(); // Not saved yet
+    let note = Request::Note { job: app.jobs[0].short_id(), text: "Called Dana".into() };
+
+    app.handle_request(note).unwrap();
+    let saved = &storage.jobs()[0];
+    assert!(saved.notes.contains("Called Dana"));
+    assert_eq!(saved.role, "Engineer", "the unsaved edit stays unsaved");
+    assert_eq!(app.jobs[0].role, "Staff Engineer");
+    assert!(app.jobs[0].notes.contains("Called Dana"));
+    app.save().unwrap();
+    assert_eq!(storage.jobs()[0].role, "Staff Engineer");
+
+    let mut app = App::new(Box::new(ReadOnlyStorage), Vec::new(), Config::default()).unwrap();
+    let before: Vec<String> = app.jobs.iter().map(|job| job.notes.clone()).collect();
+    let note = Request::Note { job: app.jobs[0].short_id(), text: "Called Dana".into() };
+    assert!(app.handle_request(note).is_err());
+    assert_eq!(app.jobs.iter().map(|job| job.notes.clone()).collect::<Vec<_>>(), before);
+}
+
+#[test]
+fn a_changed_config_applies_while_running_and_a_bad_one_is_refused() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+
+    let mut config = Config::default();
+    config.keymap.insert("down".into(), vec!["j".into()]);
+    config.theme.glyphs = career_cli::theme::GlyphStyle::Ascii;
+    app.apply_config(config.clone()).unwrap();
+    press(&mut app, KeyCode::Char('j'));
+    assert_eq!(app.selected_index(), Some(1));
+    assert!(app.theme.status_glyph(&Status::Applied).is_some());
+
+    config.keymap.insert("delete".into(), vec!["j".into()]);
+    let err = app.apply_config(config).unwrap_err();
+    assert!(err.to_string().contains("bound to both"));
+    press(&mut app, KeyCode::Char('j'));
+    assert_eq!(app.selected_index(), Some(2), "the last good keymap stays");
+}
+
+#[test]
+fn starred_jobs_are_marked_and_filter_on_their_own() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Char('*'));
+    assert!(app.jobs[1].starred);
+
+    press(&mut app, KeyCode::Char('/'));
+    type_text(&mut app, "*");
+    assert_eq!(app.visible, vec![1]);
+    let unstarred = career_cli::filter::Filter::parse("starred:no", &[]).unwrap();
+    assert_eq!(app.jobs.iter().filter(|j| unstarred.matches(j)).count(), app.jobs.len() - 1);
+
+    // Marked in the list in the glyph style, plain ASCII included
+    use ratatui::{backend::TestBackend, Terminal};
+    let mut config = Config::default();
+    config.theme.glyphs = career_cli::theme::GlyphStyle::Ascii;
+    app.apply_config(config).unwrap();
+    let mut terminal = Terminal::new(TestBackend::new(200, 50)).unwrap();
+    terminal.draw(|frame| career_cli::ui::ui(frame, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let screen: String = (0..buffer.area.height).flat_map(|y| (0..buffer.area.width).map(move |x| (x, y))).map(|(x, y)| buffer.get(x, y).symbol()).collect();
+    assert!(screen.contains(&format!("* {}", app.jobs[1].company)) && !screen.contains('★'));
+
+    press(&mut app, KeyCode::Char('*'));
+    assert!(!app.jobs[1].starred, "the same key takes the star off");
+}
+
+#[test]
+fn the_applied_date_can_be_back_filled() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    let today = chrono::Local::now().date_naive();
+
+    press(&mut app, KeyCode::Char('Y'));
+    assert_eq!(app.input_buffer, app.jobs[0].date_applied.format("%Y-%m-%d").to_string());
+    app.input_buffer.clear();
+    type_text(&mut app, "someday");
+    assert!(app.message.as_deref().is_some_and(|m| m.contains("look like")));
+    app.input_buffer.clear();
+    type_text(&mut app, &(today + chrono::Days::new(3)).to_string());
+    assert!(app.message.as_deref().is_some_and(|m| m.contains("in the future")));
+    app.input_buffer.clear();
+    type_text(&mut app, "2w ago");
+    assert_eq!(app.jobs[0].date_applied.date_naive(), today - chrono::Days::new(14));
+
+    press(&mut app, KeyCode::Char('Y'));
+    app.input_buffer.clear();
+    type_text(&mut app, "2025-03-01");
+    assert_eq!(app.jobs[0].date_applied.date_naive().to_string(), "2025-03-01");
+}
+
+#[test]
+fn settings_change_in_place_and_are_written_back() {
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char(','));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.sort, career_cli::config::SortOrder::Priority, "the new default sort applies straight away");
+    assert_eq!(storage.saved_config().unwrap().list.sort, career_cli::config::SortOrder::Priority);
+
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.input_buffer, "14");
+    app.input_buffer.clear();
+    type_text(&mut app, "0");
+    assert!(app.message.is_some(), "out of range is refused");
+    assert_eq!(app.config.metrics.health.stale_days, 14);
+
+    app.input_buffer.clear();
+    type_text(&mut app, "30");
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Settings));
+    assert_eq!(app.config.metrics.health.stale_days, 30);
+    assert_eq!(storage.saved_config().unwrap().metrics.health.stale_days, 30);
+}
+
+#[test]
+fn saving_the_config_keeps_its_comments() {
+    let existing = "# My setup\n[list]\nsort = \"added\" # oldest first\n\n[metrics]\nbusiness_days = true\n";
+    let mut config: Config = toml::from_str(existing).unwrap();
+    config.list.sort = career_cli::config::SortOrder::Priority;
+    config.metrics.health.stale_days = 30;
+
+    let written = career_cli::config::updated_config(existing, &config).unwrap();
+    assert!(written.starts_with("# My setup\n[list]\nsort = \"priority\" # oldest first\n"), "{}", written);
+    assert!(written.contains("business_days = true"));
+    let read_back: Config = toml::from_str(&written).unwrap();
+    assert_eq!(read_back.list.sort, career_cli::config::SortOrder::Priority);
+    assert_eq!(read_back.metrics.health.stale_days, 30);
+}
+
+#[test]
+fn charts_are_drawn_or_refused_by_the_build() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("funnel.svg");
+    let result = career_cli::chart::render_chart(&career_cli::chart::ChartKind::Funnel, &sample_jobs(), &output);
+    if cfg!(feature = "charts") {
+        result.unwrap();
+        assert!(output.exists());
+    } else {
+        assert!(result.unwrap_err().to_string().contains("--features charts"));
+        assert!(!output.exists());
+    }
+}
+
+#[test]
+fn a_rejection_asks_how_far_it_got() {
+    let storage = MemoryStorage::new(vec![JobBuilder::new("Acme", "Engineer").id(1).status(Status::Accepted).build()]);
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('Z'));
+    press(&mut app, KeyCode::Char('-'));
+    assert_eq!(app.jobs[0].status, Status::Rejected);
+    type_text(&mut app, "lunch");
+    assert!(app.message.is_some(), "not a stage");
+    app.input_buffer.clear();
+    type_text(&mut app, "phone screen");
+    type_text(&mut app, "Wanted more Go");
+    assert_eq!(app.jobs[0].rejected_stage, Some(career_cli::models::RejectionStage::Phone));
+    assert_eq!(app.jobs[0].rejection_label().as_deref(), Some("Phone screen: Wanted more Go"));
+    assert_eq!(career_cli::metrics::rejection_stages(&app.jobs), vec![("Phone screen", 1)]);
+}
+
+#[test]
+fn usage_is_recorded_locally_and_notices_looking_without_applying() {
+    use career_cli::usage::{summarize, UsageEvent, UsageKind};
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    for _ in 0..3 {
+        app.note_usage(UsageKind::Session, "tui");
+    }
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Char('u'));
+    assert_eq!(app.usage.as_ref().unwrap().views, vec![("stats".to_string(), 1)]);
+    app.save().unwrap();
+    assert_eq!(storage.usage_events().len(), 5);
+
+    // Sample jobs were all applied for long ago
+    let summary = summarize(&storage.usage_events(), &app.jobs, chrono::Local::now().date_naive());
+    assert!(summary.avoiding());
+    assert_eq!(summary.weeks[0].1, 3);
+
+    let mut config = Config::default();
+    config.usage.track = false;
+    let quiet = MemoryStorage::new(sample_jobs());
+    let mut app = App::new(Box::new(quiet.clone()), Vec::new(), config).unwrap();
+    app.note_usage(UsageKind::Session, "tui");
+    app.save().unwrap();
+    assert_eq!(quiet.usage_events(), Vec::<UsageEvent>::new());
+}
+
+#[test]
+fn availability_leaves_out_the_time_interviews_already_take() {
+    use career_cli::availability::message;
+    let config: Config = toml::from_str(
+        r#"
+        [availability]
+        timezone = "ET"
+        windows = [
+            { day = "Tue", from = "14:00", to = "17:00" },
+            { day = "Tue", from = "09:30", to = "11:00" },
+            { day = "Thu", from = "11:00", to = "13:00" },
+        ]
+        "#,
+    )
+    .unwrap();
+    // A Monday, so the two weeks hold two of each weekday
+    let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    let mut job = JobBuilder::new("Acme", "Engineer").status(Status::Interviewing).build();
+    job.add_interview(career_cli::models::Interview::new(today + chrono::Days::new(3), "Onsite"));
+    // 14:30-15:00 with the half-hour buffer either side
+    let mut screen = career_cli::models::Interview::new(today + chrono::Days::new(8), "Screen");
+    screen.start = chrono::NaiveTime::from_hms_opt(14, 30, 0);
+    screen.end = chrono::NaiveTime::from_hms_opt(15, 0, 0);
+    job.add_interview(screen);
+
+    let text = message(&config.availability, &[job.clone()], today, &config.interviews).unwrap();
+    assert_eq!(
+        text.lines().collect::<Vec<_>>(),
+        [
+            "I'm free for a call at these times over the next two weeks (times ET):",
+            "- Tue Oct 20: 9:30-11am, 2-5pm",
+            "- Tue Oct 27: 9:30-11am, 3:30-5pm",
+            "- Thu Oct 29: 11am-1pm",
+        ]
+    );
+
+    // A closed job's interview isn't happening
+    job.status = Status::Rejected;
+    assert!(message(&config.availability, &[job], today, &config.interviews).unwrap().contains("Thu Oct 22"));
+
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('@'));
+    assert!(app.message.as_deref().is_some_and(|m| m.contains("[availability]")));
+}
+
+#[test]
+fn interviews_too_close_to_another_warn_before_saving() {
+    use career_cli::models::Interview;
+    let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+    let mut jobs = sample_jobs();
+    jobs[1].add_interview(Interview::parse("2026-11-03 14:00-15:00 Final round", today));
+    let storage = MemoryStorage::new(jobs);
+    let mut app = app_with(&storage);
+
+    // Ten minutes after the other one ends, inside the default 30
+    press(&mut app, KeyCode::Char('I'));
+    type_text(&mut app, "2026-11-03 15:10 Onsite with Ana");
+    assert!(app.jobs[0].interviews.is_empty(), "not saved yet");
+    assert!(matches!(app.input_mode, career_cli::app::InputMode::Editing));
+    assert!(app.message.as_deref().unwrap().contains("10 min from Globex Final round 14:00-15:00"));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.jobs[0].interviews[0].when(), "2026-11-03 15:10 Onsite");
+
+    let config = career_cli::config::InterviewConfig::default();
+    let clash = |input: &str| {
+        let clashes = career_cli::models::interview_clashes(&app.jobs[1..], &Interview::parse(input, today), &config);
+        clashes.iter().map(|c| c.describe()).collect::<Vec<_>>()
+    };
+    assert_eq!(clash("2026-11-03 14:30 Screen"), ["overlaps Globex Final round 14:00-15:00"]);
+    assert_eq!(clash("2026-11-03 Screen"), ["same day as Globex Final round 14:00-15:00"]);
+    assert!(clash("2026-11-03 12:00-13:30 Screen").is_empty());
+    assert!(clash("2026-11-04 14:00 Screen").is_empty());
+}
+
+#[test]
+fn old_numeric_ids_read_as_uuids_that_keep_their_short_id() {
+    let old = serde_json::json!({
+        "id": 3, "company": "Acme", "role": "Engineer", "status": "Applied",
+        "notes": "", "date_applied": "2025-01-06T09:00:00Z"
+    });
+    let job: career_cli::models::Job = serde_json::from_value(old).unwrap();
+    assert_eq!(job.id, JobId::legacy(3));
+    assert_eq!(job.short_id(), "db018fed", "the short ID the number always had");
+    assert!(job.id.to_string().starts_with("db018fed-"));
+
+    // Written back as the UUID, and read again as the same one
+    let json = serde_json::to_value(&job).unwrap();
+    assert_eq!(json["id"], serde_json::Value::String(job.id.to_string()));
+    let again: career_cli::models::Job = serde_json::from_value(json).unwrap();
+    assert_eq!(again.id, job.id);
+    assert!(career_cli::journal::same_id("3", &job.id.to_string()), "old journal entries still find it");
+
+    // New jobs don't collide with anything, deleted or not
+    let storage = MemoryStorage::new(sample_jobs());
+    let mut app = app_with(&storage);
+    press(&mut app, KeyCode::Char('d'));
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Globex");
+    for _ in 0..4 {
+        type_text(&mut app, "");
+    }
+    app.save().unwrap();
+    let ids: std::collections::HashSet<JobId> = storage.jobs().iter().map(|j| j.id).collect();
+    assert_eq!(ids.len(), 5);
+}
+
+#[test]
+fn short_ids_grow_until_they_tell_every_job_apart() {
+    use career_cli::models::short_id_len;
+    let job = |id: &str| {
+        let mut job = JobBuilder::new("Acme", "Engineer").build();
+        job.id = id.parse().unwrap();
+        job
+    };
+    let jobs = vec![
+        job("abcd1234-0000-4000-8000-000000000001"),
+        job("abcd9876-0000-4000-8000-000000000002"),
+        job("0123abcd-0000-4000-8000-000000000003"),
+    ];
+    assert_eq!(short_id_len(&jobs[1..]), 4, "never under four");
+    assert_eq!(short_id_len(&jobs), 5);
+    assert_eq!(jobs[1].id_prefix(5), "abcd9");
+    assert_eq!(career_cli::cli::resolve(&jobs, &jobs[1].id_prefix(short_id_len(&jobs))).unwrap(), 1);
+
+    // Past the first eight digits the prefix carries on after the hyphen
+    let twins = vec![job("abcd1234-0000-4000-8000-000000000001"), job("abcd1234-1000-4000-8000-000000000002")];
+    assert_eq!(short_id_len(&twins), 9);
+    assert_eq!(twins[1].id_prefix(9), "abcd1234-1");
+    assert_eq!(twins[1].id_prefix(8), "abcd1234");
+    for digits in [4, 8, 9, 13, 32] {
+        assert_eq!(career_cli::models::id_width(digits), twins[1].id_prefix(digits).len(), "the list makes room for the hyphens too");
+    }
+}
+
+#[test]
+fn notification_text_is_quoted_the_way_applescript_reads_it() {
+    use career_cli::cli::applescript_string;
+    assert_eq!(applescript_string(r#"Acme said "soon" \o/"#), r#""Acme said \"soon\" \\o/""#);
+    assert_eq!(applescript_string("Zürich\ttwo'"), "\"Zürich\ttwo'\"", "nothing else is escaped");
+}
@@ -0,0 +1,132 @@
+use career_cli::fixtures::{epoch, JobBuilder};
+use career_cli::models::{Job, JobId, Stage, Status};
+use career_cli::storage::{JobDirStorage, JsonFileStorage, Storage};
+use career_cli::ui::{column_widths, ROW_OVERHEAD};
+use chrono::Duration;
+use proptest::prelude::*;
+
+fn any_status() -> impl Strategy<Value = Status> {
+    prop_oneof![
+        Just(Status::Applied),
+        Just(Status::Interviewing),
+        Just(Status::Offer),
+        Just(Status::Accepted),
+        Just(Status::Rejected),
+        Just(Status::Ghosted),
+    ]
+}
+
+fn any_job() -> impl Strategy<Value = Job> {
+    (
+        ("\\PC{0,30}", "\\PC{0,30}", "\\PC{0,40}", any::<String>()),
+        (any_status(), 0i64..2000, proptest::option::of(0i64..400), proptest::option::of(1u32..24)),
+        proptest::collection::vec(("\\PC{1,20}", any::<bool>()), 0..6),
+        any::<u64>(),
+    )
+        .prop_map(|((company, role, link, notes), (status, applied, responded, cooldown), stages, revision)| {
+            let mut job = JobBuilder::new(&company, &role)
+                .link(&link)
+                .notes(&notes)
+                .status(status)
+                .applied_days_after_epoch(applied)
+                .revision(revision)
+                .build();
+            job.responded_at = responded.map(|days| job.date_applied + Duration::days(days));
+            job.cooldown_months = cooldown;
+            job.stages = stages.into_iter().map(|(name, done)| Stage { done, ..Stage::new(&name) }).collect();
+            job
+        })
+}
+
+/// Jobs with unique ids, like the ones that end up on disk
+fn any_jobs() -> impl Strategy<Value = Vec<Job>> {
+    proptest::collection::vec(any_job(), 0..12).prop_map(|mut jobs| {
+        for (i, job) in jobs.iter_mut().enumerate() {
+            job.id = JobId::legacy(i as u64 + 1);
+        }
+        jobs
+    })
+}
+
+// Job has no PartialEq, compare what actually gets written
+fn as_json(jobs: &[Job]) -> serde_json::Value {
+    serde_json::to_value(jobs).unwrap()
+}
+
+proptest! {
+    #[test]
+    fn columns_fit_the_row(width in 0u16..400) {
+        let (company, role, link, status) = column_widths(width);
+        prop_assert!(company + role + link + status + ROW_OVERHEAD <= (width as usize).max(ROW_OVERHEAD));
+    }
+
+    #[test]
+    fn columns_are_never_squeezed_to_nothing(width in 0u16..400) {
+        let content = (width as usize).saturating_sub(ROW_OVERHEAD);
+        let widths = column_widths(width);
+        let widths = [widths.0, widths.1, widths.2, widths.3];
+        if content >= 12 {
+            prop_assert!(widths.iter().all(|&w| w >= 3), "{:?} at width {}", widths, width);
+        } else if content >= 4 {
+            prop_assert!(widths.iter().all(|&w| w >= 1), "{:?} at width {}", widths, width);
+        }
+        // Nothing left unused either
+        if content > 0 && content < 42 {
+            prop_assert_eq!(widths.iter().sum::<usize>(), content);
+        }
+    }
+
+    #[test]
+    fn columns_grow_with_the_terminal(width in 0u16..399) {
+        let narrow = column_widths(width);
+        let wide = column_widths(width + 1);
+        prop_assert!(narrow.0 + narrow.1 + narrow.2 + narrow.3 <= wide.0 + wide.1 + wide.2 + wide.3);
+    }
+
+    #[test]
+    fn status_timestamps_only_move_forward(start in any_status(), cycles in 0usize..20) {
+        let mut job = JobBuilder::new("Acme", "Engineer").status(start).build();
+        let mut last = (job.responded_at, job.rejected_at);
+        for _ in 0..cycles {
+            job.cycle_status();
+            prop_assert!(job.responded_at >= last.0);
+            prop_assert!(job.rejected_at >= last.1);
+            last = (job.responded_at, job.rejected_at);
+
+            if let Some(responded) = job.responded_at {
+                prop_assert!(responded >= job.date_applied);
+            }
+            if let Some(rejected) = job.rejected_at {
+                prop_assert!(rejected >= job.date_applied);
+                prop_assert!(job.responded_at.is_some_and(|responded| responded <= rejected));
+            }
+        }
+        prop_assert!(job.date_applied == epoch());
+    }
+
+    #[test]
+    fn json_file_round_trip_is_lossless(jobs in any_jobs()) {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = JsonFileStorage::new(dir.path().join("jobs.json"));
+        storage.save(&jobs).unwrap();
+        let loaded = storage.load().unwrap();
+        prop_assert_eq!(as_json(&loaded), as_json(&jobs));
+
+        storage.save(&loaded).unwrap();
+        prop_assert_eq!(as_json(&storage.load().unwrap()), as_json(&jobs));
+    }
+
+    #[test]
+    fn job_dir_round_trip_is_lossless(jobs in any_jobs()) {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = JobDirStorage::new(dir.path().join("jobs"));
+        storage.save(&jobs).unwrap();
+        let loaded = storage.load().unwrap();
+        prop_assert_eq!(as_json(&loaded), as_json(&jobs));
+
+        // And back out through the other layout
+        let file = JsonFileStorage::new(dir.path().join("jobs.json"));
+        file.save(&loaded).unwrap();
+        prop_assert_eq!(as_json(&file.load().unwrap()), as_json(&jobs));
+    }
+}
@@ -0,0 +1,19 @@
+use career_cli::spell::Dictionary;
+
+fn dictionary() -> Dictionary {
+    Dictionary::from_dic("4\nrecruiter/SM\ncall/DSG\nteam/S\nabout\n")
+}
+
+#[test]
+fn flags_unknown_words_and_accepts_simple_inflections() {
+    let dictionary = dictionary();
+    let text = "Recruiter called about the teamz";
+    let typos: Vec<&str> = dictionary.misspelled(text).into_iter().map(|r| &text[r]).collect();
+    assert_eq!(typos, ["the", "teamz"]);
+}
+
+#[test]
+fn skips_acronyms_links_and_short_words() {
+    let dictionary = dictionary();
+    assert!(dictionary.misspelled("SRE at acme.com, k8s ok.").is_empty());
+}